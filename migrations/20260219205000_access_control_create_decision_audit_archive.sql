@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS access_authorization_decision_audit_archive (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    principal_id UUID NOT NULL,
+    request_id TEXT,
+    resource_name TEXT NOT NULL,
+    action_name TEXT NOT NULL,
+    allowed BOOLEAN NOT NULL,
+    reason TEXT NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_access_authorization_decision_audit_archive_tenant
+    ON access_authorization_decision_audit_archive (tenant_id);
@@ -0,0 +1,2 @@
+ALTER TABLE data_api_table_metadata
+    ADD COLUMN IF NOT EXISTS append_only BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+ALTER TABLE data_api_column_metadata
+    ADD COLUMN IF NOT EXISTS read_transform TEXT NOT NULL DEFAULT 'none';
@@ -0,0 +1,2 @@
+ALTER TABLE provisioned_databases
+    ADD COLUMN IF NOT EXISTS encrypted_password TEXT;
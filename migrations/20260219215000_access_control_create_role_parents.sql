@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS access_role_parents (
+    tenant_id UUID NOT NULL,
+    role_name TEXT NOT NULL,
+    parent_role_name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, role_name, parent_role_name)
+);
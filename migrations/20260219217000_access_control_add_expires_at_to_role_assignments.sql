@@ -0,0 +1,2 @@
+ALTER TABLE access_role_assignments
+    ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
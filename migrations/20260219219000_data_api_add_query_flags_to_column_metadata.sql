@@ -0,0 +1,3 @@
+ALTER TABLE data_api_column_metadata
+    ADD COLUMN IF NOT EXISTS filterable BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN IF NOT EXISTS orderable BOOLEAN NOT NULL DEFAULT TRUE;
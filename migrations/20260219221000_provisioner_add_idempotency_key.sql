@@ -0,0 +1,7 @@
+ALTER TABLE provisioned_databases
+    ADD COLUMN IF NOT EXISTS idempotency_key TEXT,
+    ADD COLUMN IF NOT EXISTS request_fingerprint TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS provisioned_databases_idempotency_key_idx
+    ON provisioned_databases (idempotency_key)
+    WHERE idempotency_key IS NOT NULL;
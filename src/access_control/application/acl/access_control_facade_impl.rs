@@ -6,20 +6,24 @@ use crate::access_control::{
     domain::model::{
         commands::{
             assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+            purge_tenant_access_control_command::PurgeTenantAccessControlCommand,
             upsert_policy_rule_command::{UpsertPolicyRuleCommand, UpsertPolicyRuleCommandParts},
         },
         enums::permission_effect::PermissionEffect,
     },
     domain::{
-        model::queries::evaluate_permission_query::{
-            EvaluatePermissionQuery, EvaluatePermissionQueryParts,
+        model::queries::{
+            evaluate_permission_query::{EvaluatePermissionQuery, EvaluatePermissionQueryParts},
+            list_effective_rules_query::ListEffectiveRulesQuery,
+            list_principal_roles_query::ListPrincipalRolesQuery,
         },
         services::access_control_command_service::AccessControlCommandService,
         services::access_control_query_service::AccessControlQueryService,
     },
     interfaces::acl::access_control_facade::{
-        AccessControlFacade, AccessControlPermissionDecision, AccessControlPermissionRequest,
-        DataApiAccessBootstrapRequest,
+        AccessControlActionColumns, AccessControlFacade, AccessControlPermissionDecision,
+        AccessControlPermissionRequest, DataApiAccessBootstrapRequest, EffectiveColumnsRequest,
+        PrincipalRolesRequest, TenantAccessPurgeRequest,
     },
 };
 
@@ -55,6 +59,8 @@ impl AccessControlFacade for AccessControlFacadeImpl {
             subject_owner_id: request.subject_owner_id,
             row_owner_id: request.row_owner_id,
             request_id: request.request_id,
+            bypass_cache: request.bypass_decision_cache,
+            audit_decision: request.audit_decision,
         })?;
 
         let result = self.query_service.handle_evaluate_permission(query).await?;
@@ -65,6 +71,54 @@ impl AccessControlFacade for AccessControlFacadeImpl {
         })
     }
 
+    async fn find_permitted_columns(
+        &self,
+        request: AccessControlPermissionRequest,
+    ) -> Result<Vec<String>, crate::access_control::domain::model::enums::access_control_domain_error::AccessControlDomainError>{
+        self.query_service
+            .handle_list_permitted_columns(EvaluatePermissionQuery::new(
+                EvaluatePermissionQueryParts {
+                    tenant_id: request.tenant_id,
+                    principal_id: request.principal_id,
+                    resource_name: request.resource_name,
+                    action_name: request.action_name,
+                    requested_columns: request.requested_columns,
+                    subject_owner_id: request.subject_owner_id,
+                    row_owner_id: request.row_owner_id,
+                    request_id: request.request_id,
+                    bypass_cache: request.bypass_decision_cache,
+                    audit_decision: request.audit_decision,
+                },
+            )?)
+            .await
+    }
+
+    async fn find_effective_columns(
+        &self,
+        request: EffectiveColumnsRequest,
+    ) -> Result<Vec<AccessControlActionColumns>, crate::access_control::domain::model::enums::access_control_domain_error::AccessControlDomainError>{
+        let effective = self
+            .query_service
+            .handle_list_effective_rules(
+                ListEffectiveRulesQuery::new(
+                    request.tenant_id,
+                    request.principal_id,
+                    request.resource_name,
+                )?
+                .with_requested_columns(request.requested_columns)
+                .with_owner_ids(request.subject_owner_id, request.row_owner_id),
+            )
+            .await?;
+
+        Ok(effective
+            .into_iter()
+            .map(|action| AccessControlActionColumns {
+                action_name: action.action_name,
+                permitted_columns: action.permitted_columns,
+            })
+            .collect())
+    }
+
     async fn bootstrap_data_api_access(
         &self,
         request: DataApiAccessBootstrapRequest,
@@ -76,6 +130,7 @@ impl AccessControlFacade for AccessControlFacadeImpl {
                 request.tenant_id.clone(),
                 request.principal_id,
                 role_name.clone(),
+                None,
             )?)
             .await?;
 
@@ -96,7 +151,7 @@ impl AccessControlFacade for AccessControlFacadeImpl {
             .collect::<Vec<_>>();
 
         self.command_service
-            .handle_upsert_policy(UpsertPolicyRuleCommand::new(
+            .handle_create_policy_if_missing(UpsertPolicyRuleCommand::new(
                 UpsertPolicyRuleCommandParts {
                     tenant_id: request.tenant_id.clone(),
                     role_name: role_name.clone(),
@@ -115,7 +170,7 @@ impl AccessControlFacade for AccessControlFacadeImpl {
             .await?;
 
         self.command_service
-            .handle_upsert_policy(UpsertPolicyRuleCommand::new(
+            .handle_create_policy_if_missing(UpsertPolicyRuleCommand::new(
                 UpsertPolicyRuleCommandParts {
                     tenant_id: request.tenant_id.clone(),
                     role_name: role_name.clone(),
@@ -134,7 +189,7 @@ impl AccessControlFacade for AccessControlFacadeImpl {
             .await?;
 
         self.command_service
-            .handle_upsert_policy(UpsertPolicyRuleCommand::new(
+            .handle_create_policy_if_missing(UpsertPolicyRuleCommand::new(
                 UpsertPolicyRuleCommandParts {
                     tenant_id: request.tenant_id.clone(),
                     role_name: role_name.clone(),
@@ -153,7 +208,7 @@ impl AccessControlFacade for AccessControlFacadeImpl {
             .await?;
 
         self.command_service
-            .handle_upsert_policy(UpsertPolicyRuleCommand::new(
+            .handle_create_policy_if_missing(UpsertPolicyRuleCommand::new(
                 UpsertPolicyRuleCommandParts {
                     tenant_id: request.tenant_id,
                     role_name,
@@ -165,6 +220,32 @@ impl AccessControlFacade for AccessControlFacadeImpl {
                     owner_scope: false,
                 },
             )?)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_principal_roles(
+        &self,
+        request: PrincipalRolesRequest,
+    ) -> Result<Vec<String>, crate::access_control::domain::model::enums::access_control_domain_error::AccessControlDomainError>{
+        self.query_service
+            .handle_list_principal_roles(ListPrincipalRolesQuery::new(
+                request.tenant_id,
+                request.principal_id,
+            )?)
+            .await
+    }
+
+    async fn purge_tenant_access(
+        &self,
+        request: TenantAccessPurgeRequest,
+    ) -> Result<(), crate::access_control::domain::model::enums::access_control_domain_error::AccessControlDomainError>{
+        self.command_service
+            .handle_purge_tenant(PurgeTenantAccessControlCommand::new(
+                request.tenant_id,
+                request.archive_audit_trail,
+            )?)
             .await
     }
 }
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::access_control::domain::model::value_objects::tenant_id::TenantId;
+
+#[derive(Debug, Default)]
+pub struct DecisionCacheGenerations {
+    generations: RwLock<HashMap<Uuid, u64>>,
+}
+
+impl DecisionCacheGenerations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn current(&self, tenant_id: &TenantId) -> u64 {
+        self.generations
+            .read()
+            .await
+            .get(&tenant_id.value())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub async fn invalidate_tenant(&self, tenant_id: &TenantId) {
+        *self
+            .generations
+            .write()
+            .await
+            .entry(tenant_id.value())
+            .or_insert(0) += 1;
+    }
+}
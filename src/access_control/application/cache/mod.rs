@@ -0,0 +1 @@
+pub mod decision_cache_generations;
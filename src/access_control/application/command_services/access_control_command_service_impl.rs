@@ -3,10 +3,13 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::access_control::{
+    application::cache::decision_cache_generations::DecisionCacheGenerations,
     domain::{
         model::{
             commands::{
                 assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+                purge_tenant_access_control_command::PurgeTenantAccessControlCommand,
+                revoke_role_from_principal_command::RevokeRoleFromPrincipalCommand,
                 upsert_policy_rule_command::UpsertPolicyRuleCommand,
             },
             enums::access_control_domain_error::AccessControlDomainError,
@@ -14,6 +17,7 @@ use crate::access_control::{
         services::access_control_command_service::AccessControlCommandService,
     },
     infrastructure::persistence::repositories::{
+        authorization_decision_audit_repository::AuthorizationDecisionAuditRepository,
         policy_rule_repository::{PolicyRuleRecord, PolicyRuleRepository},
         role_assignment_repository::RoleAssignmentRepository,
     },
@@ -22,16 +26,42 @@ use crate::access_control::{
 pub struct AccessControlCommandServiceImpl {
     role_assignment_repository: Arc<dyn RoleAssignmentRepository>,
     policy_rule_repository: Arc<dyn PolicyRuleRepository>,
+    audit_repository: Arc<dyn AuthorizationDecisionAuditRepository>,
+    cache_generations: Arc<DecisionCacheGenerations>,
 }
 
 impl AccessControlCommandServiceImpl {
     pub fn new(
         role_assignment_repository: Arc<dyn RoleAssignmentRepository>,
         policy_rule_repository: Arc<dyn PolicyRuleRepository>,
+        audit_repository: Arc<dyn AuthorizationDecisionAuditRepository>,
     ) -> Self {
         Self {
             role_assignment_repository,
             policy_rule_repository,
+            audit_repository,
+            cache_generations: Arc::new(DecisionCacheGenerations::new()),
+        }
+    }
+
+    pub fn with_cache_generations(
+        mut self,
+        cache_generations: Arc<DecisionCacheGenerations>,
+    ) -> Self {
+        self.cache_generations = cache_generations;
+        self
+    }
+
+    fn policy_rule_record(command: &UpsertPolicyRuleCommand) -> PolicyRuleRecord {
+        PolicyRuleRecord {
+            tenant_id: command.tenant_id().value().to_string(),
+            role_name: command.role_name().value().to_string(),
+            resource_name: command.resource_name().value().to_string(),
+            action_name: command.action_name().value().to_string(),
+            effect: command.effect(),
+            allowed_columns: command.allowed_columns().map(|c| c.to_vec()),
+            denied_columns: command.denied_columns().map(|c| c.to_vec()),
+            owner_scope: command.owner_scope(),
         }
     }
 }
@@ -42,13 +72,38 @@ impl AccessControlCommandService for AccessControlCommandServiceImpl {
         &self,
         command: AssignRoleToPrincipalCommand,
     ) -> Result<(), AccessControlDomainError> {
-        self.role_assignment_repository
+        let changed = self
+            .role_assignment_repository
             .assign_role(
                 command.tenant_id(),
                 command.principal_id(),
                 command.role_name(),
+                command.expires_at(),
             )
-            .await
+            .await?;
+        if changed {
+            self.cache_generations
+                .invalidate_tenant(command.tenant_id())
+                .await;
+        }
+        Ok(())
+    }
+
+    async fn handle_revoke_role(
+        &self,
+        command: RevokeRoleFromPrincipalCommand,
+    ) -> Result<(), AccessControlDomainError> {
+        self.role_assignment_repository
+            .revoke_role(
+                command.tenant_id(),
+                command.principal_id(),
+                command.role_name(),
+            )
+            .await?;
+        self.cache_generations
+            .invalidate_tenant(command.tenant_id())
+            .await;
+        Ok(())
     }
 
     async fn handle_upsert_policy(
@@ -56,16 +111,50 @@ impl AccessControlCommandService for AccessControlCommandServiceImpl {
         command: UpsertPolicyRuleCommand,
     ) -> Result<(), AccessControlDomainError> {
         self.policy_rule_repository
-            .upsert_rule(PolicyRuleRecord {
-                tenant_id: command.tenant_id().value().to_string(),
-                role_name: command.role_name().value().to_string(),
-                resource_name: command.resource_name().value().to_string(),
-                action_name: command.action_name().value().to_string(),
-                effect: command.effect(),
-                allowed_columns: command.allowed_columns().map(|c| c.to_vec()),
-                denied_columns: command.denied_columns().map(|c| c.to_vec()),
-                owner_scope: command.owner_scope(),
-            })
-            .await
+            .upsert_rule(Self::policy_rule_record(&command))
+            .await?;
+        self.cache_generations
+            .invalidate_tenant(command.tenant_id())
+            .await;
+        Ok(())
+    }
+
+    async fn handle_create_policy_if_missing(
+        &self,
+        command: UpsertPolicyRuleCommand,
+    ) -> Result<bool, AccessControlDomainError> {
+        let inserted = self
+            .policy_rule_repository
+            .insert_rule_if_absent(Self::policy_rule_record(&command))
+            .await?;
+        if inserted {
+            self.cache_generations
+                .invalidate_tenant(command.tenant_id())
+                .await;
+        }
+        Ok(inserted)
+    }
+
+    async fn handle_purge_tenant(
+        &self,
+        command: PurgeTenantAccessControlCommand,
+    ) -> Result<(), AccessControlDomainError> {
+        self.policy_rule_repository
+            .delete_tenant_rules(command.tenant_id())
+            .await?;
+        self.role_assignment_repository
+            .delete_tenant_assignments(command.tenant_id())
+            .await?;
+
+        if command.archive_audit_trail() {
+            self.audit_repository
+                .archive_tenant_decisions(command.tenant_id())
+                .await?;
+        }
+
+        self.cache_generations
+            .invalidate_tenant(command.tenant_id())
+            .await;
+        Ok(())
     }
 }
@@ -1,3 +1,4 @@
 pub mod acl;
+pub mod cache;
 pub mod command_services;
 pub mod query_services;
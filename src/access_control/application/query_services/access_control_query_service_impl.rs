@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     hash::{Hash, Hasher},
     sync::Arc,
     time::{Duration, Instant},
@@ -7,9 +7,10 @@ use std::{
 
 use async_trait::async_trait;
 use chrono::Utc;
-use tokio::sync::RwLock;
+use tokio::sync::Mutex;
 
 use crate::access_control::{
+    application::cache::decision_cache_generations::DecisionCacheGenerations,
     domain::{
         model::{
             enums::{
@@ -17,10 +18,18 @@ use crate::access_control::{
                 permission_effect::PermissionEffect,
             },
             events::authorization_decision_audited_event::AuthorizationDecisionAuditedEvent,
-            queries::evaluate_permission_query::EvaluatePermissionQuery,
+            queries::{
+                evaluate_permission_query::{
+                    EvaluatePermissionQuery, EvaluatePermissionQueryParts,
+                },
+                list_effective_rules_query::ListEffectiveRulesQuery,
+                list_principal_roles_query::ListPrincipalRolesQuery,
+            },
+            value_objects::{action_name::ActionName, tenant_id::TenantId},
         },
         services::access_control_query_service::{
-            AccessControlQueryService, AuthorizationDecisionResult,
+            AccessControlQueryService, AuthorizationDecisionResult, EffectiveActionRules,
+            EffectivePolicyRule,
         },
     },
     infrastructure::persistence::repositories::{
@@ -30,6 +39,9 @@ use crate::access_control::{
     },
 };
 
+const DEFAULT_DECISION_CACHE_MAX_ENTRIES: usize = 10_000;
+const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
 #[derive(Clone, Debug, Eq)]
 struct DecisionCacheKey {
     tenant_id: String,
@@ -39,6 +51,7 @@ struct DecisionCacheKey {
     requested_columns: Vec<String>,
     subject_owner_id: Option<String>,
     row_owner_id: Option<String>,
+    generation: u64,
 }
 
 impl PartialEq for DecisionCacheKey {
@@ -50,6 +63,7 @@ impl PartialEq for DecisionCacheKey {
             && self.requested_columns == other.requested_columns
             && self.subject_owner_id == other.subject_owner_id
             && self.row_owner_id == other.row_owner_id
+            && self.generation == other.generation
     }
 }
 
@@ -62,6 +76,7 @@ impl Hash for DecisionCacheKey {
         self.requested_columns.hash(state);
         self.subject_owner_id.hash(state);
         self.row_owner_id.hash(state);
+        self.generation.hash(state);
     }
 }
 
@@ -69,6 +84,83 @@ impl Hash for DecisionCacheKey {
 struct DecisionCacheEntry {
     decision: AuthorizationDecisionResult,
     expires_at: Instant,
+    last_used: u64,
+}
+
+struct DecisionCache {
+    entries: HashMap<DecisionCacheKey, DecisionCacheEntry>,
+    recency: BTreeMap<u64, DecisionCacheKey>,
+    next_tick: u64,
+    max_entries: usize,
+}
+
+impl DecisionCache {
+    fn new(max_entries: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            next_tick: 0,
+            max_entries,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        tick
+    }
+
+    fn get(&mut self, key: &DecisionCacheKey, now: Instant) -> Option<AuthorizationDecisionResult> {
+        let (expires_at, last_used) = self
+            .entries
+            .get(key)
+            .map(|entry| (entry.expires_at, entry.last_used))?;
+        self.recency.remove(&last_used);
+
+        if expires_at <= now {
+            self.entries.remove(key);
+            return None;
+        }
+
+        let tick = self.next_tick();
+        self.recency.insert(tick, key.clone());
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = tick;
+        Some(entry.decision.clone())
+    }
+
+    fn insert(
+        &mut self,
+        key: DecisionCacheKey,
+        decision: AuthorizationDecisionResult,
+        expires_at: Instant,
+    ) {
+        if self.max_entries == 0 {
+            return;
+        }
+
+        if let Some(previous) = self.entries.remove(&key) {
+            self.recency.remove(&previous.last_used);
+        }
+
+        while self.entries.len() >= self.max_entries {
+            let Some((_, oldest_key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest_key);
+        }
+
+        let tick = self.next_tick();
+        self.recency.insert(tick, key.clone());
+        self.entries.insert(
+            key,
+            DecisionCacheEntry {
+                decision,
+                expires_at,
+                last_used: tick,
+            },
+        );
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -79,12 +171,32 @@ struct RuleSpecificity {
     owner_specificity: u8,
 }
 
+impl RuleSpecificity {
+    fn into_effective_rule(self, rule: PolicyRuleRecord) -> EffectivePolicyRule {
+        EffectivePolicyRule {
+            role_name: rule.role_name,
+            resource_name: rule.resource_name,
+            action_name: rule.action_name,
+            effect: rule.effect,
+            allowed_columns: rule.allowed_columns,
+            denied_columns: rule.denied_columns,
+            owner_scope: rule.owner_scope,
+            resource_specificity: self.resource_specificity,
+            action_specificity: self.action_specificity,
+            column_specificity: self.column_specificity,
+            owner_specificity: self.owner_specificity,
+        }
+    }
+}
+
 pub struct AccessControlQueryServiceImpl {
     policy_rule_repository: Arc<dyn PolicyRuleRepository>,
     role_assignment_repository: Arc<dyn RoleAssignmentRepository>,
     decision_audit_repository: Arc<dyn AuthorizationDecisionAuditRepository>,
-    decision_cache: RwLock<HashMap<DecisionCacheKey, DecisionCacheEntry>>,
+    decision_cache: Mutex<DecisionCache>,
     cache_ttl: Duration,
+    cache_generations: Arc<DecisionCacheGenerations>,
+    max_batch_size: usize,
 }
 
 impl AccessControlQueryServiceImpl {
@@ -106,17 +218,48 @@ impl AccessControlQueryServiceImpl {
         role_assignment_repository: Arc<dyn RoleAssignmentRepository>,
         decision_audit_repository: Arc<dyn AuthorizationDecisionAuditRepository>,
         cache_ttl: Duration,
+    ) -> Self {
+        Self::new_with_cache_config(
+            policy_rule_repository,
+            role_assignment_repository,
+            decision_audit_repository,
+            cache_ttl,
+            DEFAULT_DECISION_CACHE_MAX_ENTRIES,
+        )
+    }
+
+    pub fn new_with_cache_config(
+        policy_rule_repository: Arc<dyn PolicyRuleRepository>,
+        role_assignment_repository: Arc<dyn RoleAssignmentRepository>,
+        decision_audit_repository: Arc<dyn AuthorizationDecisionAuditRepository>,
+        cache_ttl: Duration,
+        max_entries: usize,
     ) -> Self {
         Self {
             policy_rule_repository,
             role_assignment_repository,
             decision_audit_repository,
-            decision_cache: RwLock::new(HashMap::new()),
+            decision_cache: Mutex::new(DecisionCache::new(max_entries)),
             cache_ttl,
+            cache_generations: Arc::new(DecisionCacheGenerations::new()),
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
         }
     }
 
-    fn build_cache_key(query: &EvaluatePermissionQuery) -> DecisionCacheKey {
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    pub fn with_cache_generations(
+        mut self,
+        cache_generations: Arc<DecisionCacheGenerations>,
+    ) -> Self {
+        self.cache_generations = cache_generations;
+        self
+    }
+
+    async fn build_cache_key(&self, query: &EvaluatePermissionQuery) -> DecisionCacheKey {
         let mut requested_columns = query.requested_columns().to_vec();
         requested_columns.sort();
 
@@ -128,6 +271,7 @@ impl AccessControlQueryServiceImpl {
             requested_columns,
             subject_owner_id: query.subject_owner_id().map(str::to_string),
             row_owner_id: query.row_owner_id().map(str::to_string),
+            generation: self.cache_generations.current(query.tenant_id()).await,
         }
     }
 
@@ -135,25 +279,38 @@ impl AccessControlQueryServiceImpl {
         &self,
         key: &DecisionCacheKey,
     ) -> Option<AuthorizationDecisionResult> {
-        let read_guard = self.decision_cache.read().await;
-        read_guard.get(key).and_then(|entry| {
-            if entry.expires_at > Instant::now() {
-                Some(entry.decision.clone())
-            } else {
-                None
-            }
-        })
+        self.decision_cache.lock().await.get(key, Instant::now())
     }
 
     async fn cache_decision(&self, key: DecisionCacheKey, decision: AuthorizationDecisionResult) {
-        let mut write_guard = self.decision_cache.write().await;
-        write_guard.insert(
-            key,
-            DecisionCacheEntry {
-                decision,
-                expires_at: Instant::now() + self.cache_ttl,
-            },
-        );
+        self.decision_cache
+            .lock()
+            .await
+            .insert(key, decision, Instant::now() + self.cache_ttl);
+    }
+
+    async fn expand_inherited_roles(
+        &self,
+        tenant_id: &TenantId,
+        roles: Vec<String>,
+    ) -> Result<Vec<String>, AccessControlDomainError> {
+        let mut visited = roles.iter().cloned().collect::<HashSet<_>>();
+        let mut expanded = roles.clone();
+        let mut frontier = roles;
+
+        while !frontier.is_empty() {
+            let parents = self
+                .role_assignment_repository
+                .find_parent_roles(tenant_id, &frontier)
+                .await?;
+            frontier = parents
+                .into_iter()
+                .filter(|parent| visited.insert(parent.clone()))
+                .collect();
+            expanded.extend(frontier.iter().cloned());
+        }
+
+        Ok(expanded)
     }
 
     fn rule_matches_columns(
@@ -197,19 +354,14 @@ impl AccessControlQueryServiceImpl {
 
     fn rule_specificity(
         rule: &PolicyRuleRecord,
-        query: &EvaluatePermissionQuery,
+        resource_name: &str,
+        action_name: &str,
     ) -> RuleSpecificity {
         RuleSpecificity {
-            resource_specificity: if rule.resource_name == query.resource_name().value() {
-                2
-            } else {
-                1
-            },
-            action_specificity: if rule.action_name == query.action_name().value() {
-                2
-            } else {
-                1
-            },
+            resource_specificity: Self::pattern_specificity(&rule.resource_name, resource_name)
+                .unwrap_or(0),
+            action_specificity: Self::pattern_specificity(&rule.action_name, action_name)
+                .unwrap_or(0),
             column_specificity: if rule.allowed_columns.is_some() || rule.denied_columns.is_some() {
                 1
             } else {
@@ -219,12 +371,76 @@ impl AccessControlQueryServiceImpl {
         }
     }
 
+    fn decision(
+        allowed: bool,
+        reason: &str,
+        matched_rules: usize,
+        applicable_rules: usize,
+    ) -> AuthorizationDecisionResult {
+        AuthorizationDecisionResult {
+            allowed,
+            reason: reason.to_string(),
+            client_reason: Self::client_reason(allowed, reason).to_string(),
+            matched_rules,
+            applicable_rules,
+            matched_rule: None,
+        }
+    }
+
+    fn decision_with_rule(
+        allowed: bool,
+        reason: &str,
+        matched_rules: usize,
+        applicable_rules: usize,
+        (specificity, rule): (RuleSpecificity, &PolicyRuleRecord),
+    ) -> AuthorizationDecisionResult {
+        AuthorizationDecisionResult {
+            matched_rule: Some(specificity.into_effective_rule(rule.clone())),
+            ..Self::decision(allowed, reason, matched_rules, applicable_rules)
+        }
+    }
+
+    fn client_reason(allowed: bool, reason: &str) -> &'static str {
+        if allowed {
+            return "access granted";
+        }
+
+        match reason {
+            "explicit deny rule" | "deny rule won by precedence" => "access denied by policy",
+            _ => "no permission granted for this operation",
+        }
+    }
+
+    fn pattern_specificity(pattern: &str, value: &str) -> Option<u8> {
+        if pattern == value {
+            return Some(3);
+        }
+        if pattern == "*" {
+            return Some(1);
+        }
+
+        pattern
+            .strip_suffix('*')
+            .filter(|prefix| value.starts_with(prefix))
+            .map(|_| 2)
+    }
+
     fn evaluate_rules(
         query: &EvaluatePermissionQuery,
         rules: &[PolicyRuleRecord],
     ) -> AuthorizationDecisionResult {
-        let applicable = rules
+        let matching = rules
             .iter()
+            .filter(|rule| {
+                Self::pattern_specificity(&rule.resource_name, query.resource_name().value())
+                    .is_some()
+                    && Self::pattern_specificity(&rule.action_name, query.action_name().value())
+                        .is_some()
+            })
+            .collect::<Vec<_>>();
+        let applicable = matching
+            .iter()
+            .copied()
             .filter(|rule| {
                 Self::rule_matches_columns(
                     query.requested_columns(),
@@ -240,59 +456,84 @@ impl AccessControlQueryServiceImpl {
                 )
             })
             .collect::<Vec<_>>();
+        let matched_rules = matching.len();
+        let applicable_rules = applicable.len();
 
         if applicable.is_empty() {
-            return AuthorizationDecisionResult {
-                allowed: false,
-                reason: "no rule matched context/columns".to_string(),
-            };
+            return Self::decision(
+                false,
+                "no rule matched context/columns",
+                matched_rules,
+                applicable_rules,
+            );
         }
 
-        let mut best_allow: Option<RuleSpecificity> = None;
-        let mut best_deny: Option<RuleSpecificity> = None;
-
-        for rule in &applicable {
-            let specificity = Self::rule_specificity(rule, query);
-            match rule.effect {
-                PermissionEffect::Allow => {
-                    best_allow = Some(best_allow.map_or(specificity, |s| s.max(specificity)));
-                }
-                PermissionEffect::Deny => {
-                    best_deny = Some(best_deny.map_or(specificity, |s| s.max(specificity)));
-                }
+        let mut best_allow: Option<(RuleSpecificity, &PolicyRuleRecord)> = None;
+        let mut best_deny: Option<(RuleSpecificity, &PolicyRuleRecord)> = None;
+
+        for rule in applicable {
+            let specificity = Self::rule_specificity(
+                rule,
+                query.resource_name().value(),
+                query.action_name().value(),
+            );
+            let best = match rule.effect {
+                PermissionEffect::Allow => &mut best_allow,
+                PermissionEffect::Deny => &mut best_deny,
+            };
+            if best.is_none_or(|(current, _)| specificity > current) {
+                *best = Some((specificity, rule));
             }
         }
 
         match (best_allow, best_deny) {
-            (None, Some(_)) => AuthorizationDecisionResult {
-                allowed: false,
-                reason: "explicit deny rule".to_string(),
-            },
-            (Some(_), None) => AuthorizationDecisionResult {
-                allowed: true,
-                reason: "allow rule matched".to_string(),
-            },
-            (Some(allow_spec), Some(deny_spec)) => {
-                if deny_spec >= allow_spec {
-                    AuthorizationDecisionResult {
-                        allowed: false,
-                        reason: "deny rule won by precedence".to_string(),
-                    }
+            (None, Some(deny)) => Self::decision_with_rule(
+                false,
+                "explicit deny rule",
+                matched_rules,
+                applicable_rules,
+                deny,
+            ),
+            (Some(allow), None) => Self::decision_with_rule(
+                true,
+                "allow rule matched",
+                matched_rules,
+                applicable_rules,
+                allow,
+            ),
+            (Some(allow), Some(deny)) => {
+                if deny.0 >= allow.0 {
+                    Self::decision_with_rule(
+                        false,
+                        "deny rule won by precedence",
+                        matched_rules,
+                        applicable_rules,
+                        deny,
+                    )
                 } else {
-                    AuthorizationDecisionResult {
-                        allowed: true,
-                        reason: "allow rule won by specificity".to_string(),
-                    }
+                    Self::decision_with_rule(
+                        true,
+                        "allow rule won by specificity",
+                        matched_rules,
+                        applicable_rules,
+                        allow,
+                    )
                 }
             }
-            (None, None) => AuthorizationDecisionResult {
-                allowed: false,
-                reason: "no matching policy rule".to_string(),
-            },
+            (None, None) => Self::decision(
+                false,
+                "no matching policy rule",
+                matched_rules,
+                applicable_rules,
+            ),
         }
     }
 
     async fn audit(&self, query: &EvaluatePermissionQuery, decision: &AuthorizationDecisionResult) {
+        if !query.audit_decision() {
+            return;
+        }
+
         let _ = self
             .decision_audit_repository
             .save_decision(&AuthorizationDecisionAuditedEvent {
@@ -315,13 +556,16 @@ impl AccessControlQueryService for AccessControlQueryServiceImpl {
         &self,
         query: EvaluatePermissionQuery,
     ) -> Result<AuthorizationDecisionResult, AccessControlDomainError> {
-        let cache_key = Self::build_cache_key(&query);
-        if let Some(cached) = self.load_cached_decision(&cache_key).await {
+        let cache_key = self.build_cache_key(&query).await;
+        if !query.bypass_cache()
+            && let Some(cached) = self.load_cached_decision(&cache_key).await
+        {
             self.audit(
                 &query,
                 &AuthorizationDecisionResult {
                     allowed: cached.allowed,
                     reason: format!("cached: {}", cached.reason),
+                    ..cached.clone()
                 },
             )
             .await;
@@ -334,15 +578,16 @@ impl AccessControlQueryService for AccessControlQueryServiceImpl {
             .await?;
 
         if roles.is_empty() {
-            let decision = AuthorizationDecisionResult {
-                allowed: false,
-                reason: "no roles assigned".to_string(),
-            };
+            let decision = Self::decision(false, "no roles assigned", 0, 0);
             self.audit(&query, &decision).await;
             self.cache_decision(cache_key, decision.clone()).await;
             return Ok(decision);
         }
 
+        let roles = self
+            .expand_inherited_roles(query.tenant_id(), roles)
+            .await?;
+
         let rules = self
             .policy_rule_repository
             .find_rules_for_roles(
@@ -354,10 +599,7 @@ impl AccessControlQueryService for AccessControlQueryServiceImpl {
             .await?;
 
         if rules.is_empty() {
-            let decision = AuthorizationDecisionResult {
-                allowed: false,
-                reason: "no matching policy rule".to_string(),
-            };
+            let decision = Self::decision(false, "no matching policy rule", 0, 0);
             self.audit(&query, &decision).await;
             self.cache_decision(cache_key, decision.clone()).await;
             return Ok(decision);
@@ -368,4 +610,176 @@ impl AccessControlQueryService for AccessControlQueryServiceImpl {
         self.cache_decision(cache_key, decision.clone()).await;
         Ok(decision)
     }
+
+    async fn handle_evaluate_permission_batch(
+        &self,
+        queries: Vec<EvaluatePermissionQuery>,
+    ) -> Result<Vec<AuthorizationDecisionResult>, AccessControlDomainError> {
+        if queries.len() > self.max_batch_size {
+            return Err(AccessControlDomainError::BatchTooLarge(self.max_batch_size));
+        }
+
+        let mut decided = HashMap::<DecisionCacheKey, AuthorizationDecisionResult>::new();
+        let mut decisions = Vec::with_capacity(queries.len());
+
+        for query in queries {
+            let cache_key = self.build_cache_key(&query).await;
+            if let Some(decision) = decided.get(&cache_key) {
+                self.audit(
+                    &query,
+                    &AuthorizationDecisionResult {
+                        reason: format!("batched: {}", decision.reason),
+                        ..decision.clone()
+                    },
+                )
+                .await;
+                decisions.push(decision.clone());
+                continue;
+            }
+
+            let decision = self.handle_evaluate_permission(query).await?;
+            decided.insert(cache_key, decision.clone());
+            decisions.push(decision);
+        }
+
+        Ok(decisions)
+    }
+
+    async fn handle_list_permitted_columns(
+        &self,
+        query: EvaluatePermissionQuery,
+    ) -> Result<Vec<String>, AccessControlDomainError> {
+        let roles = self
+            .role_assignment_repository
+            .find_roles_by_principal(query.tenant_id(), query.principal_id())
+            .await?;
+        if roles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let roles = self
+            .expand_inherited_roles(query.tenant_id(), roles)
+            .await?;
+        let rules = self
+            .policy_rule_repository
+            .find_rules_for_roles(
+                query.tenant_id(),
+                query.resource_name(),
+                query.action_name(),
+                &roles,
+            )
+            .await?;
+
+        Ok(query
+            .requested_columns()
+            .iter()
+            .filter(|column| {
+                Self::evaluate_rules(
+                    &query
+                        .clone()
+                        .with_requested_columns(vec![(*column).clone()]),
+                    &rules,
+                )
+                .allowed
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn handle_list_principal_roles(
+        &self,
+        query: ListPrincipalRolesQuery,
+    ) -> Result<Vec<String>, AccessControlDomainError> {
+        self.role_assignment_repository
+            .find_roles_by_principal(query.tenant_id(), query.principal_id())
+            .await
+    }
+
+    async fn handle_list_effective_rules(
+        &self,
+        query: ListEffectiveRulesQuery,
+    ) -> Result<Vec<EffectiveActionRules>, AccessControlDomainError> {
+        let roles = self
+            .role_assignment_repository
+            .find_roles_by_principal(query.tenant_id(), query.principal_id())
+            .await?;
+        if roles.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let roles = self
+            .expand_inherited_roles(query.tenant_id(), roles)
+            .await?;
+        let rules = self
+            .policy_rule_repository
+            .find_resource_rules_for_roles(query.tenant_id(), query.resource_name(), &roles)
+            .await?;
+        let resource_name = query.resource_name().value();
+        let mut effective = Vec::new();
+
+        for action in ActionName::ACTIONS {
+            let mut scored = rules
+                .iter()
+                .filter(|rule| {
+                    Self::pattern_specificity(&rule.resource_name, resource_name).is_some()
+                        && Self::pattern_specificity(&rule.action_name, action).is_some()
+                })
+                .map(|rule| {
+                    (
+                        Self::rule_specificity(rule, resource_name, action),
+                        rule.clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if scored.is_empty() {
+                continue;
+            }
+            scored.sort_by(|(left, _), (right, _)| right.cmp(left));
+
+            let action_query = EvaluatePermissionQuery::new(EvaluatePermissionQueryParts {
+                tenant_id: query.tenant_id().value().to_string(),
+                principal_id: query.principal_id().value().to_string(),
+                resource_name: resource_name.to_string(),
+                action_name: action.to_string(),
+                requested_columns: query.requested_columns().to_vec(),
+                subject_owner_id: query.subject_owner_id().map(str::to_string),
+                row_owner_id: query.row_owner_id().map(str::to_string),
+                request_id: None,
+                bypass_cache: true,
+                audit_decision: false,
+            })?;
+            let decision = Self::evaluate_rules(&action_query, &rules);
+            let permitted_columns = query
+                .requested_columns()
+                .iter()
+                .filter(|column| {
+                    Self::evaluate_rules(
+                        &action_query
+                            .clone()
+                            .with_requested_columns(vec![(*column).clone()]),
+                        &rules,
+                    )
+                    .allowed
+                })
+                .cloned()
+                .collect();
+
+            effective.push(EffectiveActionRules {
+                action_name: action.to_string(),
+                winning_effect: if decision.allowed {
+                    PermissionEffect::Allow
+                } else {
+                    PermissionEffect::Deny
+                },
+                rules: scored
+                    .into_iter()
+                    .map(|(specificity, rule)| specificity.into_effective_rule(rule))
+                    .collect(),
+
+                permitted_columns,
+            });
+        }
+
+        Ok(effective)
+    }
 }
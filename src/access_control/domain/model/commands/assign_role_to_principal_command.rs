@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::access_control::domain::model::{
     enums::access_control_domain_error::AccessControlDomainError,
     value_objects::{principal_id::PrincipalId, role_name::RoleName, tenant_id::TenantId},
@@ -8,6 +10,7 @@ pub struct AssignRoleToPrincipalCommand {
     tenant_id: TenantId,
     principal_id: PrincipalId,
     role_name: RoleName,
+    expires_at: Option<DateTime<Utc>>,
 }
 
 impl AssignRoleToPrincipalCommand {
@@ -15,11 +18,21 @@ impl AssignRoleToPrincipalCommand {
         tenant_id: String,
         principal_id: String,
         role_name: String,
+        expires_at: Option<String>,
     ) -> Result<Self, AccessControlDomainError> {
+        let expires_at = expires_at
+            .map(|value| {
+                DateTime::parse_from_rfc3339(value.trim())
+                    .map(|parsed| parsed.with_timezone(&Utc))
+                    .map_err(|_| AccessControlDomainError::InvalidExpiresAt)
+            })
+            .transpose()?;
+
         Ok(Self {
             tenant_id: TenantId::new(tenant_id)?,
             principal_id: PrincipalId::new(principal_id)?,
             role_name: RoleName::new(role_name)?,
+            expires_at,
         })
     }
 
@@ -32,4 +45,8 @@ impl AssignRoleToPrincipalCommand {
     pub fn role_name(&self) -> &RoleName {
         &self.role_name
     }
+
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
 }
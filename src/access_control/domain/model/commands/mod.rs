@@ -1,2 +1,4 @@
 pub mod assign_role_to_principal_command;
+pub mod purge_tenant_access_control_command;
+pub mod revoke_role_from_principal_command;
 pub mod upsert_policy_rule_command;
@@ -0,0 +1,29 @@
+use crate::access_control::domain::model::{
+    enums::access_control_domain_error::AccessControlDomainError,
+    value_objects::tenant_id::TenantId,
+};
+
+#[derive(Clone, Debug)]
+pub struct PurgeTenantAccessControlCommand {
+    tenant_id: TenantId,
+    archive_audit_trail: bool,
+}
+
+impl PurgeTenantAccessControlCommand {
+    pub fn new(
+        tenant_id: String,
+        archive_audit_trail: bool,
+    ) -> Result<Self, AccessControlDomainError> {
+        Ok(Self {
+            tenant_id: TenantId::new(tenant_id)?,
+            archive_audit_trail,
+        })
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+    pub fn archive_audit_trail(&self) -> bool {
+        self.archive_audit_trail
+    }
+}
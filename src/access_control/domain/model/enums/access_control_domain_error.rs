@@ -17,12 +17,20 @@ pub enum AccessControlDomainError {
     #[error("action name is invalid")]
     InvalidActionName,
 
+    #[error("role assignment expiry is invalid")]
+    InvalidExpiresAt,
+
     #[error("access denied")]
     AccessDenied,
 
     #[error("policy not found")]
     PolicyNotFound,
 
+    #[error(
+        "batch exceeds the maximum of {0} permission checks; split the request into smaller batches"
+    )]
+    BatchTooLarge(usize),
+
     #[error("infrastructure error: {0}")]
     InfrastructureError(String),
 }
@@ -16,6 +16,8 @@ pub struct EvaluatePermissionQuery {
     subject_owner_id: Option<String>,
     row_owner_id: Option<String>,
     request_id: Option<String>,
+    bypass_cache: bool,
+    audit_decision: bool,
 }
 
 pub struct EvaluatePermissionQueryParts {
@@ -27,6 +29,8 @@ pub struct EvaluatePermissionQueryParts {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub request_id: Option<String>,
+    pub bypass_cache: bool,
+    pub audit_decision: bool,
 }
 
 impl EvaluatePermissionQuery {
@@ -40,9 +44,16 @@ impl EvaluatePermissionQuery {
             subject_owner_id: parts.subject_owner_id,
             row_owner_id: parts.row_owner_id,
             request_id: parts.request_id,
+            bypass_cache: parts.bypass_cache,
+            audit_decision: parts.audit_decision,
         })
     }
 
+    pub fn with_requested_columns(mut self, requested_columns: Vec<String>) -> Self {
+        self.requested_columns = requested_columns;
+        self
+    }
+
     pub fn tenant_id(&self) -> &TenantId {
         &self.tenant_id
     }
@@ -67,4 +78,10 @@ impl EvaluatePermissionQuery {
     pub fn request_id(&self) -> Option<&str> {
         self.request_id.as_deref()
     }
+    pub fn bypass_cache(&self) -> bool {
+        self.bypass_cache
+    }
+    pub fn audit_decision(&self) -> bool {
+        self.audit_decision
+    }
 }
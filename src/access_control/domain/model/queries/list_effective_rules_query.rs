@@ -0,0 +1,65 @@
+use crate::access_control::domain::model::{
+    enums::access_control_domain_error::AccessControlDomainError,
+    value_objects::{principal_id::PrincipalId, resource_name::ResourceName, tenant_id::TenantId},
+};
+
+#[derive(Clone, Debug)]
+pub struct ListEffectiveRulesQuery {
+    tenant_id: TenantId,
+    principal_id: PrincipalId,
+    resource_name: ResourceName,
+    requested_columns: Vec<String>,
+    subject_owner_id: Option<String>,
+    row_owner_id: Option<String>,
+}
+
+impl ListEffectiveRulesQuery {
+    pub fn new(
+        tenant_id: String,
+        principal_id: String,
+        resource_name: String,
+    ) -> Result<Self, AccessControlDomainError> {
+        Ok(Self {
+            tenant_id: TenantId::new(tenant_id)?,
+            principal_id: PrincipalId::new(principal_id)?,
+            resource_name: ResourceName::new(resource_name)?,
+            requested_columns: Vec::new(),
+            subject_owner_id: None,
+            row_owner_id: None,
+        })
+    }
+
+    pub fn with_requested_columns(mut self, requested_columns: Vec<String>) -> Self {
+        self.requested_columns = requested_columns;
+        self
+    }
+
+    pub fn with_owner_ids(
+        mut self,
+        subject_owner_id: Option<String>,
+        row_owner_id: Option<String>,
+    ) -> Self {
+        self.subject_owner_id = subject_owner_id;
+        self.row_owner_id = row_owner_id;
+        self
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+    pub fn principal_id(&self) -> &PrincipalId {
+        &self.principal_id
+    }
+    pub fn resource_name(&self) -> &ResourceName {
+        &self.resource_name
+    }
+    pub fn requested_columns(&self) -> &[String] {
+        &self.requested_columns
+    }
+    pub fn subject_owner_id(&self) -> Option<&str> {
+        self.subject_owner_id.as_deref()
+    }
+    pub fn row_owner_id(&self) -> Option<&str> {
+        self.row_owner_id.as_deref()
+    }
+}
@@ -0,0 +1,26 @@
+use crate::access_control::domain::model::{
+    enums::access_control_domain_error::AccessControlDomainError,
+    value_objects::{principal_id::PrincipalId, tenant_id::TenantId},
+};
+
+#[derive(Clone, Debug)]
+pub struct ListPrincipalRolesQuery {
+    tenant_id: TenantId,
+    principal_id: PrincipalId,
+}
+
+impl ListPrincipalRolesQuery {
+    pub fn new(tenant_id: String, principal_id: String) -> Result<Self, AccessControlDomainError> {
+        Ok(Self {
+            tenant_id: TenantId::new(tenant_id)?,
+            principal_id: PrincipalId::new(principal_id)?,
+        })
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+    pub fn principal_id(&self) -> &PrincipalId {
+        &self.principal_id
+    }
+}
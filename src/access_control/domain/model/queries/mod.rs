@@ -1 +1,3 @@
 pub mod evaluate_permission_query;
+pub mod list_effective_rules_query;
+pub mod list_principal_roles_query;
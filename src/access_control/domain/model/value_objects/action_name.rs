@@ -4,9 +4,21 @@ use crate::access_control::domain::model::enums::access_control_domain_error::Ac
 pub struct ActionName(String);
 
 impl ActionName {
+    pub const ACTIONS: [&'static str; 4] = ["read", "create", "update", "delete"];
+
     pub fn new(value: String) -> Result<Self, AccessControlDomainError> {
         match value.as_str() {
-            "read" | "create" | "update" | "delete" | "*" => Ok(Self(value)),
+            "*" => Ok(Self(value)),
+            action if Self::ACTIONS.contains(&action) => Ok(Self(value)),
+            pattern
+                if pattern.strip_suffix('*').is_some_and(|prefix| {
+                    Self::ACTIONS
+                        .iter()
+                        .any(|action| action.starts_with(prefix))
+                }) =>
+            {
+                Ok(Self(value))
+            }
             _ => Err(AccessControlDomainError::InvalidActionName),
         }
     }
@@ -9,8 +9,9 @@ impl ResourceName {
             return Ok(Self(value));
         }
 
-        let valid = !value.trim().is_empty()
-            && value
+        let name = value.strip_suffix('*').unwrap_or(&value);
+        let valid = !name.trim().is_empty()
+            && name
                 .chars()
                 .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
 
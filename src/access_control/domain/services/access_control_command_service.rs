@@ -3,6 +3,8 @@ use async_trait::async_trait;
 use crate::access_control::domain::model::{
     commands::{
         assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+        purge_tenant_access_control_command::PurgeTenantAccessControlCommand,
+        revoke_role_from_principal_command::RevokeRoleFromPrincipalCommand,
         upsert_policy_rule_command::UpsertPolicyRuleCommand,
     },
     enums::access_control_domain_error::AccessControlDomainError,
@@ -15,8 +17,23 @@ pub trait AccessControlCommandService: Send + Sync {
         command: AssignRoleToPrincipalCommand,
     ) -> Result<(), AccessControlDomainError>;
 
+    async fn handle_revoke_role(
+        &self,
+        command: RevokeRoleFromPrincipalCommand,
+    ) -> Result<(), AccessControlDomainError>;
+
     async fn handle_upsert_policy(
         &self,
         command: UpsertPolicyRuleCommand,
     ) -> Result<(), AccessControlDomainError>;
+
+    async fn handle_create_policy_if_missing(
+        &self,
+        command: UpsertPolicyRuleCommand,
+    ) -> Result<bool, AccessControlDomainError>;
+
+    async fn handle_purge_tenant(
+        &self,
+        command: PurgeTenantAccessControlCommand,
+    ) -> Result<(), AccessControlDomainError>;
 }
@@ -1,14 +1,47 @@
 use async_trait::async_trait;
 
 use crate::access_control::domain::model::{
-    enums::access_control_domain_error::AccessControlDomainError,
-    queries::evaluate_permission_query::EvaluatePermissionQuery,
+    enums::{
+        access_control_domain_error::AccessControlDomainError, permission_effect::PermissionEffect,
+    },
+    queries::{
+        evaluate_permission_query::EvaluatePermissionQuery,
+        list_effective_rules_query::ListEffectiveRulesQuery,
+        list_principal_roles_query::ListPrincipalRolesQuery,
+    },
 };
 
 #[derive(Clone, Debug)]
 pub struct AuthorizationDecisionResult {
     pub allowed: bool,
     pub reason: String,
+    pub client_reason: String,
+    pub matched_rules: usize,
+    pub applicable_rules: usize,
+    pub matched_rule: Option<EffectivePolicyRule>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EffectivePolicyRule {
+    pub role_name: String,
+    pub resource_name: String,
+    pub action_name: String,
+    pub effect: PermissionEffect,
+    pub allowed_columns: Option<Vec<String>>,
+    pub denied_columns: Option<Vec<String>>,
+    pub owner_scope: bool,
+    pub resource_specificity: u8,
+    pub action_specificity: u8,
+    pub column_specificity: u8,
+    pub owner_specificity: u8,
+}
+
+#[derive(Clone, Debug)]
+pub struct EffectiveActionRules {
+    pub action_name: String,
+    pub winning_effect: PermissionEffect,
+    pub rules: Vec<EffectivePolicyRule>,
+    pub permitted_columns: Vec<String>,
 }
 
 #[async_trait]
@@ -17,4 +50,24 @@ pub trait AccessControlQueryService: Send + Sync {
         &self,
         query: EvaluatePermissionQuery,
     ) -> Result<AuthorizationDecisionResult, AccessControlDomainError>;
+
+    async fn handle_evaluate_permission_batch(
+        &self,
+        queries: Vec<EvaluatePermissionQuery>,
+    ) -> Result<Vec<AuthorizationDecisionResult>, AccessControlDomainError>;
+
+    async fn handle_list_permitted_columns(
+        &self,
+        query: EvaluatePermissionQuery,
+    ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn handle_list_principal_roles(
+        &self,
+        query: ListPrincipalRolesQuery,
+    ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn handle_list_effective_rules(
+        &self,
+        query: ListEffectiveRulesQuery,
+    ) -> Result<Vec<EffectiveActionRules>, AccessControlDomainError>;
 }
@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use crate::access_control::domain::model::{
     enums::access_control_domain_error::AccessControlDomainError,
     events::authorization_decision_audited_event::AuthorizationDecisionAuditedEvent,
+    value_objects::tenant_id::TenantId,
 };
 
 #[async_trait]
@@ -11,4 +12,9 @@ pub trait AuthorizationDecisionAuditRepository: Send + Sync {
         &self,
         event: &AuthorizationDecisionAuditedEvent,
     ) -> Result<(), AccessControlDomainError>;
+
+    async fn archive_tenant_decisions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError>;
 }
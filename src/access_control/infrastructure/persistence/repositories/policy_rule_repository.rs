@@ -23,6 +23,11 @@ pub struct PolicyRuleRecord {
 pub trait PolicyRuleRepository: Send + Sync {
     async fn upsert_rule(&self, rule: PolicyRuleRecord) -> Result<(), AccessControlDomainError>;
 
+    async fn insert_rule_if_absent(
+        &self,
+        rule: PolicyRuleRecord,
+    ) -> Result<bool, AccessControlDomainError>;
+
     async fn find_rules_for_roles(
         &self,
         tenant_id: &TenantId,
@@ -30,4 +35,16 @@ pub trait PolicyRuleRepository: Send + Sync {
         action_name: &ActionName,
         role_names: &[String],
     ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError>;
+
+    async fn find_resource_rules_for_roles(
+        &self,
+        tenant_id: &TenantId,
+        resource_name: &ResourceName,
+        role_names: &[String],
+    ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError>;
+
+    async fn delete_tenant_rules(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError>;
 }
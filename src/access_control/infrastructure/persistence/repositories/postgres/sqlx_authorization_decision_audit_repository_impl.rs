@@ -5,6 +5,7 @@ use crate::access_control::{
     domain::model::{
         enums::access_control_domain_error::AccessControlDomainError,
         events::authorization_decision_audited_event::AuthorizationDecisionAuditedEvent,
+        value_objects::tenant_id::TenantId,
     },
     infrastructure::persistence::repositories::authorization_decision_audit_repository::AuthorizationDecisionAuditRepository,
 };
@@ -54,4 +55,57 @@ impl AuthorizationDecisionAuditRepository for SqlxAuthorizationDecisionAuditRepo
 
         Ok(())
     }
+
+    async fn archive_tenant_decisions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        let statement = r#"
+            WITH archived AS (
+                DELETE FROM access_authorization_decision_audit
+                WHERE tenant_id = $1
+                RETURNING
+                    id,
+                    tenant_id,
+                    principal_id,
+                    request_id,
+                    resource_name,
+                    action_name,
+                    allowed,
+                    reason,
+                    occurred_at
+            )
+            INSERT INTO access_authorization_decision_audit_archive (
+                id,
+                tenant_id,
+                principal_id,
+                request_id,
+                resource_name,
+                action_name,
+                allowed,
+                reason,
+                occurred_at
+            )
+            SELECT
+                id,
+                tenant_id,
+                principal_id,
+                request_id,
+                resource_name,
+                action_name,
+                allowed,
+                reason,
+                occurred_at
+            FROM archived
+            ON CONFLICT (id) DO NOTHING
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
 }
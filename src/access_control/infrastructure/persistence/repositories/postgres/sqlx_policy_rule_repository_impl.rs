@@ -13,6 +13,16 @@ use crate::access_control::{
     },
 };
 
+type PolicyRuleRow = (
+    String,
+    String,
+    String,
+    String,
+    Option<Vec<String>>,
+    Option<Vec<String>>,
+    bool,
+);
+
 pub struct SqlxPolicyRuleRepositoryImpl {
     pool: PgPool,
 }
@@ -21,6 +31,36 @@ impl SqlxPolicyRuleRepositoryImpl {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    fn rules_from_rows(
+        tenant_id: &TenantId,
+        rows: Vec<PolicyRuleRow>,
+    ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError> {
+        rows.into_iter()
+            .map(
+                |(
+                    role_name,
+                    rule_resource_name,
+                    rule_action_name,
+                    effect,
+                    allowed_columns,
+                    denied_columns,
+                    owner_scope,
+                )| {
+                    Ok(PolicyRuleRecord {
+                        tenant_id: tenant_id.value().to_string(),
+                        role_name,
+                        resource_name: rule_resource_name,
+                        action_name: rule_action_name,
+                        effect: effect.parse()?,
+                        allowed_columns,
+                        denied_columns,
+                        owner_scope,
+                    })
+                },
+            )
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -62,6 +102,42 @@ impl PolicyRuleRepository for SqlxPolicyRuleRepositoryImpl {
         Ok(())
     }
 
+    async fn insert_rule_if_absent(
+        &self,
+        rule: PolicyRuleRecord,
+    ) -> Result<bool, AccessControlDomainError> {
+        let statement = r#"
+            INSERT INTO access_policy_rules (
+                tenant_id,
+                role_name,
+                resource_name,
+                action_name,
+                effect,
+                allowed_columns,
+                denied_columns,
+                owner_scope
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (tenant_id, role_name, resource_name, action_name)
+            DO NOTHING
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(rule.tenant_id)
+            .bind(rule.role_name)
+            .bind(rule.resource_name)
+            .bind(rule.action_name)
+            .bind(rule.effect.as_str())
+            .bind(rule.allowed_columns)
+            .bind(rule.denied_columns)
+            .bind(rule.owner_scope)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     async fn find_rules_for_roles(
         &self,
         tenant_id: &TenantId,
@@ -74,50 +150,79 @@ impl PolicyRuleRepository for SqlxPolicyRuleRepositoryImpl {
         }
 
         let statement = r#"
-            SELECT role_name, effect, allowed_columns, denied_columns, owner_scope
+            SELECT role_name, resource_name, action_name, effect, allowed_columns, denied_columns, owner_scope
             FROM access_policy_rules
             WHERE tenant_id = $1
-              AND resource_name = ANY($2)
-              AND action_name = ANY($3)
+              AND (
+                resource_name = $2
+                OR (right(resource_name, 1) = '*' AND starts_with($2, left(resource_name, -1)))
+              )
+              AND (
+                action_name = $3
+                OR (right(action_name, 1) = '*' AND starts_with($3, left(action_name, -1)))
+              )
               AND role_name = ANY($4)
         "#;
 
-        let resource_candidates = [resource_name.value().to_string(), "*".to_string()];
-        let action_candidates = [action_name.value().to_string(), "*".to_string()];
-
-        let rows = sqlx::query_as::<
-            _,
-            (
-                String,
-                String,
-                Option<Vec<String>>,
-                Option<Vec<String>>,
-                bool,
-            ),
-        >(statement)
-        .bind(tenant_id.value())
-        .bind(resource_candidates.as_slice())
-        .bind(action_candidates.as_slice())
-        .bind(role_names)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+        let rows = sqlx::query_as::<_, PolicyRuleRow>(statement)
+            .bind(tenant_id.value())
+            .bind(resource_name.value())
+            .bind(action_name.value())
+            .bind(role_names)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
 
-        rows.into_iter()
-            .map(
-                |(role_name, effect, allowed_columns, denied_columns, owner_scope)| {
-                    Ok(PolicyRuleRecord {
-                        tenant_id: tenant_id.value().to_string(),
-                        role_name,
-                        resource_name: resource_name.value().to_string(),
-                        action_name: action_name.value().to_string(),
-                        effect: effect.parse()?,
-                        allowed_columns,
-                        denied_columns,
-                        owner_scope,
-                    })
-                },
-            )
-            .collect()
+        Self::rules_from_rows(tenant_id, rows)
+    }
+
+    async fn find_resource_rules_for_roles(
+        &self,
+        tenant_id: &TenantId,
+        resource_name: &ResourceName,
+        role_names: &[String],
+    ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError> {
+        if role_names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let statement = r#"
+            SELECT role_name, resource_name, action_name, effect, allowed_columns, denied_columns, owner_scope
+            FROM access_policy_rules
+            WHERE tenant_id = $1
+              AND (
+                resource_name = $2
+                OR (right(resource_name, 1) = '*' AND starts_with($2, left(resource_name, -1)))
+              )
+              AND role_name = ANY($3)
+        "#;
+
+        let rows = sqlx::query_as::<_, PolicyRuleRow>(statement)
+            .bind(tenant_id.value())
+            .bind(resource_name.value())
+            .bind(role_names)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Self::rules_from_rows(tenant_id, rows)
+    }
+
+    async fn delete_tenant_rules(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        let statement = r#"
+            DELETE FROM access_policy_rules
+            WHERE tenant_id = $1
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected())
     }
 }
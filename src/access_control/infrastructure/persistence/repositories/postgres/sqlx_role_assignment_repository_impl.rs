@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
 use crate::access_control::{
@@ -26,12 +27,38 @@ impl RoleAssignmentRepository for SqlxRoleAssignmentRepositoryImpl {
         tenant_id: &TenantId,
         principal_id: &PrincipalId,
         role_name: &RoleName,
-    ) -> Result<(), AccessControlDomainError> {
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, AccessControlDomainError> {
         let statement = r#"
-            INSERT INTO access_role_assignments (tenant_id, principal_id, role_name)
-            VALUES ($1, $2, $3)
+            INSERT INTO access_role_assignments (tenant_id, principal_id, role_name, expires_at)
+            VALUES ($1, $2, $3, $4)
             ON CONFLICT (tenant_id, principal_id, role_name)
-            DO NOTHING
+            DO UPDATE SET expires_at = EXCLUDED.expires_at
+            WHERE EXCLUDED.expires_at IS NOT NULL
+              AND access_role_assignments.expires_at IS DISTINCT FROM EXCLUDED.expires_at
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .bind(principal_id.value())
+            .bind(role_name.value())
+            .bind(expires_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_role(
+        &self,
+        tenant_id: &TenantId,
+        principal_id: &PrincipalId,
+        role_name: &RoleName,
+    ) -> Result<(), AccessControlDomainError> {
+        let statement = r#"
+            DELETE FROM access_role_assignments
+            WHERE tenant_id = $1 AND principal_id = $2 AND role_name = $3
         "#;
 
         sqlx::query(statement)
@@ -53,7 +80,9 @@ impl RoleAssignmentRepository for SqlxRoleAssignmentRepositoryImpl {
         let statement = r#"
             SELECT role_name
             FROM access_role_assignments
-            WHERE tenant_id = $1 AND principal_id = $2
+            WHERE tenant_id = $1
+                AND principal_id = $2
+                AND (expires_at IS NULL OR expires_at > NOW())
         "#;
 
         let rows = sqlx::query_scalar::<_, String>(statement)
@@ -65,4 +94,53 @@ impl RoleAssignmentRepository for SqlxRoleAssignmentRepositoryImpl {
 
         Ok(rows)
     }
+
+    async fn find_parent_roles(
+        &self,
+        tenant_id: &TenantId,
+        role_names: &[String],
+    ) -> Result<Vec<String>, AccessControlDomainError> {
+        if role_names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let statement = r#"
+            SELECT DISTINCT parent_role_name
+            FROM access_role_parents
+            WHERE tenant_id = $1 AND role_name = ANY($2)
+        "#;
+
+        let rows = sqlx::query_scalar::<_, String>(statement)
+            .bind(tenant_id.value())
+            .bind(role_names)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows)
+    }
+
+    async fn delete_tenant_assignments(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        sqlx::query("DELETE FROM access_role_parents WHERE tenant_id = $1")
+            .bind(tenant_id.value())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        let statement = r#"
+            DELETE FROM access_role_assignments
+            WHERE tenant_id = $1
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AccessControlDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
 }
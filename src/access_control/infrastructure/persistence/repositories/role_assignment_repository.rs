@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::access_control::domain::model::{
     enums::access_control_domain_error::AccessControlDomainError,
@@ -12,6 +13,14 @@ pub trait RoleAssignmentRepository: Send + Sync {
         tenant_id: &TenantId,
         principal_id: &PrincipalId,
         role_name: &RoleName,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, AccessControlDomainError>;
+
+    async fn revoke_role(
+        &self,
+        tenant_id: &TenantId,
+        principal_id: &PrincipalId,
+        role_name: &RoleName,
     ) -> Result<(), AccessControlDomainError>;
 
     async fn find_roles_by_principal(
@@ -19,4 +28,15 @@ pub trait RoleAssignmentRepository: Send + Sync {
         tenant_id: &TenantId,
         principal_id: &PrincipalId,
     ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn find_parent_roles(
+        &self,
+        tenant_id: &TenantId,
+        role_names: &[String],
+    ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn delete_tenant_assignments(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError>;
 }
@@ -12,6 +12,8 @@ pub struct AccessControlPermissionRequest {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub request_id: Option<String>,
+    pub bypass_decision_cache: bool,
+    pub audit_decision: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -20,6 +22,22 @@ pub struct AccessControlPermissionDecision {
     pub reason: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct EffectiveColumnsRequest {
+    pub tenant_id: String,
+    pub principal_id: String,
+    pub resource_name: String,
+    pub requested_columns: Vec<String>,
+    pub subject_owner_id: Option<String>,
+    pub row_owner_id: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AccessControlActionColumns {
+    pub action_name: String,
+    pub permitted_columns: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct DataApiAccessBootstrapRequest {
     pub tenant_id: String,
@@ -29,6 +47,18 @@ pub struct DataApiAccessBootstrapRequest {
     pub writable_columns: Vec<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct PrincipalRolesRequest {
+    pub tenant_id: String,
+    pub principal_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct TenantAccessPurgeRequest {
+    pub tenant_id: String,
+    pub archive_audit_trail: bool,
+}
+
 #[async_trait]
 pub trait AccessControlFacade: Send + Sync {
     async fn check_permission(
@@ -36,8 +66,28 @@ pub trait AccessControlFacade: Send + Sync {
         request: AccessControlPermissionRequest,
     ) -> Result<AccessControlPermissionDecision, AccessControlDomainError>;
 
+    async fn find_permitted_columns(
+        &self,
+        request: AccessControlPermissionRequest,
+    ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn find_effective_columns(
+        &self,
+        request: EffectiveColumnsRequest,
+    ) -> Result<Vec<AccessControlActionColumns>, AccessControlDomainError>;
+
     async fn bootstrap_data_api_access(
         &self,
         request: DataApiAccessBootstrapRequest,
     ) -> Result<(), AccessControlDomainError>;
+
+    async fn find_principal_roles(
+        &self,
+        request: PrincipalRolesRequest,
+    ) -> Result<Vec<String>, AccessControlDomainError>;
+
+    async fn purge_tenant_access(
+        &self,
+        request: TenantAccessPurgeRequest,
+    ) -> Result<(), AccessControlDomainError>;
 }
@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    middleware,
+    routing::{get, post},
+};
 use validator::Validate;
 
 use crate::access_control::{
@@ -8,6 +14,7 @@ use crate::access_control::{
         model::{
             commands::{
                 assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+                revoke_role_from_principal_command::RevokeRoleFromPrincipalCommand,
                 upsert_policy_rule_command::{
                     UpsertPolicyRuleCommand, UpsertPolicyRuleCommandParts,
                 },
@@ -16,29 +23,48 @@ use crate::access_control::{
                 access_control_domain_error::AccessControlDomainError,
                 permission_effect::PermissionEffect,
             },
-            queries::evaluate_permission_query::{
-                EvaluatePermissionQuery, EvaluatePermissionQueryParts,
+            queries::{
+                evaluate_permission_query::{
+                    EvaluatePermissionQuery, EvaluatePermissionQueryParts,
+                },
+                list_effective_rules_query::ListEffectiveRulesQuery,
             },
         },
         services::{
             access_control_command_service::AccessControlCommandService,
-            access_control_query_service::AccessControlQueryService,
+            access_control_query_service::{
+                AccessControlQueryService, AuthorizationDecisionResult, EffectiveActionRules,
+                EffectivePolicyRule,
+            },
         },
     },
     interfaces::rest::resources::{
         access_control_error_response_resource::AccessControlErrorResponseResource,
         assign_role_request_resource::AssignRoleRequestResource,
+        effective_policy_rules_resource::{
+            EffectiveActionRulesResource, EffectivePolicyRuleResource,
+            EffectiveRuleSpecificityResource, EffectiveRulesQueryResource,
+        },
         evaluate_permission_request_resource::{
             EvaluatePermissionRequestResource, EvaluatePermissionResponseResource,
         },
+        revoke_role_request_resource::RevokeRoleRequestResource,
         upsert_policy_rule_request_resource::UpsertPolicyRuleRequestResource,
     },
 };
+use crate::config::admin_token::AdminToken;
+use crate::config::cache_control::decision_cache_bypass_requested;
+use crate::config::error_redaction::{
+    ErrorRedaction, client_facing_infrastructure_message, redact_server_error_messages,
+};
+use crate::config::problem_details::negotiate_problem_details;
 
 #[derive(Clone)]
 pub struct AccessControlRestControllerState {
     pub command_service: Arc<dyn AccessControlCommandService>,
     pub query_service: Arc<dyn AccessControlQueryService>,
+    pub error_redaction: ErrorRedaction,
+    pub admin_token: Option<AdminToken>,
 }
 
 pub fn router(state: AccessControlRestControllerState) -> Router {
@@ -47,11 +73,28 @@ pub fn router(state: AccessControlRestControllerState) -> Router {
             "/access-control/roles/assign",
             post(assign_role_to_principal),
         )
+        .route(
+            "/access-control/roles/revoke",
+            post(revoke_role_from_principal),
+        )
         .route("/access-control/policies/upsert", post(upsert_policy_rule))
         .route(
             "/access-control/permissions/evaluate",
             post(evaluate_permission),
         )
+        .route(
+            "/access-control/permissions/evaluate-batch",
+            post(evaluate_permission_batch),
+        )
+        .route(
+            "/access-control/principals/:principal_id/rules",
+            get(list_effective_rules),
+        )
+        .layer(middleware::map_response_with_state(
+            state.error_redaction,
+            redact_server_error_messages,
+        ))
+        .layer(middleware::from_fn(negotiate_problem_details))
         .with_state(state)
 }
 
@@ -61,7 +104,7 @@ pub fn router(state: AccessControlRestControllerState) -> Router {
     tag = "access-control",
     request_body = AssignRoleRequestResource,
     responses(
-        (status = 204, description = "Role assigned"),
+        (status = 204, description = "Role assigned or already assigned"),
         (status = 400, description = "Invalid request", body = AccessControlErrorResponseResource),
         (status = 500, description = "Infrastructure error", body = AccessControlErrorResponseResource)
     )
@@ -83,6 +126,7 @@ pub async fn assign_role_to_principal(
         request.tenant_id,
         request.principal_id,
         request.role_name,
+        request.expires_at,
     )
     .map_err(map_domain_error)?;
 
@@ -95,6 +139,46 @@ pub async fn assign_role_to_principal(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/access-control/roles/revoke",
+    tag = "access-control",
+    request_body = RevokeRoleRequestResource,
+    responses(
+        (status = 204, description = "Role revoked or was not assigned"),
+        (status = 400, description = "Invalid request", body = AccessControlErrorResponseResource),
+        (status = 500, description = "Infrastructure error", body = AccessControlErrorResponseResource)
+    )
+)]
+pub async fn revoke_role_from_principal(
+    State(state): State<AccessControlRestControllerState>,
+    Json(request): Json<RevokeRoleRequestResource>,
+) -> Result<StatusCode, (StatusCode, Json<AccessControlErrorResponseResource>)> {
+    if let Err(validation_error) = request.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(AccessControlErrorResponseResource {
+                message: validation_error.to_string(),
+            }),
+        ));
+    }
+
+    let command = RevokeRoleFromPrincipalCommand::new(
+        request.tenant_id,
+        request.principal_id,
+        request.role_name,
+    )
+    .map_err(map_domain_error)?;
+
+    state
+        .command_service
+        .handle_revoke_role(command)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     post,
     path = "/access-control/policies/upsert",
@@ -155,6 +239,9 @@ pub async fn upsert_policy_rule(
     path = "/access-control/permissions/evaluate",
     tag = "access-control",
     request_body = EvaluatePermissionRequestResource,
+    params(
+        ("Cache-Control" = Option<String>, Header, description = "no-cache forces a fresh evaluation that bypasses the decision cache; honored only with a valid x-admin-token")
+    ),
     responses(
         (status = 200, description = "Authorization decision", body = EvaluatePermissionResponseResource),
         (status = 400, description = "Invalid request", body = AccessControlErrorResponseResource),
@@ -163,10 +250,176 @@ pub async fn upsert_policy_rule(
 )]
 pub async fn evaluate_permission(
     State(state): State<AccessControlRestControllerState>,
+    headers: HeaderMap,
     Json(request): Json<EvaluatePermissionRequestResource>,
 ) -> Result<
     Json<EvaluatePermissionResponseResource>,
     (StatusCode, Json<AccessControlErrorResponseResource>),
+> {
+    let (options, query) = evaluate_query_from_request(
+        request,
+        decision_cache_bypass_requested(&headers, state.admin_token.as_ref()),
+    )?;
+
+    let decision = state
+        .query_service
+        .handle_evaluate_permission(query)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(Json(decision_response(decision, options)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/access-control/permissions/evaluate-batch",
+    tag = "access-control",
+    request_body = Vec<EvaluatePermissionRequestResource>,
+    params(
+        ("Cache-Control" = Option<String>, Header, description = "no-cache forces fresh evaluations that bypass the decision cache; honored only with a valid x-admin-token")
+    ),
+    responses(
+        (status = 200, description = "Authorization decisions in request order", body = [EvaluatePermissionResponseResource]),
+        (status = 400, description = "Invalid request or batch above the configured limit", body = AccessControlErrorResponseResource),
+        (status = 500, description = "Infrastructure error", body = AccessControlErrorResponseResource)
+    )
+)]
+pub async fn evaluate_permission_batch(
+    State(state): State<AccessControlRestControllerState>,
+    headers: HeaderMap,
+    Json(requests): Json<Vec<EvaluatePermissionRequestResource>>,
+) -> Result<
+    Json<Vec<EvaluatePermissionResponseResource>>,
+    (StatusCode, Json<AccessControlErrorResponseResource>),
+> {
+    let mut response_options = Vec::with_capacity(requests.len());
+    let mut queries = Vec::with_capacity(requests.len());
+    let bypass_cache = decision_cache_bypass_requested(&headers, state.admin_token.as_ref());
+    for request in requests {
+        let (options, query) = evaluate_query_from_request(request, bypass_cache)?;
+        response_options.push(options);
+        queries.push(query);
+    }
+
+    let decisions = state
+        .query_service
+        .handle_evaluate_permission_batch(queries)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(Json(
+        decisions
+            .into_iter()
+            .zip(response_options)
+            .map(|(decision, options)| decision_response(decision, options))
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/access-control/principals/{principal_id}/rules",
+    tag = "access-control",
+    params(
+        ("principal_id" = String, Path, description = "Principal id"),
+        ("tenant_id" = String, Query, description = "Tenant id"),
+        ("resource" = String, Query, description = "Resource to resolve rules for"),
+        ("columns" = Option<String>, Query, description = "Comma-separated columns the winning effect and the permitted columns are evaluated for"),
+        ("subject_owner_id" = Option<String>, Query, description = "Subject owner id used for owner-scoped rules"),
+        ("row_owner_id" = Option<String>, Query, description = "Row owner id used for owner-scoped rules")
+    ),
+    responses(
+        (status = 200, description = "Rules that apply to the principal per action, most specific first, with the winning effect", body = [EffectiveActionRulesResource]),
+        (status = 400, description = "Invalid request", body = AccessControlErrorResponseResource),
+        (status = 500, description = "Infrastructure error", body = AccessControlErrorResponseResource)
+    )
+)]
+pub async fn list_effective_rules(
+    State(state): State<AccessControlRestControllerState>,
+    Path(principal_id): Path<String>,
+    Query(request): Query<EffectiveRulesQueryResource>,
+) -> Result<
+    Json<Vec<EffectiveActionRulesResource>>,
+    (StatusCode, Json<AccessControlErrorResponseResource>),
+> {
+    let requested_columns = request
+        .columns
+        .as_deref()
+        .map(|columns| {
+            columns
+                .split(',')
+                .map(str::trim)
+                .filter(|column| !column.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let query = ListEffectiveRulesQuery::new(request.tenant_id, principal_id, request.resource)
+        .map_err(map_domain_error)?
+        .with_requested_columns(requested_columns)
+        .with_owner_ids(request.subject_owner_id, request.row_owner_id);
+
+    let effective = state
+        .query_service
+        .handle_list_effective_rules(query)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(Json(
+        effective
+            .into_iter()
+            .map(effective_action_rules_resource)
+            .collect(),
+    ))
+}
+
+fn effective_action_rules_resource(
+    effective: EffectiveActionRules,
+) -> EffectiveActionRulesResource {
+    EffectiveActionRulesResource {
+        action_name: effective.action_name,
+        winning_effect: effective.winning_effect.as_str().to_string(),
+        rules: effective
+            .rules
+            .into_iter()
+            .map(effective_policy_rule_resource)
+            .collect(),
+        permitted_columns: effective.permitted_columns,
+    }
+}
+
+fn effective_policy_rule_resource(
+    effective_rule: EffectivePolicyRule,
+) -> EffectivePolicyRuleResource {
+    EffectivePolicyRuleResource {
+        role_name: effective_rule.role_name,
+        resource_name: effective_rule.resource_name,
+        action_name: effective_rule.action_name,
+        effect: effective_rule.effect.as_str().to_string(),
+        allowed_columns: effective_rule.allowed_columns,
+        denied_columns: effective_rule.denied_columns,
+        owner_scope: effective_rule.owner_scope,
+        specificity: EffectiveRuleSpecificityResource {
+            resource: effective_rule.resource_specificity,
+            action: effective_rule.action_specificity,
+            columns: effective_rule.column_specificity,
+            owner: effective_rule.owner_specificity,
+        },
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DecisionResponseOptions {
+    debug: bool,
+    explain: bool,
+}
+
+fn evaluate_query_from_request(
+    request: EvaluatePermissionRequestResource,
+    bypass_cache: bool,
+) -> Result<
+    (DecisionResponseOptions, EvaluatePermissionQuery),
+    (StatusCode, Json<AccessControlErrorResponseResource>),
 > {
     if let Err(validation_error) = request.validate() {
         return Err((
@@ -186,19 +439,36 @@ pub async fn evaluate_permission(
         subject_owner_id: request.subject_owner_id,
         row_owner_id: request.row_owner_id,
         request_id: request.request_id,
+        bypass_cache,
+        audit_decision: true,
     })
     .map_err(map_domain_error)?;
 
-    let decision = state
-        .query_service
-        .handle_evaluate_permission(query)
-        .await
-        .map_err(map_domain_error)?;
+    Ok((
+        DecisionResponseOptions {
+            debug: request.debug,
+            explain: request.explain,
+        },
+        query,
+    ))
+}
 
-    Ok(Json(EvaluatePermissionResponseResource {
+fn decision_response(
+    decision: AuthorizationDecisionResult,
+    options: DecisionResponseOptions,
+) -> EvaluatePermissionResponseResource {
+    let debug = options.debug;
+    EvaluatePermissionResponseResource {
         allowed: decision.allowed,
-        reason: decision.reason,
-    }))
+        reason: decision.client_reason,
+        internal_reason: debug.then_some(decision.reason),
+        matched_rules: debug.then_some(decision.matched_rules),
+        applicable_rules: debug.then_some(decision.applicable_rules),
+        matched_rule: decision
+            .matched_rule
+            .filter(|_| options.explain)
+            .map(effective_policy_rule_resource),
+    }
 }
 
 fn map_domain_error(
@@ -210,15 +480,18 @@ fn map_domain_error(
         | AccessControlDomainError::InvalidRoleName
         | AccessControlDomainError::InvalidResourceName
         | AccessControlDomainError::InvalidActionName
-        | AccessControlDomainError::PolicyNotFound => StatusCode::BAD_REQUEST,
+        | AccessControlDomainError::InvalidExpiresAt
+        | AccessControlDomainError::PolicyNotFound
+        | AccessControlDomainError::BatchTooLarge(_) => StatusCode::BAD_REQUEST,
         AccessControlDomainError::AccessDenied => StatusCode::FORBIDDEN,
         AccessControlDomainError::InfrastructureError(_) => StatusCode::INTERNAL_SERVER_ERROR,
     };
+    let message = match &error {
+        AccessControlDomainError::InfrastructureError(detail) => {
+            client_facing_infrastructure_message("access-control", detail)
+        }
+        _ => error.to_string(),
+    };
 
-    (
-        status,
-        Json(AccessControlErrorResponseResource {
-            message: error.to_string(),
-        }),
-    )
+    (status, Json(AccessControlErrorResponseResource { message }))
 }
@@ -10,4 +10,7 @@ pub struct AssignRoleRequestResource {
     pub principal_id: String,
     #[validate(length(min = 1))]
     pub role_name: String,
+    #[serde(default)]
+    #[schema(example = "2026-12-31T23:59:59Z")]
+    pub expires_at: Option<String>,
 }
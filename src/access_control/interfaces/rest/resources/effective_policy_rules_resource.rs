@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct EffectiveRulesQueryResource {
+    pub tenant_id: String,
+    pub resource: String,
+    pub columns: Option<String>,
+    pub subject_owner_id: Option<String>,
+    pub row_owner_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct EffectiveRuleSpecificityResource {
+    pub resource: u8,
+    pub action: u8,
+    pub columns: u8,
+    pub owner: u8,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct EffectivePolicyRuleResource {
+    pub role_name: String,
+    pub resource_name: String,
+    pub action_name: String,
+    pub effect: String,
+    pub allowed_columns: Option<Vec<String>>,
+    pub denied_columns: Option<Vec<String>>,
+    pub owner_scope: bool,
+    pub specificity: EffectiveRuleSpecificityResource,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct EffectiveActionRulesResource {
+    pub action_name: String,
+    pub winning_effect: String,
+    pub rules: Vec<EffectivePolicyRuleResource>,
+    pub permitted_columns: Vec<String>,
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use crate::access_control::interfaces::rest::resources::effective_policy_rules_resource::EffectivePolicyRuleResource;
+
 #[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
 pub struct EvaluatePermissionRequestResource {
     #[validate(length(min = 1))]
@@ -16,10 +18,22 @@ pub struct EvaluatePermissionRequestResource {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub request_id: Option<String>,
+    #[serde(default)]
+    pub debug: bool,
+    #[serde(default)]
+    pub explain: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct EvaluatePermissionResponseResource {
     pub allowed: bool,
     pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_rules: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applicable_rules: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_rule: Option<EffectivePolicyRuleResource>,
 }
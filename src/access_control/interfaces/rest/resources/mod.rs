@@ -1,4 +1,6 @@
 pub mod access_control_error_response_resource;
 pub mod assign_role_request_resource;
+pub mod effective_policy_rules_resource;
 pub mod evaluate_permission_request_resource;
+pub mod revoke_role_request_resource;
 pub mod upsert_policy_rule_request_resource;
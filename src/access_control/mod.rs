@@ -3,34 +3,37 @@ use std::sync::Arc;
 use axum::Router;
 use sqlx::PgPool;
 
-use crate::{
-    access_control::{
-        application::{
-            command_services::access_control_command_service_impl::AccessControlCommandServiceImpl,
-            query_services::access_control_query_service_impl::AccessControlQueryServiceImpl,
-        },
-        infrastructure::persistence::repositories::postgres::{
-            sqlx_authorization_decision_audit_repository_impl::SqlxAuthorizationDecisionAuditRepositoryImpl,
-            sqlx_policy_rule_repository_impl::SqlxPolicyRuleRepositoryImpl,
-            sqlx_role_assignment_repository_impl::SqlxRoleAssignmentRepositoryImpl,
-        },
-        interfaces::rest::controllers::access_control_rest_controller::{
-            AccessControlRestControllerState, router,
-        },
+use crate::access_control::{
+    application::{
+        acl::access_control_facade_impl::AccessControlFacadeImpl,
+        cache::decision_cache_generations::DecisionCacheGenerations,
+        command_services::access_control_command_service_impl::AccessControlCommandServiceImpl,
+        query_services::access_control_query_service_impl::AccessControlQueryServiceImpl,
+    },
+    infrastructure::persistence::repositories::postgres::{
+        sqlx_authorization_decision_audit_repository_impl::SqlxAuthorizationDecisionAuditRepositoryImpl,
+        sqlx_policy_rule_repository_impl::SqlxPolicyRuleRepositoryImpl,
+        sqlx_role_assignment_repository_impl::SqlxRoleAssignmentRepositoryImpl,
+    },
+    interfaces::rest::controllers::access_control_rest_controller::{
+        AccessControlRestControllerState, router,
     },
-    config::app_config::AppConfig,
 };
+use crate::config::{app_config::AppConfig, error_redaction::ErrorRedaction};
 
 pub mod application;
 pub mod domain;
 pub mod infrastructure;
 pub mod interfaces;
 
-pub async fn build_access_control_router(config: &AppConfig) -> Result<Router, String> {
-    let admin_pool = PgPool::connect(&config.admin_database_url())
-        .await
-        .map_err(|e| e.to_string())?;
-
+fn build_access_control_services(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    cache_generations: Arc<DecisionCacheGenerations>,
+) -> (
+    Arc<AccessControlCommandServiceImpl>,
+    Arc<AccessControlQueryServiceImpl>,
+) {
     let role_assignment_repository =
         Arc::new(SqlxRoleAssignmentRepositoryImpl::new(admin_pool.clone()));
     let policy_rule_repository = Arc::new(SqlxPolicyRuleRepositoryImpl::new(admin_pool.clone()));
@@ -38,17 +41,49 @@ pub async fn build_access_control_router(config: &AppConfig) -> Result<Router, S
         admin_pool,
     ));
 
-    let command_service = Arc::new(AccessControlCommandServiceImpl::new(
-        role_assignment_repository.clone(),
-        policy_rule_repository.clone(),
-    ));
-    let query_service = Arc::new(AccessControlQueryServiceImpl::new(
-        policy_rule_repository,
-        role_assignment_repository.clone(),
-        audit_repository,
-    ));
+    let command_service = Arc::new(
+        AccessControlCommandServiceImpl::new(
+            role_assignment_repository.clone(),
+            policy_rule_repository.clone(),
+            audit_repository.clone(),
+        )
+        .with_cache_generations(cache_generations.clone()),
+    );
+    let query_service = Arc::new(
+        AccessControlQueryServiceImpl::new(
+            policy_rule_repository,
+            role_assignment_repository,
+            audit_repository,
+        )
+        .with_cache_generations(cache_generations)
+        .with_max_batch_size(config.access_control_max_batch_size),
+    );
+
+    (command_service, query_service)
+}
+
+pub fn build_access_control_facade(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    cache_generations: Arc<DecisionCacheGenerations>,
+) -> Arc<AccessControlFacadeImpl> {
+    let (command_service, query_service) =
+        build_access_control_services(config, admin_pool, cache_generations);
+
+    Arc::new(AccessControlFacadeImpl::new(command_service, query_service))
+}
+
+pub fn build_access_control_router(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    cache_generations: Arc<DecisionCacheGenerations>,
+) -> Result<Router, String> {
+    let (command_service, query_service) =
+        build_access_control_services(config, admin_pool, cache_generations);
 
     Ok(router(AccessControlRestControllerState {
+        error_redaction: ErrorRedaction::new(config.error_redaction_enabled),
+        admin_token: config.admin_token()?,
         command_service,
         query_service,
     }))
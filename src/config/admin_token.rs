@@ -0,0 +1,52 @@
+use axum::http::HeaderMap;
+use sha2::{Digest, Sha256};
+
+pub const ADMIN_TOKEN_HEADER: &str = "x-admin-token";
+
+const MIN_ADMIN_TOKEN_LENGTH: usize = 16;
+
+#[derive(Clone)]
+pub struct AdminToken {
+    digest: [u8; 32],
+}
+
+impl AdminToken {
+    pub fn new(token: &str) -> Result<Self, String> {
+        if token.trim().len() < MIN_ADMIN_TOKEN_LENGTH {
+            return Err(format!(
+                "admin token must be at least {MIN_ADMIN_TOKEN_LENGTH} characters"
+            ));
+        }
+
+        Ok(Self {
+            digest: Sha256::digest(token.trim().as_bytes()).into(),
+        })
+    }
+
+    pub fn verify(&self, presented: &str) -> bool {
+        let presented: [u8; 32] = Sha256::digest(presented.trim().as_bytes()).into();
+
+        self.digest
+            .iter()
+            .zip(presented.iter())
+            .fold(0u8, |acc, (expected, actual)| acc | (expected ^ actual))
+            == 0
+    }
+}
+
+impl std::fmt::Debug for AdminToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AdminToken(..)")
+    }
+}
+
+pub fn is_verified_admin(headers: &HeaderMap, admin_token: Option<&AdminToken>) -> bool {
+    let Some(admin_token) = admin_token else {
+        return false;
+    };
+
+    headers
+        .get(ADMIN_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| admin_token.verify(value))
+}
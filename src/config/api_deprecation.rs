@@ -0,0 +1,73 @@
+use axum::{
+    extract::State,
+    http::{HeaderName, HeaderValue, header},
+    response::Response,
+};
+use chrono::NaiveDateTime;
+
+const DEPRECATION_HEADER: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET_HEADER: HeaderName = HeaderName::from_static("sunset");
+
+#[derive(Clone, Debug)]
+pub struct ApiDeprecation {
+    deprecation: HeaderValue,
+    sunset: Option<HeaderValue>,
+    link: Option<HeaderValue>,
+}
+
+impl ApiDeprecation {
+    pub fn new(
+        deprecation: &str,
+        sunset: Option<&str>,
+        link: Option<&str>,
+    ) -> Result<Self, String> {
+        let header_value = |name: &str, value: &str| {
+            HeaderValue::from_str(value).map_err(|_| format!("{name} is not a valid header value"))
+        };
+
+        let deprecation_date = deprecation
+            .strip_prefix('@')
+            .filter(|seconds| !seconds.is_empty() && seconds.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|seconds| seconds.parse::<i64>().ok());
+        if deprecation_date.is_none() {
+            return Err(format!(
+                "deprecation '{deprecation}' must be a date in @<epoch seconds> format"
+            ));
+        }
+        if let Some(value) = sunset
+            && NaiveDateTime::parse_from_str(value, "%a, %d %b %Y %H:%M:%S GMT").is_err()
+        {
+            return Err(format!(
+                "sunset '{value}' must be an HTTP-date such as 'Wed, 30 Jun 2027 23:59:59 GMT'"
+            ));
+        }
+
+        Ok(Self {
+            deprecation: header_value("deprecation", deprecation)?,
+            sunset: sunset
+                .map(|value| header_value("sunset", value))
+                .transpose()?,
+            link: link
+                .map(|url| {
+                    header_value("deprecation link", &format!("<{url}>; rel=\"deprecation\""))
+                })
+                .transpose()?,
+        })
+    }
+}
+
+pub async fn apply_deprecation_headers(
+    State(deprecation): State<ApiDeprecation>,
+    mut response: Response,
+) -> Response {
+    let headers = response.headers_mut();
+    headers.insert(DEPRECATION_HEADER, deprecation.deprecation);
+    if let Some(sunset) = deprecation.sunset {
+        headers.insert(SUNSET_HEADER, sunset);
+    }
+    if let Some(link) = deprecation.link {
+        headers.append(header::LINK, link);
+    }
+
+    response
+}
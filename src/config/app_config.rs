@@ -1,3 +1,19 @@
+use std::time::Duration;
+
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+
+use crate::{
+    config::{
+        admin_token::AdminToken, api_deprecation::ApiDeprecation,
+        credential_cipher::CredentialCipher, tenant_pool_settings::TenantPoolSettings,
+    },
+    data_api::domain::model::queries::list_rows_query::MAX_LIST_ROWS_LIMIT,
+    provisioner::domain::model::value_objects::{
+        database_seed_source::DatabaseSeedSource,
+        provisioned_database_name::ProvisionedDatabaseName,
+    },
+};
+
 #[derive(Clone, Debug)]
 pub struct AppConfig {
     pub port: u16,
@@ -6,6 +22,43 @@ pub struct AppConfig {
     pub postgres_user: String,
     pub postgres_password: String,
     pub postgres_admin_database: String,
+    pub request_id_header: String,
+    pub admin_api_token: Option<String>,
+    pub acl_aware_catalog: bool,
+    pub effective_writable_catalog: bool,
+    pub data_api_debug_sql_enabled: bool,
+    pub data_api_strict_json_enabled: bool,
+    pub data_api_strip_denied_columns: bool,
+    pub data_api_owner_scoped_lists: bool,
+    pub row_owner_column: Option<String>,
+    pub archive_acl_audit_on_tenant_delete: bool,
+    pub data_api_max_batch_size: usize,
+    pub data_api_max_page_size: i64,
+    pub data_api_v1_deprecation: Option<String>,
+    pub data_api_v1_sunset: Option<String>,
+    pub data_api_v1_deprecation_link: Option<String>,
+    pub data_api_metadata_sync_interval_secs: u64,
+    pub tenant_pool_max_connections: u32,
+    pub tenant_pool_min_connections: u32,
+    pub tenant_pool_idle_timeout_secs: u64,
+    pub tenant_pool_max_lifetime_secs: u64,
+    pub tenant_pool_sweep_interval_secs: u64,
+    pub data_api_read_audit_sample_rate: f64,
+    pub tenant_pooler_url: Option<String>,
+    pub password_change_max_attempts: Option<usize>,
+    pub password_change_window_secs: u64,
+    pub password_max_failed_attempts: usize,
+    pub password_failed_attempt_window_secs: u64,
+    pub credential_encryption_key: Option<String>,
+    pub restricted_username_projection: bool,
+    pub provisioner_seed_mode: Option<String>,
+    pub provisioner_seed_file: Option<String>,
+    pub provisioner_seed_template: Option<String>,
+    pub provisioner_seed_template_allowlist: Vec<String>,
+    pub tenant_health_probe_interval_secs: u64,
+    pub tenant_health_probe_timeout_secs: u64,
+    pub error_redaction_enabled: bool,
+    pub access_control_max_batch_size: usize,
 }
 
 impl AppConfig {
@@ -27,6 +80,183 @@ impl AppConfig {
                 .unwrap_or_else(|_| "admin".to_string()),
             postgres_admin_database: std::env::var("POSTGRES_ADMIN_DATABASE")
                 .unwrap_or_else(|_| "postgres".to_string()),
+            request_id_header: std::env::var("REQUEST_ID_HEADER")
+                .map(|v| v.trim().to_ascii_lowercase())
+                .ok()
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| "x-request-id".to_string()),
+            admin_api_token: std::env::var("ADMIN_API_TOKEN")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            acl_aware_catalog: std::env::var("DATA_API_ACL_AWARE_CATALOG")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            effective_writable_catalog: std::env::var("DATA_API_EFFECTIVE_WRITABLE_CATALOG")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            data_api_debug_sql_enabled: std::env::var("DATA_API_DEBUG_SQL_ENABLED")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            data_api_strict_json_enabled: std::env::var("DATA_API_STRICT_JSON_ENABLED")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            data_api_strip_denied_columns: std::env::var("DATA_API_STRIP_DENIED_COLUMNS")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            data_api_owner_scoped_lists: std::env::var("DATA_API_OWNER_SCOPED_LISTS")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(true),
+            row_owner_column: std::env::var("DATA_API_ROW_OWNER_COLUMN")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            archive_acl_audit_on_tenant_delete: std::env::var("ACL_ARCHIVE_AUDIT_ON_TENANT_DELETE")
+                .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+                .unwrap_or(false),
+            data_api_max_batch_size: std::env::var("DATA_API_MAX_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(10_000),
+            data_api_max_page_size: std::env::var("DATA_API_MAX_PAGE_SIZE")
+                .ok()
+                .and_then(|v| v.parse::<i64>().ok())
+                .filter(|v| *v > 0)
+                .map(|v| v.min(MAX_LIST_ROWS_LIMIT))
+                .unwrap_or(MAX_LIST_ROWS_LIMIT),
+            data_api_v1_deprecation: std::env::var("DATA_API_V1_DEPRECATION")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            data_api_v1_sunset: std::env::var("DATA_API_V1_SUNSET")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            data_api_v1_deprecation_link: std::env::var("DATA_API_V1_DEPRECATION_LINK")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            data_api_metadata_sync_interval_secs: std::env::var(
+                "DATA_API_METADATA_SYNC_INTERVAL_SECS",
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0),
+            tenant_pool_max_connections: std::env::var("DATA_API_TENANT_POOL_MAX_CONNECTIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(10),
+            tenant_pool_min_connections: std::env::var("DATA_API_TENANT_POOL_MIN_CONNECTIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            tenant_pool_idle_timeout_secs: std::env::var("DATA_API_TENANT_POOL_IDLE_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(600),
+            tenant_pool_max_lifetime_secs: std::env::var("DATA_API_TENANT_POOL_MAX_LIFETIME_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(1800),
+            tenant_pool_sweep_interval_secs: std::env::var(
+                "DATA_API_TENANT_POOL_SWEEP_INTERVAL_SECS",
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60),
+            data_api_read_audit_sample_rate: std::env::var("DATA_API_READ_AUDIT_SAMPLE_RATE")
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .map(|v| v.clamp(0.0, 1.0))
+                .unwrap_or(1.0),
+            tenant_pooler_url: std::env::var("DATA_API_TENANT_POOLER_URL")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            password_change_max_attempts: std::env::var("PROVISIONER_PASSWORD_CHANGE_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0),
+            password_change_window_secs: std::env::var("PROVISIONER_PASSWORD_CHANGE_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(3600),
+            password_max_failed_attempts: std::env::var("PROVISIONER_PASSWORD_MAX_FAILED_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(5),
+            password_failed_attempt_window_secs: std::env::var(
+                "PROVISIONER_PASSWORD_FAILED_ATTEMPT_WINDOW_SECS",
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(900),
+            credential_encryption_key: std::env::var("PROVISIONER_CREDENTIAL_ENCRYPTION_KEY")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            restricted_username_projection: std::env::var(
+                "PROVISIONER_RESTRICTED_USERNAME_PROJECTION",
+            )
+            .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+            .unwrap_or(false),
+            provisioner_seed_mode: std::env::var("PROVISIONER_SEED_MODE")
+                .map(|v| v.trim().to_ascii_lowercase())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            provisioner_seed_file: std::env::var("PROVISIONER_SEED_FILE")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            provisioner_seed_template: std::env::var("PROVISIONER_SEED_TEMPLATE")
+                .map(|v| v.trim().to_string())
+                .ok()
+                .filter(|v| !v.is_empty()),
+            provisioner_seed_template_allowlist: std::env::var(
+                "PROVISIONER_SEED_TEMPLATE_ALLOWLIST",
+            )
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|template| !template.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+            tenant_health_probe_interval_secs: std::env::var("HEALTH_TENANT_PROBE_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+            tenant_health_probe_timeout_secs: std::env::var("HEALTH_TENANT_PROBE_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(5),
+            error_redaction_enabled: std::env::var("ERROR_DETAIL_REDACTION_ENABLED")
+                .map(|v| !(v.eq_ignore_ascii_case("false") || v == "0"))
+                .unwrap_or(true),
+            access_control_max_batch_size: std::env::var("ACCESS_CONTROL_MAX_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(100),
+        }
+    }
+
+    pub fn tenant_pool_settings(&self) -> TenantPoolSettings {
+        TenantPoolSettings {
+            max_connections: self.tenant_pool_max_connections,
+            min_connections: self.tenant_pool_min_connections,
+            idle_timeout: Duration::from_secs(self.tenant_pool_idle_timeout_secs),
+            max_lifetime: Duration::from_secs(self.tenant_pool_max_lifetime_secs),
         }
     }
 
@@ -51,4 +281,84 @@ impl AppConfig {
             database_name
         )
     }
+
+    pub fn tenant_database_url_for(
+        &self,
+        database_name: &str,
+        username: &str,
+        password: &str,
+    ) -> String {
+        format!(
+            "postgres://{}:{}@{}:{}/{}",
+            utf8_percent_encode(username, NON_ALPHANUMERIC),
+            utf8_percent_encode(password, NON_ALPHANUMERIC),
+            self.postgres_host,
+            self.postgres_port,
+            database_name
+        )
+    }
+
+    pub fn default_seed_source(&self) -> Result<Option<DatabaseSeedSource>, String> {
+        let mode = match self.provisioner_seed_mode.as_deref() {
+            Some("none") => return Ok(None),
+            Some(mode) => mode,
+            None if self.provisioner_seed_file.is_some() => "file",
+            None => return Ok(None),
+        };
+        let seed_source =
+            DatabaseSeedSource::parse(mode, None, self.provisioner_seed_template.clone())
+                .map_err(|e| e.to_string())?;
+        if seed_source == DatabaseSeedSource::BundledFile && self.provisioner_seed_file.is_none() {
+            return Err("PROVISIONER_SEED_MODE=file requires PROVISIONER_SEED_FILE".to_string());
+        }
+
+        Ok(Some(seed_source))
+    }
+
+    pub fn seed_template_allowlist(&self) -> Result<Vec<ProvisionedDatabaseName>, String> {
+        self.provisioner_seed_template_allowlist
+            .iter()
+            .map(|template| {
+                ProvisionedDatabaseName::new(template.clone()).map_err(|_| {
+                    format!(
+                        "PROVISIONER_SEED_TEMPLATE_ALLOWLIST entry '{template}' is not a valid database name"
+                    )
+                })
+            })
+            .collect()
+    }
+
+    pub fn data_api_v1_deprecation(&self) -> Result<Option<ApiDeprecation>, String> {
+        let Some(deprecation) = self.data_api_v1_deprecation.as_deref() else {
+            if self.data_api_v1_sunset.is_some() || self.data_api_v1_deprecation_link.is_some() {
+                return Err(
+                    "DATA_API_V1_SUNSET and DATA_API_V1_DEPRECATION_LINK require DATA_API_V1_DEPRECATION"
+                        .to_string(),
+                );
+            }
+            return Ok(None);
+        };
+
+        ApiDeprecation::new(
+            deprecation,
+            self.data_api_v1_sunset.as_deref(),
+            self.data_api_v1_deprecation_link.as_deref(),
+        )
+        .map(Some)
+        .map_err(|error| format!("invalid DATA_API_V1 deprecation settings: {error}"))
+    }
+
+    pub fn admin_token(&self) -> Result<Option<AdminToken>, String> {
+        self.admin_api_token
+            .as_deref()
+            .map(AdminToken::new)
+            .transpose()
+    }
+
+    pub fn credential_cipher(&self) -> Result<Option<CredentialCipher>, String> {
+        self.credential_encryption_key
+            .as_deref()
+            .map(CredentialCipher::from_base64_key)
+            .transpose()
+    }
 }
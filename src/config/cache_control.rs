@@ -0,0 +1,19 @@
+use axum::http::{HeaderMap, header};
+
+use crate::config::admin_token::{AdminToken, is_verified_admin};
+
+pub fn no_cache_requested(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-cache"))
+}
+
+pub fn decision_cache_bypass_requested(
+    headers: &HeaderMap,
+    admin_token: Option<&AdminToken>,
+) -> bool {
+    no_cache_requested(headers) && is_verified_admin(headers, admin_token)
+}
@@ -0,0 +1,68 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chacha20poly1305::{
+    ChaCha20Poly1305, Key, KeyInit, Nonce,
+    aead::{Aead, AeadCore, OsRng, Payload},
+};
+
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Clone)]
+pub struct CredentialCipher {
+    cipher: ChaCha20Poly1305,
+}
+
+impl CredentialCipher {
+    pub fn from_base64_key(encoded_key: &str) -> Result<Self, String> {
+        let key = STANDARD
+            .decode(encoded_key.trim())
+            .map_err(|e| format!("credential encryption key is not valid base64: {e}"))?;
+        if key.len() != 32 {
+            return Err("credential encryption key must be 32 bytes".to_string());
+        }
+
+        Ok(Self {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+        })
+    }
+
+    pub fn encrypt(&self, tenant_id: &str, secret: &str) -> Result<String, String> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: secret.as_bytes(),
+                    aad: tenant_id.as_bytes(),
+                },
+            )
+            .map_err(|_| "failed to encrypt credential".to_string())?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(STANDARD.encode(sealed))
+    }
+
+    pub fn decrypt(&self, tenant_id: &str, sealed: &str) -> Result<String, String> {
+        let sealed = STANDARD
+            .decode(sealed.trim())
+            .map_err(|_| "stored credential is not valid base64".to_string())?;
+        if sealed.len() <= NONCE_LENGTH {
+            return Err("stored credential is truncated".to_string());
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LENGTH);
+        let secret = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: tenant_id.as_bytes(),
+                },
+            )
+            .map_err(|_| "failed to decrypt stored credential".to_string())?;
+
+        String::from_utf8(secret).map_err(|_| "stored credential is not valid UTF-8".to_string())
+    }
+}
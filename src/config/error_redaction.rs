@@ -0,0 +1,88 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::State,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use regex::Regex;
+use serde_json::Value;
+
+const REDACTED: &str = "[redacted]";
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+lazy_static::lazy_static! {
+    static ref CONNECTION_URL_PATTERN: Regex =
+        Regex::new(r"(?i)\b[a-z][a-z0-9+.\-]*://[^\s'\x22,;)]+").expect("valid connection url regex");
+    static ref CONNECTION_KEYWORD_PATTERN: Regex = Regex::new(
+        r"(?i)\b(password|passwd|pwd|user|username|host|hostaddr|port|dbname|sslkey|sslpassword)\s*=\s*('[^']*'|\x22[^\x22]*\x22|[^\s,;)]+)"
+    )
+    .expect("valid connection keyword regex");
+    static ref IPV4_ADDRESS_PATTERN: Regex =
+        Regex::new(r"\b\d{1,3}(\.\d{1,3}){3}(:\d{1,5})?\b").expect("valid ipv4 regex");
+    static ref HOST_PORT_PATTERN: Regex =
+        Regex::new(r"(?i)\b[a-z][a-z0-9\-]*(\.[a-z0-9\-]+)*:\d{2,5}\b").expect("valid host port regex");
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorRedaction {
+    enabled: bool,
+}
+
+impl ErrorRedaction {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn redact(&self, message: &str) -> String {
+        if !self.enabled {
+            return message.to_string();
+        }
+
+        let redacted = CONNECTION_URL_PATTERN.replace_all(message, REDACTED);
+        let redacted = CONNECTION_KEYWORD_PATTERN.replace_all(&redacted, format!("$1={REDACTED}"));
+        let redacted = IPV4_ADDRESS_PATTERN.replace_all(&redacted, REDACTED);
+        let redacted = HOST_PORT_PATTERN.replace_all(&redacted, REDACTED);
+
+        redacted.into_owned()
+    }
+}
+
+pub fn client_facing_infrastructure_message(context: &str, detail: &str) -> String {
+    tracing::error!(context, detail, "infrastructure error");
+
+    format!("infrastructure error: {detail}")
+}
+
+pub async fn redact_server_error_messages(
+    State(redaction): State<ErrorRedaction>,
+    response: Response,
+) -> Response {
+    if !redaction.enabled() || !response.status().is_server_error() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, MAX_ERROR_BODY_BYTES).await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "error body could not be read",
+        )
+            .into_response();
+    };
+
+    let Ok(mut envelope) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    if let Some(message) = envelope.get_mut("message")
+        && let Some(text) = message.as_str()
+    {
+        *message = Value::String(redaction.redact(text));
+    }
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(envelope.to_string()))
+}
@@ -1 +1,8 @@
+pub mod admin_token;
+pub mod api_deprecation;
 pub mod app_config;
+pub mod cache_control;
+pub mod credential_cipher;
+pub mod error_redaction;
+pub mod problem_details;
+pub mod tenant_pool_settings;
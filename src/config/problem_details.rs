@@ -0,0 +1,81 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Value, json};
+
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+fn accepts_problem_json(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_type| {
+            media_type.split(';').next().is_some_and(|essence| {
+                essence
+                    .trim()
+                    .eq_ignore_ascii_case(PROBLEM_JSON_CONTENT_TYPE)
+            })
+        })
+}
+
+fn status_code_name(status: StatusCode) -> String {
+    status
+        .canonical_reason()
+        .unwrap_or("error")
+        .to_lowercase()
+        .replace([' ', '-'], "_")
+}
+
+pub fn problem_details(status: StatusCode, detail: &str, code: Option<&str>) -> Value {
+    json!({
+        "type": "about:blank",
+        "title": status.canonical_reason().unwrap_or("Error"),
+        "status": status.as_u16(),
+        "detail": detail,
+        "code": code.map_or_else(|| status_code_name(status), str::to_string),
+    })
+}
+
+pub async fn negotiate_problem_details(request: Request, next: Next) -> Response {
+    let problem_requested = accepts_problem_json(request.headers());
+    let response = next.run(request).await;
+
+    let status = response.status();
+    if !problem_requested || !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, MAX_ERROR_BODY_BYTES).await else {
+        return (status, "error body could not be read").into_response();
+    };
+
+    let envelope = serde_json::from_slice::<Value>(&bytes).ok();
+    let detail = envelope
+        .as_ref()
+        .and_then(|envelope| envelope.get("message"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| String::from_utf8_lossy(&bytes).trim().to_string());
+    let code = envelope
+        .as_ref()
+        .and_then(|envelope| envelope.get("code"))
+        .and_then(Value::as_str);
+
+    let problem = problem_details(status, &detail, code).to_string();
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+    );
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(problem))
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug)]
+pub struct TenantPoolSettings {
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub idle_timeout: Duration,
+    pub max_lifetime: Duration,
+}
+
+impl Default for TenantPoolSettings {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            min_connections: 0,
+            idle_timeout: Duration::from_secs(600),
+            max_lifetime: Duration::from_secs(1800),
+        }
+    }
+}
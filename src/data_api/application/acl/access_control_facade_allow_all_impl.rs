@@ -3,7 +3,9 @@ use async_trait::async_trait;
 use crate::data_api::{
     domain::model::enums::data_api_domain_error::DataApiDomainError,
     interfaces::acl::access_control_facade::{
-        AccessControlFacade, DataApiAuthorizationBootstrapRequest, DataApiAuthorizationCheckRequest,
+        AccessControlFacade, DataApiAuthorizationBootstrapRequest,
+        DataApiAuthorizationCheckRequest, DataApiEffectiveColumnsRequest,
+        DataApiEffectiveWriteColumns, DataApiRoleMembershipRequest,
     },
 };
 
@@ -30,10 +32,34 @@ impl AccessControlFacade for AccessControlFacadeAllowAllImpl {
         Ok(())
     }
 
+    async fn permitted_columns(
+        &self,
+        request: DataApiAuthorizationCheckRequest,
+    ) -> Result<Vec<String>, DataApiDomainError> {
+        Ok(request.requested_columns)
+    }
+
+    async fn effective_write_columns(
+        &self,
+        request: DataApiEffectiveColumnsRequest,
+    ) -> Result<DataApiEffectiveWriteColumns, DataApiDomainError> {
+        Ok(DataApiEffectiveWriteColumns {
+            create_columns: request.requested_columns.clone(),
+            update_columns: request.requested_columns,
+        })
+    }
+
     async fn bootstrap_table_access(
         &self,
         _request: DataApiAuthorizationBootstrapRequest,
     ) -> Result<(), DataApiDomainError> {
         Ok(())
     }
+
+    async fn principal_has_any_role(
+        &self,
+        _request: DataApiRoleMembershipRequest,
+    ) -> Result<bool, DataApiDomainError> {
+        Ok(true)
+    }
 }
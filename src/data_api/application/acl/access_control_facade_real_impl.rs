@@ -5,13 +5,16 @@ use async_trait::async_trait;
 use crate::{
     access_control::interfaces::acl::access_control_facade::{
         AccessControlFacade as AccessControlBcFacade, AccessControlPermissionRequest,
-        DataApiAccessBootstrapRequest,
+        DataApiAccessBootstrapRequest, EffectiveColumnsRequest, PrincipalRolesRequest,
     },
     data_api::{
-        domain::model::enums::data_api_domain_error::DataApiDomainError,
+        domain::model::enums::{
+            data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError,
+        },
         interfaces::acl::access_control_facade::{
             AccessControlFacade, DataApiAuthorizationBootstrapRequest,
-            DataApiAuthorizationCheckRequest,
+            DataApiAuthorizationCheckRequest, DataApiEffectiveColumnsRequest,
+            DataApiEffectiveWriteColumns, DataApiRoleMembershipRequest,
         },
     },
 };
@@ -43,6 +46,8 @@ impl AccessControlFacade for AccessControlFacadeRealImpl {
                 subject_owner_id: request.subject_owner_id,
                 row_owner_id: request.row_owner_id,
                 request_id: request.request_id,
+                bypass_decision_cache: request.bypass_decision_cache,
+                audit_decision: request.audit_decision,
             })
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
@@ -54,6 +59,56 @@ impl AccessControlFacade for AccessControlFacadeRealImpl {
         }
     }
 
+    async fn permitted_columns(
+        &self,
+        request: DataApiAuthorizationCheckRequest,
+    ) -> Result<Vec<String>, DataApiDomainError> {
+        self.facade
+            .find_permitted_columns(AccessControlPermissionRequest {
+                tenant_id: request.tenant_id,
+                principal_id: request.principal_id,
+                resource_name: request.resource_name,
+                action_name: request.action_name,
+                requested_columns: request.requested_columns,
+                subject_owner_id: request.subject_owner_id,
+                row_owner_id: request.row_owner_id,
+                request_id: request.request_id,
+                bypass_decision_cache: request.bypass_decision_cache,
+                audit_decision: request.audit_decision,
+            })
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn effective_write_columns(
+        &self,
+        request: DataApiEffectiveColumnsRequest,
+    ) -> Result<DataApiEffectiveWriteColumns, DataApiDomainError> {
+        let effective = self
+            .facade
+            .find_effective_columns(EffectiveColumnsRequest {
+                tenant_id: request.tenant_id,
+                principal_id: request.principal_id,
+                resource_name: request.resource_name,
+                requested_columns: request.requested_columns,
+                subject_owner_id: request.subject_owner_id,
+                row_owner_id: request.row_owner_id,
+            })
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        let mut columns = DataApiEffectiveWriteColumns::default();
+        for action in effective {
+            if action.action_name == DataApiAction::Create.as_str() {
+                columns.create_columns = action.permitted_columns;
+            } else if action.action_name == DataApiAction::Update.as_str() {
+                columns.update_columns = action.permitted_columns;
+            }
+        }
+
+        Ok(columns)
+    }
+
     async fn bootstrap_table_access(
         &self,
         request: DataApiAuthorizationBootstrapRequest,
@@ -69,4 +124,18 @@ impl AccessControlFacade for AccessControlFacadeRealImpl {
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
     }
+
+    async fn principal_has_any_role(
+        &self,
+        request: DataApiRoleMembershipRequest,
+    ) -> Result<bool, DataApiDomainError> {
+        self.facade
+            .find_principal_roles(PrincipalRolesRequest {
+                tenant_id: request.tenant_id,
+                principal_id: request.principal_id,
+            })
+            .await
+            .map(|roles| !roles.is_empty())
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+    }
 }
@@ -1,2 +1,3 @@
 pub mod access_control_facade_allow_all_impl;
 pub mod access_control_facade_real_impl;
+pub mod tenant_database_facade_impl;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::data_api::{
+    domain::model::value_objects::tenant_id::TenantId,
+    infrastructure::persistence::repositories::{
+        tenant_connection_resolver_repository::TenantConnectionResolverRepository,
+        tenant_pool_cache_repository::TenantPoolCacheRepository,
+    },
+    interfaces::acl::tenant_database_facade::{
+        TenantDatabaseFacade, TenantDatabasePingRequest, TenantDatabasePoolInvalidationRequest,
+    },
+};
+
+pub struct TenantDatabaseFacadeImpl {
+    tenant_connection_resolver: Arc<dyn TenantConnectionResolverRepository>,
+    tenant_pool_cache: Arc<dyn TenantPoolCacheRepository>,
+}
+
+impl TenantDatabaseFacadeImpl {
+    pub fn new(
+        tenant_connection_resolver: Arc<dyn TenantConnectionResolverRepository>,
+        tenant_pool_cache: Arc<dyn TenantPoolCacheRepository>,
+    ) -> Self {
+        Self {
+            tenant_connection_resolver,
+            tenant_pool_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl TenantDatabaseFacade for TenantDatabaseFacadeImpl {
+    async fn ping_tenant_database(&self, request: TenantDatabasePingRequest) -> Result<(), String> {
+        let tenant_id = TenantId::new(request.tenant_id).map_err(|e| e.to_string())?;
+        let database_url = self
+            .tenant_connection_resolver
+            .resolve_database_url(&tenant_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let pool = self
+            .tenant_pool_cache
+            .get_or_create_pool(&database_url)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        tokio::time::timeout(request.timeout, sqlx::query("SELECT 1").execute(&pool))
+            .await
+            .map_err(|_| "tenant database probe timed out".to_string())?
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    async fn invalidate_tenant_pools(&self, request: TenantDatabasePoolInvalidationRequest) {
+        let Ok(tenant_id) = TenantId::new(request.tenant_id) else {
+            return;
+        };
+        if let Ok(database_url) = self
+            .tenant_connection_resolver
+            .resolve_database_url(&tenant_id)
+            .await
+        {
+            self.tenant_pool_cache.invalidate_pool(&database_url).await;
+        }
+    }
+}
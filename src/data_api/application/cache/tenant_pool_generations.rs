@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use tokio::sync::RwLock;
+
+#[derive(Debug, Default)]
+pub struct TenantPoolGenerations {
+    generations: RwLock<HashMap<String, u64>>,
+}
+
+impl TenantPoolGenerations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn current(&self, database_name: &str) -> u64 {
+        self.generations
+            .read()
+            .await
+            .get(database_name)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub async fn invalidate_database(&self, database_name: &str) {
+        *self
+            .generations
+            .write()
+            .await
+            .entry(database_name.to_string())
+            .or_insert(0) += 1;
+    }
+}
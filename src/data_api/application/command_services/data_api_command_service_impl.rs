@@ -2,15 +2,26 @@ use std::{collections::HashSet, sync::Arc};
 
 use async_trait::async_trait;
 use chrono::Utc;
-use serde_json::{Map, Value};
+use serde_json::{Map, Value, json};
 use uuid::Uuid;
 
 use crate::data_api::{
+    application::{
+        ownership::row_owner_resolver::RowOwnerResolver,
+        projection::denied_column_stripper::DeniedColumnStripper,
+    },
     domain::{
         model::{
             commands::{
-                create_row_command::CreateRowCommand, delete_row_command::DeleteRowCommand,
-                patch_row_command::PatchRowCommand,
+                create_row_command::CreateRowCommand,
+                create_rows_command::CreateRowsCommand,
+                delete_row_command::DeleteRowCommand,
+                execute_transaction_command::{
+                    DataApiTransactionOperation, ExecuteTransactionCommand,
+                },
+                import_rows_command::ImportRowsCommand,
+                patch_row_command::{PatchRowCommand, PatchRowCommandParts},
+                replace_row_command::ReplaceRowCommand,
             },
             enums::{data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError},
             events::data_api_request_audited_event::DataApiRequestAuditedEvent,
@@ -20,22 +31,46 @@ use crate::data_api::{
     infrastructure::persistence::repositories::{
         data_api_audit_log_repository::DataApiAuditLogRepository,
         data_api_repository::{
-            CreateRowCriteria, DataApiRepository, DeleteRowCriteria, PatchRowCriteria,
+            CreateRowCriteria, CreateRowsCriteria, DataApiRepository, DataApiTransaction,
+            DeleteRowCriteria, GetRowByPrimaryKeyCriteria, PatchRowCriteria, PutRowCriteria,
+            TableAccessMetadata, TransactionOperationCriteria,
         },
         tenant_schema_resolver_repository::TenantSchemaResolverRepository,
     },
     interfaces::acl::access_control_facade::{
-        AccessControlFacade, DataApiAuthorizationBootstrapRequest, DataApiAuthorizationCheckRequest,
+        AccessControlFacade, DataApiAuthorizationBootstrapRequest,
+        DataApiAuthorizationCheckRequest, DataApiRoleMembershipRequest,
     },
 };
 
 const MAX_PAYLOAD_BYTES: usize = 64 * 1024;
+const IMPORT_BATCH_SIZE: usize = 500;
+
+#[derive(Clone, Debug)]
+pub struct DataApiCommandServiceSettings {
+    pub row_owner_column: Option<String>,
+    pub max_batch_size: usize,
+    pub strip_denied_columns: bool,
+}
+
+impl Default for DataApiCommandServiceSettings {
+    fn default() -> Self {
+        Self {
+            row_owner_column: None,
+            max_batch_size: 10_000,
+            strip_denied_columns: false,
+        }
+    }
+}
 
 pub struct DataApiCommandServiceImpl {
     repository: Arc<dyn DataApiRepository>,
     tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
     access_control_facade: Arc<dyn AccessControlFacade>,
     audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+    row_owner: RowOwnerResolver,
+    denied_columns: DeniedColumnStripper,
+    max_batch_size: usize,
 }
 
 struct AuditContext<'a> {
@@ -50,21 +85,173 @@ struct AuditContext<'a> {
     details: Option<String>,
 }
 
+struct PreparedCreate {
+    schema_name: String,
+    allowed_columns: Vec<String>,
+    denied_columns: Vec<String>,
+    payload: Value,
+}
+
+struct PreparedPatch {
+    schema_name: String,
+    primary_key_column: String,
+    allowed_columns: Vec<String>,
+    defaulted_columns: Vec<String>,
+    denied_columns: Vec<String>,
+    payload: Value,
+    soft_delete_column: Option<String>,
+}
+
+struct PreparedDelete {
+    schema_name: String,
+    primary_key_column: String,
+    soft_delete_column: Option<String>,
+}
+
+enum PreparedTransactionOperation<'a> {
+    Create(&'a CreateRowCommand, PreparedCreate),
+    Patch(&'a PatchRowCommand, PreparedPatch),
+    Delete(&'a DeleteRowCommand, PreparedDelete),
+}
+
+impl PreparedTransactionOperation<'_> {
+    fn criteria(&self) -> TransactionOperationCriteria<'_> {
+        match self {
+            Self::Create(command, prepared) => {
+                TransactionOperationCriteria::Create(CreateRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    payload: &prepared.payload,
+                    allowed_columns: &prepared.allowed_columns,
+                })
+            }
+            Self::Patch(command, prepared) => {
+                TransactionOperationCriteria::Patch(PatchRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    primary_key_column: &prepared.primary_key_column,
+                    primary_key_value: command.row_identifier().value(),
+                    payload: &prepared.payload,
+                    allowed_columns: &prepared.allowed_columns,
+                    soft_delete_column: prepared.soft_delete_column.as_deref(),
+                })
+            }
+            Self::Delete(command, prepared) => {
+                TransactionOperationCriteria::Delete(DeleteRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    primary_key_column: &prepared.primary_key_column,
+                    primary_key_value: command.row_identifier().value(),
+                    soft_delete_column: prepared.soft_delete_column.as_deref(),
+                })
+            }
+        }
+    }
+
+    fn denied_columns(&self) -> &[String] {
+        match self {
+            Self::Create(_, prepared) => &prepared.denied_columns,
+            Self::Patch(_, prepared) => &prepared.denied_columns,
+            Self::Delete(..) => &[],
+        }
+    }
+
+    fn audit_context(
+        &self,
+        success: bool,
+        status_code: u16,
+        details: Option<String>,
+    ) -> AuditContext<'_> {
+        let (tenant_id, request_id, schema_name, table_name, action, principal, success_status) =
+            match self {
+                Self::Create(command, prepared) => (
+                    command.tenant_id(),
+                    command.request_id(),
+                    prepared.schema_name.as_str(),
+                    command.table_name().value(),
+                    DataApiAction::Create,
+                    command.principal(),
+                    201,
+                ),
+                Self::Patch(command, prepared) => (
+                    command.tenant_id(),
+                    command.request_id(),
+                    prepared.schema_name.as_str(),
+                    command.table_name().value(),
+                    DataApiAction::Update,
+                    command.principal(),
+                    200,
+                ),
+                Self::Delete(command, prepared) => (
+                    command.tenant_id(),
+                    command.request_id(),
+                    prepared.schema_name.as_str(),
+                    command.table_name().value(),
+                    DataApiAction::Delete,
+                    command.principal(),
+                    204,
+                ),
+            };
+
+        AuditContext {
+            tenant_id: tenant_id.value(),
+            request_id: request_id.map(str::to_string),
+            schema_name,
+            table_name,
+            action,
+            principal,
+            success,
+            status_code: if success { success_status } else { status_code },
+            details,
+        }
+    }
+}
+
 impl DataApiCommandServiceImpl {
     pub fn new(
         repository: Arc<dyn DataApiRepository>,
         tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
         access_control_facade: Arc<dyn AccessControlFacade>,
         audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+    ) -> Self {
+        Self::new_with_settings(
+            repository,
+            tenant_schema_resolver,
+            access_control_facade,
+            audit_log_repository,
+            DataApiCommandServiceSettings::default(),
+        )
+    }
+
+    pub fn new_with_settings(
+        repository: Arc<dyn DataApiRepository>,
+        tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
+        access_control_facade: Arc<dyn AccessControlFacade>,
+        audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+        settings: DataApiCommandServiceSettings,
     ) -> Self {
         Self {
+            row_owner: RowOwnerResolver::new(repository.clone(), settings.row_owner_column),
+            denied_columns: DeniedColumnStripper::new(
+                access_control_facade.clone(),
+                settings.strip_denied_columns,
+            ),
             repository,
             tenant_schema_resolver,
             access_control_facade,
             audit_log_repository,
+            max_batch_size: settings.max_batch_size,
         }
     }
 
+    fn ensure_batch_size(&self, rows: usize) -> Result<(), DataApiDomainError> {
+        if rows > self.max_batch_size {
+            return Err(DataApiDomainError::BatchTooLarge(self.max_batch_size));
+        }
+
+        Ok(())
+    }
+
     fn ensure_action_allowed(
         action_enabled: bool,
         table_exposed: bool,
@@ -76,6 +263,16 @@ impl DataApiCommandServiceImpl {
         Ok(())
     }
 
+    fn ensure_not_append_only(
+        access_metadata: &TableAccessMetadata,
+    ) -> Result<(), DataApiDomainError> {
+        if access_metadata.exposed && access_metadata.append_only {
+            return Err(DataApiDomainError::TableAppendOnly);
+        }
+
+        Ok(())
+    }
+
     fn payload_columns(payload: &Value) -> Result<Vec<String>, DataApiDomainError> {
         let object = payload
             .as_object()
@@ -117,6 +314,84 @@ impl DataApiCommandServiceImpl {
         Value::Object(map)
     }
 
+    fn parse_csv_records(content: &str) -> Result<Vec<(usize, Vec<String>)>, DataApiDomainError> {
+        let mut records = Vec::new();
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut line = 1;
+        let mut record_line = 1;
+        let mut chars = content.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes => {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                }
+                '"' if field.is_empty() => in_quotes = true,
+                ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+                '\r' if !in_quotes => {}
+                '\n' if !in_quotes => {
+                    fields.push(std::mem::take(&mut field));
+                    Self::push_csv_record(&mut records, record_line, std::mem::take(&mut fields));
+                    line += 1;
+                    record_line = line;
+                }
+                '\n' => {
+                    field.push(c);
+                    line += 1;
+                }
+                _ => field.push(c),
+            }
+        }
+
+        if in_quotes {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
+
+        if !field.is_empty() || !fields.is_empty() {
+            fields.push(field);
+            Self::push_csv_record(&mut records, record_line, fields);
+        }
+
+        Ok(records)
+    }
+
+    fn push_csv_record(records: &mut Vec<(usize, Vec<String>)>, line: usize, fields: Vec<String>) {
+        if fields.len() == 1 && fields[0].trim().is_empty() {
+            return;
+        }
+
+        records.push((line, fields));
+    }
+
+    fn csv_value(data_type: &str, raw: &str) -> Result<Value, String> {
+        if raw.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        let valid = match data_type {
+            "smallint" | "integer" | "bigint" => raw.parse::<i64>().is_ok(),
+            "numeric" | "real" | "double precision" => raw.parse::<f64>().is_ok(),
+            "boolean" => matches!(
+                raw.to_ascii_lowercase().as_str(),
+                "true" | "false" | "t" | "f" | "1" | "0"
+            ),
+            _ => true,
+        };
+
+        if !valid {
+            return Err(format!("value '{raw}' is not a valid {data_type}"));
+        }
+
+        Ok(Value::String(raw.to_string()))
+    }
+
     async fn audit(&self, context: AuditContext<'_>) {
         let _ = self
             .audit_log_repository
@@ -148,15 +423,25 @@ impl DataApiCommandServiceImpl {
             self.access_control_facade
                 .check_table_permission(request)
                 .await?;
+        } else if authorization_mode.eq_ignore_ascii_case("role_required")
+            && !self
+                .access_control_facade
+                .principal_has_any_role(DataApiRoleMembershipRequest {
+                    tenant_id: request.tenant_id,
+                    principal_id: request.principal_id,
+                })
+                .await?
+        {
+            return Err(DataApiDomainError::AccessDenied);
         }
 
         Ok(())
     }
-}
 
-#[async_trait]
-impl DataApiCommandService for DataApiCommandServiceImpl {
-    async fn handle_create(&self, command: CreateRowCommand) -> Result<Value, DataApiDomainError> {
+    async fn prepare_create(
+        &self,
+        command: &CreateRowCommand,
+    ) -> Result<PreparedCreate, DataApiDomainError> {
         Self::ensure_payload_size(command.payload())?;
 
         let schema_name = self
@@ -205,6 +490,22 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
             .into_iter()
             .filter(|column| metadata.has_column(column))
             .collect::<Vec<_>>();
+        let check_request = DataApiAuthorizationCheckRequest {
+            tenant_id: command.tenant_id().value().to_string(),
+            principal_id: command.principal().to_string(),
+            resource_name: command.table_name().value().to_string(),
+            action_name: DataApiAction::Create.as_str().to_string(),
+            requested_columns: allowed_columns.clone(),
+            subject_owner_id: self
+                .row_owner
+                .subject_owner_id(command.principal(), command.subject_owner_id()),
+            row_owner_id: self
+                .row_owner
+                .payload_row_owner_id(command.payload(), command.row_owner_id()),
+            request_id: command.request_id().map(str::to_string),
+            bypass_decision_cache: false,
+            audit_decision: true,
+        };
 
         self.enforce_acl_if_required(
             &access_metadata.authorization_mode,
@@ -219,68 +520,34 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                     .collect(),
                 writable_columns: writable_columns.iter().cloned().collect(),
             },
-            DataApiAuthorizationCheckRequest {
-                tenant_id: command.tenant_id().value().to_string(),
-                principal_id: command.principal().to_string(),
-                resource_name: command.table_name().value().to_string(),
-                action_name: DataApiAction::Create.as_str().to_string(),
-                requested_columns: allowed_columns.clone(),
-                subject_owner_id: command.subject_owner_id().map(str::to_string),
-                row_owner_id: command.row_owner_id().map(str::to_string),
-                request_id: command.request_id().map(str::to_string),
-            },
+            check_request.clone(),
         )
         .await?;
+        let denied_columns = self
+            .denied_columns
+            .denied_columns(
+                &access_metadata.authorization_mode,
+                &check_request,
+                &metadata,
+            )
+            .await?;
 
         let filtered_payload = Self::filter_allowed_payload(command.payload(), &allowed_columns);
-        let result = self
-            .repository
-            .create_row(
-                command.tenant_id(),
-                CreateRowCriteria {
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    payload: &filtered_payload,
-                    allowed_columns: &allowed_columns,
-                },
-            )
-            .await;
 
-        match result {
-            Ok(row) => {
-                self.audit(AuditContext {
-                    tenant_id: command.tenant_id().value(),
-                    request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    action: DataApiAction::Create,
-                    principal: command.principal(),
-                    success: true,
-                    status_code: 201,
-                    details: None,
-                })
-                .await;
-                Ok(row)
-            }
-            Err(error) => {
-                self.audit(AuditContext {
-                    tenant_id: command.tenant_id().value(),
-                    request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    action: DataApiAction::Create,
-                    principal: command.principal(),
-                    success: false,
-                    status_code: 500,
-                    details: Some(error.to_string()),
-                })
-                .await;
-                Err(error)
-            }
-        }
+        Ok(PreparedCreate {
+            schema_name: schema_name.value().to_string(),
+            allowed_columns,
+            denied_columns,
+            payload: filtered_payload,
+        })
     }
 
-    async fn handle_patch(&self, command: PatchRowCommand) -> Result<Value, DataApiDomainError> {
+    async fn prepare_patch(
+        &self,
+        command: &PatchRowCommand,
+        replace: bool,
+        transaction: Option<&mut dyn DataApiTransaction>,
+    ) -> Result<PreparedPatch, DataApiDomainError> {
         Self::ensure_payload_size(command.payload())?;
 
         let schema_name = self
@@ -301,6 +568,7 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
             )
             .await?;
 
+        Self::ensure_not_append_only(&access_metadata)?;
         Self::ensure_action_allowed(access_metadata.update_enabled, access_metadata.exposed)?;
 
         let requested_columns = Self::payload_columns(command.payload())?;
@@ -311,10 +579,11 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                 schema_name.value(),
                 command.table_name().value(),
             )
-            .await?
-            .into_iter()
-            .collect::<HashSet<_>>();
-        Self::ensure_editable_columns(&writable_columns, &requested_columns)?;
+            .await?;
+        Self::ensure_editable_columns(
+            &writable_columns.iter().cloned().collect::<HashSet<_>>(),
+            &requested_columns,
+        )?;
 
         let metadata = self
             .repository
@@ -332,7 +601,70 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
         let allowed_columns = requested_columns
             .into_iter()
             .filter(|column| metadata.has_column(column) && column != &primary_key.column_name)
+            .filter(|column| !(command.skip_null_values() && command.payload()[column].is_null()))
             .collect::<Vec<_>>();
+        let defaulted_columns = if replace {
+            writable_columns
+                .iter()
+                .filter(|column| {
+                    metadata.has_column(column)
+                        && *column != &primary_key.column_name
+                        && !allowed_columns.contains(column)
+                        && Some(column.as_str()) != self.row_owner.row_owner_column()
+                        && Some(column.as_str()) != access_metadata.soft_delete_column.as_deref()
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        let stored_row_criteria = GetRowByPrimaryKeyCriteria {
+            schema_name: schema_name.value().to_string(),
+            table_name: command.table_name().value().to_string(),
+            primary_key_column: primary_key.column_name.clone(),
+            primary_key_value: command.row_identifier().value().to_string(),
+            text_columns: vec![],
+            soft_delete_column: access_metadata.soft_delete_column.clone(),
+        };
+        let row_owner_id = match transaction {
+            Some(transaction) => {
+                self.row_owner
+                    .stored_row_owner_id_in_transaction(
+                        transaction,
+                        stored_row_criteria,
+                        command.row_owner_id(),
+                    )
+                    .await?
+            }
+            None => {
+                self.row_owner
+                    .stored_row_owner_id(
+                        command.tenant_id(),
+                        stored_row_criteria,
+                        command.row_owner_id(),
+                    )
+                    .await?
+            }
+        };
+        let check_request = DataApiAuthorizationCheckRequest {
+            tenant_id: command.tenant_id().value().to_string(),
+            principal_id: command.principal().to_string(),
+            resource_name: command.table_name().value().to_string(),
+            action_name: DataApiAction::Update.as_str().to_string(),
+            requested_columns: allowed_columns
+                .iter()
+                .chain(defaulted_columns.iter())
+                .cloned()
+                .collect(),
+            subject_owner_id: self
+                .row_owner
+                .subject_owner_id(command.principal(), command.subject_owner_id()),
+            row_owner_id,
+            request_id: command.request_id().map(str::to_string),
+            bypass_decision_cache: false,
+            audit_decision: true,
+        };
 
         self.enforce_acl_if_required(
             &access_metadata.authorization_mode,
@@ -345,88 +677,38 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                     .iter()
                     .map(|column| column.column_name.clone())
                     .collect(),
-                writable_columns: writable_columns.iter().cloned().collect(),
-            },
-            DataApiAuthorizationCheckRequest {
-                tenant_id: command.tenant_id().value().to_string(),
-                principal_id: command.principal().to_string(),
-                resource_name: command.table_name().value().to_string(),
-                action_name: DataApiAction::Update.as_str().to_string(),
-                requested_columns: allowed_columns.clone(),
-                subject_owner_id: command.subject_owner_id().map(str::to_string),
-                row_owner_id: command.row_owner_id().map(str::to_string),
-                request_id: command.request_id().map(str::to_string),
+                writable_columns: writable_columns.clone(),
             },
+            check_request.clone(),
         )
         .await?;
+        let denied_columns = self
+            .denied_columns
+            .denied_columns(
+                &access_metadata.authorization_mode,
+                &check_request,
+                &metadata,
+            )
+            .await?;
 
         let filtered_payload = Self::filter_allowed_payload(command.payload(), &allowed_columns);
 
-        let result = self
-            .repository
-            .patch_row(
-                command.tenant_id(),
-                PatchRowCriteria {
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    primary_key_column: &primary_key.column_name,
-                    primary_key_value: command.row_identifier().value(),
-                    payload: &filtered_payload,
-                    allowed_columns: &allowed_columns,
-                },
-            )
-            .await;
-
-        match result {
-            Ok(Some(row)) => {
-                self.audit(AuditContext {
-                    tenant_id: command.tenant_id().value(),
-                    request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    action: DataApiAction::Update,
-                    principal: command.principal(),
-                    success: true,
-                    status_code: 200,
-                    details: None,
-                })
-                .await;
-                Ok(row)
-            }
-            Ok(None) => {
-                self.audit(AuditContext {
-                    tenant_id: command.tenant_id().value(),
-                    request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    action: DataApiAction::Update,
-                    principal: command.principal(),
-                    success: false,
-                    status_code: 404,
-                    details: Some("record not found".to_string()),
-                })
-                .await;
-                Err(DataApiDomainError::RecordNotFound)
-            }
-            Err(error) => {
-                self.audit(AuditContext {
-                    tenant_id: command.tenant_id().value(),
-                    request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
-                    table_name: command.table_name().value(),
-                    action: DataApiAction::Update,
-                    principal: command.principal(),
-                    success: false,
-                    status_code: 500,
-                    details: Some(error.to_string()),
-                })
-                .await;
-                Err(error)
-            }
-        }
+        Ok(PreparedPatch {
+            schema_name: schema_name.value().to_string(),
+            primary_key_column: primary_key.column_name.clone(),
+            allowed_columns,
+            defaulted_columns,
+            denied_columns,
+            payload: filtered_payload,
+            soft_delete_column: access_metadata.soft_delete_column.clone(),
+        })
     }
 
-    async fn handle_delete(&self, command: DeleteRowCommand) -> Result<(), DataApiDomainError> {
+    async fn prepare_delete(
+        &self,
+        command: &DeleteRowCommand,
+        transaction: Option<&mut dyn DataApiTransaction>,
+    ) -> Result<PreparedDelete, DataApiDomainError> {
         let schema_name = self
             .tenant_schema_resolver
             .resolve_schema(command.tenant_id(), Some(command.schema_name().value()))
@@ -445,6 +727,7 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
             )
             .await?;
 
+        Self::ensure_not_append_only(&access_metadata)?;
         Self::ensure_action_allowed(access_metadata.delete_enabled, access_metadata.exposed)?;
 
         let metadata = self
@@ -460,6 +743,35 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
             .primary_key_column()
             .ok_or(DataApiDomainError::PrimaryKeyNotFound)?;
 
+        let stored_row_criteria = GetRowByPrimaryKeyCriteria {
+            schema_name: schema_name.value().to_string(),
+            table_name: command.table_name().value().to_string(),
+            primary_key_column: primary_key.column_name.clone(),
+            primary_key_value: command.row_identifier().value().to_string(),
+            text_columns: vec![],
+            soft_delete_column: access_metadata.soft_delete_column.clone(),
+        };
+        let row_owner_id = match transaction {
+            Some(transaction) => {
+                self.row_owner
+                    .stored_row_owner_id_in_transaction(
+                        transaction,
+                        stored_row_criteria,
+                        command.row_owner_id(),
+                    )
+                    .await?
+            }
+            None => {
+                self.row_owner
+                    .stored_row_owner_id(
+                        command.tenant_id(),
+                        stored_row_criteria,
+                        command.row_owner_id(),
+                    )
+                    .await?
+            }
+        };
+
         self.enforce_acl_if_required(
             &access_metadata.authorization_mode,
             DataApiAuthorizationBootstrapRequest {
@@ -486,31 +798,431 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                 resource_name: command.table_name().value().to_string(),
                 action_name: DataApiAction::Delete.as_str().to_string(),
                 requested_columns: vec![primary_key.column_name.clone()],
-                subject_owner_id: command.subject_owner_id().map(str::to_string),
-                row_owner_id: command.row_owner_id().map(str::to_string),
+                subject_owner_id: self
+                    .row_owner
+                    .subject_owner_id(command.principal(), command.subject_owner_id()),
+                row_owner_id,
                 request_id: command.request_id().map(str::to_string),
+                bypass_decision_cache: false,
+                audit_decision: true,
             },
         )
         .await?;
 
-        match self
+        Ok(PreparedDelete {
+            schema_name: schema_name.value().to_string(),
+            primary_key_column: primary_key.column_name.clone(),
+            soft_delete_column: access_metadata.soft_delete_column.clone(),
+        })
+    }
+
+    async fn prepare_transaction_operation<'a>(
+        &self,
+        transaction: &mut dyn DataApiTransaction,
+        operation: &'a DataApiTransactionOperation,
+    ) -> Result<PreparedTransactionOperation<'a>, DataApiDomainError> {
+        Ok(match operation {
+            DataApiTransactionOperation::Create(create) => {
+                PreparedTransactionOperation::Create(create, self.prepare_create(create).await?)
+            }
+            DataApiTransactionOperation::Patch(patch) => PreparedTransactionOperation::Patch(
+                patch,
+                self.prepare_patch(patch, false, Some(transaction)).await?,
+            ),
+            DataApiTransactionOperation::Delete(delete) => PreparedTransactionOperation::Delete(
+                delete,
+                self.prepare_delete(delete, Some(transaction)).await?,
+            ),
+        })
+    }
+}
+
+#[async_trait]
+impl DataApiCommandService for DataApiCommandServiceImpl {
+    async fn handle_create(&self, command: CreateRowCommand) -> Result<Value, DataApiDomainError> {
+        let prepared = self.prepare_create(&command).await?;
+        let result = self
             .repository
-            .delete_row(
+            .create_row(
                 command.tenant_id(),
-                DeleteRowCriteria {
-                    schema_name: schema_name.value(),
+                CreateRowCriteria {
+                    schema_name: &prepared.schema_name,
                     table_name: command.table_name().value(),
-                    primary_key_column: &primary_key.column_name,
-                    primary_key_value: command.row_identifier().value(),
+                    payload: &prepared.payload,
+                    allowed_columns: &prepared.allowed_columns,
                 },
             )
-            .await
+            .await;
+
+        match result {
+            Ok(mut row) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Create,
+                    principal: command.principal(),
+                    success: true,
+                    status_code: 201,
+                    details: None,
+                })
+                .await;
+                DeniedColumnStripper::strip(&prepared.denied_columns, &mut row);
+                Ok(row)
+            }
+            Err(error) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Create,
+                    principal: command.principal(),
+                    success: false,
+                    status_code: error.status_code(),
+                    details: Some(error.to_string()),
+                })
+                .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn handle_create_batch(
+        &self,
+        command: CreateRowsCommand,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        self.ensure_batch_size(command.payloads().len())?;
+        Self::ensure_payload_size(&Value::Array(command.payloads().to_vec()))?;
+
+        let schema_name = self
+            .tenant_schema_resolver
+            .resolve_schema(command.tenant_id(), Some(command.schema_name().value()))
+            .await?;
+
+        self.repository
+            .synchronize_metadata(command.tenant_id(), schema_name.value())
+            .await?;
+
+        let access_metadata = self
+            .repository
+            .get_table_access_metadata(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?;
+
+        Self::ensure_action_allowed(access_metadata.create_enabled, access_metadata.exposed)?;
+
+        let mut requested_columns = Vec::new();
+        for payload in command.payloads() {
+            for column in Self::payload_columns(payload)? {
+                if !requested_columns.contains(&column) {
+                    requested_columns.push(column);
+                }
+            }
+        }
+
+        let writable_columns = self
+            .repository
+            .list_writable_columns(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+        Self::ensure_editable_columns(&writable_columns, &requested_columns)?;
+
+        let metadata = self
+            .repository
+            .introspect_table(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?;
+
+        let allowed_columns = requested_columns
+            .into_iter()
+            .filter(|column| metadata.has_column(column))
+            .collect::<Vec<_>>();
+
+        let mut row_owner_ids = Vec::new();
+        for payload in command.payloads() {
+            let row_owner_id = self
+                .row_owner
+                .payload_row_owner_id(payload, command.row_owner_id());
+            if !row_owner_ids.contains(&row_owner_id) {
+                row_owner_ids.push(row_owner_id);
+            }
+        }
+
+        let mut denied_columns = Vec::new();
+        for row_owner_id in row_owner_ids {
+            let check_request = DataApiAuthorizationCheckRequest {
+                tenant_id: command.tenant_id().value().to_string(),
+                principal_id: command.principal().to_string(),
+                resource_name: command.table_name().value().to_string(),
+                action_name: DataApiAction::Create.as_str().to_string(),
+                requested_columns: allowed_columns.clone(),
+                subject_owner_id: self
+                    .row_owner
+                    .subject_owner_id(command.principal(), command.subject_owner_id()),
+                row_owner_id,
+                request_id: command.request_id().map(str::to_string),
+                bypass_decision_cache: false,
+                audit_decision: true,
+            };
+            self.enforce_acl_if_required(
+                &access_metadata.authorization_mode,
+                DataApiAuthorizationBootstrapRequest {
+                    tenant_id: command.tenant_id().value().to_string(),
+                    principal_id: command.principal().to_string(),
+                    resource_name: command.table_name().value().to_string(),
+                    readable_columns: metadata
+                        .columns
+                        .iter()
+                        .map(|column| column.column_name.clone())
+                        .collect(),
+                    writable_columns: writable_columns.iter().cloned().collect(),
+                },
+                check_request.clone(),
+            )
+            .await?;
+            for column in self
+                .denied_columns
+                .denied_columns(
+                    &access_metadata.authorization_mode,
+                    &check_request,
+                    &metadata,
+                )
+                .await?
+            {
+                if !denied_columns.contains(&column) {
+                    denied_columns.push(column);
+                }
+            }
+        }
+
+        let filtered_payloads = command
+            .payloads()
+            .iter()
+            .map(|payload| Self::filter_allowed_payload(payload, &allowed_columns))
+            .collect::<Vec<_>>();
+        let result = self
+            .repository
+            .create_rows(
+                command.tenant_id(),
+                CreateRowsCriteria {
+                    schema_name: schema_name.value(),
+                    table_name: command.table_name().value(),
+                    payloads: &filtered_payloads,
+                    allowed_columns: &allowed_columns,
+                },
+            )
+            .await;
+
+        match result {
+            Ok(mut rows) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: schema_name.value(),
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Create,
+                    principal: command.principal(),
+                    success: true,
+                    status_code: 201,
+                    details: Some(format!("created {} rows", rows.len())),
+                })
+                .await;
+                for row in &mut rows {
+                    DeniedColumnStripper::strip(&denied_columns, row);
+                }
+                Ok(rows)
+            }
+            Err(error) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: schema_name.value(),
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Create,
+                    principal: command.principal(),
+                    success: false,
+                    status_code: error.status_code(),
+                    details: Some(format!(
+                        "rolled back batch of {} rows: {}",
+                        filtered_payloads.len(),
+                        error
+                    )),
+                })
+                .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn handle_patch(&self, command: PatchRowCommand) -> Result<Value, DataApiDomainError> {
+        let prepared = self.prepare_patch(&command, false, None).await?;
+
+        let result = self
+            .repository
+            .patch_row(
+                command.tenant_id(),
+                PatchRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    primary_key_column: &prepared.primary_key_column,
+                    primary_key_value: command.row_identifier().value(),
+                    payload: &prepared.payload,
+                    allowed_columns: &prepared.allowed_columns,
+                    soft_delete_column: prepared.soft_delete_column.as_deref(),
+                },
+            )
+            .await;
+
+        match result {
+            Ok(Some(mut row)) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Update,
+                    principal: command.principal(),
+                    success: true,
+                    status_code: 200,
+                    details: None,
+                })
+                .await;
+                DeniedColumnStripper::strip(&prepared.denied_columns, &mut row);
+                Ok(row)
+            }
+            Ok(None) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Update,
+                    principal: command.principal(),
+                    success: false,
+                    status_code: 404,
+                    details: Some("record not found".to_string()),
+                })
+                .await;
+                Err(DataApiDomainError::RecordNotFound)
+            }
+            Err(error) => {
+                self.audit(AuditContext {
+                    tenant_id: command.tenant_id().value(),
+                    request_id: command.request_id().map(str::to_string),
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    action: DataApiAction::Update,
+                    principal: command.principal(),
+                    success: false,
+                    status_code: 500,
+                    details: Some(error.to_string()),
+                })
+                .await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn handle_replace(
+        &self,
+        command: ReplaceRowCommand,
+    ) -> Result<Value, DataApiDomainError> {
+        let patch = PatchRowCommand::new(PatchRowCommandParts {
+            api_version: command.api_version().value().to_string(),
+            tenant_id: command.tenant_id().value().to_string(),
+            schema_name: command.schema_name().value().to_string(),
+            table_name: command.table_name().value().to_string(),
+            row_identifier: command.row_identifier().value().to_string(),
+            principal: command.principal().to_string(),
+            principal_type: command.principal_type(),
+            request_id: command.request_id().map(str::to_string),
+            subject_owner_id: command.subject_owner_id().map(str::to_string),
+            row_owner_id: command.row_owner_id().map(str::to_string),
+            payload: command.payload().clone(),
+            skip_null_values: false,
+        })?;
+        let prepared = self.prepare_patch(&patch, true, None).await?;
+
+        let result = self
+            .repository
+            .put_row(
+                command.tenant_id(),
+                PutRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    primary_key_column: &prepared.primary_key_column,
+                    primary_key_value: command.row_identifier().value(),
+                    payload: &prepared.payload,
+                    assigned_columns: &prepared.allowed_columns,
+                    defaulted_columns: &prepared.defaulted_columns,
+                    soft_delete_column: prepared.soft_delete_column.as_deref(),
+                },
+            )
+            .await;
+
+        let (success, status_code, details) = match &result {
+            Ok(Some(_)) => (true, 200, None),
+            Ok(None) => (false, 404, Some("record not found".to_string())),
+            Err(DataApiDomainError::InvalidPayload) => (
+                false,
+                400,
+                Some("omitted column requires a value".to_string()),
+            ),
+            Err(error) => (false, 500, Some(error.to_string())),
+        };
+        self.audit(AuditContext {
+            tenant_id: command.tenant_id().value(),
+            request_id: command.request_id().map(str::to_string),
+            schema_name: &prepared.schema_name,
+            table_name: command.table_name().value(),
+            action: DataApiAction::Update,
+            principal: command.principal(),
+            success,
+            status_code,
+            details,
+        })
+        .await;
+
+        let mut row = result?.ok_or(DataApiDomainError::RecordNotFound)?;
+        DeniedColumnStripper::strip(&prepared.denied_columns, &mut row);
+        Ok(row)
+    }
+
+    async fn handle_delete(&self, command: DeleteRowCommand) -> Result<(), DataApiDomainError> {
+        let prepared = self.prepare_delete(&command, None).await?;
+
+        match self
+            .repository
+            .delete_row(
+                command.tenant_id(),
+                DeleteRowCriteria {
+                    schema_name: &prepared.schema_name,
+                    table_name: command.table_name().value(),
+                    primary_key_column: &prepared.primary_key_column,
+                    primary_key_value: command.row_identifier().value(),
+                    soft_delete_column: prepared.soft_delete_column.as_deref(),
+                },
+            )
+            .await
         {
             Ok(true) => {
                 self.audit(AuditContext {
                     tenant_id: command.tenant_id().value(),
                     request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
+                    schema_name: &prepared.schema_name,
                     table_name: command.table_name().value(),
                     action: DataApiAction::Delete,
                     principal: command.principal(),
@@ -525,7 +1237,7 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                 self.audit(AuditContext {
                     tenant_id: command.tenant_id().value(),
                     request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
+                    schema_name: &prepared.schema_name,
                     table_name: command.table_name().value(),
                     action: DataApiAction::Delete,
                     principal: command.principal(),
@@ -540,7 +1252,7 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
                 self.audit(AuditContext {
                     tenant_id: command.tenant_id().value(),
                     request_id: command.request_id().map(str::to_string),
-                    schema_name: schema_name.value(),
+                    schema_name: &prepared.schema_name,
                     table_name: command.table_name().value(),
                     action: DataApiAction::Delete,
                     principal: command.principal(),
@@ -553,4 +1265,288 @@ impl DataApiCommandService for DataApiCommandServiceImpl {
             }
         }
     }
+
+    async fn handle_import(&self, command: ImportRowsCommand) -> Result<Value, DataApiDomainError> {
+        let schema_name = self
+            .tenant_schema_resolver
+            .resolve_schema(command.tenant_id(), Some(command.schema_name().value()))
+            .await?;
+
+        self.repository
+            .synchronize_metadata(command.tenant_id(), schema_name.value())
+            .await?;
+
+        let access_metadata = self
+            .repository
+            .get_table_access_metadata(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?;
+
+        Self::ensure_action_allowed(access_metadata.create_enabled, access_metadata.exposed)?;
+
+        let mut records = Self::parse_csv_records(command.csv_content())?.into_iter();
+        let (_, header) = records.next().ok_or(DataApiDomainError::InvalidPayload)?;
+        self.ensure_batch_size(records.len())?;
+        let header = header
+            .into_iter()
+            .map(|column| column.trim().to_string())
+            .collect::<Vec<_>>();
+        if header.iter().collect::<HashSet<_>>().len() != header.len() {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
+
+        let writable_columns = self
+            .repository
+            .list_writable_columns(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+        Self::ensure_editable_columns(&writable_columns, &header)?;
+
+        let metadata = self
+            .repository
+            .introspect_table(
+                command.tenant_id(),
+                schema_name.value(),
+                command.table_name().value(),
+            )
+            .await?;
+
+        let column_types = header
+            .iter()
+            .map(|column| {
+                metadata
+                    .columns
+                    .iter()
+                    .find(|c| &c.column_name == column)
+                    .map(|c| c.data_type.clone())
+                    .ok_or_else(|| DataApiDomainError::NonEditableColumn(column.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.enforce_acl_if_required(
+            &access_metadata.authorization_mode,
+            DataApiAuthorizationBootstrapRequest {
+                tenant_id: command.tenant_id().value().to_string(),
+                principal_id: command.principal().to_string(),
+                resource_name: command.table_name().value().to_string(),
+                readable_columns: metadata
+                    .columns
+                    .iter()
+                    .map(|column| column.column_name.clone())
+                    .collect(),
+                writable_columns: writable_columns.iter().cloned().collect(),
+            },
+            DataApiAuthorizationCheckRequest {
+                tenant_id: command.tenant_id().value().to_string(),
+                principal_id: command.principal().to_string(),
+                resource_name: command.table_name().value().to_string(),
+                action_name: DataApiAction::Create.as_str().to_string(),
+                requested_columns: header.clone(),
+                subject_owner_id: self
+                    .row_owner
+                    .subject_owner_id(command.principal(), command.subject_owner_id()),
+                row_owner_id: self.row_owner.header_row_owner_id(command.row_owner_id()),
+                request_id: command.request_id().map(str::to_string),
+                bypass_decision_cache: false,
+                audit_decision: true,
+            },
+        )
+        .await?;
+
+        let mut errors = Vec::new();
+        let mut batches: Vec<Vec<Value>> = Vec::new();
+        let mut batch_payloads = Vec::new();
+        let mut batch_bytes = 0;
+
+        for (line, fields) in records {
+            if fields.len() != header.len() {
+                errors.push(json!({
+                    "line": line,
+                    "message": format!("expected {} fields, found {}", header.len(), fields.len())
+                }));
+                continue;
+            }
+
+            let row = header
+                .iter()
+                .zip(&column_types)
+                .zip(&fields)
+                .map(|((column, data_type), raw)| {
+                    Self::csv_value(data_type, raw).map(|value| (column.clone(), value))
+                })
+                .collect::<Result<Map<_, _>, _>>();
+
+            let row = match row {
+                Ok(row) => Value::Object(row),
+                Err(message) => {
+                    errors.push(json!({ "line": line, "message": message }));
+                    continue;
+                }
+            };
+
+            let row_bytes = row.to_string().len() + 1;
+            if row_bytes > MAX_PAYLOAD_BYTES {
+                errors.push(json!({
+                    "line": line,
+                    "message": DataApiDomainError::PayloadTooLarge.to_string()
+                }));
+                continue;
+            }
+
+            if batch_payloads.len() == IMPORT_BATCH_SIZE
+                || batch_bytes + row_bytes > MAX_PAYLOAD_BYTES
+            {
+                batches.push(std::mem::take(&mut batch_payloads));
+                batch_bytes = 0;
+            }
+
+            batch_bytes += row_bytes;
+            batch_payloads.push(row);
+        }
+
+        if !batch_payloads.is_empty() {
+            batches.push(batch_payloads);
+        }
+
+        let operations = batches
+            .iter()
+            .map(|payloads| {
+                TransactionOperationCriteria::CreateMany(CreateRowsCriteria {
+                    schema_name: schema_name.value(),
+                    table_name: command.table_name().value(),
+                    payloads,
+                    allowed_columns: &header,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let imported = if operations.is_empty() {
+            0
+        } else {
+            match self
+                .repository
+                .execute_transaction(command.tenant_id(), operations)
+                .await
+            {
+                Ok(created) => created
+                    .iter()
+                    .filter_map(Value::as_array)
+                    .map(Vec::len)
+                    .sum(),
+                Err(error) => {
+                    self.audit(AuditContext {
+                        tenant_id: command.tenant_id().value(),
+                        request_id: command.request_id().map(str::to_string),
+                        schema_name: schema_name.value(),
+                        table_name: command.table_name().value(),
+                        action: DataApiAction::Create,
+                        principal: command.principal(),
+                        success: false,
+                        status_code: error.status_code(),
+                        details: Some(format!("import rolled back: {error}")),
+                    })
+                    .await;
+                    return Err(error);
+                }
+            }
+        };
+
+        self.audit(AuditContext {
+            tenant_id: command.tenant_id().value(),
+            request_id: command.request_id().map(str::to_string),
+            schema_name: schema_name.value(),
+            table_name: command.table_name().value(),
+            action: DataApiAction::Create,
+            principal: command.principal(),
+            success: errors.is_empty(),
+            status_code: if errors.is_empty() { 200 } else { 207 },
+            details: Some(format!(
+                "imported {} rows, rejected {} rows",
+                imported,
+                errors.len()
+            )),
+        })
+        .await;
+
+        Ok(json!({
+            "imported": imported,
+            "errors": errors,
+        }))
+    }
+
+    async fn handle_transaction(
+        &self,
+        command: ExecuteTransactionCommand,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        self.ensure_batch_size(command.operations().len())?;
+
+        let mut transaction = self
+            .repository
+            .begin_transaction(command.tenant_id())
+            .await?;
+        let mut prepared = Vec::with_capacity(command.operations().len());
+        let mut rows = Vec::with_capacity(command.operations().len());
+        let mut failure = None;
+        for operation in command.operations() {
+            let operation = match self
+                .prepare_transaction_operation(transaction.as_mut(), operation)
+                .await
+            {
+                Ok(operation) => operation,
+                Err(error) => {
+                    failure = Some(error);
+                    break;
+                }
+            };
+            let outcome = transaction.execute(operation.criteria()).await;
+            match outcome {
+                Ok(mut row) => {
+                    DeniedColumnStripper::strip(operation.denied_columns(), &mut row);
+                    prepared.push(operation);
+                    rows.push(row);
+                }
+                Err(error) => {
+                    prepared.push(operation);
+                    failure = Some(error);
+                    break;
+                }
+            }
+        }
+
+        let result = match failure {
+            Some(error) => {
+                drop(transaction);
+                Err(error)
+            }
+            None => transaction.commit().await.map(|()| rows),
+        };
+
+        match result {
+            Ok(rows) => {
+                for operation in &prepared {
+                    self.audit(operation.audit_context(true, 0, None)).await;
+                }
+                Ok(rows)
+            }
+            Err(error) => {
+                for operation in &prepared {
+                    self.audit(operation.audit_context(
+                        false,
+                        error.status_code(),
+                        Some(format!("transaction rolled back: {error}")),
+                    ))
+                    .await;
+                }
+                Err(error)
+            }
+        }
+    }
 }
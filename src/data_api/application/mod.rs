@@ -1,3 +1,6 @@
 pub mod acl;
+pub mod cache;
 pub mod command_services;
+pub mod ownership;
+pub mod projection;
 pub mod query_services;
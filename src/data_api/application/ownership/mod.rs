@@ -0,0 +1 @@
+pub mod row_owner_resolver;
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::data_api::{
+    domain::model::{
+        enums::data_api_domain_error::DataApiDomainError, value_objects::tenant_id::TenantId,
+    },
+    infrastructure::persistence::repositories::data_api_repository::{
+        DataApiRepository, DataApiTransaction, GetRowByPrimaryKeyCriteria,
+    },
+};
+
+#[derive(Clone)]
+pub struct RowOwnerResolver {
+    repository: Arc<dyn DataApiRepository>,
+    row_owner_column: Option<String>,
+}
+
+impl RowOwnerResolver {
+    pub fn new(repository: Arc<dyn DataApiRepository>, row_owner_column: Option<String>) -> Self {
+        Self {
+            repository,
+            row_owner_column,
+        }
+    }
+
+    pub fn row_owner_column(&self) -> Option<&str> {
+        self.row_owner_column.as_deref()
+    }
+
+    pub fn row_owner_value(row: &Value, owner_column: &str) -> Option<String> {
+        match row.get(owner_column)? {
+            Value::String(value) => Some(value.clone()),
+            Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn subject_owner_id(
+        &self,
+        principal: &str,
+        header_subject_owner_id: Option<&str>,
+    ) -> Option<String> {
+        match &self.row_owner_column {
+            Some(_) => Some(principal.to_string()),
+            None => header_subject_owner_id.map(str::to_string),
+        }
+    }
+
+    pub fn header_row_owner_id(&self, header_owner_id: Option<&str>) -> Option<String> {
+        match &self.row_owner_column {
+            Some(_) => None,
+            None => header_owner_id.map(str::to_string),
+        }
+    }
+
+    pub fn payload_row_owner_id(
+        &self,
+        payload: &Value,
+        header_owner_id: Option<&str>,
+    ) -> Option<String> {
+        match &self.row_owner_column {
+            Some(owner_column) => Self::row_owner_value(payload, owner_column),
+            None => header_owner_id.map(str::to_string),
+        }
+    }
+
+    pub async fn stored_row_owner_id(
+        &self,
+        tenant_id: &TenantId,
+        criteria: GetRowByPrimaryKeyCriteria,
+        header_owner_id: Option<&str>,
+    ) -> Result<Option<String>, DataApiDomainError> {
+        let Some(owner_column) = &self.row_owner_column else {
+            return Ok(header_owner_id.map(str::to_string));
+        };
+
+        let row = self
+            .repository
+            .get_row_by_primary_key(tenant_id, criteria)
+            .await?;
+
+        Ok(row.and_then(|row| Self::row_owner_value(&row, owner_column)))
+    }
+
+    pub async fn stored_row_owner_id_in_transaction(
+        &self,
+        transaction: &mut dyn DataApiTransaction,
+        criteria: GetRowByPrimaryKeyCriteria,
+        header_owner_id: Option<&str>,
+    ) -> Result<Option<String>, DataApiDomainError> {
+        let Some(owner_column) = &self.row_owner_column else {
+            return Ok(header_owner_id.map(str::to_string));
+        };
+
+        let row = transaction.get_row_by_primary_key(criteria).await?;
+
+        Ok(row.and_then(|row| Self::row_owner_value(&row, owner_column)))
+    }
+}
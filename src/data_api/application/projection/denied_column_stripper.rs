@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::data_api::{
+    domain::model::{
+        entities::table_schema_metadata::TableSchemaMetadata,
+        enums::{data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError},
+    },
+    interfaces::acl::access_control_facade::{
+        AccessControlFacade, DataApiAuthorizationCheckRequest,
+    },
+};
+
+#[derive(Clone)]
+pub struct DeniedColumnStripper {
+    access_control_facade: Arc<dyn AccessControlFacade>,
+    enabled: bool,
+}
+
+impl DeniedColumnStripper {
+    pub fn new(access_control_facade: Arc<dyn AccessControlFacade>, enabled: bool) -> Self {
+        Self {
+            access_control_facade,
+            enabled,
+        }
+    }
+
+    pub async fn denied_columns(
+        &self,
+        authorization_mode: &str,
+        request: &DataApiAuthorizationCheckRequest,
+        metadata: &TableSchemaMetadata,
+    ) -> Result<Vec<String>, DataApiDomainError> {
+        if !self.enabled || !authorization_mode.eq_ignore_ascii_case("acl") {
+            return Ok(Vec::new());
+        }
+
+        let columns = metadata
+            .columns
+            .iter()
+            .map(|column| column.column_name.clone())
+            .collect::<Vec<_>>();
+        let permitted = self
+            .access_control_facade
+            .permitted_columns(DataApiAuthorizationCheckRequest {
+                action_name: DataApiAction::Read.as_str().to_string(),
+                requested_columns: columns.clone(),
+                audit_decision: false,
+                ..request.clone()
+            })
+            .await?;
+
+        Ok(columns
+            .into_iter()
+            .filter(|column| !permitted.contains(column))
+            .collect())
+    }
+
+    pub fn strip(denied_columns: &[String], value: &mut Value) {
+        if denied_columns.is_empty() {
+            return;
+        }
+
+        match value {
+            Value::Array(rows) => {
+                for row in rows {
+                    Self::strip(denied_columns, row);
+                }
+            }
+            Value::Object(row) => {
+                row.retain(|field, _| {
+                    let column = field
+                        .split_once("->>")
+                        .map_or(field.as_str(), |(column, _)| column);
+                    !denied_columns.iter().any(|denied| denied == column)
+                });
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod denied_column_stripper;
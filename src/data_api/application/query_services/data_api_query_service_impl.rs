@@ -6,24 +6,41 @@ use serde_json::{Value, json};
 use uuid::Uuid;
 
 use crate::data_api::{
+    application::{
+        ownership::row_owner_resolver::RowOwnerResolver,
+        projection::denied_column_stripper::DeniedColumnStripper,
+    },
     domain::{
         model::{
-            enums::{data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError},
+            entities::table_schema_metadata::{TableColumnMetadata, TableSchemaMetadata},
+            enums::{
+                data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError,
+                data_api_filter_operator::DataApiFilterOperator,
+                data_api_read_transform::DataApiReadTransform,
+            },
             events::data_api_request_audited_event::DataApiRequestAuditedEvent,
             queries::{
                 get_row_query::GetRowQuery, list_rows_query::ListRowsQuery,
                 table_schema_introspection_query::TableSchemaIntrospectionQuery,
             },
+            value_objects::{list_rows_cursor::ListRowsCursor, tenant_id::TenantId},
+        },
+        services::data_api_query_service::{
+            DataApiQueryService, ListRowsDebugStatement, ListRowsResult,
         },
-        services::data_api_query_service::DataApiQueryService,
     },
     infrastructure::persistence::repositories::{
         data_api_audit_log_repository::DataApiAuditLogRepository,
-        data_api_repository::{DataApiRepository, GetRowByPrimaryKeyCriteria, ListRowsCriteria},
+        data_api_repository::{
+            DataApiRepository, GetRowByPrimaryKeyCriteria, ListRowsCriteria,
+            TableAccessCatalogEntry,
+        },
         tenant_schema_resolver_repository::TenantSchemaResolverRepository,
     },
     interfaces::acl::access_control_facade::{
-        AccessControlFacade, DataApiAuthorizationBootstrapRequest, DataApiAuthorizationCheckRequest,
+        AccessControlFacade, DataApiAuthorizationBootstrapRequest,
+        DataApiAuthorizationCheckRequest, DataApiEffectiveColumnsRequest,
+        DataApiRoleMembershipRequest,
     },
 };
 
@@ -32,6 +49,10 @@ pub struct DataApiQueryServiceImpl {
     tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
     access_control_facade: Arc<dyn AccessControlFacade>,
     audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+    row_owner: RowOwnerResolver,
+    read_audit_sample_rate: f64,
+    denied_columns: DeniedColumnStripper,
+    owner_scoped_lists: bool,
 }
 
 struct AuditContext<'a> {
@@ -52,15 +73,57 @@ impl DataApiQueryServiceImpl {
         tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
         access_control_facade: Arc<dyn AccessControlFacade>,
         audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+    ) -> Self {
+        Self::new_with_row_owner_column(
+            repository,
+            tenant_schema_resolver,
+            access_control_facade,
+            audit_log_repository,
+            None,
+        )
+    }
+
+    pub fn new_with_row_owner_column(
+        repository: Arc<dyn DataApiRepository>,
+        tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
+        access_control_facade: Arc<dyn AccessControlFacade>,
+        audit_log_repository: Arc<dyn DataApiAuditLogRepository>,
+        row_owner_column: Option<String>,
     ) -> Self {
         Self {
+            row_owner: RowOwnerResolver::new(repository.clone(), row_owner_column),
+            denied_columns: DeniedColumnStripper::new(access_control_facade.clone(), false),
             repository,
             tenant_schema_resolver,
             access_control_facade,
             audit_log_repository,
+            read_audit_sample_rate: 1.0,
+            owner_scoped_lists: false,
         }
     }
 
+    pub fn with_read_audit_sample_rate(mut self, read_audit_sample_rate: f64) -> Self {
+        self.read_audit_sample_rate = read_audit_sample_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_denied_column_stripping(mut self, denied_column_stripping: bool) -> Self {
+        self.denied_columns =
+            DeniedColumnStripper::new(self.access_control_facade.clone(), denied_column_stripping);
+        self
+    }
+
+    pub fn with_owner_scoped_lists(mut self, owner_scoped_lists: bool) -> Self {
+        self.owner_scoped_lists = owner_scoped_lists;
+        self
+    }
+
+    fn read_audit_sampled(&self) -> bool {
+        self.read_audit_sample_rate >= 1.0
+            || (self.read_audit_sample_rate > 0.0
+                && rand::random::<f64>() < self.read_audit_sample_rate)
+    }
+
     fn ensure_action_allowed(
         action_enabled: bool,
         table_exposed: bool,
@@ -72,6 +135,173 @@ impl DataApiQueryServiceImpl {
         Ok(())
     }
 
+    async fn authorized_table_metadata(
+        &self,
+        query: &TableSchemaIntrospectionQuery,
+    ) -> Result<TableSchemaMetadata, DataApiDomainError> {
+        let schema_name = self
+            .tenant_schema_resolver
+            .resolve_schema(query.tenant_id(), Some(query.schema_name().value()))
+            .await?;
+
+        self.repository
+            .synchronize_metadata(query.tenant_id(), schema_name.value())
+            .await?;
+
+        let access_metadata = self
+            .repository
+            .get_table_access_metadata(
+                query.tenant_id(),
+                schema_name.value(),
+                query.table_name().value(),
+            )
+            .await?;
+
+        Self::ensure_action_allowed(access_metadata.introspect_enabled, access_metadata.exposed)?;
+
+        self.enforce_acl_if_required(
+            &access_metadata.authorization_mode,
+            DataApiAuthorizationBootstrapRequest {
+                tenant_id: query.tenant_id().value().to_string(),
+                principal_id: query.principal().to_string(),
+                resource_name: query.table_name().value().to_string(),
+                readable_columns: vec![],
+                writable_columns: vec![],
+            },
+            DataApiAuthorizationCheckRequest {
+                tenant_id: query.tenant_id().value().to_string(),
+                principal_id: query.principal().to_string(),
+                resource_name: query.table_name().value().to_string(),
+                action_name: DataApiAction::Read.as_str().to_string(),
+                requested_columns: vec![],
+                subject_owner_id: self
+                    .row_owner
+                    .subject_owner_id(query.principal(), query.subject_owner_id()),
+                row_owner_id: self.row_owner.header_row_owner_id(query.row_owner_id()),
+                request_id: query.request_id().map(str::to_string),
+                bypass_decision_cache: false,
+                audit_decision: true,
+            },
+        )
+        .await?;
+
+        self.repository
+            .introspect_table(
+                query.tenant_id(),
+                schema_name.value(),
+                query.table_name().value(),
+            )
+            .await
+    }
+
+    fn json_schema_property(column: &TableColumnMetadata) -> Value {
+        let mut property = serde_json::Map::new();
+        if let Some((json_type, format)) = Self::json_schema_type(&column.data_type) {
+            property.insert(
+                "type".to_string(),
+                if column.is_nullable {
+                    json!([json_type, "null"])
+                } else {
+                    json!(json_type)
+                },
+            );
+            if let Some(format) = format {
+                property.insert("format".to_string(), json!(format));
+            }
+        }
+        if column.is_primary_key {
+            property.insert("readOnly".to_string(), json!(true));
+        }
+
+        Value::Object(property)
+    }
+
+    fn json_schema_type(data_type: &str) -> Option<(&'static str, Option<&'static str>)> {
+        match data_type.to_ascii_lowercase().as_str() {
+            "json" | "jsonb" => None,
+            "smallint" | "integer" | "bigint" => Some(("integer", None)),
+            "numeric" | "real" | "double precision" => Some(("number", None)),
+            "boolean" => Some(("boolean", None)),
+            "array" => Some(("array", None)),
+            "uuid" => Some(("string", Some("uuid"))),
+            "date" => Some(("string", Some("date"))),
+            "timestamp with time zone" | "timestamp without time zone" => {
+                Some(("string", Some("date-time")))
+            }
+            "time with time zone" | "time without time zone" => Some(("string", Some("time"))),
+            _ => Some(("string", None)),
+        }
+    }
+
+    fn numeric_text_columns(metadata: &TableSchemaMetadata, enabled: bool) -> Vec<String> {
+        if !enabled {
+            return Vec::new();
+        }
+
+        metadata
+            .columns
+            .iter()
+            .filter(|column| {
+                column.data_type.eq_ignore_ascii_case("bigint")
+                    || column.data_type.eq_ignore_ascii_case("numeric")
+            })
+            .map(|column| column.column_name.clone())
+            .collect()
+    }
+
+    fn field_column(field: &str) -> &str {
+        field.split_once("->>").map_or(field, |(column, _)| column)
+    }
+
+    fn field_columns(fields: &[String]) -> Vec<String> {
+        let mut columns: Vec<String> = Vec::with_capacity(fields.len());
+        for field in fields {
+            let column = Self::field_column(field);
+            if !columns.iter().any(|c| c == column) {
+                columns.push(column.to_string());
+            }
+        }
+
+        columns
+    }
+
+    fn apply_read_transforms(transforms: &[(String, DataApiReadTransform)], value: &mut Value) {
+        match value {
+            Value::Array(rows) => {
+                for row in rows {
+                    Self::apply_read_transforms(transforms, row);
+                }
+            }
+            Value::Object(row) => {
+                for (field, value) in row.iter_mut() {
+                    let column = Self::field_column(field);
+                    if let Some((_, transform)) = transforms.iter().find(|(c, _)| c == column) {
+                        *value = transform.apply(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn next_cursor(
+        rows: &Value,
+        limit: i64,
+        order_by: &str,
+        primary_key_column: &str,
+    ) -> Option<String> {
+        let rows = rows.as_array()?;
+        if (rows.len() as i64) < limit {
+            return None;
+        }
+
+        let last_row = rows.last()?;
+        let primary_key_value = last_row.get(primary_key_column)?;
+        let order_value = last_row.get(order_by)?;
+
+        Some(ListRowsCursor::new(order_value.clone(), primary_key_value.clone()).encode())
+    }
+
     async fn enforce_acl_if_required(
         &self,
         authorization_mode: &str,
@@ -85,12 +315,90 @@ impl DataApiQueryServiceImpl {
             self.access_control_facade
                 .check_table_permission(request)
                 .await?;
+        } else if authorization_mode.eq_ignore_ascii_case("role_required")
+            && !self
+                .access_control_facade
+                .principal_has_any_role(DataApiRoleMembershipRequest {
+                    tenant_id: request.tenant_id,
+                    principal_id: request.principal_id,
+                })
+                .await?
+        {
+            return Err(DataApiDomainError::AccessDenied);
         }
 
         Ok(())
     }
 
+    async fn partition_readable_fields(
+        &self,
+        request: &DataApiAuthorizationCheckRequest,
+        fields: Vec<String>,
+    ) -> Result<(Vec<String>, Vec<String>), DataApiDomainError> {
+        let permitted = self
+            .access_control_facade
+            .permitted_columns(DataApiAuthorizationCheckRequest {
+                requested_columns: Self::field_columns(&fields),
+                ..request.clone()
+            })
+            .await?;
+        let (allowed, dropped): (Vec<_>, Vec<_>) = fields.into_iter().partition(|field| {
+            permitted
+                .iter()
+                .any(|column| column == Self::field_column(field))
+        });
+
+        if allowed.is_empty() {
+            return Err(DataApiDomainError::AccessDenied);
+        }
+
+        Ok((allowed, dropped))
+    }
+
+    async fn owner_scope_filter(
+        &self,
+        authorization_mode: &str,
+        request: &DataApiAuthorizationCheckRequest,
+        metadata: &TableSchemaMetadata,
+    ) -> Result<Option<(String, DataApiFilterOperator)>, DataApiDomainError> {
+        let Some(owner_column) = self
+            .row_owner
+            .row_owner_column()
+            .filter(|column| metadata.has_column(column))
+        else {
+            return Ok(None);
+        };
+        if !self.owner_scoped_lists || !authorization_mode.eq_ignore_ascii_case("acl") {
+            return Ok(None);
+        }
+
+        let owner_id = request.principal_id.clone();
+        let mut owner_request = request.clone();
+        owner_request.subject_owner_id = Some(owner_id.clone());
+        owner_request.row_owner_id = Some(owner_id.clone());
+
+        match self
+            .access_control_facade
+            .check_table_permission(owner_request)
+            .await
+        {
+            Ok(()) => Ok(Some((
+                owner_column.to_string(),
+                DataApiFilterOperator::Eq(owner_id),
+            ))),
+            Err(DataApiDomainError::AccessDenied) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     async fn audit(&self, context: AuditContext<'_>) {
+        if context.success
+            && matches!(context.action, DataApiAction::Read)
+            && !self.read_audit_sampled()
+        {
+            return;
+        }
+
         let _ = self
             .audit_log_repository
             .save_event(&DataApiRequestAuditedEvent {
@@ -111,7 +419,10 @@ impl DataApiQueryServiceImpl {
 
 #[async_trait]
 impl DataApiQueryService for DataApiQueryServiceImpl {
-    async fn handle_list(&self, query: ListRowsQuery) -> Result<Value, DataApiDomainError> {
+    async fn handle_list(
+        &self,
+        query: ListRowsQuery,
+    ) -> Result<ListRowsResult, DataApiDomainError> {
         let schema_name = self
             .tenant_schema_resolver
             .resolve_schema(query.tenant_id(), Some(query.schema_name().value()))
@@ -141,7 +452,7 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
             )
             .await?;
 
-        let selected_fields = if query.select_fields().is_empty() {
+        let mut selected_fields = if query.select_fields().is_empty() {
             metadata
                 .columns
                 .iter()
@@ -151,15 +462,15 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
             query
                 .select_fields()
                 .iter()
-                .filter(|field| metadata.has_column(field))
+                .filter(|field| metadata.has_selectable_field(field))
                 .cloned()
                 .collect::<Vec<_>>()
         };
 
         let mut filters = Vec::new();
-        for (key, value) in query.filters() {
+        for (key, operator) in query.filters() {
             if metadata.has_column(key) {
-                filters.push((key.clone(), value.clone()));
+                filters.push((key.clone(), operator.clone()));
             }
         }
 
@@ -167,59 +478,200 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
             .order_by()
             .filter(|column| metadata.has_column(column))
             .map(str::to_string);
+        let primary_key_column = order_by.as_ref().and_then(|_| {
+            metadata
+                .primary_key_column()
+                .map(|column| column.column_name.clone())
+        });
 
-        self.enforce_acl_if_required(
-            &access_metadata.authorization_mode,
-            DataApiAuthorizationBootstrapRequest {
-                tenant_id: query.tenant_id().value().to_string(),
-                principal_id: query.principal().to_string(),
-                resource_name: query.table_name().value().to_string(),
-                readable_columns: metadata
-                    .columns
-                    .iter()
-                    .map(|column| column.column_name.clone())
-                    .collect(),
-                writable_columns: self
-                    .repository
-                    .list_writable_columns(
-                        query.tenant_id(),
-                        schema_name.value(),
-                        query.table_name().value(),
-                    )
-                    .await?,
-            },
-            DataApiAuthorizationCheckRequest {
-                tenant_id: query.tenant_id().value().to_string(),
-                principal_id: query.principal().to_string(),
-                resource_name: query.table_name().value().to_string(),
-                action_name: DataApiAction::Read.as_str().to_string(),
-                requested_columns: selected_fields.clone(),
-                subject_owner_id: query.subject_owner_id().map(str::to_string),
-                row_owner_id: query.row_owner_id().map(str::to_string),
-                request_id: query.request_id().map(str::to_string),
-            },
-        )
-        .await?;
+        let query_restrictions = self
+            .repository
+            .list_column_query_restrictions(
+                query.tenant_id(),
+                schema_name.value(),
+                query.table_name().value(),
+            )
+            .await?;
+        if let Some((column, _)) = filters
+            .iter()
+            .find(|(column, _)| !query_restrictions.is_filterable(column))
+        {
+            return Err(DataApiDomainError::NonFilterableColumn(column.clone()));
+        }
+        if let Some(column) = order_by
+            .as_ref()
+            .filter(|column| !query_restrictions.is_orderable(column))
+        {
+            return Err(DataApiDomainError::NonOrderableColumn(column.clone()));
+        }
 
-        let result = self
+        let read_transforms = self
             .repository
-            .list_rows(
+            .list_read_transforms(
                 query.tenant_id(),
-                ListRowsCriteria {
-                    schema_name: schema_name.value().to_string(),
-                    table_name: query.table_name().value().to_string(),
-                    fields: selected_fields,
-                    filters,
-                    limit: query.limit(),
-                    offset: query.offset(),
-                    order_by,
-                    order_desc: query.order_desc(),
+                schema_name.value(),
+                query.table_name().value(),
+            )
+            .await?;
+        let is_transformed = |column: &str| {
+            read_transforms
+                .iter()
+                .any(|(transformed, _)| transformed == column)
+        };
+        if let Some((column, _)) = filters.iter().find(|(column, _)| is_transformed(column)) {
+            return Err(DataApiDomainError::NonFilterableColumn(column.clone()));
+        }
+        if let Some(column) = order_by.as_ref().filter(|column| is_transformed(column)) {
+            return Err(DataApiDomainError::NonOrderableColumn(column.clone()));
+        }
+
+        if query.after().is_some() {
+            if order_by.is_none() {
+                return Err(DataApiDomainError::InvalidQueryParameters);
+            }
+            if primary_key_column.is_none() {
+                return Err(DataApiDomainError::PrimaryKeyNotFound);
+            }
+        }
+
+        if !selected_fields.is_empty() {
+            for column in order_by.iter().chain(primary_key_column.iter()) {
+                if !selected_fields.contains(column) {
+                    selected_fields.push(column.clone());
+                }
+            }
+        }
+
+        let check_request = DataApiAuthorizationCheckRequest {
+            tenant_id: query.tenant_id().value().to_string(),
+            principal_id: query.principal().to_string(),
+            resource_name: query.table_name().value().to_string(),
+            action_name: DataApiAction::Read.as_str().to_string(),
+            requested_columns: Self::field_columns(&selected_fields),
+            subject_owner_id: self
+                .row_owner
+                .subject_owner_id(query.principal(), query.subject_owner_id()),
+            row_owner_id: self.row_owner.header_row_owner_id(query.row_owner_id()),
+            request_id: query.request_id().map(str::to_string),
+            bypass_decision_cache: query.bypass_decision_cache(),
+            audit_decision: true,
+        };
+
+        let acl_result = self
+            .enforce_acl_if_required(
+                &access_metadata.authorization_mode,
+                DataApiAuthorizationBootstrapRequest {
+                    tenant_id: query.tenant_id().value().to_string(),
+                    principal_id: query.principal().to_string(),
+                    resource_name: query.table_name().value().to_string(),
+                    readable_columns: metadata
+                        .columns
+                        .iter()
+                        .map(|column| column.column_name.clone())
+                        .collect(),
+                    writable_columns: self
+                        .repository
+                        .list_writable_columns(
+                            query.tenant_id(),
+                            schema_name.value(),
+                            query.table_name().value(),
+                        )
+                        .await?,
                 },
+                check_request.clone(),
             )
             .await;
+        let (acl_result, owner_filter) = match acl_result {
+            Err(DataApiDomainError::AccessDenied) => match self
+                .owner_scope_filter(
+                    &access_metadata.authorization_mode,
+                    &check_request,
+                    &metadata,
+                )
+                .await?
+            {
+                Some(owner_filter) => (Ok(()), Some(owner_filter)),
+                None => (Err(DataApiDomainError::AccessDenied), None),
+            },
+            acl_result => (acl_result, None),
+        };
+        filters.extend(owner_filter);
+
+        let (selected_fields, dropped_fields) = match acl_result {
+            Ok(()) => (selected_fields, Vec::new()),
+            Err(DataApiDomainError::AccessDenied)
+                if query.lenient_projection()
+                    && !query.select_fields().is_empty()
+                    && access_metadata
+                        .authorization_mode
+                        .eq_ignore_ascii_case("acl") =>
+            {
+                self.partition_readable_fields(&check_request, selected_fields)
+                    .await?
+            }
+            Err(error) => return Err(error),
+        };
+        let denied_columns = self
+            .denied_columns
+            .denied_columns(
+                &access_metadata.authorization_mode,
+                &check_request,
+                &metadata,
+            )
+            .await?;
+        if let Some(column) = order_by
+            .iter()
+            .chain(primary_key_column.iter())
+            .find(|column| dropped_fields.contains(column) || denied_columns.contains(column))
+        {
+            return Err(DataApiDomainError::NonOrderableColumn(column.clone()));
+        }
+
+        let criteria = ListRowsCriteria {
+            schema_name: schema_name.value().to_string(),
+            table_name: query.table_name().value().to_string(),
+            fields: selected_fields,
+            filters,
+            limit: query.limit(),
+            offset: query.offset(),
+            order_by: order_by.clone(),
+            order_desc: query.order_desc(),
+            primary_key_column: primary_key_column.clone(),
+            after: query.after().cloned(),
+            text_columns: Self::numeric_text_columns(&metadata, query.numeric_as_string()),
+            soft_delete_column: access_metadata.soft_delete_column.clone(),
+        };
+        let debug_statement = if query.debug_sql() {
+            let statement = self.repository.describe_list_rows(criteria.clone())?;
+            Some(ListRowsDebugStatement {
+                sql: statement.sql,
+                parameter_count: statement.parameter_count,
+            })
+        } else {
+            None
+        };
+        let total = if query.include_count() {
+            Some(
+                self.repository
+                    .count_rows(query.tenant_id(), criteria.clone())
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let result = self.repository.list_rows(query.tenant_id(), criteria).await;
 
         match result {
-            Ok(rows) => {
+            Ok(mut rows) => {
+                let next_cursor = match (&order_by, &primary_key_column) {
+                    (Some(order_by), Some(primary_key_column)) => {
+                        Self::next_cursor(&rows, query.limit(), order_by, primary_key_column)
+                    }
+                    _ => None,
+                };
+                Self::apply_read_transforms(&read_transforms, &mut rows);
+                DeniedColumnStripper::strip(&denied_columns, &mut rows);
                 self.audit(AuditContext {
                     tenant_id: query.tenant_id().value(),
                     request_id: query.request_id().map(str::to_string),
@@ -232,7 +684,13 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
                     details: None,
                 })
                 .await;
-                Ok(rows)
+                Ok(ListRowsResult {
+                    rows,
+                    dropped_fields,
+                    debug_statement,
+                    total,
+                    next_cursor,
+                })
             }
             Err(error) => {
                 self.audit(AuditContext {
@@ -286,6 +744,37 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
             .primary_key_column()
             .ok_or(DataApiDomainError::PrimaryKeyNotFound)?;
 
+        let row_owner_id = self
+            .row_owner
+            .stored_row_owner_id(
+                query.tenant_id(),
+                GetRowByPrimaryKeyCriteria {
+                    schema_name: schema_name.value().to_string(),
+                    table_name: query.table_name().value().to_string(),
+                    primary_key_column: primary_key.column_name.clone(),
+                    primary_key_value: query.row_identifier().value().to_string(),
+                    text_columns: vec![],
+                    soft_delete_column: access_metadata.soft_delete_column.clone(),
+                },
+                query.row_owner_id(),
+            )
+            .await?;
+
+        let check_request = DataApiAuthorizationCheckRequest {
+            tenant_id: query.tenant_id().value().to_string(),
+            principal_id: query.principal().to_string(),
+            resource_name: query.table_name().value().to_string(),
+            action_name: DataApiAction::Read.as_str().to_string(),
+            requested_columns: vec![],
+            subject_owner_id: self
+                .row_owner
+                .subject_owner_id(query.principal(), query.subject_owner_id()),
+            row_owner_id,
+            request_id: query.request_id().map(str::to_string),
+            bypass_decision_cache: query.bypass_decision_cache(),
+            audit_decision: true,
+        };
+
         self.enforce_acl_if_required(
             &access_metadata.authorization_mode,
             DataApiAuthorizationBootstrapRequest {
@@ -306,18 +795,26 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
                     )
                     .await?,
             },
-            DataApiAuthorizationCheckRequest {
-                tenant_id: query.tenant_id().value().to_string(),
-                principal_id: query.principal().to_string(),
-                resource_name: query.table_name().value().to_string(),
-                action_name: DataApiAction::Read.as_str().to_string(),
-                requested_columns: vec![],
-                subject_owner_id: query.subject_owner_id().map(str::to_string),
-                row_owner_id: query.row_owner_id().map(str::to_string),
-                request_id: query.request_id().map(str::to_string),
-            },
+            check_request.clone(),
         )
         .await?;
+        let denied_columns = self
+            .denied_columns
+            .denied_columns(
+                &access_metadata.authorization_mode,
+                &check_request,
+                &metadata,
+            )
+            .await?;
+
+        let read_transforms = self
+            .repository
+            .list_read_transforms(
+                query.tenant_id(),
+                schema_name.value(),
+                query.table_name().value(),
+            )
+            .await?;
 
         match self
             .repository
@@ -328,11 +825,15 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
                     table_name: query.table_name().value().to_string(),
                     primary_key_column: primary_key.column_name.clone(),
                     primary_key_value: query.row_identifier().value().to_string(),
+                    text_columns: Self::numeric_text_columns(&metadata, query.numeric_as_string()),
+                    soft_delete_column: access_metadata.soft_delete_column.clone(),
                 },
             )
             .await
         {
-            Ok(Some(row)) => {
+            Ok(Some(mut row)) => {
+                Self::apply_read_transforms(&read_transforms, &mut row);
+                DeniedColumnStripper::strip(&denied_columns, &mut row);
                 self.audit(AuditContext {
                     tenant_id: query.tenant_id().value(),
                     request_id: query.request_id().map(str::to_string),
@@ -384,66 +885,137 @@ impl DataApiQueryService for DataApiQueryServiceImpl {
         &self,
         query: TableSchemaIntrospectionQuery,
     ) -> Result<Value, DataApiDomainError> {
-        let schema_name = self
-            .tenant_schema_resolver
-            .resolve_schema(query.tenant_id(), Some(query.schema_name().value()))
-            .await?;
-
-        self.repository
-            .synchronize_metadata(query.tenant_id(), schema_name.value())
-            .await?;
-
-        let access_metadata = self
-            .repository
-            .get_table_access_metadata(
-                query.tenant_id(),
-                schema_name.value(),
-                query.table_name().value(),
-            )
-            .await?;
-
-        Self::ensure_action_allowed(access_metadata.introspect_enabled, access_metadata.exposed)?;
-
-        self.enforce_acl_if_required(
-            &access_metadata.authorization_mode,
-            DataApiAuthorizationBootstrapRequest {
-                tenant_id: query.tenant_id().value().to_string(),
-                principal_id: query.principal().to_string(),
-                resource_name: query.table_name().value().to_string(),
-                readable_columns: vec![],
-                writable_columns: vec![],
-            },
-            DataApiAuthorizationCheckRequest {
-                tenant_id: query.tenant_id().value().to_string(),
-                principal_id: query.principal().to_string(),
-                resource_name: query.table_name().value().to_string(),
-                action_name: DataApiAction::Read.as_str().to_string(),
-                requested_columns: vec![],
-                subject_owner_id: query.subject_owner_id().map(str::to_string),
-                row_owner_id: query.row_owner_id().map(str::to_string),
-                request_id: query.request_id().map(str::to_string),
-            },
-        )
-        .await?;
+        let metadata = self.authorized_table_metadata(&query).await?;
 
-        let metadata = self
-            .repository
-            .introspect_table(
-                query.tenant_id(),
-                schema_name.value(),
-                query.table_name().value(),
-            )
-            .await?;
+        let primary_key = metadata
+            .columns
+            .iter()
+            .filter(|c| c.is_primary_key)
+            .map(|c| c.column_name.clone())
+            .collect::<Vec<_>>();
 
         Ok(json!({
             "schema": metadata.schema_name,
             "table": metadata.table_name,
+            "primary_key": primary_key,
             "columns": metadata.columns.into_iter().map(|c| json!({
                 "name": c.column_name,
                 "data_type": c.data_type,
                 "nullable": c.is_nullable,
-                "primary_key": c.is_primary_key
+                "primary_key": c.is_primary_key,
+                "ordinal_position": c.ordinal_position,
+                "default": c.column_default
             })).collect::<Vec<_>>()
         }))
     }
+
+    async fn handle_json_schema(
+        &self,
+        query: TableSchemaIntrospectionQuery,
+    ) -> Result<Value, DataApiDomainError> {
+        let metadata = self.authorized_table_metadata(&query).await?;
+
+        let properties = metadata
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    Self::json_schema_property(column),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let required = metadata
+            .columns
+            .iter()
+            .filter(|column| !column.is_nullable && column.column_default.is_none())
+            .map(|column| column.column_name.clone())
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": format!("{}.{}", metadata.schema_name, metadata.table_name),
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false
+        }))
+    }
+
+    async fn filter_access_catalog(
+        &self,
+        tenant_id: &TenantId,
+        principal: &str,
+        request_id: Option<&str>,
+        catalog: Vec<TableAccessCatalogEntry>,
+    ) -> Result<Vec<TableAccessCatalogEntry>, DataApiDomainError> {
+        let mut visible = Vec::with_capacity(catalog.len());
+
+        for entry in catalog {
+            if !entry.authorization_mode.eq_ignore_ascii_case("acl") {
+                visible.push(entry);
+                continue;
+            }
+
+            let decision = self
+                .access_control_facade
+                .check_table_permission(DataApiAuthorizationCheckRequest {
+                    tenant_id: tenant_id.value().to_string(),
+                    principal_id: principal.to_string(),
+                    resource_name: entry.table_name.clone(),
+                    action_name: DataApiAction::Read.as_str().to_string(),
+                    requested_columns: vec![],
+                    subject_owner_id: None,
+                    row_owner_id: None,
+                    request_id: request_id.map(str::to_string),
+                    bypass_decision_cache: false,
+                    audit_decision: false,
+                })
+                .await;
+
+            match decision {
+                Ok(()) => visible.push(entry),
+                Err(DataApiDomainError::AccessDenied) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(visible)
+    }
+
+    async fn effective_writable_catalog(
+        &self,
+        tenant_id: &TenantId,
+        principal: &str,
+        catalog: Vec<TableAccessCatalogEntry>,
+    ) -> Result<Vec<TableAccessCatalogEntry>, DataApiDomainError> {
+        let mut effective = Vec::with_capacity(catalog.len());
+
+        for mut entry in catalog {
+            if !entry.authorization_mode.eq_ignore_ascii_case("acl") {
+                entry.creatable_columns = Some(entry.writable_columns.clone());
+                entry.updatable_columns = Some(entry.writable_columns.clone());
+                effective.push(entry);
+                continue;
+            }
+
+            let columns = self
+                .access_control_facade
+                .effective_write_columns(DataApiEffectiveColumnsRequest {
+                    tenant_id: tenant_id.value().to_string(),
+                    principal_id: principal.to_string(),
+                    resource_name: entry.table_name.clone(),
+                    requested_columns: entry.writable_columns.clone(),
+                    subject_owner_id: Some(principal.to_string()),
+                    row_owner_id: Some(principal.to_string()),
+                })
+                .await?;
+
+            entry.creatable_columns = Some(columns.create_columns);
+            entry.updatable_columns = Some(columns.update_columns);
+            effective.push(entry);
+        }
+
+        Ok(effective)
+    }
 }
@@ -0,0 +1,58 @@
+use crate::data_api::domain::model::{
+    commands::{
+        create_row_command::CreateRowCommand, delete_row_command::DeleteRowCommand,
+        patch_row_command::PatchRowCommand,
+    },
+    enums::data_api_domain_error::DataApiDomainError,
+    value_objects::tenant_id::TenantId,
+};
+
+#[derive(Clone, Debug)]
+pub enum DataApiTransactionOperation {
+    Create(CreateRowCommand),
+    Patch(PatchRowCommand),
+    Delete(DeleteRowCommand),
+}
+
+impl DataApiTransactionOperation {
+    fn tenant_id(&self) -> &TenantId {
+        match self {
+            Self::Create(command) => command.tenant_id(),
+            Self::Patch(command) => command.tenant_id(),
+            Self::Delete(command) => command.tenant_id(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExecuteTransactionCommand {
+    tenant_id: TenantId,
+    operations: Vec<DataApiTransactionOperation>,
+}
+
+impl ExecuteTransactionCommand {
+    pub fn new(operations: Vec<DataApiTransactionOperation>) -> Result<Self, DataApiDomainError> {
+        let tenant_id = operations
+            .first()
+            .map(|operation| operation.tenant_id().clone())
+            .ok_or(DataApiDomainError::InvalidPayload)?;
+        if operations
+            .iter()
+            .any(|operation| operation.tenant_id() != &tenant_id)
+        {
+            return Err(DataApiDomainError::InvalidTenantId);
+        }
+
+        Ok(Self {
+            tenant_id,
+            operations,
+        })
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+    pub fn operations(&self) -> &[DataApiTransactionOperation] {
+        &self.operations
+    }
+}
@@ -1,3 +1,7 @@
 pub mod create_row_command;
+pub mod create_rows_command;
 pub mod delete_row_command;
+pub mod execute_transaction_command;
+pub mod import_rows_command;
 pub mod patch_row_command;
+pub mod replace_row_command;
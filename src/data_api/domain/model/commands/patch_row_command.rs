@@ -23,6 +23,7 @@ pub struct PatchRowCommand {
     subject_owner_id: Option<String>,
     row_owner_id: Option<String>,
     payload: Value,
+    skip_null_values: bool,
 }
 
 pub struct PatchRowCommandParts {
@@ -37,6 +38,7 @@ pub struct PatchRowCommandParts {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub payload: Value,
+    pub skip_null_values: bool,
 }
 
 impl PatchRowCommand {
@@ -57,6 +59,7 @@ impl PatchRowCommand {
             subject_owner_id: parts.subject_owner_id,
             row_owner_id: parts.row_owner_id,
             payload: parts.payload,
+            skip_null_values: parts.skip_null_values,
         })
     }
 
@@ -93,4 +96,7 @@ impl PatchRowCommand {
     pub fn payload(&self) -> &Value {
         &self.payload
     }
+    pub fn skip_null_values(&self) -> bool {
+        self.skip_null_values
+    }
 }
@@ -4,6 +4,8 @@ pub struct TableColumnMetadata {
     pub is_nullable: bool,
     pub data_type: String,
     pub is_primary_key: bool,
+    pub ordinal_position: i32,
+    pub column_default: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -21,4 +23,15 @@ impl TableSchemaMetadata {
     pub fn has_column(&self, column_name: &str) -> bool {
         self.columns.iter().any(|c| c.column_name == column_name)
     }
+
+    pub fn has_selectable_field(&self, field: &str) -> bool {
+        match field.split_once("->>") {
+            Some((column_name, _)) => self.columns.iter().any(|c| {
+                c.column_name == column_name
+                    && (c.data_type.eq_ignore_ascii_case("jsonb")
+                        || c.data_type.eq_ignore_ascii_case("json"))
+            }),
+            None => self.has_column(field),
+        }
+    }
 }
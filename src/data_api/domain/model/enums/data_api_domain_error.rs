@@ -11,8 +11,8 @@ pub enum DataApiDomainError {
     #[error("table name is invalid")]
     InvalidTableName,
 
-    #[error("column name is invalid")]
-    InvalidColumnName,
+    #[error("column name is invalid: {0}")]
+    InvalidColumnName(String),
 
     #[error("invalid row identifier")]
     InvalidRowIdentifier,
@@ -32,30 +32,83 @@ pub enum DataApiDomainError {
     #[error("access denied by ACL")]
     AccessDenied,
 
+    #[error("schema not found")]
+    SchemaNotFound,
+
     #[error("table not found")]
     TableNotFound,
 
     #[error("tenant database not found")]
     TenantDatabaseNotFound,
 
+    #[error("table is append-only; rows cannot be updated or deleted")]
+    TableAppendOnly,
+
     #[error("table has no primary key")]
     PrimaryKeyNotFound,
 
     #[error("payload size exceeded")]
     PayloadTooLarge,
 
+    #[error("batch exceeds the maximum of {0} rows; split the request into smaller batches")]
+    BatchTooLarge(usize),
+
     #[error("payload must be an object")]
     InvalidPayload,
 
     #[error("column is not editable: {0}")]
     NonEditableColumn(String),
 
+    #[error("column cannot be used as a filter: {0}")]
+    NonFilterableColumn(String),
+
+    #[error("column cannot be used for ordering: {0}")]
+    NonOrderableColumn(String),
+
     #[error("invalid filter or sort expression")]
     InvalidQueryParameters,
 
     #[error("record not found")]
     RecordNotFound,
 
+    #[error("row conflicts with an existing unique value")]
+    UniqueViolation,
+
+    #[error("row references a related record that does not exist")]
+    ForeignKeyViolation,
+
     #[error("infrastructure error: {0}")]
     InfrastructureError(String),
 }
+
+impl DataApiDomainError {
+    pub fn status_code(&self) -> u16 {
+        match self {
+            DataApiDomainError::InvalidTenantId
+            | DataApiDomainError::InvalidSchemaName
+            | DataApiDomainError::InvalidTableName
+            | DataApiDomainError::InvalidColumnName(_)
+            | DataApiDomainError::InvalidRowIdentifier
+            | DataApiDomainError::UnsupportedApiVersion
+            | DataApiDomainError::PayloadTooLarge
+            | DataApiDomainError::BatchTooLarge(_)
+            | DataApiDomainError::InvalidPayload
+            | DataApiDomainError::InvalidQueryParameters
+            | DataApiDomainError::ForeignKeyViolation
+            | DataApiDomainError::NonEditableColumn(_)
+            | DataApiDomainError::NonFilterableColumn(_)
+            | DataApiDomainError::NonOrderableColumn(_) => 400,
+            DataApiDomainError::MissingAuthentication
+            | DataApiDomainError::InvalidAuthentication => 401,
+            DataApiDomainError::AccessDenied | DataApiDomainError::TableNotAllowed => 403,
+            DataApiDomainError::SchemaNotFound
+            | DataApiDomainError::TableNotFound
+            | DataApiDomainError::TenantDatabaseNotFound
+            | DataApiDomainError::PrimaryKeyNotFound
+            | DataApiDomainError::RecordNotFound => 404,
+            DataApiDomainError::TableAppendOnly => 405,
+            DataApiDomainError::UniqueViolation => 409,
+            DataApiDomainError::InfrastructureError(_) => 500,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::data_api::domain::model::enums::data_api_domain_error::DataApiDomainError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataApiFilterOperator {
+    Eq(String),
+    Ne(String),
+    Gt(String),
+    Gte(String),
+    Lt(String),
+    Lte(String),
+    In(Vec<String>),
+    NotIn(Vec<String>),
+}
+
+impl DataApiFilterOperator {
+    pub const SUFFIXES: [&'static str; 8] = ["eq", "ne", "gt", "gte", "lt", "lte", "in", "nin"];
+
+    pub fn parse(suffix: Option<&str>, value: &str) -> Result<Self, DataApiDomainError> {
+        let value = value.to_string();
+
+        match suffix {
+            None | Some("eq") => Ok(Self::Eq(value)),
+            Some("ne") => Ok(Self::Ne(value)),
+            Some("gt") => Ok(Self::Gt(value)),
+            Some("gte") => Ok(Self::Gte(value)),
+            Some("lt") => Ok(Self::Lt(value)),
+            Some("lte") => Ok(Self::Lte(value)),
+            Some("in") => Ok(Self::In(Self::split_values(&value))),
+            Some("nin") => Ok(Self::NotIn(Self::split_values(&value))),
+            Some(_) => Err(DataApiDomainError::InvalidQueryParameters),
+        }
+    }
+
+    pub fn has_values(&self) -> bool {
+        match self {
+            Self::In(values) | Self::NotIn(values) => !values.is_empty(),
+            _ => true,
+        }
+    }
+
+    fn split_values(value: &str) -> Vec<String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use std::{fmt, str::FromStr};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::data_api::domain::model::enums::data_api_domain_error::DataApiDomainError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataApiReadTransform {
+    None,
+    Last4,
+    Hash,
+    Truncate(usize),
+}
+
+impl DataApiReadTransform {
+    pub fn apply(&self, value: &Value) -> Value {
+        let text = match value {
+            Value::Null => return Value::Null,
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+
+        match self {
+            Self::None => value.clone(),
+            Self::Last4 => {
+                let length = text.chars().count();
+                let visible = length.saturating_sub(4);
+                Value::String(
+                    text.chars()
+                        .enumerate()
+                        .map(|(index, c)| if index < visible { '*' } else { c })
+                        .collect(),
+                )
+            }
+            Self::Hash => Value::String(
+                Sha256::digest(text.as_bytes())
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect(),
+            ),
+            Self::Truncate(length) => Value::String(text.chars().take(*length).collect()),
+        }
+    }
+}
+
+impl fmt::Display for DataApiReadTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Last4 => write!(f, "last4"),
+            Self::Hash => write!(f, "hash"),
+            Self::Truncate(length) => write!(f, "truncate({length})"),
+        }
+    }
+}
+
+impl FromStr for DataApiReadTransform {
+    type Err = DataApiDomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "last4" => Ok(Self::Last4),
+            "hash" => Ok(Self::Hash),
+            other => other
+                .strip_prefix("truncate(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|length| length.trim().parse::<usize>().ok())
+                .filter(|length| *length > 0)
+                .map(Self::Truncate)
+                .ok_or(DataApiDomainError::InvalidQueryParameters),
+        }
+    }
+}
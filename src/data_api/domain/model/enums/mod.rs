@@ -1,3 +1,5 @@
 pub mod data_api_action;
 pub mod data_api_domain_error;
+pub mod data_api_filter_operator;
 pub mod data_api_principal_type;
+pub mod data_api_read_transform;
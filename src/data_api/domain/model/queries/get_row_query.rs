@@ -20,6 +20,8 @@ pub struct GetRowQuery {
     request_id: Option<String>,
     subject_owner_id: Option<String>,
     row_owner_id: Option<String>,
+    numeric_as_string: bool,
+    bypass_decision_cache: bool,
 }
 
 pub struct GetRowQueryParts {
@@ -33,6 +35,8 @@ pub struct GetRowQueryParts {
     pub request_id: Option<String>,
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
+    pub numeric_as_string: bool,
+    pub bypass_decision_cache: bool,
 }
 
 impl GetRowQuery {
@@ -48,6 +52,8 @@ impl GetRowQuery {
             request_id: parts.request_id,
             subject_owner_id: parts.subject_owner_id,
             row_owner_id: parts.row_owner_id,
+            numeric_as_string: parts.numeric_as_string,
+            bypass_decision_cache: parts.bypass_decision_cache,
         })
     }
 
@@ -81,4 +87,10 @@ impl GetRowQuery {
     pub fn row_owner_id(&self) -> Option<&str> {
         self.row_owner_id.as_deref()
     }
+    pub fn numeric_as_string(&self) -> bool {
+        self.numeric_as_string
+    }
+    pub fn bypass_decision_cache(&self) -> bool {
+        self.bypass_decision_cache
+    }
 }
@@ -1,12 +1,11 @@
-use std::collections::BTreeMap;
-
 use crate::data_api::domain::model::{
     enums::{
-        data_api_domain_error::DataApiDomainError, data_api_principal_type::DataApiPrincipalType,
+        data_api_domain_error::DataApiDomainError, data_api_filter_operator::DataApiFilterOperator,
+        data_api_principal_type::DataApiPrincipalType,
     },
     value_objects::{
-        api_version::ApiVersion, schema_name::SchemaName, table_name::TableName,
-        tenant_id::TenantId,
+        api_version::ApiVersion, list_rows_cursor::ListRowsCursor, schema_name::SchemaName,
+        table_name::TableName, tenant_id::TenantId,
     },
 };
 
@@ -22,11 +21,17 @@ pub struct ListRowsQuery {
     subject_owner_id: Option<String>,
     row_owner_id: Option<String>,
     select_fields: Vec<String>,
-    filters: BTreeMap<String, String>,
+    filters: Vec<(String, DataApiFilterOperator)>,
     limit: i64,
     offset: i64,
+    after: Option<ListRowsCursor>,
     order_by: Option<String>,
     order_desc: bool,
+    numeric_as_string: bool,
+    lenient_projection: bool,
+    debug_sql: bool,
+    include_count: bool,
+    bypass_decision_cache: bool,
 }
 
 pub struct ListRowsQueryParts {
@@ -40,18 +45,42 @@ pub struct ListRowsQueryParts {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub select_fields: Vec<String>,
-    pub filters: BTreeMap<String, String>,
+    pub filters: Vec<(String, DataApiFilterOperator)>,
     pub limit: i64,
     pub offset: i64,
+    pub after: Option<String>,
     pub order_by: Option<String>,
     pub order_desc: bool,
+    pub numeric_as_string: bool,
+    pub lenient_projection: bool,
+    pub debug_sql: bool,
+    pub include_count: bool,
+    pub bypass_decision_cache: bool,
 }
 
+pub const MAX_LIST_ROWS_LIMIT: i64 = 500;
+
 impl ListRowsQuery {
     pub fn new(parts: ListRowsQueryParts) -> Result<Self, DataApiDomainError> {
-        if parts.limit <= 0 || parts.limit > 500 || parts.offset < 0 {
+        if parts.limit <= 0
+            || parts.limit > MAX_LIST_ROWS_LIMIT
+            || parts.offset < 0
+            || parts
+                .filters
+                .iter()
+                .any(|(_, operator)| !operator.has_values())
+        {
+            return Err(DataApiDomainError::InvalidQueryParameters);
+        }
+
+        if parts.after.is_some() && parts.order_by.is_none() {
             return Err(DataApiDomainError::InvalidQueryParameters);
         }
+        let after = parts
+            .after
+            .as_deref()
+            .map(ListRowsCursor::decode)
+            .transpose()?;
 
         Ok(Self {
             api_version: ApiVersion::new(parts.api_version)?,
@@ -67,8 +96,14 @@ impl ListRowsQuery {
             filters: parts.filters,
             limit: parts.limit,
             offset: parts.offset,
+            after,
             order_by: parts.order_by,
             order_desc: parts.order_desc,
+            numeric_as_string: parts.numeric_as_string,
+            lenient_projection: parts.lenient_projection,
+            debug_sql: parts.debug_sql,
+            include_count: parts.include_count,
+            bypass_decision_cache: parts.bypass_decision_cache,
         })
     }
 
@@ -102,7 +137,7 @@ impl ListRowsQuery {
     pub fn select_fields(&self) -> &[String] {
         &self.select_fields
     }
-    pub fn filters(&self) -> &BTreeMap<String, String> {
+    pub fn filters(&self) -> &[(String, DataApiFilterOperator)] {
         &self.filters
     }
     pub fn limit(&self) -> i64 {
@@ -111,10 +146,28 @@ impl ListRowsQuery {
     pub fn offset(&self) -> i64 {
         self.offset
     }
+    pub fn after(&self) -> Option<&ListRowsCursor> {
+        self.after.as_ref()
+    }
     pub fn order_by(&self) -> Option<&str> {
         self.order_by.as_deref()
     }
     pub fn order_desc(&self) -> bool {
         self.order_desc
     }
+    pub fn numeric_as_string(&self) -> bool {
+        self.numeric_as_string
+    }
+    pub fn lenient_projection(&self) -> bool {
+        self.lenient_projection
+    }
+    pub fn debug_sql(&self) -> bool {
+        self.debug_sql
+    }
+    pub fn include_count(&self) -> bool {
+        self.include_count
+    }
+    pub fn bypass_decision_cache(&self) -> bool {
+        self.bypass_decision_cache
+    }
 }
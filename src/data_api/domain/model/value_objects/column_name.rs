@@ -11,7 +11,7 @@ impl ColumnName {
                 .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
 
         if !valid {
-            return Err(DataApiDomainError::InvalidColumnName);
+            return Err(DataApiDomainError::InvalidColumnName(value));
         }
 
         Ok(Self(value))
@@ -0,0 +1,47 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde_json::{Value, json};
+
+use crate::data_api::domain::model::enums::data_api_domain_error::DataApiDomainError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListRowsCursor {
+    order_value: Value,
+    primary_key_value: Value,
+}
+
+impl ListRowsCursor {
+    pub fn new(order_value: Value, primary_key_value: Value) -> Self {
+        Self {
+            order_value,
+            primary_key_value,
+        }
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, DataApiDomainError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded.trim())
+            .map_err(|_| DataApiDomainError::InvalidQueryParameters)?;
+        let value = serde_json::from_slice::<Value>(&bytes)
+            .map_err(|_| DataApiDomainError::InvalidQueryParameters)?;
+
+        match (value.get("order"), value.get("key")) {
+            (Some(order_value), Some(primary_key_value)) if !primary_key_value.is_null() => {
+                Ok(Self::new(order_value.clone(), primary_key_value.clone()))
+            }
+            _ => Err(DataApiDomainError::InvalidQueryParameters),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD
+            .encode(json!({ "order": self.order_value, "key": self.primary_key_value }).to_string())
+    }
+
+    pub fn order_value(&self) -> &Value {
+        &self.order_value
+    }
+
+    pub fn primary_key_value(&self) -> &Value {
+        &self.primary_key_value
+    }
+}
@@ -1,5 +1,6 @@
 pub mod api_version;
 pub mod column_name;
+pub mod list_rows_cursor;
 pub mod row_identifier;
 pub mod schema_name;
 pub mod table_name;
@@ -3,8 +3,11 @@ use serde_json::Value;
 
 use crate::data_api::domain::model::{
     commands::{
-        create_row_command::CreateRowCommand, delete_row_command::DeleteRowCommand,
-        patch_row_command::PatchRowCommand,
+        create_row_command::CreateRowCommand, create_rows_command::CreateRowsCommand,
+        delete_row_command::DeleteRowCommand,
+        execute_transaction_command::ExecuteTransactionCommand,
+        import_rows_command::ImportRowsCommand, patch_row_command::PatchRowCommand,
+        replace_row_command::ReplaceRowCommand,
     },
     enums::data_api_domain_error::DataApiDomainError,
 };
@@ -12,6 +15,17 @@ use crate::data_api::domain::model::{
 #[async_trait]
 pub trait DataApiCommandService: Send + Sync {
     async fn handle_create(&self, command: CreateRowCommand) -> Result<Value, DataApiDomainError>;
+    async fn handle_create_batch(
+        &self,
+        command: CreateRowsCommand,
+    ) -> Result<Vec<Value>, DataApiDomainError>;
     async fn handle_patch(&self, command: PatchRowCommand) -> Result<Value, DataApiDomainError>;
+    async fn handle_replace(&self, command: ReplaceRowCommand)
+    -> Result<Value, DataApiDomainError>;
     async fn handle_delete(&self, command: DeleteRowCommand) -> Result<(), DataApiDomainError>;
+    async fn handle_import(&self, command: ImportRowsCommand) -> Result<Value, DataApiDomainError>;
+    async fn handle_transaction(
+        &self,
+        command: ExecuteTransactionCommand,
+    ) -> Result<Vec<Value>, DataApiDomainError>;
 }
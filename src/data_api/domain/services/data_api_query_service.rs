@@ -1,20 +1,57 @@
 use async_trait::async_trait;
 use serde_json::Value;
 
-use crate::data_api::domain::model::{
-    enums::data_api_domain_error::DataApiDomainError,
-    queries::{
-        get_row_query::GetRowQuery, list_rows_query::ListRowsQuery,
-        table_schema_introspection_query::TableSchemaIntrospectionQuery,
+use crate::data_api::{
+    domain::model::{
+        enums::data_api_domain_error::DataApiDomainError,
+        queries::{
+            get_row_query::GetRowQuery, list_rows_query::ListRowsQuery,
+            table_schema_introspection_query::TableSchemaIntrospectionQuery,
+        },
+        value_objects::tenant_id::TenantId,
     },
+    infrastructure::persistence::repositories::data_api_repository::TableAccessCatalogEntry,
 };
 
+#[derive(Clone, Debug)]
+pub struct ListRowsDebugStatement {
+    pub sql: String,
+    pub parameter_count: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListRowsResult {
+    pub rows: Value,
+    pub dropped_fields: Vec<String>,
+    pub debug_statement: Option<ListRowsDebugStatement>,
+    pub total: Option<i64>,
+    pub next_cursor: Option<String>,
+}
+
 #[async_trait]
 pub trait DataApiQueryService: Send + Sync {
-    async fn handle_list(&self, query: ListRowsQuery) -> Result<Value, DataApiDomainError>;
+    async fn handle_list(&self, query: ListRowsQuery)
+    -> Result<ListRowsResult, DataApiDomainError>;
     async fn handle_get(&self, query: GetRowQuery) -> Result<Value, DataApiDomainError>;
     async fn handle_schema_introspection(
         &self,
         query: TableSchemaIntrospectionQuery,
     ) -> Result<Value, DataApiDomainError>;
+    async fn handle_json_schema(
+        &self,
+        query: TableSchemaIntrospectionQuery,
+    ) -> Result<Value, DataApiDomainError>;
+    async fn filter_access_catalog(
+        &self,
+        tenant_id: &TenantId,
+        principal: &str,
+        request_id: Option<&str>,
+        catalog: Vec<TableAccessCatalogEntry>,
+    ) -> Result<Vec<TableAccessCatalogEntry>, DataApiDomainError>;
+    async fn effective_writable_catalog(
+        &self,
+        tenant_id: &TenantId,
+        principal: &str,
+        catalog: Vec<TableAccessCatalogEntry>,
+    ) -> Result<Vec<TableAccessCatalogEntry>, DataApiDomainError>;
 }
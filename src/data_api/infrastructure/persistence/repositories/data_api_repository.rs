@@ -3,7 +3,11 @@ use serde_json::Value;
 
 use crate::data_api::domain::model::{
     entities::table_schema_metadata::TableSchemaMetadata,
-    enums::data_api_domain_error::DataApiDomainError, value_objects::tenant_id::TenantId,
+    enums::{
+        data_api_domain_error::DataApiDomainError, data_api_filter_operator::DataApiFilterOperator,
+        data_api_read_transform::DataApiReadTransform,
+    },
+    value_objects::{list_rows_cursor::ListRowsCursor, tenant_id::TenantId},
 };
 
 #[derive(Clone, Debug)]
@@ -11,11 +15,21 @@ pub struct ListRowsCriteria {
     pub schema_name: String,
     pub table_name: String,
     pub fields: Vec<String>,
-    pub filters: Vec<(String, String)>,
+    pub filters: Vec<(String, DataApiFilterOperator)>,
     pub limit: i64,
     pub offset: i64,
     pub order_by: Option<String>,
     pub order_desc: bool,
+    pub primary_key_column: Option<String>,
+    pub after: Option<ListRowsCursor>,
+    pub text_columns: Vec<String>,
+    pub soft_delete_column: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListRowsStatement {
+    pub sql: String,
+    pub parameter_count: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -24,6 +38,8 @@ pub struct GetRowByPrimaryKeyCriteria {
     pub table_name: String,
     pub primary_key_column: String,
     pub primary_key_value: String,
+    pub text_columns: Vec<String>,
+    pub soft_delete_column: Option<String>,
 }
 
 pub struct CreateRowCriteria<'a> {
@@ -33,6 +49,13 @@ pub struct CreateRowCriteria<'a> {
     pub allowed_columns: &'a [String],
 }
 
+pub struct CreateRowsCriteria<'a> {
+    pub schema_name: &'a str,
+    pub table_name: &'a str,
+    pub payloads: &'a [Value],
+    pub allowed_columns: &'a [String],
+}
+
 pub struct PatchRowCriteria<'a> {
     pub schema_name: &'a str,
     pub table_name: &'a str,
@@ -40,6 +63,18 @@ pub struct PatchRowCriteria<'a> {
     pub primary_key_value: &'a str,
     pub payload: &'a Value,
     pub allowed_columns: &'a [String],
+    pub soft_delete_column: Option<&'a str>,
+}
+
+pub struct PutRowCriteria<'a> {
+    pub schema_name: &'a str,
+    pub table_name: &'a str,
+    pub primary_key_column: &'a str,
+    pub primary_key_value: &'a str,
+    pub payload: &'a Value,
+    pub assigned_columns: &'a [String],
+    pub defaulted_columns: &'a [String],
+    pub soft_delete_column: Option<&'a str>,
 }
 
 pub struct DeleteRowCriteria<'a> {
@@ -47,6 +82,14 @@ pub struct DeleteRowCriteria<'a> {
     pub table_name: &'a str,
     pub primary_key_column: &'a str,
     pub primary_key_value: &'a str,
+    pub soft_delete_column: Option<&'a str>,
+}
+
+pub enum TransactionOperationCriteria<'a> {
+    Create(CreateRowCriteria<'a>),
+    CreateMany(CreateRowsCriteria<'a>),
+    Patch(PatchRowCriteria<'a>),
+    Delete(DeleteRowCriteria<'a>),
 }
 
 #[derive(Clone, Debug)]
@@ -56,8 +99,10 @@ pub struct TableAccessMetadata {
     pub create_enabled: bool,
     pub update_enabled: bool,
     pub delete_enabled: bool,
+    pub append_only: bool,
     pub introspect_enabled: bool,
     pub authorization_mode: String,
+    pub soft_delete_column: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -67,14 +112,41 @@ pub struct TableMetadataUpdateCriteria {
     pub create_enabled: bool,
     pub update_enabled: bool,
     pub delete_enabled: bool,
+    pub append_only: Option<bool>,
     pub introspect_enabled: bool,
     pub authorization_mode: String,
+    pub soft_delete_column: Option<String>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ColumnMetadataUpdateCriteria {
     pub readable: bool,
     pub writable: bool,
+    pub read_transform: DataApiReadTransform,
+    pub filterable: Option<bool>,
+    pub orderable: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ColumnQueryRestrictions {
+    pub non_filterable_columns: Vec<String>,
+    pub non_orderable_columns: Vec<String>,
+}
+
+impl ColumnQueryRestrictions {
+    pub fn is_filterable(&self, column_name: &str) -> bool {
+        !self
+            .non_filterable_columns
+            .iter()
+            .any(|column| column == column_name)
+    }
+
+    pub fn is_orderable(&self, column_name: &str) -> bool {
+        !self
+            .non_orderable_columns
+            .iter()
+            .any(|column| column == column_name)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -85,9 +157,28 @@ pub struct TableAccessCatalogEntry {
     pub create_enabled: bool,
     pub update_enabled: bool,
     pub delete_enabled: bool,
+    pub append_only: bool,
     pub introspect_enabled: bool,
     pub authorization_mode: String,
+    pub soft_delete_column: Option<String>,
     pub writable_columns: Vec<String>,
+    pub creatable_columns: Option<Vec<String>>,
+    pub updatable_columns: Option<Vec<String>>,
+}
+
+#[async_trait]
+pub trait DataApiTransaction: Send {
+    async fn get_row_by_primary_key(
+        &mut self,
+        criteria: GetRowByPrimaryKeyCriteria,
+    ) -> Result<Option<Value>, DataApiDomainError>;
+
+    async fn execute(
+        &mut self,
+        operation: TransactionOperationCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError>;
+
+    async fn commit(self: Box<Self>) -> Result<(), DataApiDomainError>;
 }
 
 #[async_trait]
@@ -112,6 +203,20 @@ pub trait DataApiRepository: Send + Sync {
         table_name: &str,
     ) -> Result<Vec<String>, DataApiDomainError>;
 
+    async fn list_read_transforms(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<(String, DataApiReadTransform)>, DataApiDomainError>;
+
+    async fn list_column_query_restrictions(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<ColumnQueryRestrictions, DataApiDomainError>;
+
     async fn list_access_catalog(
         &self,
         tenant_id: &TenantId,
@@ -135,6 +240,14 @@ pub trait DataApiRepository: Send + Sync {
         criteria: ColumnMetadataUpdateCriteria,
     ) -> Result<(), DataApiDomainError>;
 
+    async fn upsert_column_access_metadata_batch(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+        columns: &[(String, ColumnMetadataUpdateCriteria)],
+    ) -> Result<(), DataApiDomainError>;
+
     async fn introspect_table(
         &self,
         tenant_id: &TenantId,
@@ -148,6 +261,17 @@ pub trait DataApiRepository: Send + Sync {
         criteria: ListRowsCriteria,
     ) -> Result<Value, DataApiDomainError>;
 
+    async fn count_rows(
+        &self,
+        tenant_id: &TenantId,
+        criteria: ListRowsCriteria,
+    ) -> Result<i64, DataApiDomainError>;
+
+    fn describe_list_rows(
+        &self,
+        criteria: ListRowsCriteria,
+    ) -> Result<ListRowsStatement, DataApiDomainError>;
+
     async fn get_row_by_primary_key(
         &self,
         tenant_id: &TenantId,
@@ -160,15 +284,38 @@ pub trait DataApiRepository: Send + Sync {
         criteria: CreateRowCriteria<'_>,
     ) -> Result<Value, DataApiDomainError>;
 
+    async fn create_rows(
+        &self,
+        tenant_id: &TenantId,
+        criteria: CreateRowsCriteria<'_>,
+    ) -> Result<Vec<Value>, DataApiDomainError>;
+
     async fn patch_row(
         &self,
         tenant_id: &TenantId,
         criteria: PatchRowCriteria<'_>,
     ) -> Result<Option<Value>, DataApiDomainError>;
 
+    async fn put_row(
+        &self,
+        tenant_id: &TenantId,
+        criteria: PutRowCriteria<'_>,
+    ) -> Result<Option<Value>, DataApiDomainError>;
+
     async fn delete_row(
         &self,
         tenant_id: &TenantId,
         criteria: DeleteRowCriteria<'_>,
     ) -> Result<bool, DataApiDomainError>;
+
+    async fn execute_transaction(
+        &self,
+        tenant_id: &TenantId,
+        operations: Vec<TransactionOperationCriteria<'_>>,
+    ) -> Result<Vec<Value>, DataApiDomainError>;
+
+    async fn begin_transaction(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Box<dyn DataApiTransaction>, DataApiDomainError>;
 }
@@ -0,0 +1,57 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::data_api::{
+    domain::model::value_objects::tenant_id::TenantId,
+    infrastructure::persistence::repositories::metadata_sync_schedule_repository::MetadataSyncScheduleRepository,
+};
+
+pub struct InMemoryMetadataSyncScheduleRepositoryImpl {
+    interval: Duration,
+    last_sync: Mutex<HashMap<(Uuid, String), Instant>>,
+}
+
+impl InMemoryMetadataSyncScheduleRepositoryImpl {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_sync: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl MetadataSyncScheduleRepository for InMemoryMetadataSyncScheduleRepositoryImpl {
+    async fn try_begin_sync(&self, tenant_id: &TenantId, schema_name: &str) -> Option<Instant> {
+        let mut last_sync = self.last_sync.lock().await;
+        let now = Instant::now();
+
+        match last_sync.entry((tenant_id.value(), schema_name.to_string())) {
+            Entry::Occupied(entry) if now.duration_since(*entry.get()) < self.interval => None,
+            Entry::Occupied(mut entry) => {
+                entry.insert(now);
+                Some(now)
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(now);
+                Some(now)
+            }
+        }
+    }
+
+    async fn clear(&self, tenant_id: &TenantId, schema_name: &str, claimed_at: Instant) {
+        let mut last_sync = self.last_sync.lock().await;
+        if let Entry::Occupied(entry) =
+            last_sync.entry((tenant_id.value(), schema_name.to_string()))
+            && *entry.get() == claimed_at
+        {
+            entry.remove();
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod in_memory_metadata_sync_schedule_repository_impl;
@@ -0,0 +1,12 @@
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use crate::data_api::domain::model::value_objects::tenant_id::TenantId;
+
+#[async_trait]
+pub trait MetadataSyncScheduleRepository: Send + Sync {
+    async fn try_begin_sync(&self, tenant_id: &TenantId, schema_name: &str) -> Option<Instant>;
+
+    async fn clear(&self, tenant_id: &TenantId, schema_name: &str, claimed_at: Instant);
+}
@@ -1,5 +1,7 @@
 pub mod data_api_audit_log_repository;
 pub mod data_api_repository;
+pub mod in_memory;
+pub mod metadata_sync_schedule_repository;
 pub mod postgres;
 pub mod tenant_connection_resolver_repository;
 pub mod tenant_pool_cache_repository;
@@ -1,29 +1,76 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use serde_json::Value;
-use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+use serde_json::{Value, json};
+use sqlx::{
+    Connection, PgConnection, PgPool, Postgres, QueryBuilder, Row, Transaction,
+    pool::PoolConnection,
+};
 
 use crate::data_api::{
     domain::model::{
         entities::table_schema_metadata::{TableColumnMetadata, TableSchemaMetadata},
-        enums::data_api_domain_error::DataApiDomainError,
+        enums::{
+            data_api_domain_error::DataApiDomainError,
+            data_api_filter_operator::DataApiFilterOperator,
+            data_api_read_transform::DataApiReadTransform,
+        },
         value_objects::tenant_id::TenantId,
     },
     infrastructure::persistence::repositories::{
         data_api_repository::{
-            ColumnMetadataUpdateCriteria, CreateRowCriteria, DataApiRepository, DeleteRowCriteria,
-            GetRowByPrimaryKeyCriteria, ListRowsCriteria, PatchRowCriteria,
-            TableAccessCatalogEntry, TableAccessMetadata, TableMetadataUpdateCriteria,
+            ColumnMetadataUpdateCriteria, ColumnQueryRestrictions, CreateRowCriteria,
+            CreateRowsCriteria, DataApiRepository, DataApiTransaction, DeleteRowCriteria,
+            GetRowByPrimaryKeyCriteria, ListRowsCriteria, ListRowsStatement, PatchRowCriteria,
+            PutRowCriteria, TableAccessCatalogEntry, TableAccessMetadata,
+            TableMetadataUpdateCriteria, TransactionOperationCriteria,
+        },
+        metadata_sync_schedule_repository::MetadataSyncScheduleRepository,
+        tenant_connection_resolver_repository::{
+            TenantConnectionResolverRepository, TenantConnectionTarget,
         },
-        tenant_connection_resolver_repository::TenantConnectionResolverRepository,
         tenant_pool_cache_repository::TenantPoolCacheRepository,
     },
 };
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProjectedField {
+    Column(String),
+    JsonPath { column: String, path: Vec<String> },
+}
+
+pub struct SqlxDataApiTransaction {
+    transaction: Transaction<'static, Postgres>,
+}
+
+#[async_trait::async_trait]
+impl DataApiTransaction for SqlxDataApiTransaction {
+    async fn get_row_by_primary_key(
+        &mut self,
+        criteria: GetRowByPrimaryKeyCriteria,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        SqlxDataApiRepositoryImpl::select_row(&mut self.transaction, &criteria, true).await
+    }
+
+    async fn execute(
+        &mut self,
+        operation: TransactionOperationCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError> {
+        SqlxDataApiRepositoryImpl::execute_operation(&mut self.transaction, &operation).await
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), DataApiDomainError> {
+        self.transaction
+            .commit()
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+    }
+}
+
 pub struct SqlxDataApiRepositoryImpl {
     admin_pool: PgPool,
     tenant_connection_resolver: Arc<dyn TenantConnectionResolverRepository>,
     tenant_pool_cache: Arc<dyn TenantPoolCacheRepository>,
+    metadata_sync_schedule: Arc<dyn MetadataSyncScheduleRepository>,
 }
 
 impl SqlxDataApiRepositoryImpl {
@@ -31,26 +78,78 @@ impl SqlxDataApiRepositoryImpl {
         admin_pool: PgPool,
         tenant_connection_resolver: Arc<dyn TenantConnectionResolverRepository>,
         tenant_pool_cache: Arc<dyn TenantPoolCacheRepository>,
+        metadata_sync_schedule: Arc<dyn MetadataSyncScheduleRepository>,
     ) -> Self {
         Self {
             admin_pool,
             tenant_connection_resolver,
             tenant_pool_cache,
+            metadata_sync_schedule,
         }
     }
 
     async fn resolve_tenant_pool(
         &self,
         tenant_id: &TenantId,
-    ) -> Result<PgPool, DataApiDomainError> {
-        let database_url = self
+    ) -> Result<(TenantConnectionTarget, PgPool), DataApiDomainError> {
+        let target = self
             .tenant_connection_resolver
-            .resolve_database_url(tenant_id)
+            .resolve_connection_target(tenant_id)
             .await?;
 
-        self.tenant_pool_cache
-            .get_or_create_pool(&database_url)
-            .await
+        let tenant_pool = self
+            .tenant_pool_cache
+            .get_or_create_pool(&target.database_url)
+            .await?;
+
+        Ok((target, tenant_pool))
+    }
+
+    async fn connection_failed(
+        &self,
+        tenant_pool: &PgPool,
+        database_url: &str,
+        error: sqlx::Error,
+    ) -> DataApiDomainError {
+        if tenant_pool.size() == 0 {
+            self.tenant_pool_cache.evict(database_url).await;
+        }
+        DataApiDomainError::InfrastructureError(error.to_string())
+    }
+
+    async fn apply_session_parameters(
+        connection: &mut PgConnection,
+        target: &TenantConnectionTarget,
+    ) -> Result<(), DataApiDomainError> {
+        for (name, value) in &target.session_parameters {
+            sqlx::query("SELECT set_config($1, $2, false)")
+                .bind(name)
+                .bind(value)
+                .execute(&mut *connection)
+                .await
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    async fn acquire_tenant_connection(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<PoolConnection<Postgres>, DataApiDomainError> {
+        let (target, tenant_pool) = self.resolve_tenant_pool(tenant_id).await?;
+        let mut connection = match tenant_pool.acquire().await {
+            Ok(connection) => connection,
+            Err(error) => {
+                return Err(self
+                    .connection_failed(&tenant_pool, &target.database_url, error)
+                    .await);
+            }
+        };
+
+        Self::apply_session_parameters(&mut connection, &target).await?;
+
+        Ok(connection)
     }
 
     fn quote_identifier(identifier: &str) -> Result<String, DataApiDomainError> {
@@ -65,23 +164,517 @@ impl SqlxDataApiRepositoryImpl {
         Ok(format!("\"{}\"", identifier))
     }
 
-    fn qualified_table(schema_name: &str, table_name: &str) -> Result<String, DataApiDomainError> {
-        Ok(format!(
-            "{}.{}",
-            Self::quote_identifier(schema_name)?,
-            Self::quote_identifier(table_name)?
-        ))
+    async fn insert_row(
+        connection: &mut PgConnection,
+        criteria: &CreateRowCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
+        let columns_csv = criteria
+            .allowed_columns
+            .iter()
+            .map(|c| Self::quote_identifier(c))
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", ");
+
+        let statement = format!(
+            "INSERT INTO {} AS t ({}) SELECT {} FROM jsonb_populate_record(NULL::{}, $1::jsonb) AS r RETURNING to_jsonb(t) AS payload",
+            qualified_table,
+            columns_csv,
+            criteria
+                .allowed_columns
+                .iter()
+                .map(|c| Self::quote_identifier(c))
+                .collect::<Result<Vec<_>, _>>()?
+                .iter()
+                .map(|c| format!("r.{c}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            qualified_table
+        );
+
+        let row = sqlx::query(&statement)
+            .bind(criteria.payload)
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(Self::map_write_error)?;
+
+        row.try_get("payload")
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
     }
-}
 
-#[async_trait::async_trait]
-impl DataApiRepository for SqlxDataApiRepositoryImpl {
-    async fn synchronize_metadata(
+    async fn insert_rows(
+        connection: &mut PgConnection,
+        criteria: &CreateRowsCriteria<'_>,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
+        let quoted_columns = criteria
+            .allowed_columns
+            .iter()
+            .map(|c| Self::quote_identifier(c))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let statement = format!(
+            "INSERT INTO {} AS t ({}) SELECT {} FROM jsonb_populate_recordset(NULL::{}, $1::jsonb) AS r RETURNING to_jsonb(t) AS payload",
+            qualified_table,
+            quoted_columns.join(", "),
+            quoted_columns
+                .iter()
+                .map(|c| format!("r.{c}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            qualified_table
+        );
+
+        let rows = sqlx::query(&statement)
+            .bind(Value::Array(criteria.payloads.to_vec()))
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(Self::map_write_error)?;
+
+        rows.into_iter()
+            .map(|row| {
+                row.try_get("payload")
+                    .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+            })
+            .collect()
+    }
+
+    async fn update_row(
+        connection: &mut PgConnection,
+        criteria: &PatchRowCriteria<'_>,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
+        let primary_key_column = Self::quote_identifier(criteria.primary_key_column)?;
+
+        let set_clause = criteria
+            .allowed_columns
+            .iter()
+            .map(|c| {
+                let quoted = Self::quote_identifier(c)?;
+                Ok::<String, DataApiDomainError>(format!("{} = r.{}", quoted, quoted))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", ");
+
+        let statement = format!(
+            "UPDATE {} AS t SET {} FROM jsonb_populate_record(NULL::{}, $1::jsonb) AS r WHERE t.{}::text = $2{} RETURNING to_jsonb(t) AS payload",
+            qualified_table,
+            set_clause,
+            qualified_table,
+            primary_key_column,
+            Self::live_row_condition(criteria.soft_delete_column)?
+        );
+
+        let row = sqlx::query(&statement)
+            .bind(criteria.payload)
+            .bind(criteria.primary_key_value)
+            .fetch_optional(&mut *connection)
+            .await
+            .map_err(Self::map_write_error)?;
+
+        row.map(|r| {
+            r.try_get("payload")
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+        })
+        .transpose()
+    }
+
+    async fn remove_row(
+        connection: &mut PgConnection,
+        criteria: &DeleteRowCriteria<'_>,
+    ) -> Result<bool, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
+        let primary_key_column = Self::quote_identifier(criteria.primary_key_column)?;
+
+        let statement = match criteria.soft_delete_column {
+            Some(column) => {
+                let soft_delete_column = Self::quote_identifier(column)?;
+                format!(
+                    "UPDATE {} SET {} = NOW() WHERE {}::text = $1 AND {} IS NULL",
+                    qualified_table, soft_delete_column, primary_key_column, soft_delete_column
+                )
+            }
+            None => format!(
+                "DELETE FROM {} WHERE {}::text = $1",
+                qualified_table, primary_key_column
+            ),
+        };
+
+        let result = sqlx::query(&statement)
+            .bind(criteria.primary_key_value)
+            .execute(&mut *connection)
+            .await
+            .map_err(Self::map_write_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn select_row(
+        connection: &mut PgConnection,
+        criteria: &GetRowByPrimaryKeyCriteria,
+        lock_row: bool,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(&criteria.schema_name, &criteria.table_name)?;
+        let primary_key_column = Self::quote_identifier(&criteria.primary_key_column)?;
+
+        let statement = format!(
+            "SELECT to_jsonb(t){} AS payload FROM {} AS t WHERE t.{}::text = $1{}{}",
+            Self::text_overrides(&criteria.text_columns)?,
+            qualified_table,
+            primary_key_column,
+            Self::live_row_condition(criteria.soft_delete_column.as_deref())?,
+            if lock_row { " FOR UPDATE" } else { "" }
+        );
+
+        let row = sqlx::query(&statement)
+            .bind(&criteria.primary_key_value)
+            .fetch_optional(&mut *connection)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(|r| {
+            r.try_get("payload")
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+        })
+        .transpose()
+    }
+
+    async fn execute_operation(
+        connection: &mut PgConnection,
+        operation: &TransactionOperationCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError> {
+        match operation {
+            TransactionOperationCriteria::Create(criteria) => {
+                if criteria.allowed_columns.is_empty() {
+                    return Err(DataApiDomainError::InvalidPayload);
+                }
+                Self::insert_row(connection, criteria).await
+            }
+            TransactionOperationCriteria::CreateMany(criteria) => {
+                if criteria.allowed_columns.is_empty() || criteria.payloads.is_empty() {
+                    return Err(DataApiDomainError::InvalidPayload);
+                }
+                Ok(Value::Array(Self::insert_rows(connection, criteria).await?))
+            }
+            TransactionOperationCriteria::Patch(criteria) => {
+                if criteria.allowed_columns.is_empty() {
+                    return Err(DataApiDomainError::InvalidPayload);
+                }
+                Self::update_row(connection, criteria)
+                    .await?
+                    .ok_or(DataApiDomainError::RecordNotFound)
+            }
+            TransactionOperationCriteria::Delete(criteria) => {
+                if !Self::remove_row(connection, criteria).await? {
+                    return Err(DataApiDomainError::RecordNotFound);
+                }
+                Ok(Value::Null)
+            }
+        }
+    }
+
+    fn live_row_condition(soft_delete_column: Option<&str>) -> Result<String, DataApiDomainError> {
+        soft_delete_column
+            .map(|column| {
+                Ok(format!(
+                    " AND t.{} IS NULL",
+                    Self::quote_identifier(column)?
+                ))
+            })
+            .unwrap_or_else(|| Ok(String::new()))
+    }
+
+    pub fn map_write_error(error: sqlx::Error) -> DataApiDomainError {
+        match &error {
+            sqlx::Error::Database(database_error) => match database_error.code().as_deref() {
+                Some("23505") => DataApiDomainError::UniqueViolation,
+                Some("23503") => DataApiDomainError::ForeignKeyViolation,
+                _ => DataApiDomainError::InfrastructureError(error.to_string()),
+            },
+            _ => DataApiDomainError::InfrastructureError(error.to_string()),
+        }
+    }
+
+    pub fn map_replace_error(error: sqlx::Error) -> DataApiDomainError {
+        match &error {
+            sqlx::Error::Database(database_error)
+                if database_error.code().as_deref() == Some("23502") =>
+            {
+                DataApiDomainError::InvalidPayload
+            }
+            _ => Self::map_write_error(error),
+        }
+    }
+
+    pub fn parse_projected_field(field: &str) -> Result<ProjectedField, DataApiDomainError> {
+        let Some((column, path)) = field.split_once("->>") else {
+            Self::quote_identifier(field)?;
+            return Ok(ProjectedField::Column(field.to_string()));
+        };
+
+        Self::quote_identifier(column)?;
+        let path = path.split('.').map(str::to_string).collect::<Vec<_>>();
+        if path.iter().any(|segment| {
+            segment.is_empty()
+                || !segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        }) {
+            return Err(DataApiDomainError::InvalidQueryParameters);
+        }
+
+        Ok(ProjectedField::JsonPath {
+            column: column.to_string(),
+            path,
+        })
+    }
+
+    pub fn list_rows_statement(
+        criteria: ListRowsCriteria,
+    ) -> Result<ListRowsStatement, DataApiDomainError> {
+        let (builder, parameter_count) = Self::build_list_rows_query(criteria)?;
+
+        Ok(ListRowsStatement {
+            sql: builder.sql().to_string(),
+            parameter_count,
+        })
+    }
+
+    fn distinct_columns<'a>(columns: impl IntoIterator<Item = &'a String>) -> Vec<&'a String> {
+        let mut seen = HashSet::new();
+        columns
+            .into_iter()
+            .filter(|column| seen.insert(column.as_str()))
+            .collect()
+    }
+
+    fn build_list_rows_query(
+        criteria: ListRowsCriteria,
+    ) -> Result<(QueryBuilder<'static, Postgres>, usize), DataApiDomainError> {
+        let mut parameter_count = criteria.filters.len() + 2;
+        let qualified_table = Self::qualified_table(&criteria.schema_name, &criteria.table_name)?;
+
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT COALESCE(jsonb_agg(payload), '[]'::jsonb) AS payload FROM (SELECT ",
+        );
+        if criteria.fields.is_empty() {
+            builder.push(format!(
+                "to_jsonb(t){}",
+                Self::text_overrides(&criteria.text_columns)?
+            ));
+        } else {
+            builder.push("jsonb_build_object(");
+            for (index, field) in Self::distinct_columns(&criteria.fields)
+                .into_iter()
+                .enumerate()
+            {
+                if index > 0 {
+                    builder.push(", ");
+                }
+                match Self::parse_projected_field(field)? {
+                    ProjectedField::Column(column) => {
+                        let quoted = Self::quote_identifier(&column)?;
+                        if criteria.text_columns.contains(&column) {
+                            builder.push(format!("'{column}', t.{quoted}::text"));
+                        } else {
+                            builder.push(format!("'{column}', t.{quoted}"));
+                        }
+                    }
+                    ProjectedField::JsonPath { column, path } => {
+                        let quoted = Self::quote_identifier(&column)?;
+                        builder.push_bind(field.clone());
+                        builder.push(format!("::text, t.{quoted} #>> "));
+                        builder.push_bind(path);
+                        builder.push("::text[]");
+                        parameter_count += 2;
+                    }
+                }
+            }
+            builder.push(")");
+        }
+        builder.push(format!(" AS payload FROM {qualified_table} AS t"));
+
+        let has_where = Self::push_filters(&mut builder, &qualified_table, criteria.filters)?;
+        let has_where = Self::push_soft_delete_predicate(
+            &mut builder,
+            criteria.soft_delete_column.as_deref(),
+            has_where,
+        )?;
+        let direction = if criteria.order_desc { "DESC" } else { "ASC" };
+
+        if let Some(cursor) = &criteria.after {
+            let (Some(order_by), Some(primary_key_column)) =
+                (&criteria.order_by, &criteria.primary_key_column)
+            else {
+                return Err(DataApiDomainError::InvalidQueryParameters);
+            };
+            let order_column = Self::quote_identifier(order_by)?;
+            let primary_key = Self::quote_identifier(primary_key_column)?;
+            let comparison = if criteria.order_desc { "<" } else { ">" };
+
+            builder.push(if has_where { " AND " } else { " WHERE " });
+            builder.push(format!(
+                "(t.{order_column}, t.{primary_key}) {comparison} (SELECT c.{order_column}, c.{primary_key} FROM jsonb_populate_record(NULL::{qualified_table}, "
+            ));
+            builder.push_bind(json!({
+                order_by: cursor.order_value(),
+                primary_key_column: cursor.primary_key_value(),
+            }));
+            builder.push("::jsonb) AS c)");
+            parameter_count += 1;
+        }
+
+        if let Some(order_by) = &criteria.order_by {
+            let order_columns = Self::distinct_columns(
+                std::iter::once(order_by).chain(criteria.primary_key_column.as_ref()),
+            );
+            for (index, column) in order_columns.into_iter().enumerate() {
+                let quoted = Self::quote_identifier(column)?;
+                builder.push(if index == 0 { " ORDER BY " } else { ", " });
+                builder.push(format!("t.{quoted} {direction}"));
+            }
+        }
+
+        builder.push(" LIMIT ");
+        builder.push_bind(criteria.limit);
+        if criteria.after.is_some() {
+            parameter_count -= 1;
+        } else {
+            builder.push(" OFFSET ");
+            builder.push_bind(criteria.offset);
+        }
+        builder.push(") AS subq");
+
+        Ok((builder, parameter_count))
+    }
+
+    fn push_filters(
+        builder: &mut QueryBuilder<'_, Postgres>,
+        qualified_table: &str,
+        filters: Vec<(String, DataApiFilterOperator)>,
+    ) -> Result<bool, DataApiDomainError> {
+        let mut has_where = false;
+        for (column, operator) in filters {
+            let quoted = Self::quote_identifier(&column)?;
+            if !has_where {
+                builder.push(" WHERE ");
+                has_where = true;
+            } else {
+                builder.push(" AND ");
+            }
+            match operator {
+                DataApiFilterOperator::Eq(value) => {
+                    builder.push(format!("t.{quoted}::text = "));
+                    builder.push_bind(value);
+                }
+                DataApiFilterOperator::Ne(value) => {
+                    builder.push(format!("t.{quoted}::text <> "));
+                    builder.push_bind(value);
+                }
+                DataApiFilterOperator::In(values) | DataApiFilterOperator::NotIn(values)
+                    if values.is_empty() =>
+                {
+                    return Err(DataApiDomainError::InvalidQueryParameters);
+                }
+                DataApiFilterOperator::In(values) => {
+                    builder.push(format!("t.{quoted}::text = ANY("));
+                    builder.push_bind(values);
+                    builder.push(")");
+                }
+                DataApiFilterOperator::NotIn(values) => {
+                    builder.push(format!("NOT (t.{quoted}::text = ANY("));
+                    builder.push_bind(values);
+                    builder.push("))");
+                }
+                DataApiFilterOperator::Gt(value) => {
+                    Self::push_typed_comparison(builder, qualified_table, &column, ">", value)?
+                }
+                DataApiFilterOperator::Gte(value) => {
+                    Self::push_typed_comparison(builder, qualified_table, &column, ">=", value)?
+                }
+                DataApiFilterOperator::Lt(value) => {
+                    Self::push_typed_comparison(builder, qualified_table, &column, "<", value)?
+                }
+                DataApiFilterOperator::Lte(value) => {
+                    Self::push_typed_comparison(builder, qualified_table, &column, "<=", value)?
+                }
+            }
+        }
+
+        Ok(has_where)
+    }
+
+    fn push_soft_delete_predicate(
+        builder: &mut QueryBuilder<'_, Postgres>,
+        soft_delete_column: Option<&str>,
+        has_where: bool,
+    ) -> Result<bool, DataApiDomainError> {
+        let Some(column) = soft_delete_column else {
+            return Ok(has_where);
+        };
+
+        let quoted = Self::quote_identifier(column)?;
+        builder.push(if has_where { " AND " } else { " WHERE " });
+        builder.push(format!("t.{quoted} IS NULL"));
+
+        Ok(true)
+    }
+
+    fn push_typed_comparison(
+        builder: &mut QueryBuilder<'_, Postgres>,
+        qualified_table: &str,
+        column: &str,
+        comparison: &str,
+        value: String,
+    ) -> Result<(), DataApiDomainError> {
+        let quoted = Self::quote_identifier(column)?;
+        builder.push(format!(
+            "t.{quoted} {comparison} (jsonb_populate_record(NULL::{qualified_table}, jsonb_build_object('{column}', "
+        ));
+        builder.push_bind(value);
+        builder.push(format!("::text))).{quoted}"));
+
+        Ok(())
+    }
+
+    fn text_overrides(text_columns: &[String]) -> Result<String, DataApiDomainError> {
+        if text_columns.is_empty() {
+            return Ok(String::new());
+        }
+
+        let mut pairs = Vec::with_capacity(text_columns.len());
+        for column in text_columns {
+            let quoted = Self::quote_identifier(column)?;
+            pairs.push(format!("'{}', t.{}::text", column, quoted));
+        }
+
+        Ok(format!(" || jsonb_build_object({})", pairs.join(", ")))
+    }
+
+    async fn synchronize_metadata_now(
         &self,
         tenant_id: &TenantId,
         schema_name: &str,
     ) -> Result<(), DataApiDomainError> {
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+
+        let schema_exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM information_schema.schemata
+                WHERE schema_name = $1
+            )
+        "#,
+        )
+        .bind(schema_name)
+        .fetch_one(&mut *tenant_connection)
+        .await
+        .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        if !schema_exists {
+            return Err(DataApiDomainError::SchemaNotFound);
+        }
 
         let table_rows = sqlx::query(
             r#"
@@ -93,7 +686,7 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         "#,
         )
         .bind(schema_name)
-        .fetch_all(&tenant_pool)
+        .fetch_all(&mut *tenant_connection)
         .await
         .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
@@ -151,7 +744,7 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             )
             .bind(schema_name)
             .bind(&table_name)
-            .fetch_all(&tenant_pool)
+            .fetch_all(&mut *tenant_connection)
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
@@ -188,7 +781,41 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             }
         }
 
-        Ok(())
+        Ok(())
+    }
+
+    fn qualified_table(schema_name: &str, table_name: &str) -> Result<String, DataApiDomainError> {
+        Ok(format!(
+            "{}.{}",
+            Self::quote_identifier(schema_name)?,
+            Self::quote_identifier(table_name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataApiRepository for SqlxDataApiRepositoryImpl {
+    async fn synchronize_metadata(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+    ) -> Result<(), DataApiDomainError> {
+        let Some(claimed_at) = self
+            .metadata_sync_schedule
+            .try_begin_sync(tenant_id, schema_name)
+            .await
+        else {
+            return Ok(());
+        };
+
+        let result = self.synchronize_metadata_now(tenant_id, schema_name).await;
+        if result.is_err() {
+            self.metadata_sync_schedule
+                .clear(tenant_id, schema_name, claimed_at)
+                .await;
+        }
+
+        result
     }
 
     async fn get_table_access_metadata(
@@ -204,8 +831,10 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled,
                 update_enabled,
                 delete_enabled,
+                append_only,
                 introspect_enabled,
-                authorization_mode
+                authorization_mode,
+                soft_delete_column
             FROM data_api_table_metadata
             WHERE tenant_id = $1
                 AND schema_name = $2
@@ -227,12 +856,16 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             create_enabled: row.try_get::<bool, _>("create_enabled").unwrap_or(false),
             update_enabled: row.try_get::<bool, _>("update_enabled").unwrap_or(false),
             delete_enabled: row.try_get::<bool, _>("delete_enabled").unwrap_or(false),
+            append_only: row.try_get::<bool, _>("append_only").unwrap_or(false),
             introspect_enabled: row
                 .try_get::<bool, _>("introspect_enabled")
                 .unwrap_or(false),
             authorization_mode: row
                 .try_get::<String, _>("authorization_mode")
                 .unwrap_or_else(|_| "authenticated".to_string()),
+            soft_delete_column: row
+                .try_get::<Option<String>, _>("soft_delete_column")
+                .unwrap_or(None),
         })
     }
 
@@ -265,6 +898,98 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             .collect())
     }
 
+    async fn list_read_transforms(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<(String, DataApiReadTransform)>, DataApiDomainError> {
+        let statement = r#"
+            SELECT column_name, read_transform
+            FROM data_api_column_metadata
+            WHERE tenant_id = $1
+                AND schema_name = $2
+                AND table_name = $3
+                AND read_transform <> 'none'
+        "#;
+
+        let rows = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .bind(schema_name)
+            .bind(table_name)
+            .fetch_all(&self.admin_pool)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let column_name = row
+                    .try_get::<String, _>("column_name")
+                    .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+                let read_transform = row
+                    .try_get::<String, _>("read_transform")
+                    .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+                let read_transform =
+                    read_transform
+                        .parse::<DataApiReadTransform>()
+                        .map_err(|_| {
+                            DataApiDomainError::InfrastructureError(format!(
+                                "invalid read transform for column {column_name}: {read_transform}"
+                            ))
+                        })?;
+
+                Ok((column_name, read_transform))
+            })
+            .collect()
+    }
+
+    async fn list_column_query_restrictions(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<ColumnQueryRestrictions, DataApiDomainError> {
+        let statement = r#"
+            SELECT column_name, filterable, orderable
+            FROM data_api_column_metadata
+            WHERE tenant_id = $1
+                AND schema_name = $2
+                AND table_name = $3
+                AND (filterable = FALSE OR orderable = FALSE)
+        "#;
+
+        let rows = sqlx::query(statement)
+            .bind(tenant_id.value())
+            .bind(schema_name)
+            .bind(table_name)
+            .fetch_all(&self.admin_pool)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        let mut restrictions = ColumnQueryRestrictions::default();
+        for row in rows {
+            let column_name = row
+                .try_get::<String, _>("column_name")
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+            if !row
+                .try_get::<bool, _>("filterable")
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?
+            {
+                restrictions
+                    .non_filterable_columns
+                    .push(column_name.clone());
+            }
+            if !row
+                .try_get::<bool, _>("orderable")
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?
+            {
+                restrictions.non_orderable_columns.push(column_name);
+            }
+        }
+
+        Ok(restrictions)
+    }
+
     async fn list_access_catalog(
         &self,
         tenant_id: &TenantId,
@@ -278,8 +1003,10 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled,
                 update_enabled,
                 delete_enabled,
+                append_only,
                 introspect_enabled,
-                authorization_mode
+                authorization_mode,
+                soft_delete_column
             FROM data_api_table_metadata
             WHERE tenant_id = $1
                 AND schema_name = $2
@@ -309,13 +1036,19 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled: row.try_get::<bool, _>("create_enabled").unwrap_or(false),
                 update_enabled: row.try_get::<bool, _>("update_enabled").unwrap_or(false),
                 delete_enabled: row.try_get::<bool, _>("delete_enabled").unwrap_or(false),
+                append_only: row.try_get::<bool, _>("append_only").unwrap_or(false),
                 introspect_enabled: row
                     .try_get::<bool, _>("introspect_enabled")
                     .unwrap_or(false),
                 authorization_mode: row
                     .try_get::<String, _>("authorization_mode")
                     .unwrap_or_else(|_| "authenticated".to_string()),
+                soft_delete_column: row
+                    .try_get::<Option<String>, _>("soft_delete_column")
+                    .unwrap_or(None),
                 writable_columns,
+                creatable_columns: None,
+                updatable_columns: None,
             });
         }
 
@@ -329,6 +1062,73 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         table_name: &str,
         criteria: TableMetadataUpdateCriteria,
     ) -> Result<TableAccessMetadata, DataApiDomainError> {
+        let mut transaction = self
+            .admin_pool
+            .begin()
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(format!(
+                "data_api_table_metadata:{}:{}:{}",
+                tenant_id.value(),
+                schema_name,
+                table_name
+            ))
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        self.synchronize_metadata(tenant_id, schema_name).await?;
+
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        let table_exists = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM information_schema.tables
+                WHERE table_schema = $1
+                    AND table_name = $2
+                    AND table_type = 'BASE TABLE'
+            )
+        "#,
+        )
+        .bind(schema_name)
+        .bind(table_name)
+        .fetch_one(&mut *tenant_connection)
+        .await
+        .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        if !table_exists {
+            return Err(DataApiDomainError::TableNotFound);
+        }
+
+        if let Some(soft_delete_column) = criteria.soft_delete_column.as_deref() {
+            let column_exists = sqlx::query_scalar::<_, bool>(
+                r#"
+                SELECT EXISTS (
+                    SELECT 1
+                    FROM information_schema.columns
+                    WHERE table_schema = $1
+                        AND table_name = $2
+                        AND column_name = $3
+                )
+            "#,
+            )
+            .bind(schema_name)
+            .bind(table_name)
+            .bind(soft_delete_column)
+            .fetch_one(&mut *tenant_connection)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+            if !column_exists {
+                return Err(DataApiDomainError::InvalidColumnName(
+                    soft_delete_column.to_string(),
+                ));
+            }
+        }
+
         let statement = r#"
             INSERT INTO data_api_table_metadata (
                 tenant_id,
@@ -339,10 +1139,12 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled,
                 update_enabled,
                 delete_enabled,
+                append_only,
                 introspect_enabled,
-                authorization_mode
+                authorization_mode,
+                soft_delete_column
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, COALESCE($9, FALSE), $10, $11, $12)
             ON CONFLICT (tenant_id, schema_name, table_name)
             DO UPDATE SET
                 exposed = EXCLUDED.exposed,
@@ -350,8 +1152,10 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled = EXCLUDED.create_enabled,
                 update_enabled = EXCLUDED.update_enabled,
                 delete_enabled = EXCLUDED.delete_enabled,
+                append_only = COALESCE($9, data_api_table_metadata.append_only),
                 introspect_enabled = EXCLUDED.introspect_enabled,
                 authorization_mode = EXCLUDED.authorization_mode,
+                soft_delete_column = EXCLUDED.soft_delete_column,
                 updated_at = NOW()
             RETURNING
                 exposed,
@@ -359,8 +1163,10 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 create_enabled,
                 update_enabled,
                 delete_enabled,
+                append_only,
                 introspect_enabled,
-                authorization_mode
+                authorization_mode,
+                soft_delete_column
         "#;
 
         let row = sqlx::query(statement)
@@ -372,9 +1178,16 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             .bind(criteria.create_enabled)
             .bind(criteria.update_enabled)
             .bind(criteria.delete_enabled)
+            .bind(criteria.append_only)
             .bind(criteria.introspect_enabled)
             .bind(criteria.authorization_mode)
-            .fetch_one(&self.admin_pool)
+            .bind(criteria.soft_delete_column)
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        transaction
+            .commit()
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
@@ -384,12 +1197,16 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             create_enabled: row.try_get::<bool, _>("create_enabled").unwrap_or(false),
             update_enabled: row.try_get::<bool, _>("update_enabled").unwrap_or(false),
             delete_enabled: row.try_get::<bool, _>("delete_enabled").unwrap_or(false),
+            append_only: row.try_get::<bool, _>("append_only").unwrap_or(false),
             introspect_enabled: row
                 .try_get::<bool, _>("introspect_enabled")
                 .unwrap_or(false),
             authorization_mode: row
                 .try_get::<String, _>("authorization_mode")
                 .unwrap_or_else(|_| "authenticated".to_string()),
+            soft_delete_column: row
+                .try_get::<Option<String>, _>("soft_delete_column")
+                .unwrap_or(None),
         })
     }
 
@@ -400,6 +1217,22 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         table_name: &str,
         column_name: &str,
         criteria: ColumnMetadataUpdateCriteria,
+    ) -> Result<(), DataApiDomainError> {
+        self.upsert_column_access_metadata_batch(
+            tenant_id,
+            schema_name,
+            table_name,
+            &[(column_name.to_string(), criteria)],
+        )
+        .await
+    }
+
+    async fn upsert_column_access_metadata_batch(
+        &self,
+        tenant_id: &TenantId,
+        schema_name: &str,
+        table_name: &str,
+        columns: &[(String, ColumnMetadataUpdateCriteria)],
     ) -> Result<(), DataApiDomainError> {
         let statement = r#"
             INSERT INTO data_api_column_metadata (
@@ -408,24 +1241,46 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                 table_name,
                 column_name,
                 readable,
-                writable
+                writable,
+                read_transform,
+                filterable,
+                orderable
             )
-            VALUES ($1, $2, $3, $4, $5, $6)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, TRUE), COALESCE($9, TRUE))
             ON CONFLICT (tenant_id, schema_name, table_name, column_name)
             DO UPDATE SET
                 readable = EXCLUDED.readable,
                 writable = EXCLUDED.writable,
+                read_transform = EXCLUDED.read_transform,
+                filterable = COALESCE($8, data_api_column_metadata.filterable),
+                orderable = COALESCE($9, data_api_column_metadata.orderable),
                 updated_at = NOW()
         "#;
 
-        sqlx::query(statement)
-            .bind(tenant_id.value())
-            .bind(schema_name)
-            .bind(table_name)
-            .bind(column_name)
-            .bind(criteria.readable)
-            .bind(criteria.writable)
-            .execute(&self.admin_pool)
+        let mut transaction = self
+            .admin_pool
+            .begin()
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        for (column_name, criteria) in columns {
+            sqlx::query(statement)
+                .bind(tenant_id.value())
+                .bind(schema_name)
+                .bind(table_name)
+                .bind(column_name)
+                .bind(criteria.readable)
+                .bind(criteria.writable)
+                .bind(criteria.read_transform.to_string())
+                .bind(criteria.filterable)
+                .bind(criteria.orderable)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+        }
+
+        transaction
+            .commit()
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
@@ -438,13 +1293,15 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         schema_name: &str,
         table_name: &str,
     ) -> Result<TableSchemaMetadata, DataApiDomainError> {
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
 
         let statement = r#"
             SELECT
                 c.column_name,
                 c.is_nullable,
                 c.data_type,
+                c.ordinal_position::int4 AS ordinal_position,
+                c.column_default::text AS column_default,
                 EXISTS (
                     SELECT 1
                     FROM information_schema.table_constraints tc
@@ -464,7 +1321,7 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         let rows = sqlx::query(statement)
             .bind(schema_name)
             .bind(table_name)
-            .fetch_all(&tenant_pool)
+            .fetch_all(&mut *tenant_connection)
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
@@ -484,6 +1341,12 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
                     .try_get::<String, _>("data_type")
                     .unwrap_or_else(|_| "text".to_string()),
                 is_primary_key: row.try_get::<bool, _>("is_primary_key").unwrap_or(false),
+                ordinal_position: row
+                    .try_get::<i32, _>("ordinal_position")
+                    .unwrap_or_default(),
+                column_default: row
+                    .try_get::<Option<String>, _>("column_default")
+                    .unwrap_or(None),
             })
             .collect::<Vec<_>>();
 
@@ -499,85 +1362,61 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         tenant_id: &TenantId,
         criteria: ListRowsCriteria,
     ) -> Result<Value, DataApiDomainError> {
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
-        let qualified_table = Self::qualified_table(&criteria.schema_name, &criteria.table_name)?;
+        let (mut builder, _) = Self::build_list_rows_query(criteria)?;
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
 
-        let selected_projection = if criteria.fields.is_empty() {
-            "to_jsonb(t)".to_string()
-        } else {
-            let mut pairs = Vec::with_capacity(criteria.fields.len());
-            for field in &criteria.fields {
-                let quoted = Self::quote_identifier(field)?;
-                pairs.push(format!("'{}', t.{}", field, quoted));
-            }
-            format!("jsonb_build_object({})", pairs.join(", "))
-        };
+        let row = builder
+            .build()
+            .fetch_one(&mut *tenant_connection)
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        row.try_get("payload")
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+    }
 
+    async fn count_rows(
+        &self,
+        tenant_id: &TenantId,
+        criteria: ListRowsCriteria,
+    ) -> Result<i64, DataApiDomainError> {
+        let qualified_table = Self::qualified_table(&criteria.schema_name, &criteria.table_name)?;
         let mut builder = QueryBuilder::<Postgres>::new(format!(
-            "SELECT COALESCE(jsonb_agg(payload), '[]'::jsonb) AS payload FROM (SELECT {} AS payload FROM {} AS t",
-            selected_projection, qualified_table
+            "SELECT COUNT(*) AS total FROM {} AS t",
+            qualified_table
         ));
-
-        let mut has_where = false;
-        for (column, value) in criteria.filters {
-            let quoted = Self::quote_identifier(&column)?;
-            if !has_where {
-                builder.push(" WHERE ");
-                has_where = true;
-            } else {
-                builder.push(" AND ");
-            }
-            builder.push(format!("t.{quoted}::text = "));
-            builder.push_bind(value);
-        }
-
-        if let Some(order_by) = criteria.order_by {
-            let quoted = Self::quote_identifier(&order_by)?;
-            builder.push(format!(" ORDER BY t.{quoted} "));
-            builder.push(if criteria.order_desc { "DESC" } else { "ASC" });
-        }
-
-        builder.push(" LIMIT ");
-        builder.push_bind(criteria.limit);
-        builder.push(" OFFSET ");
-        builder.push_bind(criteria.offset);
-        builder.push(") AS subq");
-
+        let has_where = Self::push_filters(&mut builder, &qualified_table, criteria.filters)?;
+        Self::push_soft_delete_predicate(
+            &mut builder,
+            criteria.soft_delete_column.as_deref(),
+            has_where,
+        )?;
+
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
         let row = builder
             .build()
-            .fetch_one(&tenant_pool)
+            .fetch_one(&mut *tenant_connection)
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
-        row.try_get("payload")
+        row.try_get("total")
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
     }
 
+    fn describe_list_rows(
+        &self,
+        criteria: ListRowsCriteria,
+    ) -> Result<ListRowsStatement, DataApiDomainError> {
+        Self::list_rows_statement(criteria)
+    }
+
     async fn get_row_by_primary_key(
         &self,
         tenant_id: &TenantId,
         criteria: GetRowByPrimaryKeyCriteria,
     ) -> Result<Option<Value>, DataApiDomainError> {
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
-        let qualified_table = Self::qualified_table(&criteria.schema_name, &criteria.table_name)?;
-        let primary_key_column = Self::quote_identifier(&criteria.primary_key_column)?;
-
-        let statement = format!(
-            "SELECT to_jsonb(t) AS payload FROM {} AS t WHERE t.{}::text = $1",
-            qualified_table, primary_key_column
-        );
-
-        let row = sqlx::query(&statement)
-            .bind(criteria.primary_key_value)
-            .fetch_optional(&tenant_pool)
-            .await
-            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
-
-        row.map(|r| {
-            r.try_get("payload")
-                .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
-        })
-        .transpose()
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        Self::select_row(&mut tenant_connection, &criteria, false).await
     }
 
     async fn create_row(
@@ -589,39 +1428,33 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             return Err(DataApiDomainError::InvalidPayload);
         }
 
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
-        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
-        let columns_csv = criteria
-            .allowed_columns
-            .iter()
-            .map(|c| Self::quote_identifier(c))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(", ");
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        Self::insert_row(&mut tenant_connection, &criteria).await
+    }
 
-        let statement = format!(
-            "INSERT INTO {} AS t ({}) SELECT {} FROM jsonb_populate_record(NULL::{}, $1::jsonb) AS r RETURNING to_jsonb(t) AS payload",
-            qualified_table,
-            columns_csv,
-            criteria
-                .allowed_columns
-                .iter()
-                .map(|c| Self::quote_identifier(c))
-                .collect::<Result<Vec<_>, _>>()?
-                .iter()
-                .map(|c| format!("r.{c}"))
-                .collect::<Vec<_>>()
-                .join(", "),
-            qualified_table
-        );
+    async fn create_rows(
+        &self,
+        tenant_id: &TenantId,
+        criteria: CreateRowsCriteria<'_>,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        if criteria.allowed_columns.is_empty() || criteria.payloads.is_empty() {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
 
-        let row = sqlx::query(&statement)
-            .bind(criteria.payload)
-            .fetch_one(&tenant_pool)
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        let mut transaction = tenant_connection
+            .begin()
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
-        row.try_get("payload")
-            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))
+        let rows = Self::insert_rows(&mut transaction, &criteria).await?;
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(rows)
     }
 
     async fn patch_row(
@@ -633,31 +1466,50 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
             return Err(DataApiDomainError::InvalidPayload);
         }
 
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        Self::update_row(&mut tenant_connection, &criteria).await
+    }
+
+    async fn put_row(
+        &self,
+        tenant_id: &TenantId,
+        criteria: PutRowCriteria<'_>,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        if criteria.assigned_columns.is_empty() && criteria.defaulted_columns.is_empty() {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
+
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
         let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
         let primary_key_column = Self::quote_identifier(criteria.primary_key_column)?;
 
-        let set_clause = criteria
-            .allowed_columns
+        let mut assignments = criteria
+            .assigned_columns
             .iter()
             .map(|c| {
                 let quoted = Self::quote_identifier(c)?;
                 Ok::<String, DataApiDomainError>(format!("{} = r.{}", quoted, quoted))
             })
-            .collect::<Result<Vec<_>, _>>()?
-            .join(", ");
+            .collect::<Result<Vec<_>, _>>()?;
+        for column in criteria.defaulted_columns {
+            assignments.push(format!("{} = DEFAULT", Self::quote_identifier(column)?));
+        }
 
         let statement = format!(
-            "UPDATE {} AS t SET {} FROM jsonb_populate_record(NULL::{}, $1::jsonb) AS r WHERE t.{}::text = $2 RETURNING to_jsonb(t) AS payload",
-            qualified_table, set_clause, qualified_table, primary_key_column
+            "UPDATE {} AS t SET {} FROM jsonb_populate_record(NULL::{}, $1::jsonb) AS r WHERE t.{}::text = $2{} RETURNING to_jsonb(t) AS payload",
+            qualified_table,
+            assignments.join(", "),
+            qualified_table,
+            primary_key_column,
+            Self::live_row_condition(criteria.soft_delete_column)?
         );
 
         let row = sqlx::query(&statement)
             .bind(criteria.payload)
             .bind(criteria.primary_key_value)
-            .fetch_optional(&tenant_pool)
+            .fetch_optional(&mut *tenant_connection)
             .await
-            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+            .map_err(Self::map_replace_error)?;
 
         row.map(|r| {
             r.try_get("payload")
@@ -671,21 +1523,54 @@ impl DataApiRepository for SqlxDataApiRepositoryImpl {
         tenant_id: &TenantId,
         criteria: DeleteRowCriteria<'_>,
     ) -> Result<bool, DataApiDomainError> {
-        let tenant_pool = self.resolve_tenant_pool(tenant_id).await?;
-        let qualified_table = Self::qualified_table(criteria.schema_name, criteria.table_name)?;
-        let primary_key_column = Self::quote_identifier(criteria.primary_key_column)?;
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        Self::remove_row(&mut tenant_connection, &criteria).await
+    }
 
-        let statement = format!(
-            "DELETE FROM {} WHERE {}::text = $1",
-            qualified_table, primary_key_column
-        );
+    async fn execute_transaction(
+        &self,
+        tenant_id: &TenantId,
+        operations: Vec<TransactionOperationCriteria<'_>>,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        if operations.is_empty() {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
 
-        let result = sqlx::query(&statement)
-            .bind(criteria.primary_key_value)
-            .execute(&tenant_pool)
+        let mut tenant_connection = self.acquire_tenant_connection(tenant_id).await?;
+        let mut transaction = tenant_connection
+            .begin()
             .await
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
-        Ok(result.rows_affected() > 0)
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in &operations {
+            results.push(Self::execute_operation(&mut transaction, operation).await?);
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(results)
+    }
+
+    async fn begin_transaction(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Box<dyn DataApiTransaction>, DataApiDomainError> {
+        let (target, tenant_pool) = self.resolve_tenant_pool(tenant_id).await?;
+        let mut transaction = match tenant_pool.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => {
+                return Err(self
+                    .connection_failed(&tenant_pool, &target.database_url, error)
+                    .await);
+            }
+        };
+
+        Self::apply_session_parameters(&mut transaction, &target).await?;
+
+        Ok(Box::new(SqlxDataApiTransaction { transaction }))
     }
 }
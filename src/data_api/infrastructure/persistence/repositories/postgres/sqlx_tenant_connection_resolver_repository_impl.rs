@@ -1,35 +1,94 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use sqlx::{PgPool, Row};
 
 use crate::{
-    config::app_config::AppConfig,
+    config::{app_config::AppConfig, credential_cipher::CredentialCipher},
     data_api::{
         domain::model::{
             enums::data_api_domain_error::DataApiDomainError, value_objects::tenant_id::TenantId,
         },
-        infrastructure::persistence::repositories::tenant_connection_resolver_repository::TenantConnectionResolverRepository,
+        infrastructure::persistence::repositories::tenant_connection_resolver_repository::{
+            TenantConnectionResolverRepository, TenantConnectionTarget,
+        },
     },
 };
 
 pub struct SqlxTenantConnectionResolverRepositoryImpl {
     admin_pool: PgPool,
     config: AppConfig,
+    credential_cipher: Option<Arc<CredentialCipher>>,
+}
+
+struct ActiveTenantDatabase {
+    database_name: String,
+    username: String,
+    encrypted_password: Option<String>,
 }
 
 impl SqlxTenantConnectionResolverRepositoryImpl {
     pub fn new(admin_pool: PgPool, config: AppConfig) -> Self {
-        Self { admin_pool, config }
+        Self {
+            admin_pool,
+            config,
+            credential_cipher: None,
+        }
     }
-}
 
-#[async_trait]
-impl TenantConnectionResolverRepository for SqlxTenantConnectionResolverRepositoryImpl {
-    async fn resolve_database_url(
+    pub fn with_credential_cipher(
+        mut self,
+        credential_cipher: Option<Arc<CredentialCipher>>,
+    ) -> Self {
+        self.credential_cipher = credential_cipher;
+        self
+    }
+
+    pub fn connection_target_for(
+        &self,
+        database_name: &str,
+        username: &str,
+    ) -> TenantConnectionTarget {
+        match &self.config.tenant_pooler_url {
+            Some(pooler_url) => TenantConnectionTarget {
+                database_url: pooler_url.replace("{database}", database_name),
+                session_parameters: vec![("role".to_string(), username.to_string())],
+            },
+            None => TenantConnectionTarget {
+                database_url: self.config.database_url_for(database_name),
+                session_parameters: Vec::new(),
+            },
+        }
+    }
+
+    pub fn credential_connection_target_for(
+        &self,
+        database_name: &str,
+        username: &str,
+        encrypted_password: &str,
+    ) -> Result<TenantConnectionTarget, DataApiDomainError> {
+        let cipher = match (&self.credential_cipher, &self.config.tenant_pooler_url) {
+            (Some(cipher), None) => cipher,
+            _ => return Ok(self.connection_target_for(database_name, username)),
+        };
+        let password = cipher
+            .decrypt(database_name, encrypted_password)
+            .map_err(DataApiDomainError::InfrastructureError)?;
+
+        Ok(TenantConnectionTarget {
+            database_url: self
+                .config
+                .tenant_database_url_for(database_name, username, &password),
+            session_parameters: Vec::new(),
+        })
+    }
+
+    async fn find_active_database(
         &self,
         tenant_id: &TenantId,
-    ) -> Result<String, DataApiDomainError> {
+    ) -> Result<ActiveTenantDatabase, DataApiDomainError> {
         let statement = r#"
-            SELECT database_name, status
+            SELECT database_name, username, encrypted_password, status
             FROM provisioned_databases
             WHERE id = $1
         "#;
@@ -44,6 +103,12 @@ impl TenantConnectionResolverRepository for SqlxTenantConnectionResolverReposito
         let database_name: String = row
             .try_get("database_name")
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+        let username: String = row
+            .try_get("username")
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
+        let encrypted_password: Option<String> = row
+            .try_get("encrypted_password")
+            .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
         let status: String = row
             .try_get("status")
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
@@ -52,6 +117,39 @@ impl TenantConnectionResolverRepository for SqlxTenantConnectionResolverReposito
             return Err(DataApiDomainError::AccessDenied);
         }
 
-        Ok(self.config.database_url_for(&database_name))
+        Ok(ActiveTenantDatabase {
+            database_name,
+            username,
+            encrypted_password,
+        })
+    }
+}
+
+#[async_trait]
+impl TenantConnectionResolverRepository for SqlxTenantConnectionResolverRepositoryImpl {
+    async fn resolve_database_url(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<String, DataApiDomainError> {
+        Ok(self
+            .resolve_connection_target(tenant_id)
+            .await?
+            .database_url)
+    }
+
+    async fn resolve_connection_target(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<TenantConnectionTarget, DataApiDomainError> {
+        let database = self.find_active_database(tenant_id).await?;
+
+        match database.encrypted_password.as_deref() {
+            Some(encrypted_password) => self.credential_connection_target_for(
+                &database.database_name,
+                &database.username,
+                encrypted_password,
+            ),
+            None => Ok(self.connection_target_for(&database.database_name, &database.username)),
+        }
     }
 }
@@ -1,23 +1,133 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
-use sqlx::PgPool;
-use tokio::sync::RwLock;
+use sqlx::{PgPool, postgres::PgPoolOptions};
+use tokio::{sync::RwLock, task::JoinHandle};
 
-use crate::data_api::{
-    domain::model::enums::data_api_domain_error::DataApiDomainError,
-    infrastructure::persistence::repositories::tenant_pool_cache_repository::TenantPoolCacheRepository,
+use crate::{
+    config::tenant_pool_settings::TenantPoolSettings,
+    data_api::{
+        application::cache::tenant_pool_generations::TenantPoolGenerations,
+        domain::model::enums::data_api_domain_error::DataApiDomainError,
+        infrastructure::persistence::repositories::tenant_pool_cache_repository::{
+            TenantPoolCacheRepository, TenantPoolMetrics,
+        },
+    },
 };
 
+struct CachedTenantPool {
+    pool: PgPool,
+    generation: u64,
+    last_used: Mutex<Instant>,
+}
+
+impl CachedTenantPool {
+    fn touch(&self) -> PgPool {
+        *self.last_used.lock().expect("mutex poisoned") = Instant::now();
+        self.pool.clone()
+    }
+
+    fn is_expired(&self, idle_timeout: Duration) -> bool {
+        self.last_used.lock().expect("mutex poisoned").elapsed() >= idle_timeout
+            && self.pool.size() as usize == self.pool.num_idle()
+    }
+}
+
 pub struct SqlxTenantPoolCacheRepositoryImpl {
-    pools: Arc<RwLock<HashMap<String, PgPool>>>,
+    pools: Arc<RwLock<HashMap<String, CachedTenantPool>>>,
+    settings: TenantPoolSettings,
+    pool_generations: Arc<TenantPoolGenerations>,
 }
 
 impl SqlxTenantPoolCacheRepositoryImpl {
     pub fn new() -> Self {
+        Self::new_with_settings(TenantPoolSettings::default())
+    }
+
+    pub fn new_with_settings(settings: TenantPoolSettings) -> Self {
         Self {
             pools: Arc::new(RwLock::new(HashMap::new())),
+            settings: TenantPoolSettings {
+                min_connections: settings.min_connections.min(settings.max_connections),
+                ..settings
+            },
+            pool_generations: Arc::new(TenantPoolGenerations::new()),
+        }
+    }
+
+    pub fn with_pool_generations(mut self, pool_generations: Arc<TenantPoolGenerations>) -> Self {
+        self.pool_generations = pool_generations;
+        self
+    }
+
+    fn database_name_from_url(database_url: &str) -> String {
+        database_url
+            .rsplit('/')
+            .next()
+            .and_then(|segment| segment.split('?').next())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    fn connection_target(database_url: &str) -> &str {
+        let without_scheme = database_url
+            .split_once("://")
+            .map_or(database_url, |(_, rest)| rest);
+
+        without_scheme
+            .rsplit_once('@')
+            .map_or(without_scheme, |(_, target)| target)
+    }
+
+    fn remove_pools_for_target(
+        pools: &mut HashMap<String, CachedTenantPool>,
+        database_url: &str,
+        should_remove: impl Fn(&CachedTenantPool) -> bool,
+    ) -> Vec<CachedTenantPool> {
+        let target = Self::connection_target(database_url);
+        let matching = pools
+            .iter()
+            .filter(|(cached_url, cached)| {
+                Self::connection_target(cached_url) == target && should_remove(cached)
+            })
+            .map(|(cached_url, _)| cached_url.clone())
+            .collect::<Vec<_>>();
+
+        matching
+            .iter()
+            .filter_map(|cached_url| pools.remove(cached_url))
+            .collect()
+    }
+
+    pub fn spawn_idle_pool_sweeper(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                self.close_idle_pools().await;
+            }
+        })
+    }
+
+    async fn remove_idle_pools(&self, pools: &mut HashMap<String, CachedTenantPool>) -> usize {
+        let expired = pools
+            .iter()
+            .filter(|(_, cached)| cached.is_expired(self.settings.idle_timeout))
+            .map(|(database_url, _)| database_url.clone())
+            .collect::<Vec<_>>();
+
+        let mut closed = 0;
+        for database_url in expired {
+            if let Some(cached) = pools.remove(&database_url) {
+                cached.pool.close().await;
+                closed += 1;
+            }
         }
+        closed
     }
 }
 
@@ -30,23 +140,102 @@ impl Default for SqlxTenantPoolCacheRepositoryImpl {
 #[async_trait]
 impl TenantPoolCacheRepository for SqlxTenantPoolCacheRepositoryImpl {
     async fn get_or_create_pool(&self, database_url: &str) -> Result<PgPool, DataApiDomainError> {
+        let generation = self
+            .pool_generations
+            .current(&Self::database_name_from_url(database_url))
+            .await;
+
         {
             let read_guard = self.pools.read().await;
-            if let Some(pool) = read_guard.get(database_url) {
-                return Ok(pool.clone());
+            if let Some(cached) = read_guard.get(database_url)
+                && cached.generation == generation
+            {
+                return Ok(cached.touch());
             }
         }
 
-        let pool = PgPool::connect(database_url)
-            .await
+        let pool = PgPoolOptions::new()
+            .max_connections(self.settings.max_connections)
+            .min_connections(self.settings.min_connections)
+            .idle_timeout(self.settings.idle_timeout)
+            .max_lifetime(self.settings.max_lifetime)
+            .connect_lazy(database_url)
             .map_err(|e| DataApiDomainError::InfrastructureError(e.to_string()))?;
 
         let mut write_guard = self.pools.write().await;
-        if let Some(existing) = write_guard.get(database_url) {
-            return Ok(existing.clone());
+        if let Some(existing) = write_guard.get(database_url)
+            && existing.generation == generation
+        {
+            return Ok(existing.touch());
         }
 
-        write_guard.insert(database_url.to_string(), pool.clone());
+        for stale in Self::remove_pools_for_target(&mut write_guard, database_url, |cached| {
+            cached.generation != generation
+        }) {
+            stale.pool.close().await;
+        }
+        self.remove_idle_pools(&mut write_guard).await;
+        write_guard.insert(
+            database_url.to_string(),
+            CachedTenantPool {
+                pool: pool.clone(),
+                generation,
+                last_used: Mutex::new(Instant::now()),
+            },
+        );
         Ok(pool)
     }
+
+    async fn invalidate_pool(&self, database_url: &str) -> bool {
+        self.pool_generations
+            .invalidate_database(&Self::database_name_from_url(database_url))
+            .await;
+        let invalidated =
+            Self::remove_pools_for_target(&mut *self.pools.write().await, database_url, |_| true);
+
+        for cached in &invalidated {
+            cached.pool.close().await;
+        }
+        !invalidated.is_empty()
+    }
+
+    async fn evict(&self, database_url: &str) -> bool {
+        let evicted = self.pools.write().await.remove(database_url);
+
+        match evicted {
+            Some(cached) => {
+                cached.pool.close().await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    async fn close_idle_pools(&self) -> usize {
+        self.remove_idle_pools(&mut *self.pools.write().await).await
+    }
+
+    async fn pool_metrics(&self) -> Vec<TenantPoolMetrics> {
+        let read_guard = self.pools.read().await;
+        let mut metrics = read_guard
+            .iter()
+            .map(|(database_url, cached)| {
+                let size = cached.pool.size();
+                let idle = cached.pool.num_idle();
+                TenantPoolMetrics {
+                    database_name: Self::database_name_from_url(database_url),
+                    size,
+                    idle,
+                    in_use: size.saturating_sub(idle as u32),
+                    max_connections: cached.pool.options().get_max_connections(),
+                }
+            })
+            .collect::<Vec<_>>();
+        metrics.sort_by(|a, b| a.database_name.cmp(&b.database_name));
+        metrics
+    }
+
+    async fn pool_count(&self) -> usize {
+        self.pools.read().await.len()
+    }
 }
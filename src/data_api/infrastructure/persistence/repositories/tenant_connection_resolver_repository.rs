@@ -4,10 +4,21 @@ use crate::data_api::domain::model::{
     enums::data_api_domain_error::DataApiDomainError, value_objects::tenant_id::TenantId,
 };
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TenantConnectionTarget {
+    pub database_url: String,
+    pub session_parameters: Vec<(String, String)>,
+}
+
 #[async_trait]
 pub trait TenantConnectionResolverRepository: Send + Sync {
     async fn resolve_database_url(
         &self,
         tenant_id: &TenantId,
     ) -> Result<String, DataApiDomainError>;
+
+    async fn resolve_connection_target(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<TenantConnectionTarget, DataApiDomainError>;
 }
@@ -3,7 +3,26 @@ use sqlx::PgPool;
 
 use crate::data_api::domain::model::enums::data_api_domain_error::DataApiDomainError;
 
+#[derive(Clone, Debug)]
+pub struct TenantPoolMetrics {
+    pub database_name: String,
+    pub size: u32,
+    pub idle: usize,
+    pub in_use: u32,
+    pub max_connections: u32,
+}
+
 #[async_trait]
 pub trait TenantPoolCacheRepository: Send + Sync {
     async fn get_or_create_pool(&self, database_url: &str) -> Result<PgPool, DataApiDomainError>;
+
+    async fn evict(&self, database_url: &str) -> bool;
+
+    async fn invalidate_pool(&self, database_url: &str) -> bool;
+
+    async fn close_idle_pools(&self) -> usize;
+
+    async fn pool_metrics(&self) -> Vec<TenantPoolMetrics>;
+
+    async fn pool_count(&self) -> usize;
 }
@@ -12,6 +12,24 @@ pub struct DataApiAuthorizationCheckRequest {
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
     pub request_id: Option<String>,
+    pub bypass_decision_cache: bool,
+    pub audit_decision: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct DataApiEffectiveColumnsRequest {
+    pub tenant_id: String,
+    pub principal_id: String,
+    pub resource_name: String,
+    pub requested_columns: Vec<String>,
+    pub subject_owner_id: Option<String>,
+    pub row_owner_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataApiEffectiveWriteColumns {
+    pub create_columns: Vec<String>,
+    pub update_columns: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -23,6 +41,12 @@ pub struct DataApiAuthorizationBootstrapRequest {
     pub writable_columns: Vec<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct DataApiRoleMembershipRequest {
+    pub tenant_id: String,
+    pub principal_id: String,
+}
+
 #[async_trait]
 pub trait AccessControlFacade: Send + Sync {
     async fn check_table_permission(
@@ -30,8 +54,23 @@ pub trait AccessControlFacade: Send + Sync {
         request: DataApiAuthorizationCheckRequest,
     ) -> Result<(), DataApiDomainError>;
 
+    async fn permitted_columns(
+        &self,
+        request: DataApiAuthorizationCheckRequest,
+    ) -> Result<Vec<String>, DataApiDomainError>;
+
+    async fn effective_write_columns(
+        &self,
+        request: DataApiEffectiveColumnsRequest,
+    ) -> Result<DataApiEffectiveWriteColumns, DataApiDomainError>;
+
     async fn bootstrap_table_access(
         &self,
         request: DataApiAuthorizationBootstrapRequest,
     ) -> Result<(), DataApiDomainError>;
+
+    async fn principal_has_any_role(
+        &self,
+        request: DataApiRoleMembershipRequest,
+    ) -> Result<bool, DataApiDomainError>;
 }
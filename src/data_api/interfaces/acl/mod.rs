@@ -1 +1,2 @@
 pub mod access_control_facade;
+pub mod tenant_database_facade;
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+#[derive(Clone, Debug)]
+pub struct TenantDatabasePingRequest {
+    pub tenant_id: String,
+    pub timeout: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct TenantDatabasePoolInvalidationRequest {
+    pub tenant_id: String,
+}
+
+#[async_trait]
+pub trait TenantDatabaseFacade: Send + Sync {
+    async fn ping_tenant_database(&self, request: TenantDatabasePingRequest) -> Result<(), String>;
+
+    async fn invalidate_tenant_pools(&self, request: TenantDatabasePoolInvalidationRequest);
+}
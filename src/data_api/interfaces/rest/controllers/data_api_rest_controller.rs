@@ -5,26 +5,47 @@ use std::{
 };
 
 use axum::{
-    Json, Router,
-    extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    Json, Router, async_trait,
+    body::{Body, Bytes},
+    extract::{FromRequest, Path, Query, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
     routing::{delete, get, patch, post, put},
 };
-use serde_json::Value;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::config::cache_control::decision_cache_bypass_requested;
+use crate::config::error_redaction::{
+    ErrorRedaction, client_facing_infrastructure_message, redact_server_error_messages,
+};
+use crate::config::problem_details::{PROBLEM_JSON_CONTENT_TYPE, negotiate_problem_details};
+use crate::config::{
+    admin_token::{AdminToken, is_verified_admin},
+    api_deprecation::{ApiDeprecation, apply_deprecation_headers},
+};
 use crate::data_api::{
     domain::{
         model::{
             commands::{
                 create_row_command::{CreateRowCommand, CreateRowCommandParts},
+                create_rows_command::{CreateRowsCommand, CreateRowsCommandParts},
                 delete_row_command::{DeleteRowCommand, DeleteRowCommandParts},
+                execute_transaction_command::{
+                    DataApiTransactionOperation, ExecuteTransactionCommand,
+                },
+                import_rows_command::{ImportRowsCommand, ImportRowsCommandParts},
                 patch_row_command::{PatchRowCommand, PatchRowCommandParts},
+                replace_row_command::{ReplaceRowCommand, ReplaceRowCommandParts},
             },
             enums::{
                 data_api_domain_error::DataApiDomainError,
+                data_api_filter_operator::DataApiFilterOperator,
                 data_api_principal_type::DataApiPrincipalType,
+                data_api_read_transform::DataApiReadTransform,
             },
             queries::{
                 get_row_query::{GetRowQuery, GetRowQueryParts},
@@ -39,15 +60,30 @@ use crate::data_api::{
             data_api_query_service::DataApiQueryService,
         },
     },
-    infrastructure::persistence::repositories::data_api_repository::{
-        ColumnMetadataUpdateCriteria, DataApiRepository, TableMetadataUpdateCriteria,
+    infrastructure::persistence::repositories::{
+        data_api_repository::{
+            ColumnMetadataUpdateCriteria, DataApiRepository, TableMetadataUpdateCriteria,
+        },
+        tenant_connection_resolver_repository::TenantConnectionResolverRepository,
+        tenant_pool_cache_repository::TenantPoolCacheRepository,
+        tenant_schema_resolver_repository::TenantSchemaResolverRepository,
     },
     interfaces::rest::resources::{
+        data_api_batch_payload_resource::DataApiBatchPayloadResource,
+        data_api_capabilities_resource::{
+            DataApiCapabilitiesAuthenticationResource, DataApiCapabilitiesResource,
+        },
         data_api_column_access_metadata_update_request_resource::DataApiColumnAccessMetadataUpdateRequestResource,
         data_api_error_response_resource::DataApiErrorResponseResource,
-        data_api_payload_resource::DataApiPayloadResource,
+        data_api_payload_resource::{DataApiPayloadResource, ensure_unique_json_keys},
         data_api_table_access_catalog_resource::DataApiTableAccessCatalogEntryResource,
         data_api_table_access_metadata_update_request_resource::DataApiTableAccessMetadataUpdateRequestResource,
+        data_api_tenant_pool_metrics_resource::{
+            DataApiTenantPoolMetricsResource, DataApiTenantPoolsResource,
+        },
+        data_api_transaction_request_resource::{
+            DataApiTransactionOperationKindResource, DataApiTransactionRequestResource,
+        },
     },
 };
 
@@ -56,15 +92,40 @@ pub struct DataApiRestControllerState {
     pub command_service: Arc<dyn DataApiCommandService>,
     pub query_service: Arc<dyn DataApiQueryService>,
     pub repository: Arc<dyn DataApiRepository>,
+    pub tenant_connection_resolver: Arc<dyn TenantConnectionResolverRepository>,
+    pub tenant_pool_cache: Arc<dyn TenantPoolCacheRepository>,
+    pub tenant_schema_resolver: Arc<dyn TenantSchemaResolverRepository>,
+    pub request_id_header: String,
+    pub acl_aware_catalog: bool,
+    pub effective_writable_catalog: bool,
+    pub debug_sql_enabled: bool,
+    pub strict_json_enabled: bool,
+    pub max_page_size: i64,
+    pub max_batch_size: usize,
+    pub deprecation: Option<ApiDeprecation>,
+    pub admin_token: Option<AdminToken>,
+    pub error_redaction: ErrorRedaction,
 }
 
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const JSON_CONTENT_TYPE: &str = "application/json";
+
 pub fn router(state: DataApiRestControllerState) -> Router {
-    Router::new()
+    let deprecation = state.deprecation.clone();
+    let router = Router::new()
+        .route("/api/v1/", get(get_capabilities))
         .route("/api/v1/_metadata", get(list_access_catalog))
+        .route("/api/v1/_pools", get(list_tenant_pool_metrics))
+        .route("/api/v1/_pools", delete(evict_tenant_pool))
+        .route("/api/v1/_transaction", post(execute_transaction))
         .route(
             "/api/v1/_metadata/:table_name",
             put(upsert_table_access_metadata),
         )
+        .route(
+            "/api/v1/_metadata/:table_name/columns",
+            put(upsert_column_access_metadata_batch),
+        )
         .route(
             "/api/v1/_metadata/:table_name/columns/:column_name",
             put(upsert_column_access_metadata),
@@ -72,10 +133,104 @@ pub fn router(state: DataApiRestControllerState) -> Router {
         .route("/api/v1/:table_name", get(list_rows))
         .route("/api/v1/:table_name", post(create_row))
         .route("/api/v1/:table_name/_schema", get(introspect_table_schema))
+        .route(
+            "/api/v1/:table_name/_jsonschema",
+            get(get_table_json_schema),
+        )
+        .route("/api/v1/:table_name/_import", post(import_rows))
+        .route("/api/v1/:table_name/_batch", post(create_rows_batch))
         .route("/api/v1/:table_name/:row_id", get(get_row))
+        .route("/api/v1/:table_name/:row_id", put(replace_row))
         .route("/api/v1/:table_name/:row_id", patch(patch_row))
         .route("/api/v1/:table_name/:row_id", delete(delete_row))
-        .with_state(state)
+        .layer(middleware::map_response_with_state(
+            state.error_redaction,
+            redact_server_error_messages,
+        ))
+        .layer(middleware::from_fn(negotiate_problem_details))
+        .with_state(state);
+
+    match deprecation {
+        Some(deprecation) => router.layer(middleware::map_response_with_state(
+            deprecation,
+            apply_deprecation_headers,
+        )),
+        None => router,
+    }
+}
+
+pub struct DataApiJsonPayload<T = DataApiPayloadResource>(T);
+
+#[async_trait]
+impl<T> FromRequest<DataApiRestControllerState> for DataApiJsonPayload<T>
+where
+    T: DeserializeOwned + Send,
+{
+    type Rejection = Response;
+
+    async fn from_request(
+        request: Request,
+        state: &DataApiRestControllerState,
+    ) -> Result<Self, Self::Rejection> {
+        let strict_json = strict_json_requested(request.headers(), state.strict_json_enabled);
+        let (parts, body) = request.into_parts();
+        let body = Bytes::from_request(Request::from_parts(parts.clone(), body), state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        if strict_json && let Err(message) = ensure_unique_json_keys(&body) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(DataApiErrorResponseResource { message }),
+            )
+                .into_response());
+        }
+
+        Json::<T>::from_request(Request::from_parts(parts, Body::from(body)), state)
+            .await
+            .map(|Json(resource)| Self(resource))
+            .map_err(IntoResponse::into_response)
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/",
+    tag = "data-api",
+    responses(
+        (status = 200, description = "Capacidades efectivas de la API: operadores de filtro, límites, content types y autenticación", body = DataApiCapabilitiesResource)
+    )
+)]
+pub async fn get_capabilities(
+    State(state): State<DataApiRestControllerState>,
+) -> Json<DataApiCapabilitiesResource> {
+    let optional_headers = vec![
+        "x-api-key".to_string(),
+        "x-tenant-schema".to_string(),
+        state.request_id_header.clone(),
+    ];
+    Json(DataApiCapabilitiesResource {
+        api_version: "v1".to_string(),
+        filter_operators: DataApiFilterOperator::SUFFIXES
+            .iter()
+            .map(|suffix| suffix.to_string())
+            .collect(),
+        default_page_size: DEFAULT_PAGE_SIZE.min(state.max_page_size),
+        max_page_size: state.max_page_size,
+        max_batch_size: state.max_batch_size,
+        request_content_types: vec![JSON_CONTENT_TYPE.to_string(), "text/csv".to_string()],
+        response_content_types: vec![
+            JSON_CONTENT_TYPE.to_string(),
+            PROBLEM_JSON_CONTENT_TYPE.to_string(),
+        ],
+        idempotency_keys_supported: false,
+        debug_sql_enabled: state.debug_sql_enabled,
+        authentication: DataApiCapabilitiesAuthenticationResource {
+            schemes: vec!["bearer".to_string(), "api_key".to_string()],
+            required_headers: vec!["x-tenant-id".to_string(), "authorization".to_string()],
+            optional_headers,
+        },
+    })
 }
 
 #[utoipa::path(
@@ -89,7 +244,7 @@ pub fn router(state: DataApiRestControllerState) -> Router {
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional")
     ),
     responses(
-        (status = 200, description = "Catálogo de metadatos", body = [DataApiTableAccessCatalogEntryResource]),
+        (status = 200, description = "Catálogo de metadatos; con DATA_API_EFFECTIVE_WRITABLE_CATALOG, creatable_columns y updatable_columns indican por separado las columnas de writable_columns que el principal puede crear y actualizar en sus propias filas según ACL", body = [DataApiTableAccessCatalogEntryResource]),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
     )
@@ -101,7 +256,7 @@ pub async fn list_access_catalog(
     Json<Vec<DataApiTableAccessCatalogEntryResource>>,
     (StatusCode, Json<DataApiErrorResponseResource>),
 > {
-    let auth = parse_auth_headers(&headers)?;
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
     let tenant_id = parse_tenant_id(&auth.tenant_id)?;
 
     state
@@ -110,12 +265,33 @@ pub async fn list_access_catalog(
         .await
         .map_err(map_domain_error)?;
 
-    let catalog = state
+    let mut catalog = state
         .repository
         .list_access_catalog(&tenant_id, &auth.schema_name)
         .await
         .map_err(map_domain_error)?;
 
+    if state.acl_aware_catalog {
+        catalog = state
+            .query_service
+            .filter_access_catalog(
+                &tenant_id,
+                &auth.principal,
+                auth.request_id.as_deref(),
+                catalog,
+            )
+            .await
+            .map_err(map_domain_error)?;
+    }
+
+    if state.effective_writable_catalog {
+        catalog = state
+            .query_service
+            .effective_writable_catalog(&tenant_id, &auth.principal, catalog)
+            .await
+            .map_err(map_domain_error)?;
+    }
+
     Ok(Json(
         catalog
             .into_iter()
@@ -126,14 +302,101 @@ pub async fn list_access_catalog(
                 create_enabled: entry.create_enabled,
                 update_enabled: entry.update_enabled,
                 delete_enabled: entry.delete_enabled,
+                append_only: entry.append_only,
                 introspect_enabled: entry.introspect_enabled,
                 authorization_mode: entry.authorization_mode,
+                soft_delete_column: entry.soft_delete_column,
                 writable_columns: entry.writable_columns,
+                creatable_columns: entry.creatable_columns,
+                updatable_columns: entry.updatable_columns,
             })
             .collect(),
     ))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/_pools",
+    tag = "data-api",
+    params(
+        ("x-admin-token" = String, Header, description = "Token de administración configurado en ADMIN_API_TOKEN")
+    ),
+    responses(
+        (status = 200, description = "Métricas de pools por base de datos tenant", body = DataApiTenantPoolsResource),
+        (status = 403, description = "Requiere token de administración", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn list_tenant_pool_metrics(
+    State(state): State<DataApiRestControllerState>,
+    headers: HeaderMap,
+) -> Result<Json<DataApiTenantPoolsResource>, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    if !is_verified_admin(&headers, state.admin_token.as_ref()) {
+        return Err(map_domain_error(DataApiDomainError::AccessDenied));
+    }
+
+    let metrics = state.tenant_pool_cache.pool_metrics().await;
+
+    Ok(Json(DataApiTenantPoolsResource {
+        pool_count: metrics.len(),
+        pools: metrics
+            .into_iter()
+            .map(|entry| DataApiTenantPoolMetricsResource {
+                database_name: entry.database_name,
+                size: entry.size,
+                idle: entry.idle,
+                in_use: entry.in_use,
+                max_connections: entry.max_connections,
+            })
+            .collect(),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/_pools",
+    tag = "data-api",
+    params(
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-admin-token" = String, Header, description = "Token de administración configurado en ADMIN_API_TOKEN")
+    ),
+    responses(
+        (status = 204, description = "Pool del tenant cerrado y descartado"),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 403, description = "Requiere token de administración", body = DataApiErrorResponseResource),
+        (status = 404, description = "Base de datos del tenant no encontrada o sin pool en caché", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn evict_tenant_pool(
+    State(state): State<DataApiRestControllerState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    if !is_verified_admin(&headers, state.admin_token.as_ref()) {
+        return Err(map_domain_error(DataApiDomainError::AccessDenied));
+    }
+
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let tenant_id = parse_tenant_id(&auth.tenant_id)?;
+
+    let database_url = state
+        .tenant_connection_resolver
+        .resolve_database_url(&tenant_id)
+        .await
+        .map_err(map_domain_error)?;
+
+    if !state.tenant_pool_cache.evict(&database_url).await {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(DataApiErrorResponseResource {
+                message: "no cached pool exists for this tenant".to_string(),
+            }),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     put,
     path = "/api/v1/_metadata/{table_name}",
@@ -161,22 +424,16 @@ pub async fn upsert_table_access_metadata(
     Json<DataApiTableAccessCatalogEntryResource>,
     (StatusCode, Json<DataApiErrorResponseResource>),
 > {
-    let auth = parse_auth_headers(&headers)?;
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
     if !matches!(
         resource.authorization_mode.as_str(),
-        "acl" | "authenticated"
+        "acl" | "authenticated" | "role_required"
     ) {
         return Err(map_domain_error(DataApiDomainError::InvalidQueryParameters));
     }
 
     let tenant_id = parse_tenant_id(&auth.tenant_id)?;
 
-    state
-        .repository
-        .synchronize_metadata(&tenant_id, &auth.schema_name)
-        .await
-        .map_err(map_domain_error)?;
-
     let metadata = state
         .repository
         .upsert_table_access_metadata(
@@ -189,8 +446,10 @@ pub async fn upsert_table_access_metadata(
                 create_enabled: resource.create_enabled,
                 update_enabled: resource.update_enabled,
                 delete_enabled: resource.delete_enabled,
+                append_only: resource.append_only,
                 introspect_enabled: resource.introspect_enabled,
                 authorization_mode: resource.authorization_mode,
+                soft_delete_column: resource.soft_delete_column,
             },
         )
         .await
@@ -209,9 +468,13 @@ pub async fn upsert_table_access_metadata(
         create_enabled: metadata.create_enabled,
         update_enabled: metadata.update_enabled,
         delete_enabled: metadata.delete_enabled,
+        append_only: metadata.append_only,
         introspect_enabled: metadata.introspect_enabled,
         authorization_mode: metadata.authorization_mode,
+        soft_delete_column: metadata.soft_delete_column,
         writable_columns,
+        creatable_columns: None,
+        updatable_columns: None,
     }))
 }
 
@@ -229,7 +492,7 @@ pub async fn upsert_table_access_metadata(
     request_body = DataApiColumnAccessMetadataUpdateRequestResource,
     responses(
         (status = 204, description = "Metadatos de columna actualizados"),
-        (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
+        (status = 400, description = "Request inválido o columna inexistente", body = DataApiErrorResponseResource),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
     )
@@ -240,8 +503,80 @@ pub async fn upsert_column_access_metadata(
     headers: HeaderMap,
     Json(resource): Json<DataApiColumnAccessMetadataUpdateRequestResource>,
 ) -> Result<StatusCode, (StatusCode, Json<DataApiErrorResponseResource>)> {
-    let auth = parse_auth_headers(&headers)?;
+    apply_column_access_metadata(
+        &state,
+        &headers,
+        &table_name,
+        BTreeMap::from([(column_name, resource)]),
+    )
+    .await
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/_metadata/{table_name}/columns",
+    tag = "data-api",
+    params(
+        ("table_name" = String, Path, description = "Nombre de tabla"),
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal")
+    ),
+    request_body = BTreeMap<String, DataApiColumnAccessMetadataUpdateRequestResource>,
+    responses(
+        (status = 204, description = "Metadatos de todas las columnas actualizados en una sola transacción"),
+        (status = 400, description = "Request inválido, lote vacío, lote mayor que DATA_API_MAX_BATCH_SIZE o columna inexistente", body = DataApiErrorResponseResource),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 404, description = "Tabla no encontrada", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn upsert_column_access_metadata_batch(
+    State(state): State<DataApiRestControllerState>,
+    Path(table_name): Path<String>,
+    headers: HeaderMap,
+    Json(resources): Json<BTreeMap<String, DataApiColumnAccessMetadataUpdateRequestResource>>,
+) -> Result<StatusCode, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    apply_column_access_metadata(&state, &headers, &table_name, resources).await
+}
+
+async fn apply_column_access_metadata(
+    state: &DataApiRestControllerState,
+    headers: &HeaderMap,
+    table_name: &str,
+    resources: BTreeMap<String, DataApiColumnAccessMetadataUpdateRequestResource>,
+) -> Result<StatusCode, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(headers, &state.request_id_header)?;
     let tenant_id = parse_tenant_id(&auth.tenant_id)?;
+    if resources.is_empty() {
+        return Err(map_domain_error(DataApiDomainError::InvalidPayload));
+    }
+    if resources.len() > state.max_batch_size {
+        return Err(map_domain_error(DataApiDomainError::BatchTooLarge(
+            state.max_batch_size,
+        )));
+    }
+
+    let mut columns = Vec::with_capacity(resources.len());
+    for (column_name, resource) in resources {
+        let read_transform = resource
+            .read_transform
+            .as_deref()
+            .map(str::parse::<DataApiReadTransform>)
+            .transpose()
+            .map_err(map_domain_error)?
+            .unwrap_or(DataApiReadTransform::None);
+        columns.push((
+            column_name,
+            ColumnMetadataUpdateCriteria {
+                readable: resource.readable,
+                writable: resource.writable,
+                read_transform,
+                filterable: resource.filterable,
+                orderable: resource.orderable,
+            },
+        ));
+    }
 
     state
         .repository
@@ -249,18 +584,23 @@ pub async fn upsert_column_access_metadata(
         .await
         .map_err(map_domain_error)?;
 
+    let table_schema = state
+        .repository
+        .introspect_table(&tenant_id, &auth.schema_name, table_name)
+        .await
+        .map_err(map_domain_error)?;
+    if let Some((column_name, _)) = columns
+        .iter()
+        .find(|(column_name, _)| !table_schema.has_column(column_name))
+    {
+        return Err(map_domain_error(DataApiDomainError::InvalidColumnName(
+            column_name.clone(),
+        )));
+    }
+
     state
         .repository
-        .upsert_column_access_metadata(
-            &tenant_id,
-            &auth.schema_name,
-            &table_name,
-            &column_name,
-            ColumnMetadataUpdateCriteria {
-                readable: resource.readable,
-                writable: resource.writable,
-            },
-        )
+        .upsert_column_access_metadata_batch(&tenant_id, &auth.schema_name, table_name, &columns)
         .await
         .map_err(map_domain_error)?;
 
@@ -277,16 +617,24 @@ pub async fn upsert_column_access_metadata(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; con reglas owner-scoped el listado se filtra por DATA_API_ROW_OWNER_COLUMN con el principal autenticado, no con este header"),
         ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
         ("fields" = Option<String>, Query, description = "Campos separados por coma"),
-        ("limit" = Option<i64>, Query, description = "Límite (1..500)"),
+        ("limit" = Option<i64>, Query, description = "Límite (1..DATA_API_MAX_PAGE_SIZE, máximo 500)"),
         ("offset" = Option<i64>, Query, description = "Offset >= 0"),
+        ("after" = Option<String>, Query, description = "Cursor opaco devuelto en x-next-cursor; requiere order_by y reemplaza offset"),
         ("order_by" = Option<String>, Query, description = "Campo de orden"),
-        ("order_dir" = Option<String>, Query, description = "asc|desc"),
+        ("order_dir" = Option<String>, Query, description = "asc|desc (cualquier otro valor responde 400)"),
+        ("numeric_as_string" = Option<bool>, Query, description = "Serializa bigint/numeric como string"),
+        ("count" = Option<bool>, Query, description = "Responde { data, total } con el total de registros filtrados"),
+        ("x-include-count" = Option<bool>, Header, description = "Equivalente a count=true"),
+        ("filter_{columna}" = Option<String>, Query, description = "Filtro por igualdad; sufijos __ne, __gt, __gte, __lt, __lte aplican comparaciones (filter_precio__gte=100); __in y __nin aceptan valores separados por coma (filter_estado__in=activo,pendiente)"),
+        ("prefer" = Option<String>, Header, description = "projection=lenient omite los campos denegados en lugar de responder 403; debug=sql devuelve el SQL generado (requiere x-admin-token)"),
+        ("x-admin-token" = Option<String>, Header, description = "Token de administrador verificado; habilita Prefer: debug=sql cuando DATA_API_DEBUG_SQL_ENABLED está activo"),
+        ("cache-control" = Option<String>, Header, description = "no-cache fuerza una evaluación ACL nueva sin usar la caché de decisiones; solo se respeta con un x-admin-token válido"),
     ),
     responses(
-        (status = 200, description = "Listado dinámico", body = Value),
+        (status = 200, description = "Listado dinámico; [] cuando no hay registros, o { data, total } con count=true", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"), ("x-dropped-fields" = Option<String>, description = "Campos omitidos por ACL en modo lenient"), ("x-next-cursor" = Option<String>, description = "Cursor para la siguiente página cuando se usa order_by"), ("x-debug-sql" = Option<String>, description = "SQL generado con placeholders, sin valores"), ("x-debug-sql-parameters" = Option<usize>, description = "Cantidad de parámetros enlazados"))),
         (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
@@ -299,8 +647,9 @@ pub async fn list_rows(
     Path(table_name): Path<String>,
     Query(params): Query<BTreeMap<String, String>>,
     headers: HeaderMap,
-) -> Result<Json<Value>, (StatusCode, Json<DataApiErrorResponseResource>)> {
-    let auth = parse_auth_headers(&headers)?;
+) -> Result<(HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
 
     let fields = params
         .get("fields")
@@ -316,28 +665,30 @@ pub async fn list_rows(
     let limit = params
         .get("limit")
         .and_then(|v| v.parse::<i64>().ok())
-        .unwrap_or(50);
+        .unwrap_or(DEFAULT_PAGE_SIZE.min(state.max_page_size));
+    if limit > state.max_page_size {
+        return Err(map_domain_error(DataApiDomainError::InvalidQueryParameters));
+    }
     let offset = params
         .get("offset")
         .and_then(|v| v.parse::<i64>().ok())
         .unwrap_or(0);
 
     let order_by = params.get("order_by").cloned();
-    let order_desc = params
-        .get("order_dir")
-        .map(|v| v.eq_ignore_ascii_case("desc"))
-        .unwrap_or(false);
+    let order_desc = order_desc_requested(&params).map_err(map_domain_error)?;
+    let numeric_as_string = numeric_as_string_requested(&params);
+    let lenient_projection = lenient_projection_requested(&headers);
+    let debug_sql = state.debug_sql_enabled
+        && is_verified_admin(&headers, state.admin_token.as_ref())
+        && preference_requested(&headers, "debug=sql");
+    let include_count = count_requested(&params, &headers);
 
-    let filters = params
-        .iter()
-        .filter(|(key, _)| key.starts_with("filter_"))
-        .map(|(key, value)| (key.trim_start_matches("filter_").to_string(), value.clone()))
-        .collect::<BTreeMap<_, _>>();
+    let filters = parse_filters(&params).map_err(map_domain_error)?;
 
     let query = ListRowsQuery::new(ListRowsQueryParts {
         api_version: "v1".to_string(),
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
         table_name,
         principal: auth.principal,
         principal_type: auth.principal_type,
@@ -348,18 +699,53 @@ pub async fn list_rows(
         filters,
         limit,
         offset,
+        after: params.get("after").cloned(),
         order_by,
         order_desc,
+        numeric_as_string,
+        lenient_projection,
+        debug_sql,
+        include_count,
+        bypass_decision_cache: decision_cache_bypass_requested(
+            &headers,
+            state.admin_token.as_ref(),
+        ),
     })
     .map_err(map_domain_error)?;
 
-    let rows = state
+    let result = state
         .query_service
         .handle_list(query)
         .await
         .map_err(map_domain_error)?;
 
-    Ok(Json(rows))
+    let mut response_headers = resolved_schema_headers(&schema_name);
+    if !result.dropped_fields.is_empty()
+        && let Ok(value) = HeaderValue::from_str(&result.dropped_fields.join(","))
+    {
+        response_headers.insert("x-dropped-fields", value);
+    }
+    if let Some(cursor) = &result.next_cursor
+        && let Ok(value) = HeaderValue::from_str(cursor)
+    {
+        response_headers.insert("x-next-cursor", value);
+    }
+    if let Some(statement) = &result.debug_statement {
+        if let Ok(value) = HeaderValue::from_str(&statement.sql) {
+            response_headers.insert("x-debug-sql", value);
+        }
+        response_headers.insert(
+            "x-debug-sql-parameters",
+            HeaderValue::from(statement.parameter_count),
+        );
+    }
+
+    let body = match result.total {
+        Some(total) => json!({ "data": result.rows, "total": total }),
+        None => result.rows,
+    };
+
+    Ok((response_headers, Json(body)))
 }
 
 #[utoipa::path(
@@ -373,28 +759,33 @@ pub async fn list_rows(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
-        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
+        ("numeric_as_string" = Option<bool>, Query, description = "Serializa bigint/numeric como string"),
+        ("prefer" = Option<String>, Header, description = "return=nullable responde 200 con cuerpo null cuando el registro no existe"),
+        ("cache-control" = Option<String>, Header, description = "no-cache fuerza una evaluación ACL nueva sin usar la caché de decisiones; solo se respeta con un x-admin-token válido"),
     ),
     responses(
-        (status = 200, description = "Registro encontrado", body = Value),
+        (status = 200, description = "Registro encontrado, o null con Prefer: return=nullable", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
-        (status = 404, description = "No encontrado", body = DataApiErrorResponseResource),
+        (status = 404, description = "Registro no encontrado (comportamiento por defecto)", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
     )
 )]
 pub async fn get_row(
     State(state): State<DataApiRestControllerState>,
     Path((table_name, row_id)): Path<(String, String)>,
+    Query(params): Query<BTreeMap<String, String>>,
     headers: HeaderMap,
-) -> Result<Json<Value>, (StatusCode, Json<DataApiErrorResponseResource>)> {
-    let auth = parse_auth_headers(&headers)?;
+) -> Result<(HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
 
     let query = GetRowQuery::new(GetRowQueryParts {
         api_version: "v1".to_string(),
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
         table_name,
         row_identifier: row_id,
         principal: auth.principal,
@@ -402,16 +793,23 @@ pub async fn get_row(
         request_id: auth.request_id,
         subject_owner_id: auth.subject_owner_id,
         row_owner_id: auth.row_owner_id,
+        numeric_as_string: numeric_as_string_requested(&params),
+        bypass_decision_cache: decision_cache_bypass_requested(
+            &headers,
+            state.admin_token.as_ref(),
+        ),
     })
     .map_err(map_domain_error)?;
 
-    let row = state
-        .query_service
-        .handle_get(query)
-        .await
-        .map_err(map_domain_error)?;
+    let row = match state.query_service.handle_get(query).await {
+        Ok(row) => row,
+        Err(DataApiDomainError::RecordNotFound) if nullable_return_requested(&headers) => {
+            Value::Null
+        }
+        Err(error) => return Err(map_domain_error(error)),
+    };
 
-    Ok(Json(row))
+    Ok((resolved_schema_headers(&schema_name), Json(row)))
 }
 
 #[utoipa::path(
@@ -424,13 +822,15 @@ pub async fn get_row(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
-        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
+        ("prefer" = Option<String>, Header, description = "json=strict rechaza payloads con claves duplicadas; json=lenient desactiva el modo estricto configurado")
     ),
     request_body = DataApiPayloadResource,
     responses(
-        (status = 201, description = "Registro creado", body = Value),
-        (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
+        (status = 201, description = "Registro creado", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido o referencia a registro inexistente", body = DataApiErrorResponseResource),
+        (status = 409, description = "Conflicto con un valor único existente", body = DataApiErrorResponseResource),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
@@ -440,8 +840,9 @@ pub async fn create_row(
     State(state): State<DataApiRestControllerState>,
     Path(table_name): Path<String>,
     headers: HeaderMap,
-    Json(resource): Json<DataApiPayloadResource>,
-) -> Result<(StatusCode, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    DataApiJsonPayload(resource): DataApiJsonPayload,
+) -> Result<(StatusCode, HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)>
+{
     if let Err(validation_error) = resource.validate() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -451,12 +852,13 @@ pub async fn create_row(
         ));
     }
 
-    let auth = parse_auth_headers(&headers)?;
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
 
     let command = CreateRowCommand::new(CreateRowCommandParts {
         api_version: "v1".to_string(),
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
         table_name,
         principal: auth.principal,
         principal_type: auth.principal_type,
@@ -473,7 +875,265 @@ pub async fn create_row(
         .await
         .map_err(map_domain_error)?;
 
-    Ok((StatusCode::CREATED, Json(created)))
+    Ok((
+        StatusCode::CREATED,
+        resolved_schema_headers(&schema_name),
+        Json(created),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/{table_name}/_batch",
+    tag = "data-api",
+    params(
+        ("table_name" = String, Path, description = "Nombre de tabla"),
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+    ),
+    request_body = DataApiBatchPayloadResource,
+    responses(
+        (status = 201, description = "Registros creados en una sola transacción", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido, lote mayor al máximo configurado o payload demasiado grande", body = DataApiErrorResponseResource),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno; ningún registro fue insertado", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn create_rows_batch(
+    State(state): State<DataApiRestControllerState>,
+    Path(table_name): Path<String>,
+    headers: HeaderMap,
+    DataApiJsonPayload(resource): DataApiJsonPayload<DataApiBatchPayloadResource>,
+) -> Result<(StatusCode, HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)>
+{
+    if let Err(validation_error) = resource.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(DataApiErrorResponseResource {
+                message: validation_error.to_string(),
+            }),
+        ));
+    }
+
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+
+    let command = CreateRowsCommand::new(CreateRowsCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: auth.tenant_id,
+        schema_name: schema_name.clone(),
+        table_name,
+        principal: auth.principal,
+        principal_type: auth.principal_type,
+        request_id: auth.request_id,
+        subject_owner_id: auth.subject_owner_id,
+        row_owner_id: auth.row_owner_id,
+        payloads: resource.payloads,
+    })
+    .map_err(map_domain_error)?;
+
+    let created = state
+        .command_service
+        .handle_create_batch(command)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        resolved_schema_headers(&schema_name),
+        Json(Value::Array(created)),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/_transaction",
+    tag = "data-api",
+    params(
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
+        ("prefer" = Option<String>, Header, description = "nulls=skip aplica a las operaciones patch del lote")
+    ),
+    request_body = DataApiTransactionRequestResource,
+    responses(
+        (status = 200, description = "Resultados de cada operación en orden; todas fueron confirmadas en una sola transacción", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido, lote mayor al máximo configurado o payload demasiado grande", body = DataApiErrorResponseResource),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 403, description = "Sin permisos sobre alguna de las operaciones", body = DataApiErrorResponseResource),
+        (status = 404, description = "Algún registro no fue encontrado; la transacción fue revertida", body = DataApiErrorResponseResource),
+        (status = 405, description = "Alguna tabla es append-only", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno; la transacción fue revertida", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn execute_transaction(
+    State(state): State<DataApiRestControllerState>,
+    headers: HeaderMap,
+    DataApiJsonPayload(resource): DataApiJsonPayload<DataApiTransactionRequestResource>,
+) -> Result<(HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    if let Err(validation_error) = resource.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(DataApiErrorResponseResource {
+                message: validation_error.to_string(),
+            }),
+        ));
+    }
+
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+    let skip_null_values = skip_null_values_requested(&headers);
+
+    let mut operations = Vec::with_capacity(resource.operations.len());
+    for operation in resource.operations {
+        let auth = auth.clone();
+        let row_identifier = operation.row_id.unwrap_or_default();
+        let payload = operation.payload.unwrap_or(Value::Null);
+
+        operations.push(match operation.op {
+            DataApiTransactionOperationKindResource::Create => DataApiTransactionOperation::Create(
+                CreateRowCommand::new(CreateRowCommandParts {
+                    api_version: "v1".to_string(),
+                    tenant_id: auth.tenant_id,
+                    schema_name: schema_name.clone(),
+                    table_name: operation.table,
+                    principal: auth.principal,
+                    principal_type: auth.principal_type,
+                    request_id: auth.request_id,
+                    subject_owner_id: auth.subject_owner_id,
+                    row_owner_id: auth.row_owner_id,
+                    payload,
+                })
+                .map_err(map_domain_error)?,
+            ),
+            DataApiTransactionOperationKindResource::Patch => DataApiTransactionOperation::Patch(
+                PatchRowCommand::new(PatchRowCommandParts {
+                    api_version: "v1".to_string(),
+                    tenant_id: auth.tenant_id,
+                    schema_name: schema_name.clone(),
+                    table_name: operation.table,
+                    row_identifier,
+                    principal: auth.principal,
+                    principal_type: auth.principal_type,
+                    request_id: auth.request_id,
+                    subject_owner_id: auth.subject_owner_id,
+                    row_owner_id: auth.row_owner_id,
+                    payload,
+                    skip_null_values,
+                })
+                .map_err(map_domain_error)?,
+            ),
+            DataApiTransactionOperationKindResource::Delete => DataApiTransactionOperation::Delete(
+                DeleteRowCommand::new(DeleteRowCommandParts {
+                    api_version: "v1".to_string(),
+                    tenant_id: auth.tenant_id,
+                    schema_name: schema_name.clone(),
+                    table_name: operation.table,
+                    row_identifier,
+                    principal: auth.principal,
+                    principal_type: auth.principal_type,
+                    request_id: auth.request_id,
+                    subject_owner_id: auth.subject_owner_id,
+                    row_owner_id: auth.row_owner_id,
+                })
+                .map_err(map_domain_error)?,
+            ),
+        });
+    }
+
+    let command = ExecuteTransactionCommand::new(operations).map_err(map_domain_error)?;
+    let results = state
+        .command_service
+        .handle_transaction(command)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok((
+        resolved_schema_headers(&schema_name),
+        Json(Value::Array(results)),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/{table_name}/_import",
+    tag = "data-api",
+    params(
+        ("table_name" = String, Path, description = "Nombre de tabla"),
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+    ),
+    request_body(content = String, content_type = "text/csv", description = "CSV con cabecera de columnas"),
+    responses(
+        (status = 200, description = "Resumen de importación sin errores", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 207, description = "Importación parcial con errores por línea", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido o lote mayor al máximo configurado", body = DataApiErrorResponseResource),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn import_rows(
+    State(state): State<DataApiRestControllerState>,
+    Path(table_name): Path<String>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<(StatusCode, HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)>
+{
+    let is_csv = headers
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_ascii_lowercase().starts_with("text/csv"))
+        .unwrap_or(false);
+    if !is_csv {
+        return Err(map_domain_error(DataApiDomainError::InvalidPayload));
+    }
+
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+
+    let command = ImportRowsCommand::new(ImportRowsCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: auth.tenant_id,
+        schema_name: schema_name.clone(),
+        table_name,
+        principal: auth.principal,
+        principal_type: auth.principal_type,
+        request_id: auth.request_id,
+        subject_owner_id: auth.subject_owner_id,
+        row_owner_id: auth.row_owner_id,
+        csv_content: body,
+    })
+    .map_err(map_domain_error)?;
+
+    let report = state
+        .command_service
+        .handle_import(command)
+        .await
+        .map_err(map_domain_error)?;
+
+    let status = if report["errors"]
+        .as_array()
+        .is_some_and(|errors| !errors.is_empty())
+    {
+        StatusCode::MULTI_STATUS
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((status, resolved_schema_headers(&schema_name), Json(report)))
 }
 
 #[utoipa::path(
@@ -487,16 +1147,19 @@ pub async fn create_row(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
-        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
+        ("prefer" = Option<String>, Header, description = "nulls=skip ignora las claves con valor null en lugar de asignar NULL; json=strict rechaza payloads con claves duplicadas; json=lenient desactiva el modo estricto configurado")
     ),
     request_body = DataApiPayloadResource,
     responses(
-        (status = 200, description = "Registro actualizado", body = Value),
-        (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
+        (status = 200, description = "Registro actualizado", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"), ("x-rows-affected" = u64, description = "Filas actualizadas"))),
+        (status = 400, description = "Request inválido o referencia a registro inexistente", body = DataApiErrorResponseResource),
+        (status = 409, description = "Conflicto con un valor único existente", body = DataApiErrorResponseResource),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
-        (status = 404, description = "No encontrado", body = DataApiErrorResponseResource),
+        (status = 404, description = "No encontrado", body = DataApiErrorResponseResource, headers(("x-rows-affected" = u64, description = "0 cuando ningún registro coincide"))),
+        (status = 405, description = "Tabla append-only", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
     )
 )]
@@ -504,8 +1167,8 @@ pub async fn patch_row(
     State(state): State<DataApiRestControllerState>,
     Path((table_name, row_id)): Path<(String, String)>,
     headers: HeaderMap,
-    Json(resource): Json<DataApiPayloadResource>,
-) -> Result<Json<Value>, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    DataApiJsonPayload(resource): DataApiJsonPayload,
+) -> Result<Response, (StatusCode, Json<DataApiErrorResponseResource>)> {
     if let Err(validation_error) = resource.validate() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -515,12 +1178,91 @@ pub async fn patch_row(
         ));
     }
 
-    let auth = parse_auth_headers(&headers)?;
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+    let skip_null_values = skip_null_values_requested(&headers);
 
     let command = PatchRowCommand::new(PatchRowCommandParts {
         api_version: "v1".to_string(),
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
+        table_name,
+        row_identifier: row_id,
+        principal: auth.principal,
+        principal_type: auth.principal_type,
+        request_id: auth.request_id,
+        subject_owner_id: auth.subject_owner_id,
+        row_owner_id: auth.row_owner_id,
+        payload: resource.payload,
+        skip_null_values,
+    })
+    .map_err(map_domain_error)?;
+
+    let mut response_headers = resolved_schema_headers(&schema_name);
+
+    match state.command_service.handle_patch(command).await {
+        Ok(updated) => {
+            response_headers.insert("x-rows-affected", HeaderValue::from_static("1"));
+            Ok((response_headers, Json(updated)).into_response())
+        }
+        Err(DataApiDomainError::RecordNotFound) => {
+            response_headers.insert("x-rows-affected", HeaderValue::from_static("0"));
+            let (status, body) = map_domain_error(DataApiDomainError::RecordNotFound);
+            Ok((status, response_headers, body).into_response())
+        }
+        Err(error) => Err(map_domain_error(error)),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/{table_name}/{row_id}",
+    tag = "data-api",
+    params(
+        ("table_name" = String, Path, description = "Nombre de tabla"),
+        ("row_id" = String, Path, description = "ID lógico (columna PK)"),
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso"),
+        ("prefer" = Option<String>, Header, description = "json=strict rechaza payloads con claves duplicadas; json=lenient desactiva el modo estricto configurado")
+    ),
+    request_body = DataApiPayloadResource,
+    responses(
+        (status = 200, description = "Registro reemplazado; las columnas editables omitidas vuelven a su default o null", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido o columna omitida sin default que no admite null", body = DataApiErrorResponseResource),
+        (status = 409, description = "Conflicto con un valor único existente", body = DataApiErrorResponseResource),
+        (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
+        (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
+        (status = 404, description = "No encontrado", body = DataApiErrorResponseResource),
+        (status = 405, description = "Tabla append-only", body = DataApiErrorResponseResource),
+        (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn replace_row(
+    State(state): State<DataApiRestControllerState>,
+    Path((table_name, row_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    DataApiJsonPayload(resource): DataApiJsonPayload,
+) -> Result<Response, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    if let Err(validation_error) = resource.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(DataApiErrorResponseResource {
+                message: validation_error.to_string(),
+            }),
+        ));
+    }
+
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+
+    let command = ReplaceRowCommand::new(ReplaceRowCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: auth.tenant_id,
+        schema_name: schema_name.clone(),
         table_name,
         row_identifier: row_id,
         principal: auth.principal,
@@ -532,13 +1274,13 @@ pub async fn patch_row(
     })
     .map_err(map_domain_error)?;
 
-    let updated = state
+    let replaced = state
         .command_service
-        .handle_patch(command)
+        .handle_replace(command)
         .await
         .map_err(map_domain_error)?;
 
-    Ok(Json(updated))
+    Ok((resolved_schema_headers(&schema_name), Json(replaced)).into_response())
 }
 
 #[utoipa::path(
@@ -552,14 +1294,15 @@ pub async fn patch_row(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
         ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
     ),
     responses(
-        (status = 204, description = "Registro eliminado"),
+        (status = 204, description = "Registro eliminado", headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
         (status = 401, description = "Auth faltante o inválida", body = DataApiErrorResponseResource),
         (status = 403, description = "Sin permisos", body = DataApiErrorResponseResource),
         (status = 404, description = "No encontrado", body = DataApiErrorResponseResource),
+        (status = 405, description = "Tabla append-only", body = DataApiErrorResponseResource),
         (status = 500, description = "Error interno", body = DataApiErrorResponseResource)
     )
 )]
@@ -567,13 +1310,14 @@ pub async fn delete_row(
     State(state): State<DataApiRestControllerState>,
     Path((table_name, row_id)): Path<(String, String)>,
     headers: HeaderMap,
-) -> Result<StatusCode, (StatusCode, Json<DataApiErrorResponseResource>)> {
-    let auth = parse_auth_headers(&headers)?;
+) -> Result<(StatusCode, HeaderMap), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
 
     let command = DeleteRowCommand::new(DeleteRowCommandParts {
         api_version: "v1".to_string(),
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
         table_name,
         row_identifier: row_id,
         principal: auth.principal,
@@ -590,7 +1334,10 @@ pub async fn delete_row(
         .await
         .map_err(map_domain_error)?;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok((
+        StatusCode::NO_CONTENT,
+        resolved_schema_headers(&schema_name),
+    ))
 }
 
 #[utoipa::path(
@@ -603,11 +1350,11 @@ pub async fn delete_row(
         ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
         ("authorization" = String, Header, description = "JWT o API key del principal"),
         ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
-        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
         ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
     ),
     responses(
-        (status = 200, description = "Metadatos de schema", body = Value),
+        (status = 200, description = "Metadatos de schema", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
         (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
         (status = 404, description = "Tabla no encontrada", body = DataApiErrorResponseResource)
     )
@@ -616,12 +1363,13 @@ pub async fn introspect_table_schema(
     State(state): State<DataApiRestControllerState>,
     Path(table_name): Path<String>,
     headers: HeaderMap,
-) -> Result<Json<Value>, (StatusCode, Json<DataApiErrorResponseResource>)> {
-    let auth = parse_auth_headers(&headers)?;
+) -> Result<(HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
 
     let query = TableSchemaIntrospectionQuery::new(TableSchemaIntrospectionQueryParts {
         tenant_id: auth.tenant_id,
-        schema_name: auth.schema_name,
+        schema_name: schema_name.clone(),
         table_name,
         principal: auth.principal,
         principal_type: auth.principal_type,
@@ -637,9 +1385,59 @@ pub async fn introspect_table_schema(
         .await
         .map_err(map_domain_error)?;
 
-    Ok(Json(metadata))
+    Ok((resolved_schema_headers(&schema_name), Json(metadata)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/{table_name}/_jsonschema",
+    tag = "data-api",
+    params(
+        ("table_name" = String, Path, description = "Nombre de tabla"),
+        ("x-tenant-id" = String, Header, description = "Tenant id"),
+        ("x-tenant-schema" = Option<String>, Header, description = "Schema opcional por tenant"),
+        ("authorization" = String, Header, description = "JWT o API key del principal"),
+        ("x-request-id" = Option<String>, Header, description = "Correlation id opcional"),
+        ("x-subject-owner-id" = Option<String>, Header, description = "Owner id del sujeto; se ignora y se usa el principal autenticado cuando DATA_API_ROW_OWNER_COLUMN está configurado"),
+        ("x-row-owner-id" = Option<String>, Header, description = "Owner id del recurso")
+    ),
+    responses(
+        (status = 200, description = "Documento JSON Schema generado a partir de las columnas de la tabla", body = Value, headers(("x-resolved-schema" = String, description = "Schema efectivo usado"))),
+        (status = 400, description = "Request inválido", body = DataApiErrorResponseResource),
+        (status = 403, description = "Sin permisos de introspección", body = DataApiErrorResponseResource),
+        (status = 404, description = "Tabla no encontrada", body = DataApiErrorResponseResource)
+    )
+)]
+pub async fn get_table_json_schema(
+    State(state): State<DataApiRestControllerState>,
+    Path(table_name): Path<String>,
+    headers: HeaderMap,
+) -> Result<(HeaderMap, Json<Value>), (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let auth = parse_auth_headers(&headers, &state.request_id_header)?;
+    let schema_name = resolve_schema_name(&state, &auth).await?;
+
+    let query = TableSchemaIntrospectionQuery::new(TableSchemaIntrospectionQueryParts {
+        tenant_id: auth.tenant_id,
+        schema_name: schema_name.clone(),
+        table_name,
+        principal: auth.principal,
+        principal_type: auth.principal_type,
+        request_id: auth.request_id,
+        subject_owner_id: auth.subject_owner_id,
+        row_owner_id: auth.row_owner_id,
+    })
+    .map_err(map_domain_error)?;
+
+    let json_schema = state
+        .query_service
+        .handle_json_schema(query)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok((resolved_schema_headers(&schema_name), Json(json_schema)))
 }
 
+#[derive(Clone)]
 struct AuthContext {
     tenant_id: String,
     schema_name: String,
@@ -652,6 +1450,7 @@ struct AuthContext {
 
 fn parse_auth_headers(
     headers: &HeaderMap,
+    request_id_header: &str,
 ) -> Result<AuthContext, (StatusCode, Json<DataApiErrorResponseResource>)> {
     let tenant_id = header_string(headers, "x-tenant-id")?;
     let schema_name = headers
@@ -690,7 +1489,7 @@ fn parse_auth_headers(
         .unwrap_or_else(|_| deterministic_principal_uuid(&raw_principal));
 
     let request_id = headers
-        .get("x-request-id")
+        .get(request_id_header)
         .and_then(|v| v.to_str().ok())
         .map(str::trim)
         .filter(|v| !v.is_empty())
@@ -721,6 +1520,103 @@ fn parse_auth_headers(
     })
 }
 
+fn numeric_as_string_requested(params: &BTreeMap<String, String>) -> bool {
+    params
+        .get("numeric_as_string")
+        .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        .unwrap_or(false)
+}
+
+fn count_requested(params: &BTreeMap<String, String>, headers: &HeaderMap) -> bool {
+    let is_enabled = |value: &str| value.eq_ignore_ascii_case("true") || value == "1";
+
+    params.get("count").is_some_and(|v| is_enabled(v))
+        || headers
+            .get("x-include-count")
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|v| is_enabled(v.trim()))
+}
+
+fn parse_filters(
+    params: &BTreeMap<String, String>,
+) -> Result<Vec<(String, DataApiFilterOperator)>, DataApiDomainError> {
+    params
+        .iter()
+        .filter_map(|(key, value)| key.strip_prefix("filter_").map(|key| (key, value)))
+        .map(|(key, value)| {
+            let (column, suffix) = match key.rsplit_once("__") {
+                Some((column, suffix)) => (column, Some(suffix)),
+                None => (key, None),
+            };
+
+            Ok((
+                column.to_string(),
+                DataApiFilterOperator::parse(suffix, value)?,
+            ))
+        })
+        .collect()
+}
+
+fn order_desc_requested(params: &BTreeMap<String, String>) -> Result<bool, DataApiDomainError> {
+    match params.get("order_dir").map(|v| v.trim()) {
+        None => Ok(false),
+        Some(v) if v.eq_ignore_ascii_case("asc") => Ok(false),
+        Some(v) if v.eq_ignore_ascii_case("desc") => Ok(true),
+        Some(_) => Err(DataApiDomainError::InvalidQueryParameters),
+    }
+}
+
+fn lenient_projection_requested(headers: &HeaderMap) -> bool {
+    preference_requested(headers, "projection=lenient")
+}
+
+fn nullable_return_requested(headers: &HeaderMap) -> bool {
+    preference_requested(headers, "return=nullable")
+}
+
+fn skip_null_values_requested(headers: &HeaderMap) -> bool {
+    preference_requested(headers, "nulls=skip")
+}
+
+fn strict_json_requested(headers: &HeaderMap, strict_json_enabled: bool) -> bool {
+    if preference_requested(headers, "json=lenient") {
+        return false;
+    }
+
+    strict_json_enabled || preference_requested(headers, "json=strict")
+}
+
+fn preference_requested(headers: &HeaderMap, expected: &str) -> bool {
+    headers
+        .get_all("prefer")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|preference| preference.trim().eq_ignore_ascii_case(expected))
+}
+
+async fn resolve_schema_name(
+    state: &DataApiRestControllerState,
+    auth: &AuthContext,
+) -> Result<String, (StatusCode, Json<DataApiErrorResponseResource>)> {
+    let tenant_id = parse_tenant_id(&auth.tenant_id)?;
+
+    state
+        .tenant_schema_resolver
+        .resolve_schema(&tenant_id, Some(&auth.schema_name))
+        .await
+        .map(|schema_name| schema_name.value().to_string())
+        .map_err(map_domain_error)
+}
+
+fn resolved_schema_headers(schema_name: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(schema_name) {
+        headers.insert("x-resolved-schema", value);
+    }
+    headers
+}
+
 fn deterministic_principal_uuid(raw: &str) -> String {
     let mut hasher_a = DefaultHasher::new();
     raw.hash(&mut hasher_a);
@@ -765,34 +1661,14 @@ fn parse_tenant_id(
 }
 
 fn map_domain_error(error: DataApiDomainError) -> (StatusCode, Json<DataApiErrorResponseResource>) {
-    let status = match error {
-        DataApiDomainError::InvalidTenantId
-        | DataApiDomainError::InvalidSchemaName
-        | DataApiDomainError::InvalidTableName
-        | DataApiDomainError::InvalidColumnName
-        | DataApiDomainError::InvalidRowIdentifier
-        | DataApiDomainError::UnsupportedApiVersion
-        | DataApiDomainError::PayloadTooLarge
-        | DataApiDomainError::InvalidPayload
-        | DataApiDomainError::InvalidQueryParameters
-        | DataApiDomainError::NonEditableColumn(_) => StatusCode::BAD_REQUEST,
-        DataApiDomainError::MissingAuthentication | DataApiDomainError::InvalidAuthentication => {
-            StatusCode::UNAUTHORIZED
-        }
-        DataApiDomainError::AccessDenied | DataApiDomainError::TableNotAllowed => {
-            StatusCode::FORBIDDEN
+    let status =
+        StatusCode::from_u16(error.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let message = match &error {
+        DataApiDomainError::InfrastructureError(detail) => {
+            client_facing_infrastructure_message("data-api", detail)
         }
-        DataApiDomainError::TableNotFound
-        | DataApiDomainError::TenantDatabaseNotFound
-        | DataApiDomainError::PrimaryKeyNotFound
-        | DataApiDomainError::RecordNotFound => StatusCode::NOT_FOUND,
-        DataApiDomainError::InfrastructureError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        _ => error.to_string(),
     };
 
-    (
-        status,
-        Json(DataApiErrorResponseResource {
-            message: error.to_string(),
-        }),
-    )
+    (status, Json(DataApiErrorResponseResource { message }))
 }
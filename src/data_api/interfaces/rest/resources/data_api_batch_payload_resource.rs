@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DataApiBatchPayloadResource {
+    #[validate(custom(function = "validate_object_payloads"))]
+    pub payloads: Vec<Value>,
+}
+
+fn validate_object_payloads(payloads: &[Value]) -> Result<(), validator::ValidationError> {
+    if payloads.is_empty() {
+        return Err(validator::ValidationError::new(
+            "payloads_must_not_be_empty",
+        ));
+    }
+
+    if payloads.iter().all(Value::is_object) {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("payloads_must_be_objects"))
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataApiCapabilitiesResource {
+    pub api_version: String,
+    pub filter_operators: Vec<String>,
+    pub default_page_size: i64,
+    pub max_page_size: i64,
+    pub max_batch_size: usize,
+    pub request_content_types: Vec<String>,
+    pub response_content_types: Vec<String>,
+    pub idempotency_keys_supported: bool,
+    pub debug_sql_enabled: bool,
+    pub authentication: DataApiCapabilitiesAuthenticationResource,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataApiCapabilitiesAuthenticationResource {
+    pub schemes: Vec<String>,
+    pub required_headers: Vec<String>,
+    pub optional_headers: Vec<String>,
+}
@@ -6,4 +6,10 @@ use validator::Validate;
 pub struct DataApiColumnAccessMetadataUpdateRequestResource {
     pub readable: bool,
     pub writable: bool,
+    #[serde(default)]
+    pub read_transform: Option<String>,
+    #[serde(default)]
+    pub filterable: Option<bool>,
+    #[serde(default)]
+    pub orderable: Option<bool>,
 }
@@ -8,4 +8,5 @@ pub struct DataApiListRowsQueryResource {
     pub offset: Option<i64>,
     pub order_by: Option<String>,
     pub order_dir: Option<String>,
+    pub numeric_as_string: Option<bool>,
 }
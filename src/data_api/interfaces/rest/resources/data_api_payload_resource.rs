@@ -1,4 +1,9 @@
-use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fmt};
+
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{Error, MapAccess, SeqAccess, Visitor},
+};
 use serde_json::Value;
 use utoipa::ToSchema;
 use validator::Validate;
@@ -16,3 +21,67 @@ fn validate_object_payload(payload: &Value) -> Result<(), validator::ValidationE
         Err(validator::ValidationError::new("payload_must_be_object"))
     }
 }
+
+pub fn ensure_unique_json_keys(body: &[u8]) -> Result<(), String> {
+    serde_json::from_slice::<UniqueKeysJson>(body)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+struct UniqueKeysJson;
+
+impl<'de> Deserialize<'de> for UniqueKeysJson {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(UniqueKeysJsonVisitor)
+    }
+}
+
+struct UniqueKeysJsonVisitor;
+
+impl<'de> Visitor<'de> for UniqueKeysJsonVisitor {
+    type Value = UniqueKeysJson;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_i64<E: Error>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_u64<E: Error>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_f64<E: Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_str<E: Error>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<UniqueKeysJson>()?.is_some() {}
+        Ok(UniqueKeysJson)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut keys = HashSet::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if !keys.insert(key.clone()) {
+                return Err(A::Error::custom(format!("duplicate key `{key}`")));
+            }
+            map.next_value::<UniqueKeysJson>()?;
+        }
+        Ok(UniqueKeysJson)
+    }
+}
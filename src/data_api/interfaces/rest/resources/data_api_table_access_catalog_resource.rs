@@ -9,7 +9,13 @@ pub struct DataApiTableAccessCatalogEntryResource {
     pub create_enabled: bool,
     pub update_enabled: bool,
     pub delete_enabled: bool,
+    pub append_only: bool,
     pub introspect_enabled: bool,
     pub authorization_mode: String,
+    pub soft_delete_column: Option<String>,
     pub writable_columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creatable_columns: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updatable_columns: Option<Vec<String>>,
 }
@@ -9,6 +9,10 @@ pub struct DataApiTableAccessMetadataUpdateRequestResource {
     pub create_enabled: bool,
     pub update_enabled: bool,
     pub delete_enabled: bool,
+    #[serde(default)]
+    pub append_only: Option<bool>,
     pub introspect_enabled: bool,
     pub authorization_mode: String,
+    #[serde(default)]
+    pub soft_delete_column: Option<String>,
 }
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataApiTenantPoolMetricsResource {
+    pub database_name: String,
+    pub size: u32,
+    pub idle: usize,
+    pub in_use: u32,
+    pub max_connections: u32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataApiTenantPoolsResource {
+    pub pool_count: usize,
+    pub pools: Vec<DataApiTenantPoolMetricsResource>,
+}
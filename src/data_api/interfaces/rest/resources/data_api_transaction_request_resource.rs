@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DataApiTransactionOperationKindResource {
+    Create,
+    Patch,
+    Delete,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataApiTransactionOperationResource {
+    pub op: DataApiTransactionOperationKindResource,
+    pub table: String,
+    #[serde(default)]
+    pub row_id: Option<String>,
+    #[serde(default)]
+    pub payload: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct DataApiTransactionRequestResource {
+    #[validate(custom(function = "validate_operations"))]
+    pub operations: Vec<DataApiTransactionOperationResource>,
+}
+
+fn validate_operations(
+    operations: &[DataApiTransactionOperationResource],
+) -> Result<(), validator::ValidationError> {
+    if operations.is_empty() {
+        return Err(validator::ValidationError::new(
+            "operations_must_not_be_empty",
+        ));
+    }
+
+    for operation in operations {
+        let requires_row_id = operation.op != DataApiTransactionOperationKindResource::Create;
+        if requires_row_id && operation.row_id.is_none() {
+            return Err(validator::ValidationError::new("row_id_is_required"));
+        }
+
+        let requires_payload = operation.op != DataApiTransactionOperationKindResource::Delete;
+        if requires_payload && !operation.payload.as_ref().is_some_and(Value::is_object) {
+            return Err(validator::ValidationError::new("payload_must_be_object"));
+        }
+    }
+
+    Ok(())
+}
@@ -1,34 +1,32 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::Router;
 use sqlx::PgPool;
 
 use crate::{
-    access_control::{
-        application::{
-            acl::access_control_facade_impl::AccessControlFacadeImpl,
-            command_services::access_control_command_service_impl::AccessControlCommandServiceImpl,
-            query_services::access_control_query_service_impl::AccessControlQueryServiceImpl,
-        },
-        infrastructure::persistence::repositories::postgres::{
-            sqlx_authorization_decision_audit_repository_impl::SqlxAuthorizationDecisionAuditRepositoryImpl,
-            sqlx_policy_rule_repository_impl::SqlxPolicyRuleRepositoryImpl,
-            sqlx_role_assignment_repository_impl::SqlxRoleAssignmentRepositoryImpl,
-        },
-    },
-    config::app_config::AppConfig,
+    access_control::interfaces::acl::access_control_facade::AccessControlFacade as AccessControlBcFacade,
+    config::{app_config::AppConfig, error_redaction::ErrorRedaction},
     data_api::{
         application::{
-            acl::access_control_facade_real_impl::AccessControlFacadeRealImpl,
-            command_services::data_api_command_service_impl::DataApiCommandServiceImpl,
+            acl::{
+                access_control_facade_real_impl::AccessControlFacadeRealImpl,
+                tenant_database_facade_impl::TenantDatabaseFacadeImpl,
+            },
+            cache::tenant_pool_generations::TenantPoolGenerations,
+            command_services::data_api_command_service_impl::{
+                DataApiCommandServiceImpl, DataApiCommandServiceSettings,
+            },
             query_services::data_api_query_service_impl::DataApiQueryServiceImpl,
         },
-        infrastructure::persistence::repositories::postgres::{
-            sqlx_data_api_audit_log_repository_impl::SqlxDataApiAuditLogRepositoryImpl,
-            sqlx_data_api_repository_impl::SqlxDataApiRepositoryImpl,
-            sqlx_tenant_connection_resolver_repository_impl::SqlxTenantConnectionResolverRepositoryImpl,
-            sqlx_tenant_pool_cache_repository_impl::SqlxTenantPoolCacheRepositoryImpl,
-            sqlx_tenant_schema_resolver_repository_impl::SqlxTenantSchemaResolverRepositoryImpl,
+        infrastructure::persistence::repositories::{
+            in_memory::in_memory_metadata_sync_schedule_repository_impl::InMemoryMetadataSyncScheduleRepositoryImpl,
+            postgres::{
+                sqlx_data_api_audit_log_repository_impl::SqlxDataApiAuditLogRepositoryImpl,
+                sqlx_data_api_repository_impl::SqlxDataApiRepositoryImpl,
+                sqlx_tenant_connection_resolver_repository_impl::SqlxTenantConnectionResolverRepositoryImpl,
+                sqlx_tenant_pool_cache_repository_impl::SqlxTenantPoolCacheRepositoryImpl,
+                sqlx_tenant_schema_resolver_repository_impl::SqlxTenantSchemaResolverRepositoryImpl,
+            },
         },
         interfaces::rest::controllers::data_api_rest_controller::{
             DataApiRestControllerState, router,
@@ -41,58 +39,103 @@ pub mod domain;
 pub mod infrastructure;
 pub mod interfaces;
 
-pub async fn build_data_api_router(config: &AppConfig) -> Result<Router, String> {
-    let admin_pool = PgPool::connect(&config.admin_database_url())
-        .await
-        .map_err(|e| e.to_string())?;
+pub fn build_tenant_pool_cache(
+    config: &AppConfig,
+    pool_generations: Arc<TenantPoolGenerations>,
+) -> Arc<SqlxTenantPoolCacheRepositoryImpl> {
+    let tenant_pool_cache = Arc::new(
+        SqlxTenantPoolCacheRepositoryImpl::new_with_settings(config.tenant_pool_settings())
+            .with_pool_generations(pool_generations),
+    );
+    if config.tenant_pool_sweep_interval_secs > 0 {
+        tenant_pool_cache
+            .clone()
+            .spawn_idle_pool_sweeper(Duration::from_secs(config.tenant_pool_sweep_interval_secs));
+    }
+    tenant_pool_cache
+}
 
-    let tenant_connection_resolver = Arc::new(SqlxTenantConnectionResolverRepositoryImpl::new(
-        admin_pool.clone(),
-        config.clone(),
+fn build_tenant_connection_resolver(
+    config: &AppConfig,
+    admin_pool: PgPool,
+) -> Result<Arc<SqlxTenantConnectionResolverRepositoryImpl>, String> {
+    Ok(Arc::new(
+        SqlxTenantConnectionResolverRepositoryImpl::new(admin_pool, config.clone())
+            .with_credential_cipher(config.credential_cipher()?.map(Arc::new)),
+    ))
+}
+
+pub fn build_tenant_database_facade(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    tenant_pool_cache: Arc<SqlxTenantPoolCacheRepositoryImpl>,
+) -> Result<Arc<TenantDatabaseFacadeImpl>, String> {
+    Ok(Arc::new(TenantDatabaseFacadeImpl::new(
+        build_tenant_connection_resolver(config, admin_pool)?,
+        tenant_pool_cache,
+    )))
+}
+
+pub fn build_data_api_router(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    tenant_pool_cache: Arc<SqlxTenantPoolCacheRepositoryImpl>,
+    access_control_facade: Arc<dyn AccessControlBcFacade>,
+) -> Result<Router, String> {
+    let tenant_connection_resolver = build_tenant_connection_resolver(config, admin_pool.clone())?;
+    let metadata_sync_schedule = Arc::new(InMemoryMetadataSyncScheduleRepositoryImpl::new(
+        Duration::from_secs(config.data_api_metadata_sync_interval_secs),
     ));
-    let tenant_pool_cache = Arc::new(SqlxTenantPoolCacheRepositoryImpl::new());
     let repository = Arc::new(SqlxDataApiRepositoryImpl::new(
         admin_pool.clone(),
-        tenant_connection_resolver,
-        tenant_pool_cache,
+        tenant_connection_resolver.clone(),
+        tenant_pool_cache.clone(),
+        metadata_sync_schedule,
     ));
     let tenant_schema_resolver = Arc::new(SqlxTenantSchemaResolverRepositoryImpl::new());
     let audit_log_repository = Arc::new(SqlxDataApiAuditLogRepositoryImpl::new(admin_pool.clone()));
-    let acl_role_assignment_repository =
-        Arc::new(SqlxRoleAssignmentRepositoryImpl::new(admin_pool.clone()));
-    let acl_policy_repository = Arc::new(SqlxPolicyRuleRepositoryImpl::new(admin_pool.clone()));
-    let acl_audit_repository = Arc::new(SqlxAuthorizationDecisionAuditRepositoryImpl::new(
-        admin_pool,
-    ));
-    let acl_query_service = Arc::new(AccessControlQueryServiceImpl::new(
-        acl_policy_repository.clone(),
-        acl_role_assignment_repository.clone(),
-        acl_audit_repository,
-    ));
-    let acl_command_service = Arc::new(AccessControlCommandServiceImpl::new(
-        acl_role_assignment_repository.clone(),
-        acl_policy_repository.clone(),
-    ));
-    let access_control_facade = Arc::new(AccessControlFacadeRealImpl::new(Arc::new(
-        AccessControlFacadeImpl::new(acl_command_service, acl_query_service),
-    )));
+    let access_control_facade = Arc::new(AccessControlFacadeRealImpl::new(access_control_facade));
 
-    let command_service = Arc::new(DataApiCommandServiceImpl::new(
+    let command_service = Arc::new(DataApiCommandServiceImpl::new_with_settings(
         repository.clone(),
         tenant_schema_resolver.clone(),
         access_control_facade.clone(),
         audit_log_repository.clone(),
+        DataApiCommandServiceSettings {
+            row_owner_column: config.row_owner_column.clone(),
+            max_batch_size: config.data_api_max_batch_size,
+            strip_denied_columns: config.data_api_strip_denied_columns,
+        },
     ));
-    let query_service = Arc::new(DataApiQueryServiceImpl::new(
-        repository.clone(),
-        tenant_schema_resolver,
-        access_control_facade,
-        audit_log_repository,
-    ));
+    let query_service = Arc::new(
+        DataApiQueryServiceImpl::new_with_row_owner_column(
+            repository.clone(),
+            tenant_schema_resolver.clone(),
+            access_control_facade,
+            audit_log_repository,
+            config.row_owner_column.clone(),
+        )
+        .with_read_audit_sample_rate(config.data_api_read_audit_sample_rate)
+        .with_denied_column_stripping(config.data_api_strip_denied_columns)
+        .with_owner_scoped_lists(config.data_api_owner_scoped_lists),
+    );
 
     Ok(router(DataApiRestControllerState {
+        error_redaction: ErrorRedaction::new(config.error_redaction_enabled),
         command_service,
         query_service,
         repository,
+        tenant_connection_resolver,
+        tenant_pool_cache,
+        tenant_schema_resolver,
+        request_id_header: config.request_id_header.clone(),
+        acl_aware_catalog: config.acl_aware_catalog,
+        effective_writable_catalog: config.effective_writable_catalog,
+        debug_sql_enabled: config.data_api_debug_sql_enabled,
+        strict_json_enabled: config.data_api_strict_json_enabled,
+        max_page_size: config.data_api_max_page_size,
+        max_batch_size: config.data_api_max_batch_size,
+        deprecation: config.data_api_v1_deprecation()?,
+        admin_token: config.admin_token()?,
     }))
 }
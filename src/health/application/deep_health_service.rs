@@ -0,0 +1,48 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::health::domain::component_health_probe::ComponentHealthProbe;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentHealth {
+    pub healthy: bool,
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeepHealthReport {
+    pub healthy: bool,
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+pub struct DeepHealthService {
+    probes: Vec<Arc<dyn ComponentHealthProbe>>,
+}
+
+impl DeepHealthService {
+    pub fn new(probes: Vec<Arc<dyn ComponentHealthProbe>>) -> Self {
+        Self { probes }
+    }
+
+    pub async fn check(&self) -> DeepHealthReport {
+        let mut components = BTreeMap::new();
+
+        for probe in &self.probes {
+            let health = match probe.probe().await {
+                Ok(()) => ComponentHealth {
+                    healthy: true,
+                    detail: None,
+                },
+                Err(detail) => ComponentHealth {
+                    healthy: false,
+                    detail: Some(detail),
+                },
+            };
+            components.insert(probe.component().to_string(), health);
+        }
+
+        DeepHealthReport {
+            healthy: components.values().all(|component| component.healthy),
+            components,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod deep_health_service;
+pub mod tenant_health_monitor;
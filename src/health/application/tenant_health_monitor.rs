@@ -0,0 +1,87 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::{
+    data_api::interfaces::acl::tenant_database_facade::{
+        TenantDatabaseFacade, TenantDatabasePingRequest,
+    },
+    health::{
+        application::deep_health_service::{ComponentHealth, DeepHealthReport},
+        domain::active_tenant_source::ActiveTenantSource,
+    },
+};
+
+pub struct TenantHealthMonitor {
+    tenant_source: Arc<dyn ActiveTenantSource>,
+    tenant_database_facade: Arc<dyn TenantDatabaseFacade>,
+    probe_timeout: Duration,
+    statuses: RwLock<BTreeMap<String, ComponentHealth>>,
+}
+
+impl TenantHealthMonitor {
+    pub fn new(
+        tenant_source: Arc<dyn ActiveTenantSource>,
+        tenant_database_facade: Arc<dyn TenantDatabaseFacade>,
+        probe_timeout: Duration,
+    ) -> Self {
+        Self {
+            tenant_source,
+            tenant_database_facade,
+            probe_timeout,
+            statuses: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                self.probe_all().await;
+            }
+        })
+    }
+
+    pub async fn probe_all(&self) {
+        let tenant_ids = match self.tenant_source.list_active_tenant_ids().await {
+            Ok(tenant_ids) => tenant_ids,
+            Err(_) => return,
+        };
+
+        let mut statuses = BTreeMap::new();
+        for tenant_id in tenant_ids {
+            let health = match self.ping(&tenant_id).await {
+                Ok(()) => ComponentHealth {
+                    healthy: true,
+                    detail: None,
+                },
+                Err(detail) => ComponentHealth {
+                    healthy: false,
+                    detail: Some(detail),
+                },
+            };
+            statuses.insert(tenant_id, health);
+        }
+
+        *self.statuses.write().await = statuses;
+    }
+
+    pub async fn report(&self) -> DeepHealthReport {
+        let components = self.statuses.read().await.clone();
+
+        DeepHealthReport {
+            healthy: components.values().all(|component| component.healthy),
+            components,
+        }
+    }
+
+    async fn ping(&self, tenant_id: &str) -> Result<(), String> {
+        self.tenant_database_facade
+            .ping_tenant_database(TenantDatabasePingRequest {
+                tenant_id: tenant_id.to_string(),
+                timeout: self.probe_timeout,
+            })
+            .await
+    }
+}
@@ -0,0 +1,6 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ActiveTenantSource: Send + Sync {
+    async fn list_active_tenant_ids(&self) -> Result<Vec<String>, String>;
+}
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ComponentHealthProbe: Send + Sync {
+    fn component(&self) -> &str;
+
+    async fn probe(&self) -> Result<(), String>;
+}
@@ -0,0 +1,2 @@
+pub mod active_tenant_source;
+pub mod component_health_probe;
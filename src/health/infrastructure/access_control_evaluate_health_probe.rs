@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    access_control::interfaces::acl::access_control_facade::{
+        AccessControlFacade, AccessControlPermissionRequest,
+    },
+    health::domain::component_health_probe::ComponentHealthProbe,
+};
+
+pub struct AccessControlEvaluateHealthProbe {
+    component: String,
+    access_control_facade: Arc<dyn AccessControlFacade>,
+}
+
+impl AccessControlEvaluateHealthProbe {
+    pub fn new(component: &str, access_control_facade: Arc<dyn AccessControlFacade>) -> Self {
+        Self {
+            component: component.to_string(),
+            access_control_facade,
+        }
+    }
+}
+
+#[async_trait]
+impl ComponentHealthProbe for AccessControlEvaluateHealthProbe {
+    fn component(&self) -> &str {
+        &self.component
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        self.access_control_facade
+            .check_permission(AccessControlPermissionRequest {
+                tenant_id: Uuid::nil().to_string(),
+                principal_id: Uuid::nil().to_string(),
+                resource_name: "health_probe".to_string(),
+                action_name: "read".to_string(),
+                requested_columns: vec![],
+                subject_owner_id: None,
+                row_owner_id: None,
+                request_id: None,
+                bypass_decision_cache: true,
+                audit_decision: false,
+            })
+            .await
+            .map(|_| ())
+            .map_err(|error| {
+                tracing::warn!(component = %self.component, %error, "acl evaluation probe failed");
+                "acl evaluation failed".to_string()
+            })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod access_control_evaluate_health_probe;
+pub mod sqlx_active_tenant_source;
+pub mod sqlx_ping_health_probe;
+pub mod sqlx_table_health_probe;
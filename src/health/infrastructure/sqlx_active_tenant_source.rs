@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::health::domain::active_tenant_source::ActiveTenantSource;
+
+pub struct SqlxActiveTenantSource {
+    pool: PgPool,
+}
+
+impl SqlxActiveTenantSource {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ActiveTenantSource for SqlxActiveTenantSource {
+    async fn list_active_tenant_ids(&self) -> Result<Vec<String>, String> {
+        let statement = r#"
+            SELECT id
+            FROM provisioned_databases
+            WHERE status = 'active'
+            ORDER BY database_name
+        "#;
+
+        sqlx::query_scalar::<_, Uuid>(statement)
+            .fetch_all(&self.pool)
+            .await
+            .map(|ids| ids.into_iter().map(|id| id.to_string()).collect())
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::health::domain::component_health_probe::ComponentHealthProbe;
+
+pub struct SqlxPingHealthProbe {
+    component: String,
+    pool: PgPool,
+}
+
+impl SqlxPingHealthProbe {
+    pub fn new(component: &str, pool: PgPool) -> Self {
+        Self {
+            component: component.to_string(),
+            pool,
+        }
+    }
+}
+
+#[async_trait]
+impl ComponentHealthProbe for SqlxPingHealthProbe {
+    fn component(&self) -> &str {
+        &self.component
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|error| {
+                tracing::warn!(component = %self.component, %error, "database ping failed");
+                "database is unreachable".to_string()
+            })
+    }
+}
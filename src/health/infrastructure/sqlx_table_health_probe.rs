@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::health::domain::component_health_probe::ComponentHealthProbe;
+
+pub struct SqlxTableHealthProbe {
+    component: String,
+    table_name: &'static str,
+    pool: PgPool,
+}
+
+impl SqlxTableHealthProbe {
+    pub fn new(component: &str, table_name: &'static str, pool: PgPool) -> Self {
+        Self {
+            component: component.to_string(),
+            table_name,
+            pool,
+        }
+    }
+}
+
+#[async_trait]
+impl ComponentHealthProbe for SqlxTableHealthProbe {
+    fn component(&self) -> &str {
+        &self.component
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        let statement = format!("SELECT 1 FROM {} LIMIT 1", self.table_name);
+
+        sqlx::query(&statement)
+            .fetch_optional(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|error| {
+                tracing::warn!(component = %self.component, table = self.table_name, %error, "table probe failed");
+                format!("{} is unreachable", self.table_name)
+            })
+    }
+}
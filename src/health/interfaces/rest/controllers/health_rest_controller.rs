@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::get,
+};
+
+use crate::config::admin_token::{AdminToken, is_verified_admin};
+use crate::config::error_redaction::ErrorRedaction;
+use crate::health::{
+    application::{
+        deep_health_service::{DeepHealthReport, DeepHealthService},
+        tenant_health_monitor::TenantHealthMonitor,
+    },
+    interfaces::rest::resources::deep_health_resource::{
+        ComponentHealthResource, DeepHealthResource,
+    },
+};
+
+#[derive(Clone)]
+pub struct HealthRestControllerState {
+    pub deep_health_service: Arc<DeepHealthService>,
+    pub readiness_service: Arc<DeepHealthService>,
+    pub tenant_health_monitor: Arc<TenantHealthMonitor>,
+    pub admin_token: Option<AdminToken>,
+    pub error_redaction: ErrorRedaction,
+}
+
+pub fn router(state: HealthRestControllerState) -> Router {
+    Router::new()
+        .route("/health", get(liveness))
+        .route("/ready", get(readiness))
+        .route("/health/deep", get(deep_health))
+        .route("/health/tenants", get(tenant_health))
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Process is alive", body = DeepHealthResource)
+    )
+)]
+pub async fn liveness() -> Json<DeepHealthResource> {
+    Json(DeepHealthResource {
+        status: "ok".to_string(),
+        components: Default::default(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Admin database reachable; ready to serve traffic", body = DeepHealthResource),
+        (status = 503, description = "Admin database unreachable", body = DeepHealthResource)
+    )
+)]
+pub async fn readiness(
+    State(state): State<HealthRestControllerState>,
+) -> (StatusCode, Json<DeepHealthResource>) {
+    let report = state.readiness_service.check().await;
+
+    to_status_response(report, state.error_redaction)
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/deep",
+    tag = "health",
+    responses(
+        (status = 200, description = "All components healthy", body = DeepHealthResource),
+        (status = 503, description = "At least one component degraded", body = DeepHealthResource)
+    )
+)]
+pub async fn deep_health(
+    State(state): State<HealthRestControllerState>,
+) -> (StatusCode, Json<DeepHealthResource>) {
+    let report = state.deep_health_service.check().await;
+
+    to_status_response(report, state.error_redaction)
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/tenants",
+    tag = "health",
+    params(
+        ("x-admin-token" = String, Header, description = "Verified admin token")
+    ),
+    responses(
+        (status = 200, description = "Last background probe result per active tenant database", body = DeepHealthResource),
+        (status = 403, description = "Missing or invalid admin token")
+    )
+)]
+pub async fn tenant_health(
+    State(state): State<HealthRestControllerState>,
+    headers: HeaderMap,
+) -> Result<Json<DeepHealthResource>, StatusCode> {
+    if !is_verified_admin(&headers, state.admin_token.as_ref()) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let report = state.tenant_health_monitor.report().await;
+
+    Ok(Json(to_deep_health_resource(report, state.error_redaction)))
+}
+
+fn to_status_response(
+    report: DeepHealthReport,
+    error_redaction: ErrorRedaction,
+) -> (StatusCode, Json<DeepHealthResource>) {
+    let status = if report.healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status,
+        Json(to_deep_health_resource(report, error_redaction)),
+    )
+}
+
+fn to_deep_health_resource(
+    report: DeepHealthReport,
+    error_redaction: ErrorRedaction,
+) -> DeepHealthResource {
+    DeepHealthResource {
+        status: if report.healthy { "ok" } else { "degraded" }.to_string(),
+        components: report
+            .components
+            .into_iter()
+            .map(|(component, health)| {
+                (
+                    component,
+                    ComponentHealthResource {
+                        status: if health.healthy { "ok" } else { "down" }.to_string(),
+                        detail: health
+                            .detail
+                            .as_deref()
+                            .map(|detail| error_redaction.redact(detail)),
+                    },
+                )
+            })
+            .collect(),
+    }
+}
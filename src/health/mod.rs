@@ -0,0 +1,82 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::Router;
+use sqlx::PgPool;
+
+use crate::{
+    access_control::interfaces::acl::access_control_facade::AccessControlFacade,
+    config::{app_config::AppConfig, error_redaction::ErrorRedaction},
+    data_api::interfaces::acl::tenant_database_facade::TenantDatabaseFacade,
+    health::{
+        application::{
+            deep_health_service::DeepHealthService, tenant_health_monitor::TenantHealthMonitor,
+        },
+        domain::component_health_probe::ComponentHealthProbe,
+        infrastructure::{
+            access_control_evaluate_health_probe::AccessControlEvaluateHealthProbe,
+            sqlx_active_tenant_source::SqlxActiveTenantSource,
+            sqlx_ping_health_probe::SqlxPingHealthProbe,
+            sqlx_table_health_probe::SqlxTableHealthProbe,
+        },
+        interfaces::rest::controllers::health_rest_controller::{
+            HealthRestControllerState, router,
+        },
+    },
+};
+
+pub mod application;
+pub mod domain;
+pub mod infrastructure;
+pub mod interfaces;
+
+pub fn build_health_router(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    tenant_database_facade: Arc<dyn TenantDatabaseFacade>,
+    access_control_facade: Arc<dyn AccessControlFacade>,
+) -> Result<Router, String> {
+    let probes: Vec<Arc<dyn ComponentHealthProbe>> = vec![
+        Arc::new(SqlxTableHealthProbe::new(
+            "provisioner",
+            "provisioned_databases",
+            admin_pool.clone(),
+        )),
+        Arc::new(SqlxTableHealthProbe::new(
+            "data_api",
+            "data_api_table_metadata",
+            admin_pool.clone(),
+        )),
+        Arc::new(SqlxTableHealthProbe::new(
+            "access_control",
+            "access_policy_rules",
+            admin_pool.clone(),
+        )),
+        Arc::new(AccessControlEvaluateHealthProbe::new(
+            "access_control_evaluate",
+            access_control_facade,
+        )),
+    ];
+
+    let readiness_probes: Vec<Arc<dyn ComponentHealthProbe>> = vec![Arc::new(
+        SqlxPingHealthProbe::new("database", admin_pool.clone()),
+    )];
+
+    let tenant_health_monitor = Arc::new(TenantHealthMonitor::new(
+        Arc::new(SqlxActiveTenantSource::new(admin_pool)),
+        tenant_database_facade,
+        Duration::from_secs(config.tenant_health_probe_timeout_secs),
+    ));
+    if config.tenant_health_probe_interval_secs > 0 {
+        tenant_health_monitor.clone().spawn(Duration::from_secs(
+            config.tenant_health_probe_interval_secs,
+        ));
+    }
+
+    Ok(router(HealthRestControllerState {
+        error_redaction: ErrorRedaction::new(config.error_redaction_enabled),
+        deep_health_service: Arc::new(DeepHealthService::new(probes)),
+        readiness_service: Arc::new(DeepHealthService::new(readiness_probes)),
+        tenant_health_monitor,
+        admin_token: config.admin_token()?,
+    }))
+}
@@ -1,4 +1,5 @@
 pub mod access_control;
 pub mod config;
 pub mod data_api;
+pub mod health;
 pub mod provisioner;
@@ -1,28 +1,55 @@
+use std::sync::Arc;
+
 use axum::Router;
 use dotenvy::dotenv;
+use sqlx::PgPool;
 use swagger_axum_api::{
     access_control::{
-        build_access_control_router,
+        application::cache::decision_cache_generations::DecisionCacheGenerations,
+        build_access_control_facade, build_access_control_router,
         interfaces::rest::resources::{
             access_control_error_response_resource::AccessControlErrorResponseResource,
             assign_role_request_resource::AssignRoleRequestResource,
+            effective_policy_rules_resource::{
+                EffectiveActionRulesResource, EffectivePolicyRuleResource,
+                EffectiveRuleSpecificityResource,
+            },
             evaluate_permission_request_resource::{
                 EvaluatePermissionRequestResource, EvaluatePermissionResponseResource,
             },
+            revoke_role_request_resource::RevokeRoleRequestResource,
             upsert_policy_rule_request_resource::UpsertPolicyRuleRequestResource,
         },
     },
     config::app_config::AppConfig,
     data_api::{
-        build_data_api_router,
+        application::cache::tenant_pool_generations::TenantPoolGenerations,
+        build_data_api_router, build_tenant_database_facade, build_tenant_pool_cache,
         interfaces::rest::resources::{
             data_api_auth_headers_resource::DataApiAuthHeadersResource,
+            data_api_batch_payload_resource::DataApiBatchPayloadResource,
+            data_api_capabilities_resource::{
+                DataApiCapabilitiesAuthenticationResource, DataApiCapabilitiesResource,
+            },
             data_api_column_access_metadata_update_request_resource::DataApiColumnAccessMetadataUpdateRequestResource,
             data_api_error_response_resource::DataApiErrorResponseResource,
             data_api_list_rows_query_resource::DataApiListRowsQueryResource,
             data_api_payload_resource::DataApiPayloadResource,
             data_api_table_access_catalog_resource::DataApiTableAccessCatalogEntryResource,
             data_api_table_access_metadata_update_request_resource::DataApiTableAccessMetadataUpdateRequestResource,
+            data_api_tenant_pool_metrics_resource::{
+                DataApiTenantPoolMetricsResource, DataApiTenantPoolsResource,
+            },
+            data_api_transaction_request_resource::{
+                DataApiTransactionOperationKindResource, DataApiTransactionOperationResource,
+                DataApiTransactionRequestResource,
+            },
+        },
+    },
+    health::{
+        build_health_router,
+        interfaces::rest::resources::deep_health_resource::{
+            ComponentHealthResource, DeepHealthResource,
         },
     },
     provisioner::{
@@ -33,10 +60,16 @@ use swagger_axum_api::{
                 CreateProvisionedDatabaseRequestResource, ListProvisionedDatabasesQueryResource,
             },
             error_response_resource::ErrorResponseResource,
+            provisioned_database_privileges_resource::{
+                ProvisionedDatabasePrivilegesResource, ProvisionedDatabaseTableGrantResource,
+            },
             provisioned_database_resource::ProvisionedDatabaseResource,
+            provisioning_validation_resource::ProvisioningValidationResource,
+            retry_provisioned_database_request_resource::RetryProvisionedDatabaseRequestResource,
         },
     },
 };
+use tracing_subscriber::EnvFilter;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -44,21 +77,40 @@ use utoipa_swagger_ui::SwaggerUi;
 #[openapi(
     paths(
         swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::create_provisioned_database,
+        swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::validate_provisioned_database,
         swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::delete_provisioned_database,
         swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::change_provisioned_database_password,
+        swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::retry_provisioned_database,
         swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::list_provisioned_databases,
+        swagger_axum_api::provisioner::interfaces::rest::controllers::provisioner_rest_controller::describe_provisioned_database_privileges,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::get_capabilities,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::list_rows,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::get_row,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::create_row,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::import_rows,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::create_rows_batch,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::execute_transaction,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::list_access_catalog,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::list_tenant_pool_metrics,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::evict_tenant_pool,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::upsert_table_access_metadata,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::upsert_column_access_metadata,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::upsert_column_access_metadata_batch,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::replace_row,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::patch_row,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::delete_row,
         swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::introspect_table_schema,
+        swagger_axum_api::data_api::interfaces::rest::controllers::data_api_rest_controller::get_table_json_schema,
         swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::assign_role_to_principal,
+        swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::revoke_role_from_principal,
         swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::upsert_policy_rule,
-        swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::evaluate_permission
+        swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::evaluate_permission,
+        swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::evaluate_permission_batch,
+        swagger_axum_api::access_control::interfaces::rest::controllers::access_control_rest_controller::list_effective_rules,
+        swagger_axum_api::health::interfaces::rest::controllers::health_rest_controller::liveness,
+        swagger_axum_api::health::interfaces::rest::controllers::health_rest_controller::readiness,
+        swagger_axum_api::health::interfaces::rest::controllers::health_rest_controller::deep_health,
+        swagger_axum_api::health::interfaces::rest::controllers::health_rest_controller::tenant_health
     ),
     components(
         schemas(
@@ -66,49 +118,134 @@ use utoipa_swagger_ui::SwaggerUi;
             ChangeProvisionedDatabasePasswordRequestResource,
             ListProvisionedDatabasesQueryResource,
             ProvisionedDatabaseResource,
+            ProvisionedDatabasePrivilegesResource,
+            ProvisionedDatabaseTableGrantResource,
+            ProvisioningValidationResource,
+            RetryProvisionedDatabaseRequestResource,
             ErrorResponseResource,
             DataApiAuthHeadersResource,
             DataApiErrorResponseResource,
             DataApiListRowsQueryResource,
             DataApiPayloadResource,
+            DataApiBatchPayloadResource,
+            DataApiTransactionRequestResource,
+            DataApiTransactionOperationResource,
+            DataApiTransactionOperationKindResource,
             DataApiTableAccessMetadataUpdateRequestResource,
             DataApiColumnAccessMetadataUpdateRequestResource,
             DataApiTableAccessCatalogEntryResource,
+            DataApiTenantPoolMetricsResource,
+            DataApiTenantPoolsResource,
+            DataApiCapabilitiesResource,
+            DataApiCapabilitiesAuthenticationResource,
             AssignRoleRequestResource,
+            RevokeRoleRequestResource,
             UpsertPolicyRuleRequestResource,
             EvaluatePermissionRequestResource,
             EvaluatePermissionResponseResource,
-            AccessControlErrorResponseResource
+            EffectiveActionRulesResource,
+            EffectivePolicyRuleResource,
+            EffectiveRuleSpecificityResource,
+            AccessControlErrorResponseResource,
+            DeepHealthResource,
+            ComponentHealthResource
         )
     ),
     tags(
         (name = "provisioner", description = "PostgreSQL database provisioning bounded context"),
         (name = "data-api", description = "Dynamic and versioned CRUD data API bounded context"),
-        (name = "access-control", description = "Authorization policy engine bounded context")
+        (name = "access-control", description = "Authorization policy engine bounded context"),
+        (name = "health", description = "Cross-context health checks")
     )
 )]
 struct ApiDoc;
 
+fn dump_openapi_path() -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if let Some(path) = arg.strip_prefix("--dump-openapi=") {
+            return Some(path.to_string());
+        }
+        if arg == "--dump-openapi" {
+            return Some(args.next().expect("--dump-openapi requires an output path"));
+        }
+    }
+
+    None
+}
+
+fn dump_openapi(path: &str) {
+    let document = ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("failed to serialize openapi document");
+    std::fs::write(path, document).expect("failed to write openapi document");
+}
+
 #[tokio::main]
 async fn main() {
+    if let Some(path) = dump_openapi_path() {
+        dump_openapi(&path);
+        return;
+    }
+
     dotenv().ok();
 
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
     let config = AppConfig::from_env();
 
-    let provisioner_router = build_provisioner_router(&config)
+    let admin_pool = PgPool::connect(&config.admin_database_url())
         .await
-        .expect("failed to build provisioner router");
-    let data_api_router = build_data_api_router(&config)
-        .await
-        .expect("failed to build data api router");
-    let access_control_router = build_access_control_router(&config)
-        .await
-        .expect("failed to build access control router");
+        .expect("failed to connect to admin database");
+
+    let decision_cache_generations = Arc::new(DecisionCacheGenerations::new());
+    let access_control_facade = build_access_control_facade(
+        &config,
+        admin_pool.clone(),
+        decision_cache_generations.clone(),
+    );
+
+    let tenant_pool_generations = Arc::new(TenantPoolGenerations::new());
+    let tenant_pool_cache = build_tenant_pool_cache(&config, tenant_pool_generations);
+    let tenant_database_facade =
+        build_tenant_database_facade(&config, admin_pool.clone(), tenant_pool_cache.clone())
+            .expect("failed to build tenant database facade");
+
+    let provisioner_router = build_provisioner_router(
+        &config,
+        admin_pool.clone(),
+        access_control_facade.clone(),
+        tenant_database_facade.clone(),
+    )
+    .await
+    .expect("failed to build provisioner router");
+    let data_api_router = build_data_api_router(
+        &config,
+        admin_pool.clone(),
+        tenant_pool_cache,
+        access_control_facade.clone(),
+    )
+    .expect("failed to build data api router");
+    let access_control_router =
+        build_access_control_router(&config, admin_pool.clone(), decision_cache_generations)
+            .expect("failed to build access control router");
+    let health_router = build_health_router(
+        &config,
+        admin_pool,
+        tenant_database_facade,
+        access_control_facade,
+    )
+    .expect("failed to build health router");
 
     let app = Router::new()
         .merge(provisioner_router)
         .merge(data_api_router)
         .merge(access_control_router)
+        .merge(health_router)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()));
 
     let addr = format!("0.0.0.0:{}", config.port);
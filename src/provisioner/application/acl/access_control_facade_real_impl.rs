@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    access_control::interfaces::acl::access_control_facade::{
+        AccessControlFacade as AccessControlBcFacade, TenantAccessPurgeRequest,
+    },
+    provisioner::{
+        domain::model::enums::provisioner_domain_error::ProvisionerDomainError,
+        interfaces::acl::access_control_facade::{AccessControlFacade, TenantAccessCleanupRequest},
+    },
+};
+
+pub struct AccessControlFacadeRealImpl {
+    facade: Arc<dyn AccessControlBcFacade>,
+    archive_audit_trail: bool,
+}
+
+impl AccessControlFacadeRealImpl {
+    pub fn new(facade: Arc<dyn AccessControlBcFacade>, archive_audit_trail: bool) -> Self {
+        Self {
+            facade,
+            archive_audit_trail,
+        }
+    }
+}
+
+#[async_trait]
+impl AccessControlFacade for AccessControlFacadeRealImpl {
+    async fn cleanup_tenant_access(
+        &self,
+        request: TenantAccessCleanupRequest,
+    ) -> Result<(), ProvisionerDomainError> {
+        self.facade
+            .purge_tenant_access(TenantAccessPurgeRequest {
+                tenant_id: request.tenant_id,
+                archive_audit_trail: self.archive_audit_trail,
+            })
+            .await
+            .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()))
+    }
+}
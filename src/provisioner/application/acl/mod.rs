@@ -0,0 +1,2 @@
+pub mod access_control_facade_real_impl;
+pub mod tenant_pool_facade_real_impl;
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    data_api::interfaces::acl::tenant_database_facade::{
+        TenantDatabaseFacade, TenantDatabasePoolInvalidationRequest,
+    },
+    provisioner::interfaces::acl::tenant_pool_facade::{
+        TenantPoolFacade, TenantPoolInvalidationRequest,
+    },
+};
+
+pub struct TenantPoolFacadeRealImpl {
+    facade: Arc<dyn TenantDatabaseFacade>,
+}
+
+impl TenantPoolFacadeRealImpl {
+    pub fn new(facade: Arc<dyn TenantDatabaseFacade>) -> Self {
+        Self { facade }
+    }
+}
+
+#[async_trait]
+impl TenantPoolFacade for TenantPoolFacadeRealImpl {
+    async fn invalidate_tenant_pools(&self, request: TenantPoolInvalidationRequest) {
+        self.facade
+            .invalidate_tenant_pools(TenantDatabasePoolInvalidationRequest {
+                tenant_id: request.tenant_id,
+            })
+            .await;
+    }
+}
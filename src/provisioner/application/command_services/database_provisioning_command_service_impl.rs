@@ -3,33 +3,50 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::Utc;
 
-use crate::provisioner::{
-    domain::{
-        model::{
-            commands::{
-                change_provisioned_database_password_command::ChangeProvisionedDatabasePasswordCommand,
-                create_provisioned_database_command::CreateProvisionedDatabaseCommand,
-                delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+use crate::{
+    config::credential_cipher::CredentialCipher,
+    provisioner::{
+        domain::{
+            model::{
+                commands::{
+                    change_provisioned_database_password_command::ChangeProvisionedDatabasePasswordCommand,
+                    create_provisioned_database_command::CreateProvisionedDatabaseCommand,
+                    delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+                    retry_provisioned_database_command::RetryProvisionedDatabaseCommand,
+                },
+                entities::provisioned_database::ProvisionedDatabase,
+                enums::{
+                    provisioned_database_status::ProvisionedDatabaseStatus,
+                    provisioner_domain_error::ProvisionerDomainError,
+                },
+                events::{
+                    provisioned_database_created_event::ProvisionedDatabaseCreatedEvent,
+                    provisioned_database_deleted_event::ProvisionedDatabaseDeletedEvent,
+                    provisioned_database_password_changed_event::ProvisionedDatabasePasswordChangedEvent,
+                },
+                value_objects::{
+                    database_password::DatabasePassword, database_seed_source::DatabaseSeedSource,
+                    provisioned_database_id::ProvisionedDatabaseId,
+                    provisioned_database_name::ProvisionedDatabaseName,
+                },
             },
-            entities::provisioned_database::ProvisionedDatabase,
-            enums::{
-                provisioned_database_status::ProvisionedDatabaseStatus,
-                provisioner_domain_error::ProvisionerDomainError,
+            services::database_provisioning_command_service::DatabaseProvisioningCommandService,
+        },
+        infrastructure::persistence::repositories::{
+            failed_password_attempt_repository::FailedPasswordAttemptRepository,
+            password_change_attempt_repository::PasswordChangeAttemptRepository,
+            postgres_database_administration_repository::{
+                CreatedDatabaseStack, DatabaseStackCreationError,
+                PostgresDatabaseAdministrationRepository,
             },
-            events::{
-                provisioned_database_created_event::ProvisionedDatabaseCreatedEvent,
-                provisioned_database_deleted_event::ProvisionedDatabaseDeletedEvent,
-                provisioned_database_password_changed_event::ProvisionedDatabasePasswordChangedEvent,
+            provisioned_database_repository::ProvisionedDatabaseRepository,
+            provisioning_audit_event_repository::{
+                ProvisioningAuditEventRecord, ProvisioningAuditEventRepository,
             },
-            value_objects::provisioned_database_id::ProvisionedDatabaseId,
         },
-        services::database_provisioning_command_service::DatabaseProvisioningCommandService,
-    },
-    infrastructure::persistence::repositories::{
-        postgres_database_administration_repository::PostgresDatabaseAdministrationRepository,
-        provisioned_database_repository::ProvisionedDatabaseRepository,
-        provisioning_audit_event_repository::{
-            ProvisioningAuditEventRecord, ProvisioningAuditEventRepository,
+        interfaces::acl::{
+            access_control_facade::{AccessControlFacade, TenantAccessCleanupRequest},
+            tenant_pool_facade::{TenantPoolFacade, TenantPoolInvalidationRequest},
         },
     },
 };
@@ -38,6 +55,13 @@ pub struct DatabaseProvisioningCommandServiceImpl {
     metadata_repository: Arc<dyn ProvisionedDatabaseRepository>,
     postgres_administration_repository: Arc<dyn PostgresDatabaseAdministrationRepository>,
     audit_event_repository: Arc<dyn ProvisioningAuditEventRepository>,
+    access_control_facade: Arc<dyn AccessControlFacade>,
+    password_change_attempts: Option<Arc<dyn PasswordChangeAttemptRepository>>,
+    failed_password_attempts: Option<Arc<dyn FailedPasswordAttemptRepository>>,
+    credential_cipher: Option<Arc<CredentialCipher>>,
+    tenant_pool_facade: Option<Arc<dyn TenantPoolFacade>>,
+    default_seed_source: Option<DatabaseSeedSource>,
+    allowed_seed_templates: Vec<ProvisionedDatabaseName>,
 }
 
 impl DatabaseProvisioningCommandServiceImpl {
@@ -45,21 +69,147 @@ impl DatabaseProvisioningCommandServiceImpl {
         metadata_repository: Arc<dyn ProvisionedDatabaseRepository>,
         postgres_administration_repository: Arc<dyn PostgresDatabaseAdministrationRepository>,
         audit_event_repository: Arc<dyn ProvisioningAuditEventRepository>,
+        access_control_facade: Arc<dyn AccessControlFacade>,
     ) -> Self {
         Self {
             metadata_repository,
             postgres_administration_repository,
             audit_event_repository,
+            access_control_facade,
+            password_change_attempts: None,
+            failed_password_attempts: None,
+            credential_cipher: None,
+            tenant_pool_facade: None,
+            default_seed_source: None,
+            allowed_seed_templates: Vec::new(),
         }
     }
-}
 
-#[async_trait]
-impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceImpl {
-    async fn handle_create(
+    pub fn new_with_password_change_attempts(
+        metadata_repository: Arc<dyn ProvisionedDatabaseRepository>,
+        postgres_administration_repository: Arc<dyn PostgresDatabaseAdministrationRepository>,
+        audit_event_repository: Arc<dyn ProvisioningAuditEventRepository>,
+        access_control_facade: Arc<dyn AccessControlFacade>,
+        password_change_attempts: Option<Arc<dyn PasswordChangeAttemptRepository>>,
+    ) -> Self {
+        Self {
+            password_change_attempts,
+            ..Self::new(
+                metadata_repository,
+                postgres_administration_repository,
+                audit_event_repository,
+                access_control_facade,
+            )
+        }
+    }
+
+    pub fn with_credential_cipher(
+        mut self,
+        credential_cipher: Option<Arc<CredentialCipher>>,
+    ) -> Self {
+        self.credential_cipher = credential_cipher;
+        self
+    }
+
+    pub fn with_failed_password_attempts(
+        mut self,
+        failed_password_attempts: Arc<dyn FailedPasswordAttemptRepository>,
+    ) -> Self {
+        self.failed_password_attempts = Some(failed_password_attempts);
+        self
+    }
+
+    pub fn with_tenant_pool_facade(
+        mut self,
+        tenant_pool_facade: Arc<dyn TenantPoolFacade>,
+    ) -> Self {
+        self.tenant_pool_facade = Some(tenant_pool_facade);
+        self
+    }
+
+    pub fn with_default_seed_source(
+        mut self,
+        default_seed_source: Option<DatabaseSeedSource>,
+    ) -> Self {
+        self.default_seed_source = default_seed_source;
+        self
+    }
+
+    pub fn with_allowed_seed_templates(
+        mut self,
+        allowed_seed_templates: Vec<ProvisionedDatabaseName>,
+    ) -> Self {
+        self.allowed_seed_templates = allowed_seed_templates;
+        self
+    }
+
+    fn resolve_seed_source<'a>(
+        &'a self,
+        apply_seed_data: bool,
+        requested: Option<&'a DatabaseSeedSource>,
+    ) -> Option<&'a DatabaseSeedSource> {
+        apply_seed_data
+            .then(|| requested.or(self.default_seed_source.as_ref()))
+            .flatten()
+    }
+
+    fn ensure_seed_source_allowed(
         &self,
-        command: CreateProvisionedDatabaseCommand,
-    ) -> Result<ProvisionedDatabase, ProvisionerDomainError> {
+        requested: Option<&DatabaseSeedSource>,
+    ) -> Result<(), ProvisionerDomainError> {
+        match requested {
+            Some(DatabaseSeedSource::TemplateDatabase(template))
+                if !self.allowed_seed_templates.contains(template) =>
+            {
+                Err(ProvisionerDomainError::InvalidSeedSource(format!(
+                    "seed_template '{}' is not in PROVISIONER_SEED_TEMPLATE_ALLOWLIST",
+                    template.value()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn seal_password(
+        &self,
+        database: &ProvisionedDatabase,
+        password: &DatabasePassword,
+    ) -> Result<Option<String>, ProvisionerDomainError> {
+        self.credential_cipher
+            .as_ref()
+            .map(|cipher| cipher.encrypt(database.database_name().value(), password.value()))
+            .transpose()
+            .map_err(ProvisionerDomainError::InfrastructureError)
+    }
+
+    async fn find_idempotent_replay(
+        &self,
+        command: &CreateProvisionedDatabaseCommand,
+    ) -> Result<Option<ProvisionedDatabase>, ProvisionerDomainError> {
+        let Some(idempotency_key) = command.idempotency_key() else {
+            return Ok(None);
+        };
+        let Some(record) = self
+            .metadata_repository
+            .find_by_idempotency_key(idempotency_key)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if record.request_fingerprint != command.request_fingerprint()
+            || !record.database.password_hash().verifies(command.password())
+        {
+            return Err(ProvisionerDomainError::IdempotencyKeyConflict);
+        }
+
+        Ok(Some(record.database))
+    }
+
+    async fn ensure_name_available(
+        &self,
+        command: &CreateProvisionedDatabaseCommand,
+    ) -> Result<(), ProvisionerDomainError> {
         if self
             .metadata_repository
             .find_by_name(command.database_name())
@@ -69,15 +219,56 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
             return Err(ProvisionerDomainError::DatabaseAlreadyProvisioned);
         }
 
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceImpl {
+    async fn handle_create(
+        &self,
+        command: CreateProvisionedDatabaseCommand,
+    ) -> Result<ProvisionedDatabase, ProvisionerDomainError> {
+        self.ensure_seed_source_allowed(command.seed_source())?;
+        let failed_attempt = match self.find_idempotent_replay(&command).await? {
+            Some(original) if original.status() != ProvisionedDatabaseStatus::Failed => {
+                return Ok(original);
+            }
+            failed_attempt => failed_attempt,
+        };
+        if failed_attempt.is_none() {
+            self.ensure_name_available(&command).await?;
+        }
+
         let mut database = ProvisionedDatabase::new_provisioning(
-            ProvisionedDatabaseId::new_random(),
+            failed_attempt
+                .as_ref()
+                .map(|failed| failed.id().clone())
+                .unwrap_or_else(ProvisionedDatabaseId::new_random),
             command.database_name().clone(),
             command.username().clone(),
             command.password_hash().clone(),
             Utc::now(),
         );
+        let encrypted_password = self.seal_password(&database, command.password())?;
+        database.update_encrypted_password(encrypted_password);
 
-        self.metadata_repository.save(&database).await?;
+        if failed_attempt.is_some() {
+            self.metadata_repository.save(&database).await?;
+        } else if !self
+            .metadata_repository
+            .insert_provisioning(
+                &database,
+                command.idempotency_key(),
+                &command.request_fingerprint(),
+            )
+            .await?
+        {
+            return self
+                .find_idempotent_replay(&command)
+                .await?
+                .ok_or(ProvisionerDomainError::DatabaseAlreadyProvisioned);
+        }
         let _ = self
             .audit_event_repository
             .save_event(&ProvisioningAuditEventRecord::new(
@@ -96,7 +287,8 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
                 command.database_name(),
                 command.username(),
                 command.password(),
-                command.apply_seed_data(),
+                self.resolve_seed_source(command.apply_seed_data(), command.seed_source()),
+                command.extensions(),
             )
             .await;
 
@@ -123,7 +315,7 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
 
                 Ok(database)
             }
-            Err(error) => {
+            Err(DatabaseStackCreationError { created, error }) => {
                 database.mark_failed();
                 self.metadata_repository.save(&database).await?;
                 let _ = self
@@ -140,7 +332,7 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
 
                 let _ = self
                     .postgres_administration_repository
-                    .rollback_database_stack(command.database_name(), command.username())
+                    .rollback_database_stack(command.database_name(), command.username(), created)
                     .await;
 
                 Err(error)
@@ -148,6 +340,13 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
         }
     }
 
+    async fn handle_validate(
+        &self,
+        command: CreateProvisionedDatabaseCommand,
+    ) -> Result<(), ProvisionerDomainError> {
+        self.ensure_name_available(&command).await
+    }
+
     async fn handle_delete(
         &self,
         command: DeleteProvisionedDatabaseCommand,
@@ -213,6 +412,26 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
             ))
             .await;
 
+        if let Err(error) = self
+            .access_control_facade
+            .cleanup_tenant_access(TenantAccessCleanupRequest {
+                tenant_id: database.id().value().to_string(),
+            })
+            .await
+        {
+            let _ = self
+                .audit_event_repository
+                .save_event(&ProvisioningAuditEventRecord::new(
+                    "tenant_access_cleanup_failed",
+                    database.database_name().value(),
+                    Some(database.username().value().to_string()),
+                    database.status().as_str(),
+                    Some(error.to_string()),
+                    Utc::now(),
+                ))
+                .await;
+        }
+
         Ok(())
     }
 
@@ -232,6 +451,27 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
             return Err(ProvisionerDomainError::InvalidStatusTransition);
         }
 
+        if let Some(password_change_attempts) = &self.password_change_attempts
+            && !password_change_attempts
+                .try_record_attempt(database.database_name())
+                .await
+        {
+            let error = ProvisionerDomainError::TooManyPasswordChangeAttempts;
+            let _ = self
+                .audit_event_repository
+                .save_event(&ProvisioningAuditEventRecord::new(
+                    "database_password_change_rate_limited",
+                    database.database_name().value(),
+                    Some(database.username().value().to_string()),
+                    database.status().as_str(),
+                    Some(error.to_string()),
+                    Utc::now(),
+                ))
+                .await;
+
+            return Err(error);
+        }
+
         let _ = self
             .audit_event_repository
             .save_event(&ProvisioningAuditEventRecord::new(
@@ -265,8 +505,18 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
             return Err(error);
         }
 
+        let encrypted_password = self.seal_password(&database, command.password())?;
         database.update_password_hash(command.password_hash().clone());
+        database.update_encrypted_password(encrypted_password);
         self.metadata_repository.save(&database).await?;
+        if let Some(tenant_pool_facade) = &self.tenant_pool_facade {
+            tenant_pool_facade
+                .invalidate_tenant_pools(TenantPoolInvalidationRequest {
+                    tenant_id: database.id().value().to_string(),
+                    database_name: database.database_name().value().to_string(),
+                })
+                .await;
+        }
         let event = ProvisionedDatabasePasswordChangedEvent::new(
             database.database_name().clone(),
             database.username().clone(),
@@ -287,4 +537,147 @@ impl DatabaseProvisioningCommandService for DatabaseProvisioningCommandServiceIm
 
         Ok(())
     }
+
+    async fn handle_retry(
+        &self,
+        command: RetryProvisionedDatabaseCommand,
+    ) -> Result<ProvisionedDatabase, ProvisionerDomainError> {
+        let mut database = self
+            .metadata_repository
+            .find_by_name(command.database_name())
+            .await?
+            .ok_or(ProvisionerDomainError::DatabaseNotFound)?;
+
+        if database.status() != ProvisionedDatabaseStatus::Failed {
+            return Err(ProvisionerDomainError::InvalidStatusTransition);
+        }
+        self.ensure_seed_source_allowed(command.seed_source())?;
+
+        if let Some(failed_password_attempts) = &self.failed_password_attempts
+            && failed_password_attempts
+                .is_locked(database.database_name())
+                .await
+        {
+            let error = ProvisionerDomainError::TooManyFailedPasswordAttempts;
+            let _ = self
+                .audit_event_repository
+                .save_event(&ProvisioningAuditEventRecord::new(
+                    "database_reprovision_rate_limited",
+                    database.database_name().value(),
+                    Some(database.username().value().to_string()),
+                    database.status().as_str(),
+                    Some(error.to_string()),
+                    Utc::now(),
+                ))
+                .await;
+
+            return Err(error);
+        }
+
+        if !database.password_hash().verifies(command.password()) {
+            if let Some(failed_password_attempts) = &self.failed_password_attempts {
+                failed_password_attempts
+                    .record_failure(database.database_name())
+                    .await;
+            }
+            let error = ProvisionerDomainError::DatabaseOwnershipMismatch;
+            let _ = self
+                .audit_event_repository
+                .save_event(&ProvisioningAuditEventRecord::new(
+                    "database_reprovision_rejected",
+                    database.database_name().value(),
+                    Some(database.username().value().to_string()),
+                    database.status().as_str(),
+                    Some(error.to_string()),
+                    Utc::now(),
+                ))
+                .await;
+
+            return Err(error);
+        }
+
+        if let Some(failed_password_attempts) = &self.failed_password_attempts {
+            failed_password_attempts
+                .reset(database.database_name())
+                .await;
+        }
+
+        let encrypted_password = self.seal_password(&database, command.password())?;
+        database.restart_provisioning()?;
+        database.update_encrypted_password(encrypted_password);
+        self.metadata_repository.save(&database).await?;
+        let _ = self
+            .audit_event_repository
+            .save_event(&ProvisioningAuditEventRecord::new(
+                "database_reprovision_started",
+                database.database_name().value(),
+                Some(database.username().value().to_string()),
+                database.status().as_str(),
+                None,
+                Utc::now(),
+            ))
+            .await;
+
+        let _ = self
+            .postgres_administration_repository
+            .rollback_database_stack(
+                database.database_name(),
+                database.username(),
+                CreatedDatabaseStack::complete(),
+            )
+            .await;
+
+        let creation_result = self
+            .postgres_administration_repository
+            .create_database_stack(
+                database.database_name(),
+                database.username(),
+                command.password(),
+                self.resolve_seed_source(command.apply_seed_data(), command.seed_source()),
+                command.extensions(),
+            )
+            .await;
+
+        match creation_result {
+            Ok(()) => {
+                database.mark_active()?;
+                self.metadata_repository.save(&database).await?;
+                let _ = self
+                    .audit_event_repository
+                    .save_event(&ProvisioningAuditEventRecord::new(
+                        "database_reprovision_succeeded",
+                        database.database_name().value(),
+                        Some(database.username().value().to_string()),
+                        database.status().as_str(),
+                        None,
+                        Utc::now(),
+                    ))
+                    .await;
+
+                Ok(database)
+            }
+            Err(DatabaseStackCreationError { created, error }) => {
+                database.mark_failed();
+                self.metadata_repository.save(&database).await?;
+                let _ = self
+                    .audit_event_repository
+                    .save_event(&ProvisioningAuditEventRecord::new(
+                        "database_reprovision_failed",
+                        database.database_name().value(),
+                        Some(database.username().value().to_string()),
+                        database.status().as_str(),
+                        Some(error.to_string()),
+                        Utc::now(),
+                    ))
+                    .await;
+
+                let _ = self
+                    .postgres_administration_repository
+                    .rollback_database_stack(database.database_name(), database.username(), created)
+                    .await;
+
+                Err(error)
+            }
+        }
+    }
 }
@@ -1,2 +1,3 @@
+pub mod acl;
 pub mod command_services;
 pub mod query_services;
@@ -6,24 +6,83 @@ use crate::provisioner::{
     domain::{
         model::{
             entities::provisioned_database::ProvisionedDatabase,
-            enums::provisioner_domain_error::ProvisionerDomainError,
-            queries::list_provisioned_databases_query::ListProvisionedDatabasesQuery,
+            enums::{
+                provisioned_database_status::ProvisionedDatabaseStatus,
+                provisioner_domain_error::ProvisionerDomainError,
+            },
+            queries::{
+                describe_provisioned_database_privileges_query::DescribeProvisionedDatabasePrivilegesQuery,
+                list_provisioned_databases_query::ListProvisionedDatabasesQuery,
+            },
+            value_objects::database_password::DatabasePassword,
         },
         services::database_provisioning_query_service::DatabaseProvisioningQueryService,
     },
-    infrastructure::persistence::repositories::provisioned_database_repository::ProvisionedDatabaseRepository,
+    infrastructure::persistence::repositories::{
+        failed_password_attempt_repository::FailedPasswordAttemptRepository,
+        postgres_database_administration_repository::{
+            DatabasePrivilegesRecord, PostgresDatabaseAdministrationRepository,
+        },
+        provisioned_database_repository::ProvisionedDatabaseRepository,
+    },
 };
 
 pub struct DatabaseProvisioningQueryServiceImpl {
     metadata_repository: Arc<dyn ProvisionedDatabaseRepository>,
+    administration_repository: Arc<dyn PostgresDatabaseAdministrationRepository>,
+    failed_password_attempts: Option<Arc<dyn FailedPasswordAttemptRepository>>,
 }
 
 impl DatabaseProvisioningQueryServiceImpl {
-    pub fn new(metadata_repository: Arc<dyn ProvisionedDatabaseRepository>) -> Self {
+    pub fn new(
+        metadata_repository: Arc<dyn ProvisionedDatabaseRepository>,
+        administration_repository: Arc<dyn PostgresDatabaseAdministrationRepository>,
+    ) -> Self {
         Self {
             metadata_repository,
+            administration_repository,
+            failed_password_attempts: None,
         }
     }
+
+    pub fn with_failed_password_attempts(
+        mut self,
+        failed_password_attempts: Arc<dyn FailedPasswordAttemptRepository>,
+    ) -> Self {
+        self.failed_password_attempts = Some(failed_password_attempts);
+        self
+    }
+
+    async fn verify_owner_password(
+        &self,
+        database: &ProvisionedDatabase,
+        password: Option<&DatabasePassword>,
+    ) -> Result<(), ProvisionerDomainError> {
+        if let Some(failed_password_attempts) = &self.failed_password_attempts
+            && failed_password_attempts
+                .is_locked(database.database_name())
+                .await
+        {
+            return Err(ProvisionerDomainError::TooManyFailedPasswordAttempts);
+        }
+
+        if !password.is_some_and(|password| database.password_hash().verifies(password)) {
+            if let Some(failed_password_attempts) = &self.failed_password_attempts {
+                failed_password_attempts
+                    .record_failure(database.database_name())
+                    .await;
+            }
+            return Err(ProvisionerDomainError::DatabaseOwnershipMismatch);
+        }
+
+        if let Some(failed_password_attempts) = &self.failed_password_attempts {
+            failed_password_attempts
+                .reset(database.database_name())
+                .await;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -32,10 +91,35 @@ impl DatabaseProvisioningQueryService for DatabaseProvisioningQueryServiceImpl {
         &self,
         query: ListProvisionedDatabasesQuery,
     ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError> {
-        if query.include_deleted() {
+        if !query.statuses().is_empty() {
+            self.metadata_repository
+                .list_by_statuses(query.statuses())
+                .await
+        } else if query.include_deleted() {
             self.metadata_repository.list_all().await
         } else {
             self.metadata_repository.list_active_and_failed().await
         }
     }
+
+    async fn handle_describe_privileges(
+        &self,
+        query: DescribeProvisionedDatabasePrivilegesQuery,
+    ) -> Result<DatabasePrivilegesRecord, ProvisionerDomainError> {
+        let database = self
+            .metadata_repository
+            .find_by_name(query.database_name())
+            .await?
+            .filter(|database| database.status() != ProvisionedDatabaseStatus::Deleted)
+            .ok_or(ProvisionerDomainError::DatabaseNotFound)?;
+
+        if !query.admin_caller() {
+            self.verify_owner_password(&database, query.password())
+                .await?;
+        }
+
+        self.administration_repository
+            .describe_privileges(database.database_name(), database.username())
+            .await
+    }
 }
@@ -1,2 +1,3 @@
 pub mod model;
 pub mod services;
+pub mod validation;
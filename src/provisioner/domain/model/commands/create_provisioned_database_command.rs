@@ -1,8 +1,12 @@
+use sha2::{Digest, Sha256};
+
 use crate::provisioner::domain::model::{
     enums::provisioner_domain_error::ProvisionerDomainError,
     value_objects::{
-        database_password::DatabasePassword, database_password_hash::DatabasePasswordHash,
-        database_username::DatabaseUsername, provisioned_database_name::ProvisionedDatabaseName,
+        database_extension_name::DatabaseExtensionName, database_password::DatabasePassword,
+        database_password_hash::DatabasePasswordHash, database_seed_source::DatabaseSeedSource,
+        database_username::DatabaseUsername, idempotency_key::IdempotencyKey,
+        provisioned_database_name::ProvisionedDatabaseName,
     },
 };
 
@@ -13,6 +17,9 @@ pub struct CreateProvisionedDatabaseCommand {
     password: DatabasePassword,
     password_hash: DatabasePasswordHash,
     apply_seed_data: bool,
+    extensions: Vec<DatabaseExtensionName>,
+    seed_source: Option<DatabaseSeedSource>,
+    idempotency_key: Option<IdempotencyKey>,
 }
 
 impl CreateProvisionedDatabaseCommand {
@@ -22,6 +29,7 @@ impl CreateProvisionedDatabaseCommand {
         password: String,
         password_hash: String,
         apply_seed_data: bool,
+        extensions: Vec<String>,
     ) -> Result<Self, ProvisionerDomainError> {
         Ok(Self {
             database_name: ProvisionedDatabaseName::new(database_name)?,
@@ -29,9 +37,25 @@ impl CreateProvisionedDatabaseCommand {
             password: DatabasePassword::new(password)?,
             password_hash: DatabasePasswordHash::new(password_hash)?,
             apply_seed_data,
+            extensions: DatabaseExtensionName::parse_all(extensions)?,
+            seed_source: None,
+            idempotency_key: None,
         })
     }
 
+    pub fn with_idempotency_key(
+        mut self,
+        idempotency_key: Option<String>,
+    ) -> Result<Self, ProvisionerDomainError> {
+        self.idempotency_key = idempotency_key.map(IdempotencyKey::new).transpose()?;
+        Ok(self)
+    }
+
+    pub fn with_seed_source(mut self, seed_source: Option<DatabaseSeedSource>) -> Self {
+        self.seed_source = seed_source;
+        self
+    }
+
     pub fn database_name(&self) -> &ProvisionedDatabaseName {
         &self.database_name
     }
@@ -51,4 +75,42 @@ impl CreateProvisionedDatabaseCommand {
     pub fn apply_seed_data(&self) -> bool {
         self.apply_seed_data
     }
+
+    pub fn extensions(&self) -> &[DatabaseExtensionName] {
+        &self.extensions
+    }
+
+    pub fn seed_source(&self) -> Option<&DatabaseSeedSource> {
+        self.seed_source.as_ref()
+    }
+
+    pub fn idempotency_key(&self) -> Option<&IdempotencyKey> {
+        self.idempotency_key.as_ref()
+    }
+
+    pub fn request_fingerprint(&self) -> String {
+        let mut extensions = self
+            .extensions
+            .iter()
+            .map(DatabaseExtensionName::value)
+            .collect::<Vec<_>>();
+        extensions.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.database_name.value().as_bytes());
+        hasher.update([0, u8::from(self.apply_seed_data), 0]);
+        hasher.update(extensions.join(",").as_bytes());
+        if let Some(seed_source) = &self.seed_source {
+            hasher.update([0]);
+            hasher.update(seed_source.mode().as_bytes());
+            hasher.update([0]);
+            hasher.update(seed_source.detail().as_bytes());
+        }
+
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
 }
@@ -0,0 +1,59 @@
+use crate::provisioner::domain::model::{
+    enums::provisioner_domain_error::ProvisionerDomainError,
+    value_objects::{
+        database_extension_name::DatabaseExtensionName, database_password::DatabasePassword,
+        database_seed_source::DatabaseSeedSource,
+        provisioned_database_name::ProvisionedDatabaseName,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct RetryProvisionedDatabaseCommand {
+    database_name: ProvisionedDatabaseName,
+    password: DatabasePassword,
+    apply_seed_data: bool,
+    extensions: Vec<DatabaseExtensionName>,
+    seed_source: Option<DatabaseSeedSource>,
+}
+
+impl RetryProvisionedDatabaseCommand {
+    pub fn new(
+        database_name: String,
+        password: String,
+        apply_seed_data: bool,
+        extensions: Vec<String>,
+    ) -> Result<Self, ProvisionerDomainError> {
+        Ok(Self {
+            database_name: ProvisionedDatabaseName::new(database_name)?,
+            password: DatabasePassword::new(password)?,
+            apply_seed_data,
+            extensions: DatabaseExtensionName::parse_all(extensions)?,
+            seed_source: None,
+        })
+    }
+
+    pub fn with_seed_source(mut self, seed_source: Option<DatabaseSeedSource>) -> Self {
+        self.seed_source = seed_source;
+        self
+    }
+
+    pub fn database_name(&self) -> &ProvisionedDatabaseName {
+        &self.database_name
+    }
+
+    pub fn password(&self) -> &DatabasePassword {
+        &self.password
+    }
+
+    pub fn apply_seed_data(&self) -> bool {
+        self.apply_seed_data
+    }
+
+    pub fn extensions(&self) -> &[DatabaseExtensionName] {
+        &self.extensions
+    }
+
+    pub fn seed_source(&self) -> Option<&DatabaseSeedSource> {
+        self.seed_source.as_ref()
+    }
+}
@@ -18,6 +18,7 @@ pub struct ProvisionedDatabase {
     database_name: ProvisionedDatabaseName,
     username: DatabaseUsername,
     password_hash: DatabasePasswordHash,
+    encrypted_password: Option<String>,
     status: ProvisionedDatabaseStatus,
     created_at: DateTime<Utc>,
 }
@@ -35,6 +36,7 @@ impl ProvisionedDatabase {
             database_name,
             username,
             password_hash,
+            encrypted_password: None,
             status: ProvisionedDatabaseStatus::Provisioning,
             created_at,
         }
@@ -53,11 +55,17 @@ impl ProvisionedDatabase {
             database_name,
             username,
             password_hash,
+            encrypted_password: None,
             status,
             created_at,
         }
     }
 
+    pub fn with_encrypted_password(mut self, encrypted_password: Option<String>) -> Self {
+        self.encrypted_password = encrypted_password;
+        self
+    }
+
     pub fn mark_active(&mut self) -> Result<(), ProvisionerDomainError> {
         if self.status != ProvisionedDatabaseStatus::Provisioning {
             return Err(ProvisionerDomainError::InvalidStatusTransition);
@@ -66,6 +74,14 @@ impl ProvisionedDatabase {
         Ok(())
     }
 
+    pub fn restart_provisioning(&mut self) -> Result<(), ProvisionerDomainError> {
+        if self.status != ProvisionedDatabaseStatus::Failed {
+            return Err(ProvisionerDomainError::InvalidStatusTransition);
+        }
+        self.status = ProvisionedDatabaseStatus::Provisioning;
+        Ok(())
+    }
+
     pub fn mark_failed(&mut self) {
         self.status = ProvisionedDatabaseStatus::Failed;
     }
@@ -92,6 +108,10 @@ impl ProvisionedDatabase {
         self.password_hash = password_hash;
     }
 
+    pub fn update_encrypted_password(&mut self, encrypted_password: Option<String>) {
+        self.encrypted_password = encrypted_password;
+    }
+
     pub fn database_name(&self) -> &ProvisionedDatabaseName {
         &self.database_name
     }
@@ -112,6 +132,10 @@ impl ProvisionedDatabase {
         &self.password_hash
     }
 
+    pub fn encrypted_password(&self) -> Option<&str> {
+        self.encrypted_password.as_deref()
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -11,15 +11,39 @@ pub enum ProvisionerDomainError {
     #[error("database password is invalid; minimum length is 8")]
     InvalidDatabasePassword,
 
+    #[error("database extension '{0}' is not in the allowed extension list")]
+    InvalidDatabaseExtension(String),
+
+    #[error("seed source is invalid: {0}")]
+    InvalidSeedSource(String),
+
+    #[error("idempotency key is invalid; use 1..255 visible ASCII characters")]
+    InvalidIdempotencyKey,
+
+    #[error("idempotency key was already used with a different request payload")]
+    IdempotencyKeyConflict,
+
     #[error("database already provisioned")]
     DatabaseAlreadyProvisioned,
 
     #[error("database not found")]
     DatabaseNotFound,
 
+    #[error("database status is invalid; use provisioning, active, failed, deleting or deleted")]
+    InvalidDatabaseStatus,
+
+    #[error("password does not match the provisioned database owner")]
+    DatabaseOwnershipMismatch,
+
     #[error("invalid status transition")]
     InvalidStatusTransition,
 
+    #[error("too many password change attempts; retry later")]
+    TooManyPasswordChangeAttempts,
+
+    #[error("too many failed password attempts for this database; retry later")]
+    TooManyFailedPasswordAttempts,
+
     #[error("infrastructure error: {0}")]
     InfrastructureError(String),
 }
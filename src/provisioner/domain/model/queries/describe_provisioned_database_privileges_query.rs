@@ -0,0 +1,39 @@
+use crate::provisioner::domain::model::{
+    enums::provisioner_domain_error::ProvisionerDomainError,
+    value_objects::{
+        database_password::DatabasePassword, provisioned_database_name::ProvisionedDatabaseName,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct DescribeProvisionedDatabasePrivilegesQuery {
+    database_name: ProvisionedDatabaseName,
+    password: Option<DatabasePassword>,
+    admin_caller: bool,
+}
+
+impl DescribeProvisionedDatabasePrivilegesQuery {
+    pub fn new(
+        database_name: String,
+        password: Option<String>,
+        admin_caller: bool,
+    ) -> Result<Self, ProvisionerDomainError> {
+        Ok(Self {
+            database_name: ProvisionedDatabaseName::new(database_name)?,
+            password: password.map(DatabasePassword::new).transpose()?,
+            admin_caller,
+        })
+    }
+
+    pub fn database_name(&self) -> &ProvisionedDatabaseName {
+        &self.database_name
+    }
+
+    pub fn password(&self) -> Option<&DatabasePassword> {
+        self.password.as_ref()
+    }
+
+    pub fn admin_caller(&self) -> bool {
+        self.admin_caller
+    }
+}
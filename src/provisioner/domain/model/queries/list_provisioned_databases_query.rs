@@ -1,14 +1,34 @@
+use crate::provisioner::domain::model::enums::provisioned_database_status::ProvisionedDatabaseStatus;
+
 #[derive(Clone, Debug, Default)]
 pub struct ListProvisionedDatabasesQuery {
     include_deleted: bool,
+    statuses: Vec<ProvisionedDatabaseStatus>,
 }
 
 impl ListProvisionedDatabasesQuery {
     pub fn new(include_deleted: bool) -> Self {
-        Self { include_deleted }
+        Self {
+            include_deleted,
+            statuses: Vec::new(),
+        }
+    }
+
+    pub fn new_with_statuses(
+        include_deleted: bool,
+        statuses: Vec<ProvisionedDatabaseStatus>,
+    ) -> Self {
+        Self {
+            include_deleted,
+            statuses,
+        }
     }
 
     pub fn include_deleted(&self) -> bool {
         self.include_deleted
     }
+
+    pub fn statuses(&self) -> &[ProvisionedDatabaseStatus] {
+        &self.statuses
+    }
 }
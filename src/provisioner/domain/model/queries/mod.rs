@@ -1 +1,2 @@
+pub mod describe_provisioned_database_privileges_query;
 pub mod list_provisioned_databases_query;
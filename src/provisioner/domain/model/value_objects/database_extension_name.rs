@@ -0,0 +1,49 @@
+use crate::provisioner::domain::model::enums::provisioner_domain_error::ProvisionerDomainError;
+
+const ALLOWED_EXTENSIONS: &[&str] = &[
+    "btree_gin",
+    "btree_gist",
+    "citext",
+    "cube",
+    "earthdistance",
+    "fuzzystrmatch",
+    "hstore",
+    "intarray",
+    "ltree",
+    "pg_trgm",
+    "pgcrypto",
+    "tablefunc",
+    "unaccent",
+    "uuid-ossp",
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DatabaseExtensionName(String);
+
+impl DatabaseExtensionName {
+    pub fn new(value: String) -> Result<Self, ProvisionerDomainError> {
+        let normalized = value.trim().to_lowercase();
+
+        if !ALLOWED_EXTENSIONS.contains(&normalized.as_str()) {
+            return Err(ProvisionerDomainError::InvalidDatabaseExtension(value));
+        }
+
+        Ok(Self(normalized))
+    }
+
+    pub fn parse_all(values: Vec<String>) -> Result<Vec<Self>, ProvisionerDomainError> {
+        let mut extensions = Vec::with_capacity(values.len());
+        for value in values {
+            let extension = Self::new(value)?;
+            if !extensions.contains(&extension) {
+                extensions.push(extension);
+            }
+        }
+
+        Ok(extensions)
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
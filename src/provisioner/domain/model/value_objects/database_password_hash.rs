@@ -1,4 +1,12 @@
-use crate::provisioner::domain::model::enums::provisioner_domain_error::ProvisionerDomainError;
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHash, PasswordVerifier},
+};
+
+use crate::provisioner::domain::model::{
+    enums::provisioner_domain_error::ProvisionerDomainError,
+    value_objects::database_password::DatabasePassword,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DatabasePasswordHash(String);
@@ -17,4 +25,14 @@ impl DatabasePasswordHash {
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    pub fn verifies(&self, password: &DatabasePassword) -> bool {
+        PasswordHash::new(&self.0)
+            .map(|hash| {
+                Argon2::default()
+                    .verify_password(password.value().as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    }
 }
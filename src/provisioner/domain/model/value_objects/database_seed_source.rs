@@ -0,0 +1,73 @@
+use crate::provisioner::domain::model::{
+    enums::provisioner_domain_error::ProvisionerDomainError,
+    value_objects::provisioned_database_name::ProvisionedDatabaseName,
+};
+
+pub const MAX_INLINE_SEED_SQL_BYTES: usize = 64 * 1024;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DatabaseSeedSource {
+    BundledFile,
+    InlineSql(String),
+    TemplateDatabase(ProvisionedDatabaseName),
+}
+
+impl DatabaseSeedSource {
+    pub fn parse(
+        mode: &str,
+        inline_sql: Option<String>,
+        template_database: Option<String>,
+    ) -> Result<Self, ProvisionerDomainError> {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "file" => Ok(Self::BundledFile),
+            "inline" => {
+                let sql = inline_sql.unwrap_or_default();
+                if sql.trim().is_empty() {
+                    return Err(ProvisionerDomainError::InvalidSeedSource(
+                        "inline mode requires seed_sql".to_string(),
+                    ));
+                }
+                if sql.len() > MAX_INLINE_SEED_SQL_BYTES {
+                    return Err(ProvisionerDomainError::InvalidSeedSource(format!(
+                        "seed_sql exceeds {MAX_INLINE_SEED_SQL_BYTES} bytes"
+                    )));
+                }
+
+                Ok(Self::InlineSql(sql))
+            }
+            "template" => template_database
+                .ok_or_else(|| {
+                    ProvisionerDomainError::InvalidSeedSource(
+                        "template mode requires seed_template".to_string(),
+                    )
+                })
+                .and_then(|name| {
+                    ProvisionedDatabaseName::new(name).map_err(|_| {
+                        ProvisionerDomainError::InvalidSeedSource(
+                            "seed_template is not a valid database name".to_string(),
+                        )
+                    })
+                })
+                .map(Self::TemplateDatabase),
+            other => Err(ProvisionerDomainError::InvalidSeedSource(format!(
+                "unknown seed mode '{other}'; use file, inline or template"
+            ))),
+        }
+    }
+
+    pub fn mode(&self) -> &'static str {
+        match self {
+            Self::BundledFile => "file",
+            Self::InlineSql(_) => "inline",
+            Self::TemplateDatabase(_) => "template",
+        }
+    }
+
+    pub fn detail(&self) -> &str {
+        match self {
+            Self::BundledFile => "",
+            Self::InlineSql(sql) => sql,
+            Self::TemplateDatabase(name) => name.value(),
+        }
+    }
+}
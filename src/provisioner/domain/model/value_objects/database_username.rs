@@ -1,20 +1,20 @@
-use regex::Regex;
-
-use crate::provisioner::domain::model::enums::provisioner_domain_error::ProvisionerDomainError;
+use crate::provisioner::domain::{
+    model::enums::provisioner_domain_error::ProvisionerDomainError,
+    validation::sql_identifier::is_safe_sql_identifier,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DatabaseUsername(String);
 
 impl DatabaseUsername {
     pub fn new(value: String) -> Result<Self, ProvisionerDomainError> {
-        let normalized = value.trim().to_lowercase();
-        let regex = Regex::new(r"^[a-z][a-z0-9_]{2,62}$").expect("valid regex");
+        let trimmed = value.trim();
 
-        if !regex.is_match(&normalized) {
+        if !is_safe_sql_identifier(trimmed) {
             return Err(ProvisionerDomainError::InvalidDatabaseUsername);
         }
 
-        Ok(Self(normalized))
+        Ok(Self(trimmed.to_string()))
     }
 
     pub fn value(&self) -> &str {
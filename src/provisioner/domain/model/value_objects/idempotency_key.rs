@@ -0,0 +1,23 @@
+use crate::provisioner::domain::model::enums::provisioner_domain_error::ProvisionerDomainError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    pub fn new(value: String) -> Result<Self, ProvisionerDomainError> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty()
+            || trimmed.len() > 255
+            || !trimmed.chars().all(|c| c.is_ascii_graphic())
+        {
+            return Err(ProvisionerDomainError::InvalidIdempotencyKey);
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
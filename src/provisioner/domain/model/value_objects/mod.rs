@@ -1,5 +1,8 @@
+pub mod database_extension_name;
 pub mod database_password;
 pub mod database_password_hash;
+pub mod database_seed_source;
 pub mod database_username;
+pub mod idempotency_key;
 pub mod provisioned_database_id;
 pub mod provisioned_database_name;
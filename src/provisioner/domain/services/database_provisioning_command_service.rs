@@ -5,6 +5,7 @@ use crate::provisioner::domain::model::{
         change_provisioned_database_password_command::ChangeProvisionedDatabasePasswordCommand,
         create_provisioned_database_command::CreateProvisionedDatabaseCommand,
         delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+        retry_provisioned_database_command::RetryProvisionedDatabaseCommand,
     },
     entities::provisioned_database::ProvisionedDatabase,
     enums::provisioner_domain_error::ProvisionerDomainError,
@@ -17,6 +18,11 @@ pub trait DatabaseProvisioningCommandService: Send + Sync {
         command: CreateProvisionedDatabaseCommand,
     ) -> Result<ProvisionedDatabase, ProvisionerDomainError>;
 
+    async fn handle_validate(
+        &self,
+        command: CreateProvisionedDatabaseCommand,
+    ) -> Result<(), ProvisionerDomainError>;
+
     async fn handle_delete(
         &self,
         command: DeleteProvisionedDatabaseCommand,
@@ -26,4 +32,9 @@ pub trait DatabaseProvisioningCommandService: Send + Sync {
         &self,
         command: ChangeProvisionedDatabasePasswordCommand,
     ) -> Result<(), ProvisionerDomainError>;
+
+    async fn handle_retry(
+        &self,
+        command: RetryProvisionedDatabaseCommand,
+    ) -> Result<ProvisionedDatabase, ProvisionerDomainError>;
 }
@@ -1,9 +1,15 @@
 use async_trait::async_trait;
 
-use crate::provisioner::domain::model::{
-    entities::provisioned_database::ProvisionedDatabase,
-    enums::provisioner_domain_error::ProvisionerDomainError,
-    queries::list_provisioned_databases_query::ListProvisionedDatabasesQuery,
+use crate::provisioner::{
+    domain::model::{
+        entities::provisioned_database::ProvisionedDatabase,
+        enums::provisioner_domain_error::ProvisionerDomainError,
+        queries::{
+            describe_provisioned_database_privileges_query::DescribeProvisionedDatabasePrivilegesQuery,
+            list_provisioned_databases_query::ListProvisionedDatabasesQuery,
+        },
+    },
+    infrastructure::persistence::repositories::postgres_database_administration_repository::DatabasePrivilegesRecord,
 };
 
 #[async_trait]
@@ -12,4 +18,9 @@ pub trait DatabaseProvisioningQueryService: Send + Sync {
         &self,
         query: ListProvisionedDatabasesQuery,
     ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError>;
+
+    async fn handle_describe_privileges(
+        &self,
+        query: DescribeProvisionedDatabasePrivilegesQuery,
+    ) -> Result<DatabasePrivilegesRecord, ProvisionerDomainError>;
 }
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::provisioner::domain::model::value_objects::provisioned_database_name::ProvisionedDatabaseName;
+
+#[async_trait]
+pub trait FailedPasswordAttemptRepository: Send + Sync {
+    async fn is_locked(&self, database_name: &ProvisionedDatabaseName) -> bool;
+
+    async fn record_failure(&self, database_name: &ProvisionedDatabaseName);
+
+    async fn reset(&self, database_name: &ProvisionedDatabaseName);
+}
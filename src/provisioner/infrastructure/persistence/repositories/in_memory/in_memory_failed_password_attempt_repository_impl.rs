@@ -0,0 +1,65 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::provisioner::{
+    domain::model::value_objects::provisioned_database_name::ProvisionedDatabaseName,
+    infrastructure::persistence::repositories::failed_password_attempt_repository::FailedPasswordAttemptRepository,
+};
+
+pub struct InMemoryFailedPasswordAttemptRepositoryImpl {
+    max_failures: usize,
+    window: Duration,
+    failures: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl InMemoryFailedPasswordAttemptRepositoryImpl {
+    pub fn new(max_failures: usize, window: Duration) -> Self {
+        Self {
+            max_failures,
+            window,
+            failures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn prune(history: &mut VecDeque<Instant>, now: Instant, window: Duration) {
+        while let Some(oldest) = history.front()
+            && now.duration_since(*oldest) >= window
+        {
+            history.pop_front();
+        }
+    }
+}
+
+#[async_trait]
+impl FailedPasswordAttemptRepository for InMemoryFailedPasswordAttemptRepositoryImpl {
+    async fn is_locked(&self, database_name: &ProvisionedDatabaseName) -> bool {
+        let now = Instant::now();
+        let mut failures = self.failures.lock().await;
+        let Some(history) = failures.get_mut(database_name.value()) else {
+            return false;
+        };
+
+        Self::prune(history, now, self.window);
+        history.len() >= self.max_failures
+    }
+
+    async fn record_failure(&self, database_name: &ProvisionedDatabaseName) {
+        let now = Instant::now();
+        let mut failures = self.failures.lock().await;
+        let history = failures
+            .entry(database_name.value().to_string())
+            .or_default();
+
+        Self::prune(history, now, self.window);
+        history.push_back(now);
+    }
+
+    async fn reset(&self, database_name: &ProvisionedDatabaseName) {
+        self.failures.lock().await.remove(database_name.value());
+    }
+}
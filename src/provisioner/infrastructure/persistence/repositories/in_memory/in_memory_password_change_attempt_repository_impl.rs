@@ -0,0 +1,52 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::provisioner::{
+    domain::model::value_objects::provisioned_database_name::ProvisionedDatabaseName,
+    infrastructure::persistence::repositories::password_change_attempt_repository::PasswordChangeAttemptRepository,
+};
+
+pub struct InMemoryPasswordChangeAttemptRepositoryImpl {
+    max_attempts: usize,
+    window: Duration,
+    attempts: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl InMemoryPasswordChangeAttemptRepositoryImpl {
+    pub fn new(max_attempts: usize, window: Duration) -> Self {
+        Self {
+            max_attempts,
+            window,
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordChangeAttemptRepository for InMemoryPasswordChangeAttemptRepositoryImpl {
+    async fn try_record_attempt(&self, database_name: &ProvisionedDatabaseName) -> bool {
+        let now = Instant::now();
+        let mut attempts = self.attempts.lock().await;
+        let history = attempts
+            .entry(database_name.value().to_string())
+            .or_default();
+
+        while let Some(oldest) = history.front()
+            && now.duration_since(*oldest) >= self.window
+        {
+            history.pop_front();
+        }
+
+        if history.len() >= self.max_attempts {
+            return false;
+        }
+
+        history.push_back(now);
+        true
+    }
+}
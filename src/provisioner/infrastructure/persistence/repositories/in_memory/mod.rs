@@ -0,0 +1,2 @@
+pub mod in_memory_failed_password_attempt_repository_impl;
+pub mod in_memory_password_change_attempt_repository_impl;
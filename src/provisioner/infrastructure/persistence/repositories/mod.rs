@@ -1,3 +1,6 @@
+pub mod failed_password_attempt_repository;
+pub mod in_memory;
+pub mod password_change_attempt_repository;
 pub mod postgres;
 pub mod postgres_database_administration_repository;
 pub mod provisioned_database_repository;
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+use crate::provisioner::domain::model::value_objects::provisioned_database_name::ProvisionedDatabaseName;
+
+#[async_trait]
+pub trait PasswordChangeAttemptRepository: Send + Sync {
+    async fn try_record_attempt(&self, database_name: &ProvisionedDatabaseName) -> bool;
+}
@@ -1,24 +1,42 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
-
-use crate::provisioner::{
-    domain::model::{
-        enums::provisioner_domain_error::ProvisionerDomainError,
-        value_objects::{
-            database_password::DatabasePassword, database_username::DatabaseUsername,
-            provisioned_database_name::ProvisionedDatabaseName,
+use sqlx::{Connection, PgConnection, PgPool, postgres::PgConnectOptions};
+
+use crate::{
+    config::app_config::AppConfig,
+    provisioner::{
+        domain::model::{
+            enums::provisioner_domain_error::ProvisionerDomainError,
+            value_objects::{
+                database_extension_name::DatabaseExtensionName,
+                database_password::DatabasePassword, database_seed_source::DatabaseSeedSource,
+                database_username::DatabaseUsername,
+                provisioned_database_name::ProvisionedDatabaseName,
+            },
+        },
+        infrastructure::persistence::repositories::postgres_database_administration_repository::{
+            CreatedDatabaseStack, DatabasePrivilegesRecord, DatabaseStackCreationError,
+            PostgresDatabaseAdministrationRepository, TableGrantRecord,
         },
     },
-    infrastructure::persistence::repositories::postgres_database_administration_repository::PostgresDatabaseAdministrationRepository,
 };
 
 pub struct SqlxPostgresDatabaseAdministrationRepositoryImpl {
     admin_pool: PgPool,
+    config: AppConfig,
 }
 
 impl SqlxPostgresDatabaseAdministrationRepositoryImpl {
-    pub fn new(admin_pool: PgPool) -> Self {
-        Self { admin_pool }
+    pub fn new(admin_pool: PgPool, config: AppConfig) -> Self {
+        Self { admin_pool, config }
+    }
+
+    pub fn connect_options_for(&self, database_name: &str) -> PgConnectOptions {
+        PgConnectOptions::new()
+            .host(&self.config.postgres_host)
+            .port(self.config.postgres_port)
+            .username(&self.config.postgres_user)
+            .password(&self.config.postgres_password)
+            .database(database_name)
     }
 
     async fn run_statement(&self, statement: &str) -> Result<(), ProvisionerDomainError> {
@@ -35,19 +53,7 @@ impl SqlxPostgresDatabaseAdministrationRepositoryImpl {
         database_name: &str,
         statement: &str,
     ) -> Result<(), ProvisionerDomainError> {
-        let options = sqlx::postgres::PgConnectOptions::new()
-            .host(&std::env::var("POSTGRES_HOST").unwrap_or_else(|_| "127.0.0.1".to_string()))
-            .port(
-                std::env::var("POSTGRES_PORT")
-                    .unwrap_or_else(|_| "5432".to_string())
-                    .parse()
-                    .unwrap_or(5432),
-            )
-            .username(&std::env::var("POSTGRES_USER").unwrap_or_else(|_| "postgres".to_string()))
-            .password(&std::env::var("POSTGRES_PASSWORD").unwrap_or_else(|_| "admin".to_string()))
-            .database(database_name);
-
-        let db_pool = PgPool::connect_with(options)
+        let db_pool = PgPool::connect_with(self.connect_options_for(database_name))
             .await
             .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()))?;
 
@@ -58,16 +64,75 @@ impl SqlxPostgresDatabaseAdministrationRepositoryImpl {
 
         Ok(())
     }
-}
 
-#[async_trait]
-impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministrationRepositoryImpl {
-    async fn create_database_stack(
+    async fn run_script_on_database(
+        &self,
+        database_name: &str,
+        script: &str,
+    ) -> Result<(), ProvisionerDomainError> {
+        self.run_script_with_options(self.connect_options_for(database_name), script)
+            .await
+    }
+
+    async fn run_script_as_role(
+        &self,
+        database_name: &str,
+        username: &DatabaseUsername,
+        password: &DatabasePassword,
+        script: &str,
+    ) -> Result<(), ProvisionerDomainError> {
+        self.run_script_with_options(
+            self.connect_options_for(database_name)
+                .username(username.value())
+                .password(password.value()),
+            script,
+        )
+        .await
+    }
+
+    async fn run_script_with_options(
+        &self,
+        options: PgConnectOptions,
+        script: &str,
+    ) -> Result<(), ProvisionerDomainError> {
+        let db_pool = PgPool::connect_with(options)
+            .await
+            .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()))?;
+
+        let result = sqlx::raw_sql(script)
+            .execute(&db_pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()));
+        db_pool.close().await;
+
+        result
+    }
+
+    async fn bundled_seed_script(&self) -> Result<String, ProvisionerDomainError> {
+        let path = self
+            .config
+            .provisioner_seed_file
+            .as_deref()
+            .ok_or_else(|| {
+                ProvisionerDomainError::InfrastructureError(
+                    "no bundled seed file configured; set PROVISIONER_SEED_FILE".to_string(),
+                )
+            })?;
+
+        tokio::fs::read_to_string(path).await.map_err(|e| {
+            ProvisionerDomainError::InfrastructureError(format!("failed to read seed file: {e}"))
+        })
+    }
+
+    async fn run_creation_steps(
         &self,
         database_name: &ProvisionedDatabaseName,
         username: &DatabaseUsername,
         password: &DatabasePassword,
-        apply_seed_data: bool,
+        seed_source: Option<&DatabaseSeedSource>,
+        extensions: &[DatabaseExtensionName],
+        created: &mut CreatedDatabaseStack,
     ) -> Result<(), ProvisionerDomainError> {
         let db_identifier = database_name.value();
         let user_identifier = username.value();
@@ -77,11 +142,19 @@ impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministra
             "CREATE ROLE {user_identifier} LOGIN PASSWORD '{escaped_password}'"
         ))
         .await?;
+        created.role = true;
 
+        let template_clause = match seed_source {
+            Some(DatabaseSeedSource::TemplateDatabase(template)) => {
+                format!(" TEMPLATE {}", template.value())
+            }
+            _ => String::new(),
+        };
         self.run_statement(&format!(
-            "CREATE DATABASE {db_identifier} OWNER {user_identifier}"
+            "CREATE DATABASE {db_identifier}{template_clause} OWNER {user_identifier}"
         ))
         .await?;
+        created.database = true;
 
         self.run_statement(&format!(
             "GRANT CONNECT ON DATABASE {db_identifier} TO {user_identifier}"
@@ -110,10 +183,52 @@ impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministra
         )
         .await?;
 
-        let _ = apply_seed_data;
+        for extension in extensions {
+            self.run_statement_on_database(
+                db_identifier,
+                &format!("CREATE EXTENSION IF NOT EXISTS \"{}\"", extension.value()),
+            )
+            .await?;
+        }
+
+        match seed_source {
+            Some(DatabaseSeedSource::BundledFile) => {
+                let script = self.bundled_seed_script().await?;
+                self.run_script_on_database(db_identifier, &script).await?;
+            }
+            Some(DatabaseSeedSource::InlineSql(script)) => {
+                self.run_script_as_role(db_identifier, username, password, script)
+                    .await?;
+            }
+            Some(DatabaseSeedSource::TemplateDatabase(_)) | None => {}
+        }
 
         Ok(())
     }
+}
+
+#[async_trait]
+impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministrationRepositoryImpl {
+    async fn create_database_stack(
+        &self,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
+        password: &DatabasePassword,
+        seed_source: Option<&DatabaseSeedSource>,
+        extensions: &[DatabaseExtensionName],
+    ) -> Result<(), DatabaseStackCreationError> {
+        let mut created = CreatedDatabaseStack::default();
+        self.run_creation_steps(
+            database_name,
+            username,
+            password,
+            seed_source,
+            extensions,
+            &mut created,
+        )
+        .await
+        .map_err(|error| DatabaseStackCreationError { created, error })
+    }
 
     async fn delete_database_stack(
         &self,
@@ -141,8 +256,35 @@ impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministra
         &self,
         database_name: &ProvisionedDatabaseName,
         username: &DatabaseUsername,
+        created: CreatedDatabaseStack,
     ) -> Result<(), ProvisionerDomainError> {
-        self.delete_database_stack(database_name, username).await
+        let db_identifier = database_name.value();
+        let user_identifier = username.value();
+
+        let mut statements = Vec::new();
+        if created.database {
+            statements.push(format!(
+                "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = '{db_identifier}' AND pid <> pg_backend_pid()"
+            ));
+            statements.push(format!("DROP DATABASE IF EXISTS {db_identifier}"));
+        }
+        if created.role {
+            statements.push(format!("DROP ROLE IF EXISTS {user_identifier}"));
+        }
+
+        let mut first_error = None;
+        for statement in statements {
+            if let Err(error) = self.run_statement(&statement).await {
+                tracing::warn!(
+                    database = db_identifier,
+                    error = %error,
+                    "database stack rollback step failed"
+                );
+                first_error.get_or_insert(error);
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
     }
 
     async fn change_database_user_password(
@@ -158,4 +300,54 @@ impl PostgresDatabaseAdministrationRepository for SqlxPostgresDatabaseAdministra
         ))
         .await
     }
+
+    async fn describe_privileges(
+        &self,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
+    ) -> Result<DatabasePrivilegesRecord, ProvisionerDomainError> {
+        let database_privileges = sqlx::query_scalar::<_, String>(
+            "SELECT privilege FROM unnest(ARRAY['CONNECT', 'CREATE', 'TEMPORARY']) AS privilege WHERE has_database_privilege($1, $2, privilege)",
+        )
+        .bind(username.value())
+        .bind(database_name.value())
+        .fetch_all(&self.admin_pool)
+        .await
+        .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()))?;
+
+        let mut connection = PgConnection::connect_with(
+            &self
+                .admin_pool
+                .connect_options()
+                .as_ref()
+                .clone()
+                .database(database_name.value()),
+        )
+        .await
+        .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()))?;
+        let table_grants = sqlx::query_as::<_, (String, String, String)>(
+            "SELECT table_schema, table_name, privilege_type FROM information_schema.role_table_grants WHERE grantee = $1 ORDER BY table_schema, table_name, privilege_type",
+        )
+        .bind(username.value())
+        .fetch_all(&mut connection)
+        .await
+        .map_err(|e| ProvisionerDomainError::InfrastructureError(e.to_string()));
+        let _ = connection.close().await;
+
+        Ok(DatabasePrivilegesRecord {
+            database_name: database_name.value().to_string(),
+            username: username.value().to_string(),
+            database_privileges,
+            table_grants: table_grants?
+                .into_iter()
+                .map(
+                    |(table_schema, table_name, privilege_type)| TableGrantRecord {
+                        table_schema,
+                        table_name,
+                        privilege_type,
+                    },
+                )
+                .collect(),
+        })
+    }
 }
@@ -12,11 +12,13 @@ use crate::provisioner::{
         },
         value_objects::{
             database_password_hash::DatabasePasswordHash, database_username::DatabaseUsername,
-            provisioned_database_id::ProvisionedDatabaseId,
+            idempotency_key::IdempotencyKey, provisioned_database_id::ProvisionedDatabaseId,
             provisioned_database_name::ProvisionedDatabaseName,
         },
     },
-    infrastructure::persistence::repositories::provisioned_database_repository::ProvisionedDatabaseRepository,
+    infrastructure::persistence::repositories::provisioned_database_repository::{
+        IdempotentProvisioningRecord, ProvisionedDatabaseRepository,
+    },
 };
 
 pub struct SqlxProvisionedDatabaseRepositoryImpl {
@@ -35,6 +37,8 @@ impl SqlxProvisionedDatabaseRepositoryImpl {
         let id_raw: String = row.try_get("id").map_err(map_infra_error)?;
         let username_raw: String = row.try_get("username").map_err(map_infra_error)?;
         let password_hash_raw: String = row.try_get("password_hash").map_err(map_infra_error)?;
+        let encrypted_password: Option<String> =
+            row.try_get("encrypted_password").map_err(map_infra_error)?;
         let status_raw: String = row.try_get("status").map_err(map_infra_error)?;
         let created_at: DateTime<Utc> = row.try_get("created_at").map_err(map_infra_error)?;
 
@@ -49,7 +53,8 @@ impl SqlxProvisionedDatabaseRepositoryImpl {
             DatabasePasswordHash::new(password_hash_raw)?,
             status,
             created_at,
-        ))
+        )
+        .with_encrypted_password(encrypted_password))
     }
 }
 
@@ -57,12 +62,13 @@ impl SqlxProvisionedDatabaseRepositoryImpl {
 impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
     async fn save(&self, database: &ProvisionedDatabase) -> Result<(), ProvisionerDomainError> {
         let statement = r#"
-            INSERT INTO provisioned_databases (id, database_name, username, password_hash, status, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO provisioned_databases (id, database_name, username, password_hash, encrypted_password, status, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ON CONFLICT (database_name)
             DO UPDATE SET
                 username = EXCLUDED.username,
                 password_hash = EXCLUDED.password_hash,
+                encrypted_password = EXCLUDED.encrypted_password,
                 status = EXCLUDED.status,
                 created_at = EXCLUDED.created_at
         "#;
@@ -72,6 +78,7 @@ impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
             .bind(database.database_name().value())
             .bind(database.username().value())
             .bind(database.password_hash().value())
+            .bind(database.encrypted_password())
             .bind(database.status().as_str())
             .bind(database.created_at())
             .execute(&self.pool)
@@ -86,7 +93,7 @@ impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
         database_name: &ProvisionedDatabaseName,
     ) -> Result<Option<ProvisionedDatabase>, ProvisionerDomainError> {
         let statement = r#"
-            SELECT id::text AS id, database_name, username, password_hash, status, created_at
+            SELECT id::text AS id, database_name, username, password_hash, encrypted_password, status, created_at
             FROM provisioned_databases
             WHERE database_name = $1
         "#;
@@ -100,9 +107,68 @@ impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
         maybe_row.map(Self::row_to_entity).transpose()
     }
 
+    async fn find_by_idempotency_key(
+        &self,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<Option<IdempotentProvisioningRecord>, ProvisionerDomainError> {
+        let statement = r#"
+            SELECT id::text AS id, database_name, username, password_hash, encrypted_password, status, created_at, request_fingerprint
+            FROM provisioned_databases
+            WHERE idempotency_key = $1
+        "#;
+
+        let maybe_row = sqlx::query(statement)
+            .bind(idempotency_key.value())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_infra_error)?;
+
+        maybe_row
+            .map(|row| {
+                let request_fingerprint: Option<String> = row
+                    .try_get("request_fingerprint")
+                    .map_err(map_infra_error)?;
+
+                Ok(IdempotentProvisioningRecord {
+                    database: Self::row_to_entity(row)?,
+                    request_fingerprint: request_fingerprint.unwrap_or_default(),
+                })
+            })
+            .transpose()
+    }
+
+    async fn insert_provisioning(
+        &self,
+        database: &ProvisionedDatabase,
+        idempotency_key: Option<&IdempotencyKey>,
+        request_fingerprint: &str,
+    ) -> Result<bool, ProvisionerDomainError> {
+        let statement = r#"
+            INSERT INTO provisioned_databases (id, database_name, username, password_hash, encrypted_password, status, created_at, idempotency_key, request_fingerprint)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT DO NOTHING
+        "#;
+
+        let result = sqlx::query(statement)
+            .bind(database.id().value())
+            .bind(database.database_name().value())
+            .bind(database.username().value())
+            .bind(database.password_hash().value())
+            .bind(database.encrypted_password())
+            .bind(database.status().as_str())
+            .bind(database.created_at())
+            .bind(idempotency_key.map(IdempotencyKey::value))
+            .bind(idempotency_key.map(|_| request_fingerprint))
+            .execute(&self.pool)
+            .await
+            .map_err(map_infra_error)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
     async fn list_all(&self) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError> {
         let statement = r#"
-            SELECT id::text AS id, database_name, username, password_hash, status, created_at
+            SELECT id::text AS id, database_name, username, password_hash, encrypted_password, status, created_at
             FROM provisioned_databases
             ORDER BY created_at DESC
         "#;
@@ -119,7 +185,7 @@ impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
         &self,
     ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError> {
         let statement = r#"
-            SELECT id::text AS id, database_name, username, password_hash, status, created_at
+            SELECT id::text AS id, database_name, username, password_hash, encrypted_password, status, created_at
             FROM provisioned_databases
             WHERE status IN ('active', 'failed')
             ORDER BY created_at DESC
@@ -132,6 +198,31 @@ impl ProvisionedDatabaseRepository for SqlxProvisionedDatabaseRepositoryImpl {
 
         rows.into_iter().map(Self::row_to_entity).collect()
     }
+
+    async fn list_by_statuses(
+        &self,
+        statuses: &[ProvisionedDatabaseStatus],
+    ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError> {
+        let statement = r#"
+            SELECT id::text AS id, database_name, username, password_hash, encrypted_password, status, created_at
+            FROM provisioned_databases
+            WHERE status = ANY($1)
+            ORDER BY created_at DESC
+        "#;
+
+        let rows = sqlx::query(statement)
+            .bind(
+                statuses
+                    .iter()
+                    .map(|status| status.as_str().to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_infra_error)?;
+
+        rows.into_iter().map(Self::row_to_entity).collect()
+    }
 }
 
 fn map_infra_error(error: sqlx::Error) -> ProvisionerDomainError {
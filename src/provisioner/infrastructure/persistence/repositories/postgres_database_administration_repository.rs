@@ -3,11 +3,48 @@ use async_trait::async_trait;
 use crate::provisioner::domain::model::{
     enums::provisioner_domain_error::ProvisionerDomainError,
     value_objects::{
-        database_password::DatabasePassword, database_username::DatabaseUsername,
+        database_extension_name::DatabaseExtensionName, database_password::DatabasePassword,
+        database_seed_source::DatabaseSeedSource, database_username::DatabaseUsername,
         provisioned_database_name::ProvisionedDatabaseName,
     },
 };
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableGrantRecord {
+    pub table_schema: String,
+    pub table_name: String,
+    pub privilege_type: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct DatabasePrivilegesRecord {
+    pub database_name: String,
+    pub username: String,
+    pub database_privileges: Vec<String>,
+    pub table_grants: Vec<TableGrantRecord>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CreatedDatabaseStack {
+    pub role: bool,
+    pub database: bool,
+}
+
+impl CreatedDatabaseStack {
+    pub fn complete() -> Self {
+        Self {
+            role: true,
+            database: true,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DatabaseStackCreationError {
+    pub created: CreatedDatabaseStack,
+    pub error: ProvisionerDomainError,
+}
+
 #[async_trait]
 pub trait PostgresDatabaseAdministrationRepository: Send + Sync {
     async fn create_database_stack(
@@ -15,8 +52,9 @@ pub trait PostgresDatabaseAdministrationRepository: Send + Sync {
         database_name: &ProvisionedDatabaseName,
         username: &DatabaseUsername,
         password: &DatabasePassword,
-        apply_seed_data: bool,
-    ) -> Result<(), ProvisionerDomainError>;
+        seed_source: Option<&DatabaseSeedSource>,
+        extensions: &[DatabaseExtensionName],
+    ) -> Result<(), DatabaseStackCreationError>;
 
     async fn delete_database_stack(
         &self,
@@ -28,6 +66,7 @@ pub trait PostgresDatabaseAdministrationRepository: Send + Sync {
         &self,
         database_name: &ProvisionedDatabaseName,
         username: &DatabaseUsername,
+        created: CreatedDatabaseStack,
     ) -> Result<(), ProvisionerDomainError>;
 
     async fn change_database_user_password(
@@ -35,4 +74,10 @@ pub trait PostgresDatabaseAdministrationRepository: Send + Sync {
         username: &DatabaseUsername,
         password: &DatabasePassword,
     ) -> Result<(), ProvisionerDomainError>;
+
+    async fn describe_privileges(
+        &self,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
+    ) -> Result<DatabasePrivilegesRecord, ProvisionerDomainError>;
 }
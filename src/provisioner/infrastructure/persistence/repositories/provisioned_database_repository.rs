@@ -2,10 +2,21 @@ use async_trait::async_trait;
 
 use crate::provisioner::domain::model::{
     entities::provisioned_database::ProvisionedDatabase,
-    enums::provisioner_domain_error::ProvisionerDomainError,
-    value_objects::provisioned_database_name::ProvisionedDatabaseName,
+    enums::{
+        provisioned_database_status::ProvisionedDatabaseStatus,
+        provisioner_domain_error::ProvisionerDomainError,
+    },
+    value_objects::{
+        idempotency_key::IdempotencyKey, provisioned_database_name::ProvisionedDatabaseName,
+    },
 };
 
+#[derive(Clone, Debug)]
+pub struct IdempotentProvisioningRecord {
+    pub database: ProvisionedDatabase,
+    pub request_fingerprint: String,
+}
+
 #[async_trait]
 pub trait ProvisionedDatabaseRepository: Send + Sync {
     async fn save(&self, database: &ProvisionedDatabase) -> Result<(), ProvisionerDomainError>;
@@ -15,9 +26,26 @@ pub trait ProvisionedDatabaseRepository: Send + Sync {
         database_name: &ProvisionedDatabaseName,
     ) -> Result<Option<ProvisionedDatabase>, ProvisionerDomainError>;
 
+    async fn find_by_idempotency_key(
+        &self,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<Option<IdempotentProvisioningRecord>, ProvisionerDomainError>;
+
+    async fn insert_provisioning(
+        &self,
+        database: &ProvisionedDatabase,
+        idempotency_key: Option<&IdempotencyKey>,
+        request_fingerprint: &str,
+    ) -> Result<bool, ProvisionerDomainError>;
+
     async fn list_all(&self) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError>;
 
     async fn list_active_and_failed(
         &self,
     ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError>;
+
+    async fn list_by_statuses(
+        &self,
+        statuses: &[ProvisionedDatabaseStatus],
+    ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError>;
 }
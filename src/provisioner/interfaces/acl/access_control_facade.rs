@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::provisioner::domain::model::enums::provisioner_domain_error::ProvisionerDomainError;
+
+#[derive(Clone, Debug)]
+pub struct TenantAccessCleanupRequest {
+    pub tenant_id: String,
+}
+
+#[async_trait]
+pub trait AccessControlFacade: Send + Sync {
+    async fn cleanup_tenant_access(
+        &self,
+        request: TenantAccessCleanupRequest,
+    ) -> Result<(), ProvisionerDomainError>;
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+#[derive(Clone, Debug)]
+pub struct TenantPoolInvalidationRequest {
+    pub tenant_id: String,
+    pub database_name: String,
+}
+
+#[async_trait]
+pub trait TenantPoolFacade: Send + Sync {
+    async fn invalidate_tenant_pools(&self, request: TenantPoolInvalidationRequest);
+}
@@ -1 +1,2 @@
+pub mod acl;
 pub mod rest;
@@ -7,12 +7,18 @@ use argon2::{
 use axum::{
     Json, Router,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
+    middleware,
     routing::{delete, get, patch, post},
 };
 use rand::{Rng, distributions::Alphanumeric, thread_rng};
 use validator::Validate;
 
+use crate::config::admin_token::{AdminToken, is_verified_admin};
+use crate::config::error_redaction::{
+    ErrorRedaction, client_facing_infrastructure_message, redact_server_error_messages,
+};
+use crate::config::problem_details::negotiate_problem_details;
 use crate::provisioner::{
     domain::{
         model::{
@@ -20,9 +26,18 @@ use crate::provisioner::{
                 change_provisioned_database_password_command::ChangeProvisionedDatabasePasswordCommand,
                 create_provisioned_database_command::CreateProvisionedDatabaseCommand,
                 delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+                retry_provisioned_database_command::RetryProvisionedDatabaseCommand,
+            },
+            entities::provisioned_database::ProvisionedDatabase,
+            enums::{
+                provisioned_database_status::ProvisionedDatabaseStatus,
+                provisioner_domain_error::ProvisionerDomainError,
+            },
+            queries::{
+                describe_provisioned_database_privileges_query::DescribeProvisionedDatabasePrivilegesQuery,
+                list_provisioned_databases_query::ListProvisionedDatabasesQuery,
             },
-            enums::provisioner_domain_error::ProvisionerDomainError,
-            queries::list_provisioned_databases_query::ListProvisionedDatabasesQuery,
+            value_objects::database_seed_source::DatabaseSeedSource,
         },
         services::{
             database_provisioning_command_service::DatabaseProvisioningCommandService,
@@ -35,7 +50,12 @@ use crate::provisioner::{
             CreateProvisionedDatabaseRequestResource, ListProvisionedDatabasesQueryResource,
         },
         error_response_resource::ErrorResponseResource,
+        provisioned_database_privileges_resource::{
+            ProvisionedDatabasePrivilegesResource, ProvisionedDatabaseTableGrantResource,
+        },
         provisioned_database_resource::ProvisionedDatabaseResource,
+        provisioning_validation_resource::ProvisioningValidationResource,
+        retry_provisioned_database_request_resource::RetryProvisionedDatabaseRequestResource,
     },
 };
 
@@ -43,12 +63,22 @@ use crate::provisioner::{
 pub struct ProvisionerRestControllerState {
     pub command_service: Arc<dyn DatabaseProvisioningCommandService>,
     pub query_service: Arc<dyn DatabaseProvisioningQueryService>,
+    pub restricted_username_projection: bool,
+    pub admin_token: Option<AdminToken>,
+    pub error_redaction: ErrorRedaction,
 }
 
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+const DATABASE_PASSWORD_HEADER: &str = "x-database-password";
+
 pub fn router(state: ProvisionerRestControllerState) -> Router {
     Router::new()
         .route("/provisioner/databases", post(create_provisioned_database))
         .route("/provisioner/databases", get(list_provisioned_databases))
+        .route(
+            "/provisioner/databases/_validate",
+            post(validate_provisioned_database),
+        )
         .route(
             "/provisioner/databases/:database_name",
             delete(delete_provisioned_database),
@@ -57,6 +87,19 @@ pub fn router(state: ProvisionerRestControllerState) -> Router {
             "/provisioner/databases/:database_name/password",
             patch(change_provisioned_database_password),
         )
+        .route(
+            "/provisioner/databases/:database_name/retry",
+            post(retry_provisioned_database),
+        )
+        .route(
+            "/provisioner/databases/:database_name/privileges",
+            get(describe_provisioned_database_privileges),
+        )
+        .layer(middleware::map_response_with_state(
+            state.error_redaction,
+            redact_server_error_messages,
+        ))
+        .layer(middleware::from_fn(negotiate_problem_details))
         .with_state(state)
 }
 
@@ -64,16 +107,20 @@ pub fn router(state: ProvisionerRestControllerState) -> Router {
     post,
     path = "/provisioner/databases",
     tag = "provisioner",
+    params(
+        ("idempotency-key" = Option<String>, Header, description = "Replays the original result when a request with the same key and payload is retried")
+    ),
     request_body = CreateProvisionedDatabaseRequestResource,
     responses(
-        (status = 201, description = "Provisioned database created", body = ProvisionedDatabaseResource),
-        (status = 400, description = "Invalid payload", body = ErrorResponseResource),
-        (status = 409, description = "Database already exists", body = ErrorResponseResource),
+        (status = 201, description = "Provisioned database created, or the original result of a replayed idempotency key", body = ProvisionedDatabaseResource),
+        (status = 400, description = "Invalid payload or idempotency key", body = ErrorResponseResource),
+        (status = 409, description = "Database already exists, or the idempotency key was used with a different payload", body = ErrorResponseResource),
         (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
     )
 )]
 pub async fn create_provisioned_database(
     State(state): State<ProvisionerRestControllerState>,
+    headers: HeaderMap,
     Json(request): Json<CreateProvisionedDatabaseRequestResource>,
 ) -> Result<
     (StatusCode, Json<ProvisionedDatabaseResource>),
@@ -90,6 +137,9 @@ pub async fn create_provisioned_database(
 
     let generated_username = generate_database_username();
     let password_hash = hash_database_password(&request.password)?;
+    let seed_source =
+        seed_source_from_request(request.seed_mode, request.seed_sql, request.seed_template)
+            .map_err(map_domain_error)?;
 
     let command = CreateProvisionedDatabaseCommand::new(
         request.database_name,
@@ -97,7 +147,10 @@ pub async fn create_provisioned_database(
         request.password,
         password_hash,
         request.apply_seed_data,
+        request.extensions,
     )
+    .and_then(|command| command.with_idempotency_key(idempotency_key(&headers)))
+    .map(|command| command.with_seed_source(seed_source))
     .map_err(map_domain_error)?;
 
     let created = state
@@ -108,16 +161,91 @@ pub async fn create_provisioned_database(
 
     Ok((
         StatusCode::CREATED,
-        Json(ProvisionedDatabaseResource {
-            id: created.id().value().to_string(),
-            database_name: created.database_name().value().to_string(),
-            username: created.username().value().to_string(),
-            status: created.status().as_str().to_string(),
-            created_at: created.created_at().to_rfc3339(),
-        }),
+        Json(to_provisioned_database_resource(&created, true)),
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/provisioner/databases/_validate",
+    tag = "provisioner",
+    request_body = CreateProvisionedDatabaseRequestResource,
+    responses(
+        (status = 200, description = "Validation result; nothing is provisioned", body = ProvisioningValidationResource),
+        (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
+    )
+)]
+pub async fn validate_provisioned_database(
+    State(state): State<ProvisionerRestControllerState>,
+    Json(request): Json<CreateProvisionedDatabaseRequestResource>,
+) -> Result<Json<ProvisioningValidationResource>, (StatusCode, Json<ErrorResponseResource>)> {
+    if let Err(validation_error) = request.validate() {
+        return Ok(Json(ProvisioningValidationResource {
+            valid: false,
+            errors: vec![validation_error.to_string()],
+        }));
+    }
+
+    let password_hash = hash_database_password(&request.password)?;
+    let command =
+        match seed_source_from_request(request.seed_mode, request.seed_sql, request.seed_template)
+            .and_then(|seed_source| {
+                CreateProvisionedDatabaseCommand::new(
+                    request.database_name,
+                    generate_database_username(),
+                    request.password,
+                    password_hash,
+                    request.apply_seed_data,
+                    request.extensions,
+                )
+                .map(|command| command.with_seed_source(seed_source))
+            }) {
+            Ok(command) => command,
+            Err(error) => {
+                return Ok(Json(ProvisioningValidationResource {
+                    valid: false,
+                    errors: vec![error.to_string()],
+                }));
+            }
+        };
+
+    match state.command_service.handle_validate(command).await {
+        Ok(()) => Ok(Json(ProvisioningValidationResource {
+            valid: true,
+            errors: vec![],
+        })),
+        Err(ProvisionerDomainError::InfrastructureError(message)) => Err(map_domain_error(
+            ProvisionerDomainError::InfrastructureError(message),
+        )),
+        Err(error) => Ok(Json(ProvisioningValidationResource {
+            valid: false,
+            errors: vec![error.to_string()],
+        })),
+    }
+}
+
+fn idempotency_key(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned())
+}
+
+fn seed_source_from_request(
+    seed_mode: Option<String>,
+    seed_sql: Option<String>,
+    seed_template: Option<String>,
+) -> Result<Option<DatabaseSeedSource>, ProvisionerDomainError> {
+    if seed_mode.is_none() && (seed_sql.is_some() || seed_template.is_some()) {
+        return Err(ProvisionerDomainError::InvalidSeedSource(
+            "seed_sql and seed_template require seed_mode".to_string(),
+        ));
+    }
+
+    seed_mode
+        .map(|mode| DatabaseSeedSource::parse(&mode, seed_sql, seed_template))
+        .transpose()
+}
+
 fn generate_database_username() -> String {
     format!("dbu_{}", random_alphanumeric_lowercase(16))
 }
@@ -190,6 +318,7 @@ pub async fn delete_provisioned_database(
         (status = 204, description = "Database password changed"),
         (status = 400, description = "Invalid payload", body = ErrorResponseResource),
         (status = 404, description = "Database not found", body = ErrorResponseResource),
+        (status = 429, description = "Too many password change attempts", body = ErrorResponseResource),
         (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
     )
 )]
@@ -224,56 +353,210 @@ pub async fn change_provisioned_database_password(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/provisioner/databases/{database_name}/retry",
+    tag = "provisioner",
+    params(("database_name" = String, Path, description = "Failed database to provision again")),
+    request_body = RetryProvisionedDatabaseRequestResource,
+    responses(
+        (status = 200, description = "Provisioning retried and database is active", body = ProvisionedDatabaseResource),
+        (status = 400, description = "Invalid payload or database is not failed", body = ErrorResponseResource),
+        (status = 403, description = "Password does not match the database owner", body = ErrorResponseResource),
+        (status = 404, description = "Database not found", body = ErrorResponseResource),
+        (status = 429, description = "Too many failed password attempts for this database", body = ErrorResponseResource),
+        (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
+    )
+)]
+pub async fn retry_provisioned_database(
+    State(state): State<ProvisionerRestControllerState>,
+    Path(database_name): Path<String>,
+    Json(request): Json<RetryProvisionedDatabaseRequestResource>,
+) -> Result<Json<ProvisionedDatabaseResource>, (StatusCode, Json<ErrorResponseResource>)> {
+    if let Err(validation_error) = request.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponseResource {
+                message: validation_error.to_string(),
+            }),
+        ));
+    }
+
+    let seed_source =
+        seed_source_from_request(request.seed_mode, request.seed_sql, request.seed_template)
+            .map_err(map_domain_error)?;
+    let command = RetryProvisionedDatabaseCommand::new(
+        database_name,
+        request.password,
+        request.apply_seed_data,
+        request.extensions,
+    )
+    .map(|command| command.with_seed_source(seed_source))
+    .map_err(map_domain_error)?;
+
+    let database = state
+        .command_service
+        .handle_retry(command)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(Json(to_provisioned_database_resource(&database, true)))
+}
+
 #[utoipa::path(
     get,
     path = "/provisioner/databases",
     tag = "provisioner",
-    params(("include_deleted" = Option<bool>, Query, description = "Include deleted entries")),
+    params(
+        ("include_deleted" = Option<bool>, Query, description = "Include deleted entries"),
+        ("status" = Option<String>, Query, description = "Comma-separated statuses to return (provisioning, active, failed, deleting, deleted); overrides include_deleted"),
+        ("x-admin-token" = Option<String>, Header, description = "Verified admin token; keeps username visible when the restricted projection is enabled")
+    ),
     responses(
         (status = 200, description = "Provisioned database metadata", body = [ProvisionedDatabaseResource]),
+        (status = 400, description = "Invalid status filter", body = ErrorResponseResource),
         (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
     )
 )]
 pub async fn list_provisioned_databases(
     State(state): State<ProvisionerRestControllerState>,
     Query(query): Query<ListProvisionedDatabasesQueryResource>,
+    headers: HeaderMap,
 ) -> Result<Json<Vec<ProvisionedDatabaseResource>>, (StatusCode, Json<ErrorResponseResource>)> {
-    let query = ListProvisionedDatabasesQuery::new(query.include_deleted.unwrap_or(false));
+    let statuses = parse_status_filter(query.status.as_deref()).map_err(map_domain_error)?;
+    let query = ListProvisionedDatabasesQuery::new_with_statuses(
+        query.include_deleted.unwrap_or(false),
+        statuses,
+    );
     let databases = state
         .query_service
         .handle_list(query)
         .await
         .map_err(map_domain_error)?;
 
+    let include_username = !state.restricted_username_projection
+        || is_verified_admin(&headers, state.admin_token.as_ref());
     let payload = databases
-        .into_iter()
-        .map(|database| ProvisionedDatabaseResource {
-            id: database.id().value().to_string(),
-            database_name: database.database_name().value().to_string(),
-            username: database.username().value().to_string(),
-            status: database.status().as_str().to_string(),
-            created_at: database.created_at().to_rfc3339(),
-        })
+        .iter()
+        .map(|database| to_provisioned_database_resource(database, include_username))
         .collect();
 
     Ok(Json(payload))
 }
 
+#[utoipa::path(
+    get,
+    path = "/provisioner/databases/{database_name}/privileges",
+    tag = "provisioner",
+    params(
+        ("database_name" = String, Path, description = "Database whose role privileges are audited"),
+        ("x-database-password" = Option<String>, Header, description = "Owner password; required unless the caller is admin"),
+        ("x-admin-token" = Option<String>, Header, description = "Verified admin token; audits any database without the owner password and keeps username visible when the restricted projection is enabled")
+    ),
+    responses(
+        (status = 200, description = "Database-level and table-level privileges granted to the tenant role", body = ProvisionedDatabasePrivilegesResource),
+        (status = 400, description = "Invalid database name or password", body = ErrorResponseResource),
+        (status = 403, description = "Password does not match the database owner", body = ErrorResponseResource),
+        (status = 404, description = "Database not found", body = ErrorResponseResource),
+        (status = 429, description = "Too many failed password attempts for this database", body = ErrorResponseResource),
+        (status = 500, description = "Infrastructure failure", body = ErrorResponseResource)
+    )
+)]
+pub async fn describe_provisioned_database_privileges(
+    State(state): State<ProvisionerRestControllerState>,
+    Path(database_name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Json<ProvisionedDatabasePrivilegesResource>, (StatusCode, Json<ErrorResponseResource>)>
+{
+    let password = headers
+        .get(DATABASE_PASSWORD_HEADER)
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned());
+    let admin_caller = is_verified_admin(&headers, state.admin_token.as_ref());
+    let query =
+        DescribeProvisionedDatabasePrivilegesQuery::new(database_name, password, admin_caller)
+            .map_err(map_domain_error)?;
+
+    let privileges = state
+        .query_service
+        .handle_describe_privileges(query)
+        .await
+        .map_err(map_domain_error)?;
+
+    Ok(Json(ProvisionedDatabasePrivilegesResource {
+        database_name: privileges.database_name,
+        username: (!state.restricted_username_projection || admin_caller)
+            .then_some(privileges.username),
+        database_privileges: privileges.database_privileges,
+        table_grants: privileges
+            .table_grants
+            .into_iter()
+            .map(|grant| ProvisionedDatabaseTableGrantResource {
+                table_schema: grant.table_schema,
+                table_name: grant.table_name,
+                privilege_type: grant.privilege_type,
+            })
+            .collect(),
+    }))
+}
+
+fn to_provisioned_database_resource(
+    database: &ProvisionedDatabase,
+    include_username: bool,
+) -> ProvisionedDatabaseResource {
+    ProvisionedDatabaseResource {
+        id: database.id().value().to_string(),
+        database_name: database.database_name().value().to_string(),
+        username: include_username.then(|| database.username().value().to_string()),
+        status: database.status().as_str().to_string(),
+        created_at: database.created_at().to_rfc3339(),
+    }
+}
+
+fn parse_status_filter(
+    raw: Option<&str>,
+) -> Result<Vec<ProvisionedDatabaseStatus>, ProvisionerDomainError> {
+    let mut statuses = Vec::new();
+    for value in raw
+        .unwrap_or_default()
+        .split(',')
+        .map(|value| value.trim().to_ascii_lowercase())
+        .filter(|value| !value.is_empty())
+    {
+        let status = value
+            .parse::<ProvisionedDatabaseStatus>()
+            .map_err(|_| ProvisionerDomainError::InvalidDatabaseStatus)?;
+        if !statuses.contains(&status) {
+            statuses.push(status);
+        }
+    }
+
+    Ok(statuses)
+}
+
 fn map_domain_error(error: ProvisionerDomainError) -> (StatusCode, Json<ErrorResponseResource>) {
     let status = match error {
         ProvisionerDomainError::InvalidDatabaseName
         | ProvisionerDomainError::InvalidDatabaseUsername
         | ProvisionerDomainError::InvalidDatabasePassword
+        | ProvisionerDomainError::InvalidDatabaseExtension(_)
+        | ProvisionerDomainError::InvalidSeedSource(_)
+        | ProvisionerDomainError::InvalidIdempotencyKey
+        | ProvisionerDomainError::InvalidDatabaseStatus
         | ProvisionerDomainError::InvalidStatusTransition => StatusCode::BAD_REQUEST,
-        ProvisionerDomainError::DatabaseAlreadyProvisioned => StatusCode::CONFLICT,
+        ProvisionerDomainError::DatabaseOwnershipMismatch => StatusCode::FORBIDDEN,
+        ProvisionerDomainError::DatabaseAlreadyProvisioned
+        | ProvisionerDomainError::IdempotencyKeyConflict => StatusCode::CONFLICT,
         ProvisionerDomainError::DatabaseNotFound => StatusCode::NOT_FOUND,
+        ProvisionerDomainError::TooManyPasswordChangeAttempts
+        | ProvisionerDomainError::TooManyFailedPasswordAttempts => StatusCode::TOO_MANY_REQUESTS,
         ProvisionerDomainError::InfrastructureError(_) => StatusCode::INTERNAL_SERVER_ERROR,
     };
+    let message = match &error {
+        ProvisionerDomainError::InfrastructureError(detail) => {
+            client_facing_infrastructure_message("provisioner", detail)
+        }
+        _ => error.to_string(),
+    };
 
-    (
-        status,
-        Json(ErrorResponseResource {
-            message: error.to_string(),
-        }),
-    )
+    (status, Json(ErrorResponseResource { message }))
 }
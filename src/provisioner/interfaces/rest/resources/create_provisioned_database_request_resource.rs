@@ -11,11 +11,25 @@ pub struct CreateProvisionedDatabaseRequestResource {
     pub password: String,
 
     pub apply_seed_data: bool,
+
+    #[serde(default)]
+    pub extensions: Vec<String>,
+
+    #[serde(default)]
+    #[schema(example = "file")]
+    pub seed_mode: Option<String>,
+
+    #[serde(default)]
+    pub seed_sql: Option<String>,
+
+    #[serde(default)]
+    pub seed_template: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct ListProvisionedDatabasesQueryResource {
     pub include_deleted: Option<bool>,
+    pub status: Option<String>,
 }
 
 lazy_static::lazy_static! {
@@ -1,4 +1,7 @@
 pub mod change_provisioned_database_password_request_resource;
 pub mod create_provisioned_database_request_resource;
 pub mod error_response_resource;
+pub mod provisioned_database_privileges_resource;
 pub mod provisioned_database_resource;
+pub mod provisioning_validation_resource;
+pub mod retry_provisioned_database_request_resource;
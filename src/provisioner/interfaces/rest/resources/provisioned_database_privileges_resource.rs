@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ProvisionedDatabaseTableGrantResource {
+    pub table_schema: String,
+    pub table_name: String,
+    pub privilege_type: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ProvisionedDatabasePrivilegesResource {
+    pub database_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub database_privileges: Vec<String>,
+    pub table_grants: Vec<ProvisionedDatabaseTableGrantResource>,
+}
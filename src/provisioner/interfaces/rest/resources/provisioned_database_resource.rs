@@ -5,7 +5,8 @@ use utoipa::ToSchema;
 pub struct ProvisionedDatabaseResource {
     pub id: String,
     pub database_name: String,
-    pub username: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
     pub status: String,
     pub created_at: String,
 }
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ProvisioningValidationResource {
+    pub valid: bool,
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct RetryProvisionedDatabaseRequestResource {
+    #[validate(length(min = 8))]
+    pub password: String,
+
+    pub apply_seed_data: bool,
+
+    #[serde(default)]
+    pub extensions: Vec<String>,
+
+    #[serde(default)]
+    #[schema(example = "file")]
+    pub seed_mode: Option<String>,
+
+    #[serde(default)]
+    pub seed_sql: Option<String>,
+
+    #[serde(default)]
+    pub seed_template: Option<String>,
+}
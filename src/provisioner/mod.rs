@@ -1,19 +1,32 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use axum::Router;
 use sqlx::{PgPool, migrate};
 
 use crate::{
-    config::app_config::AppConfig,
+    access_control::interfaces::acl::access_control_facade::AccessControlFacade as AccessControlBcFacade,
+    config::{app_config::AppConfig, error_redaction::ErrorRedaction},
+    data_api::interfaces::acl::tenant_database_facade::TenantDatabaseFacade,
     provisioner::{
         application::{
+            acl::{
+                access_control_facade_real_impl::AccessControlFacadeRealImpl,
+                tenant_pool_facade_real_impl::TenantPoolFacadeRealImpl,
+            },
             command_services::database_provisioning_command_service_impl::DatabaseProvisioningCommandServiceImpl,
             query_services::database_provisioning_query_service_impl::DatabaseProvisioningQueryServiceImpl,
         },
-        infrastructure::persistence::repositories::postgres::{
-            sqlx_postgres_database_administration_repository_impl::SqlxPostgresDatabaseAdministrationRepositoryImpl,
-            sqlx_provisioned_database_repository_impl::SqlxProvisionedDatabaseRepositoryImpl,
-            sqlx_provisioning_audit_event_repository_impl::SqlxProvisioningAuditEventRepositoryImpl,
+        infrastructure::persistence::repositories::{
+            in_memory::{
+                in_memory_failed_password_attempt_repository_impl::InMemoryFailedPasswordAttemptRepositoryImpl,
+                in_memory_password_change_attempt_repository_impl::InMemoryPasswordChangeAttemptRepositoryImpl,
+            },
+            password_change_attempt_repository::PasswordChangeAttemptRepository,
+            postgres::{
+                sqlx_postgres_database_administration_repository_impl::SqlxPostgresDatabaseAdministrationRepositoryImpl,
+                sqlx_provisioned_database_repository_impl::SqlxProvisionedDatabaseRepositoryImpl,
+                sqlx_provisioning_audit_event_repository_impl::SqlxProvisioningAuditEventRepositoryImpl,
+            },
         },
         interfaces::rest::controllers::provisioner_rest_controller::{
             ProvisionerRestControllerState, router,
@@ -26,11 +39,12 @@ pub mod domain;
 pub mod infrastructure;
 pub mod interfaces;
 
-pub async fn build_provisioner_router(config: &AppConfig) -> Result<Router, String> {
-    let admin_pool = PgPool::connect(&config.admin_database_url())
-        .await
-        .map_err(|e| e.to_string())?;
-
+pub async fn build_provisioner_router(
+    config: &AppConfig,
+    admin_pool: PgPool,
+    access_control_facade: Arc<dyn AccessControlBcFacade>,
+    tenant_database_facade: Arc<dyn TenantDatabaseFacade>,
+) -> Result<Router, String> {
     migrate!("./migrations")
         .run(&admin_pool)
         .await
@@ -41,23 +55,59 @@ pub async fn build_provisioner_router(config: &AppConfig) -> Result<Router, Stri
     ));
 
     let postgres_administration_repository = Arc::new(
-        SqlxPostgresDatabaseAdministrationRepositoryImpl::new(admin_pool.clone()),
+        SqlxPostgresDatabaseAdministrationRepositoryImpl::new(admin_pool.clone(), config.clone()),
     );
     let audit_event_repository = Arc::new(SqlxProvisioningAuditEventRepositoryImpl::new(
         admin_pool.clone(),
     ));
-
-    let command_service = Arc::new(DatabaseProvisioningCommandServiceImpl::new(
-        metadata_repository.clone(),
-        postgres_administration_repository,
-        audit_event_repository,
+    let access_control_facade = Arc::new(AccessControlFacadeRealImpl::new(
+        access_control_facade,
+        config.archive_acl_audit_on_tenant_delete,
     ));
-    let query_service = Arc::new(DatabaseProvisioningQueryServiceImpl::new(
-        metadata_repository,
+
+    let password_change_attempts = config.password_change_max_attempts.map(|max_attempts| {
+        Arc::new(InMemoryPasswordChangeAttemptRepositoryImpl::new(
+            max_attempts,
+            Duration::from_secs(config.password_change_window_secs),
+        )) as Arc<dyn PasswordChangeAttemptRepository>
+    });
+
+    let failed_password_attempts = Arc::new(InMemoryFailedPasswordAttemptRepositoryImpl::new(
+        config.password_max_failed_attempts,
+        Duration::from_secs(config.password_failed_attempt_window_secs),
     ));
 
+    let credential_cipher = config.credential_cipher()?.map(Arc::new);
+
+    let command_service = Arc::new(
+        DatabaseProvisioningCommandServiceImpl::new_with_password_change_attempts(
+            metadata_repository.clone(),
+            postgres_administration_repository.clone(),
+            audit_event_repository,
+            access_control_facade,
+            password_change_attempts,
+        )
+        .with_credential_cipher(credential_cipher)
+        .with_failed_password_attempts(failed_password_attempts.clone())
+        .with_default_seed_source(config.default_seed_source()?)
+        .with_allowed_seed_templates(config.seed_template_allowlist()?)
+        .with_tenant_pool_facade(Arc::new(TenantPoolFacadeRealImpl::new(
+            tenant_database_facade,
+        ))),
+    );
+    let query_service = Arc::new(
+        DatabaseProvisioningQueryServiceImpl::new(
+            metadata_repository,
+            postgres_administration_repository,
+        )
+        .with_failed_password_attempts(failed_password_attempts),
+    );
+
     Ok(router(ProvisionerRestControllerState {
+        error_redaction: ErrorRedaction::new(config.error_redaction_enabled),
         command_service,
         query_service,
+        restricted_username_projection: config.restricted_username_projection,
+        admin_token: config.admin_token()?,
     }))
 }
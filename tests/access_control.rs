@@ -1,6 +1,10 @@
 #[path = "access_control/command_service_tests.rs"]
 mod command_service_tests;
+#[path = "access_control/facade_tests.rs"]
+mod facade_tests;
 #[path = "access_control/query_service_tests.rs"]
 mod query_service_tests;
+#[path = "access_control/role_assignment_repository_tests.rs"]
+mod role_assignment_repository_tests;
 #[path = "access_control/support.rs"]
 mod support;
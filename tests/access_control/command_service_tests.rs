@@ -1,10 +1,23 @@
+use chrono::Utc;
 use swagger_axum_api::access_control::{
-    domain::services::access_control_command_service::AccessControlCommandService,
-    infrastructure::persistence::repositories::policy_rule_repository::PolicyRuleRecord,
+    domain::{
+        model::{
+            commands::assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+            enums::access_control_domain_error::AccessControlDomainError,
+            events::authorization_decision_audited_event::AuthorizationDecisionAuditedEvent,
+        },
+        services::access_control_command_service::AccessControlCommandService,
+    },
+    infrastructure::persistence::repositories::{
+        authorization_decision_audit_repository::AuthorizationDecisionAuditRepository,
+        policy_rule_repository::PolicyRuleRecord,
+    },
 };
+use uuid::Uuid;
 
 use crate::support::{
-    assign_role_command, create_command_harness, upsert_policy_allow_all_command,
+    TENANT_A_ID, assign_role_command, create_command_harness, fixtures::PRINCIPAL_1_ID,
+    purge_tenant_command, revoke_role_command, upsert_policy_allow_all_command,
     upsert_policy_deny_all_command,
 };
 
@@ -21,6 +34,45 @@ async fn handle_assign_role_persists_assignment() {
     assert_eq!(harness.role_repository.assign_calls(), 1);
 }
 
+#[tokio::test]
+async fn handle_revoke_role_removes_assignment_and_is_idempotent() {
+    let harness = create_command_harness();
+    harness
+        .service
+        .handle_assign_role(assign_role_command())
+        .await
+        .expect("assignment should succeed");
+
+    let first = harness
+        .service
+        .handle_revoke_role(revoke_role_command())
+        .await;
+    let second = harness
+        .service
+        .handle_revoke_role(revoke_role_command())
+        .await;
+
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    assert_eq!(harness.role_repository.revoke_calls(), 2);
+    assert!(harness.role_repository.assignments().is_empty());
+}
+
+#[test]
+fn assign_role_command_rejects_non_rfc3339_expiry() {
+    let result = AssignRoleToPrincipalCommand::new(
+        TENANT_A_ID.to_string(),
+        PRINCIPAL_1_ID.to_string(),
+        "admin".to_string(),
+        Some("tomorrow".to_string()),
+    );
+
+    assert!(matches!(
+        result,
+        Err(AccessControlDomainError::InvalidExpiresAt)
+    ));
+}
+
 #[tokio::test]
 async fn handle_upsert_policy_persists_rule_with_denied_columns() {
     let harness = create_command_harness();
@@ -55,3 +107,76 @@ async fn handle_upsert_policy_accepts_deny_effect() {
         .expect("deny upsert should be captured");
     assert_eq!(last.effect.as_str(), "deny");
 }
+
+#[tokio::test]
+async fn handle_create_policy_if_missing_keeps_existing_rule() {
+    let harness = create_command_harness();
+    harness
+        .service
+        .handle_upsert_policy(upsert_policy_deny_all_command())
+        .await
+        .expect("custom rule should be stored");
+
+    let created = harness
+        .service
+        .handle_create_policy_if_missing(upsert_policy_allow_all_command())
+        .await;
+
+    assert!(matches!(created, Ok(false)));
+    let rules = harness.policy_repository.stored_rules();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].effect.as_str(), "deny");
+}
+
+#[tokio::test]
+async fn handle_purge_tenant_removes_rules_and_role_assignments() {
+    let harness = create_command_harness();
+    harness
+        .service
+        .handle_assign_role(assign_role_command())
+        .await
+        .expect("assign should succeed");
+    harness
+        .service
+        .handle_upsert_policy(upsert_policy_allow_all_command())
+        .await
+        .expect("upsert should succeed");
+
+    let result = harness
+        .service
+        .handle_purge_tenant(purge_tenant_command(false))
+        .await;
+
+    assert!(result.is_ok());
+    assert!(harness.policy_repository.stored_rules().is_empty());
+    assert!(harness.role_repository.assignments().is_empty());
+    assert!(harness.audit_repository.archived_events().is_empty());
+}
+
+#[tokio::test]
+async fn handle_purge_tenant_archives_audit_trail_when_requested() {
+    let harness = create_command_harness();
+    harness
+        .audit_repository
+        .save_decision(&AuthorizationDecisionAuditedEvent {
+            tenant_id: Uuid::parse_str(TENANT_A_ID).expect("valid uuid"),
+            principal_id: Uuid::now_v7(),
+            request_id: None,
+            resource_name: "productos".to_string(),
+            action_name: "read".to_string(),
+            allowed: true,
+            reason: "allowed".to_string(),
+            occurred_at: Utc::now(),
+        })
+        .await
+        .expect("save should succeed");
+
+    let result = harness
+        .service
+        .handle_purge_tenant(purge_tenant_command(true))
+        .await;
+
+    assert!(result.is_ok());
+    assert!(harness.audit_repository.events().is_empty());
+    assert_eq!(harness.audit_repository.archived_events().len(), 1);
+}
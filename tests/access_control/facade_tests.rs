@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use swagger_axum_api::access_control::{
+    domain::model::{
+        enums::permission_effect::PermissionEffect,
+        value_objects::{principal_id::PrincipalId, role_name::RoleName, tenant_id::TenantId},
+    },
+    infrastructure::persistence::repositories::{
+        policy_rule_repository::{PolicyRuleRecord, PolicyRuleRepository},
+        role_assignment_repository::RoleAssignmentRepository,
+    },
+    interfaces::acl::access_control_facade::{
+        AccessControlFacade, AccessControlPermissionRequest, DataApiAccessBootstrapRequest,
+        PrincipalRolesRequest,
+    },
+};
+
+use crate::support::{TENANT_A_ID, create_facade_harness, fixtures};
+
+fn bootstrap_request() -> DataApiAccessBootstrapRequest {
+    DataApiAccessBootstrapRequest {
+        tenant_id: TENANT_A_ID.to_string(),
+        principal_id: fixtures::PRINCIPAL_1_ID.to_string(),
+        resource_name: "productos".to_string(),
+        readable_columns: vec!["id".to_string(), "nombre".to_string()],
+        writable_columns: vec!["nombre".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn bootstrap_creates_default_rules_when_absent() {
+    let harness = create_facade_harness();
+
+    let result = harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await;
+
+    assert!(result.is_ok());
+    let actions = harness
+        .policy_repository
+        .stored_rules()
+        .into_iter()
+        .map(|rule| rule.action_name)
+        .collect::<Vec<_>>();
+    assert_eq!(actions, vec!["read", "create", "update", "delete"]);
+}
+
+fn read_permission_request() -> AccessControlPermissionRequest {
+    AccessControlPermissionRequest {
+        tenant_id: TENANT_A_ID.to_string(),
+        principal_id: fixtures::PRINCIPAL_1_ID.to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        requested_columns: vec!["nombre".to_string()],
+        subject_owner_id: None,
+        row_owner_id: None,
+        request_id: None,
+        bypass_decision_cache: false,
+        audit_decision: false,
+    }
+}
+
+#[tokio::test]
+async fn repeated_bootstrap_keeps_cached_decisions() {
+    let harness = create_facade_harness();
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        tenant_id: TENANT_A_ID.to_string(),
+        role_name: "data_api_authenticated".to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        effect: PermissionEffect::Allow,
+        allowed_columns: None,
+        denied_columns: None,
+        owner_scope: false,
+    }]);
+    harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await
+        .expect("bootstrap should succeed");
+    let first = harness
+        .facade
+        .check_permission(read_permission_request())
+        .await
+        .expect("decision expected");
+
+    harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await
+        .expect("repeated bootstrap should succeed");
+    let second = harness
+        .facade
+        .check_permission(read_permission_request())
+        .await
+        .expect("decision expected");
+
+    assert!(first.allowed && second.allowed);
+    assert_eq!(harness.role_repository.assign_calls(), 2);
+    assert_eq!(harness.role_repository.find_calls(), 1);
+}
+
+#[tokio::test]
+async fn bootstrap_keeps_an_explicit_role_expiry() {
+    let harness = create_facade_harness();
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        tenant_id: TENANT_A_ID.to_string(),
+        role_name: "data_api_authenticated".to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        effect: PermissionEffect::Allow,
+        allowed_columns: None,
+        denied_columns: None,
+        owner_scope: false,
+    }]);
+    harness
+        .role_repository
+        .assign_role(
+            &TenantId::new(TENANT_A_ID.to_string()).expect("valid tenant"),
+            &PrincipalId::new(fixtures::PRINCIPAL_1_ID.to_string()).expect("valid principal"),
+            &RoleName::new("data_api_authenticated".to_string()).expect("valid role"),
+            Some(
+                DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+                    .expect("valid expiry")
+                    .with_timezone(&Utc),
+            ),
+        )
+        .await
+        .expect("expiring assignment should succeed");
+
+    harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await
+        .expect("bootstrap should succeed");
+    let decision = harness
+        .facade
+        .check_permission(read_permission_request())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.reason, "no roles assigned");
+}
+
+#[tokio::test]
+async fn bootstrap_does_not_overwrite_customized_rule() {
+    let harness = create_facade_harness();
+    harness
+        .policy_repository
+        .upsert_rule(PolicyRuleRecord {
+            tenant_id: TENANT_A_ID.to_string(),
+            role_name: "data_api_authenticated".to_string(),
+            resource_name: "productos".to_string(),
+            action_name: "read".to_string(),
+            effect: PermissionEffect::Allow,
+            allowed_columns: Some(vec!["nombre".to_string()]),
+            denied_columns: None,
+            owner_scope: true,
+        })
+        .await
+        .expect("custom rule should be stored");
+
+    harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await
+        .expect("bootstrap should succeed");
+    harness
+        .facade
+        .bootstrap_data_api_access(bootstrap_request())
+        .await
+        .expect("repeated bootstrap should succeed");
+
+    let rules = harness.policy_repository.stored_rules();
+    assert_eq!(rules.len(), 4);
+    let read_rule = rules
+        .iter()
+        .find(|rule| rule.action_name == "read")
+        .expect("read rule should exist");
+    assert_eq!(read_rule.allowed_columns, Some(vec!["nombre".to_string()]));
+    assert!(read_rule.owner_scope);
+}
+
+#[tokio::test]
+async fn principal_roles_are_listed_through_the_facade() {
+    let harness = create_facade_harness();
+    let request = PrincipalRolesRequest {
+        tenant_id: TENANT_A_ID.to_string(),
+        principal_id: fixtures::PRINCIPAL_1_ID.to_string(),
+    };
+
+    let before = harness
+        .facade
+        .find_principal_roles(request.clone())
+        .await
+        .expect("roles should load");
+    harness
+        .role_repository
+        .set_roles(vec!["editor".to_string()]);
+    let after = harness
+        .facade
+        .find_principal_roles(request)
+        .await
+        .expect("roles should load");
+
+    assert!(before.is_empty());
+    assert_eq!(after, vec!["editor".to_string()]);
+}
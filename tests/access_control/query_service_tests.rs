@@ -2,15 +2,24 @@ use std::time::Duration;
 
 use swagger_axum_api::access_control::{
     domain::{
-        model::enums::permission_effect::PermissionEffect,
-        services::access_control_query_service::AccessControlQueryService,
+        model::enums::{
+            access_control_domain_error::AccessControlDomainError,
+            permission_effect::PermissionEffect,
+        },
+        services::{
+            access_control_command_service::AccessControlCommandService,
+            access_control_query_service::{AccessControlQueryService, EffectiveActionRules},
+        },
     },
     infrastructure::persistence::repositories::policy_rule_repository::PolicyRuleRecord,
 };
 
 use crate::support::{
-    TENANT_A_ID, create_query_harness, evaluate_query, evaluate_query_with_columns,
-    evaluate_query_with_request_id,
+    TENANT_A_ID, assign_role_command_expiring_at, create_policy_change_harness,
+    create_query_harness, create_query_harness_with_cache_config, evaluate_query,
+    evaluate_query_bypassing_cache, evaluate_query_with_columns, evaluate_query_with_request_id,
+    evaluate_query_without_audit, list_effective_rules_query, revoke_role_command,
+    upsert_policy_allow_all_command,
 };
 
 #[tokio::test]
@@ -27,6 +36,20 @@ async fn evaluate_permission_default_deny_when_no_roles() {
     assert_eq!(decision.reason, "no roles assigned");
 }
 
+#[tokio::test]
+async fn evaluate_permission_without_audit_does_not_record_decision() {
+    let harness = create_query_harness(Duration::from_secs(30));
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query_without_audit())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert!(harness.audit_repository.events().is_empty());
+}
+
 #[tokio::test]
 async fn evaluate_permission_allow_when_matching_allow_rule() {
     let harness = create_query_harness(Duration::from_secs(30));
@@ -126,6 +149,201 @@ async fn evaluate_permission_specific_allow_beats_wildcard_deny() {
     assert_eq!(decision.reason, "allow rule won by specificity");
 }
 
+fn rule(resource_name: &str, action_name: &str, effect: PermissionEffect) -> PolicyRuleRecord {
+    PolicyRuleRecord {
+        tenant_id: TENANT_A_ID.to_string(),
+        role_name: "admin".to_string(),
+        resource_name: resource_name.to_string(),
+        action_name: action_name.to_string(),
+        effect,
+        allowed_columns: None,
+        denied_columns: None,
+        owner_scope: false,
+    }
+}
+
+#[tokio::test]
+async fn evaluate_permission_reports_winning_rule_with_specificity() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("*", "*", PermissionEffect::Deny),
+        rule("produc*", "read", PermissionEffect::Allow),
+    ]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    let matched = decision.matched_rule.expect("winning rule expected");
+    assert_eq!(matched.role_name, "admin");
+    assert_eq!(matched.resource_name, "produc*");
+    assert_eq!(matched.effect, PermissionEffect::Allow);
+    assert_eq!(matched.resource_specificity, 2);
+    assert_eq!(matched.action_specificity, 3);
+    assert_eq!(matched.column_specificity, 0);
+    assert_eq!(matched.owner_specificity, 0);
+}
+
+#[tokio::test]
+async fn evaluate_permission_reports_no_winning_rule_without_applicable_rules() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness
+        .policy_repository
+        .set_rules(vec![rule("clientes", "read", PermissionEffect::Allow)]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert!(decision.matched_rule.is_none());
+}
+
+#[tokio::test]
+async fn evaluate_permission_prefix_allow_beats_full_wildcard_deny() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("*", "read", PermissionEffect::Deny),
+        rule("produc*", "read", PermissionEffect::Allow),
+    ]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(decision.reason, "allow rule won by specificity");
+}
+
+#[tokio::test]
+async fn evaluate_permission_exact_allow_beats_prefix_deny() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("produc*", "read", PermissionEffect::Deny),
+        rule("productos", "read", PermissionEffect::Allow),
+    ]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(decision.reason, "allow rule won by specificity");
+}
+
+#[tokio::test]
+async fn evaluate_permission_prefix_action_allow_beats_full_wildcard_deny() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("productos", "*", PermissionEffect::Deny),
+        rule("productos", "re*", PermissionEffect::Allow),
+    ]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(decision.reason, "allow rule won by specificity");
+}
+
+#[tokio::test]
+async fn evaluate_permission_ignores_prefix_rules_for_other_resources() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness
+        .policy_repository
+        .set_rules(vec![rule("clientes_*", "read", PermissionEffect::Allow)]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.matched_rules, 0);
+}
+
+#[tokio::test]
+async fn evaluate_permission_expands_three_level_role_chain() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness
+        .role_repository
+        .set_roles(vec!["editor".to_string()]);
+    harness
+        .role_repository
+        .set_parent_roles("editor", &["viewer"]);
+    harness
+        .role_repository
+        .set_parent_roles("viewer", &["guest"]);
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        role_name: "guest".to_string(),
+        ..rule("productos", "read", PermissionEffect::Allow)
+    }]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(
+        harness.policy_repository.last_role_names(),
+        vec![
+            "editor".to_string(),
+            "viewer".to_string(),
+            "guest".to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn evaluate_permission_stops_expanding_cyclic_role_parents() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness
+        .role_repository
+        .set_roles(vec!["editor".to_string()]);
+    harness
+        .role_repository
+        .set_parent_roles("editor", &["viewer"]);
+    harness
+        .role_repository
+        .set_parent_roles("viewer", &["editor"]);
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        role_name: "viewer".to_string(),
+        ..rule("productos", "read", PermissionEffect::Allow)
+    }]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(
+        harness.policy_repository.last_role_names(),
+        vec!["editor".to_string(), "viewer".to_string()]
+    );
+}
+
 #[tokio::test]
 async fn evaluate_permission_denies_when_requested_column_is_denied() {
     let harness = create_query_harness(Duration::from_secs(30));
@@ -184,6 +402,47 @@ async fn evaluate_permission_caches_decision_within_ttl() {
     assert_eq!(harness.policy_repository.find_calls(), 1);
 }
 
+#[tokio::test]
+async fn evaluate_permission_bypasses_cache_when_requested_and_refreshes_it() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        tenant_id: TENANT_A_ID.to_string(),
+        role_name: "admin".to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        effect: PermissionEffect::Allow,
+        allowed_columns: None,
+        denied_columns: None,
+        owner_scope: false,
+    }]);
+
+    let _ = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("first decision expected");
+    let bypassed = harness
+        .service
+        .handle_evaluate_permission(evaluate_query_bypassing_cache())
+        .await
+        .expect("bypassed decision expected");
+
+    assert!(bypassed.allowed);
+    assert_eq!(harness.role_repository.find_calls(), 2);
+    assert_eq!(harness.policy_repository.find_calls(), 2);
+
+    harness.policy_repository.set_rules(vec![]);
+    let cached = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("cached decision expected");
+
+    assert!(cached.allowed);
+    assert_eq!(harness.policy_repository.find_calls(), 2);
+}
+
 #[tokio::test]
 async fn evaluate_permission_cache_expires_after_ttl() {
     let harness = create_query_harness(Duration::from_millis(20));
@@ -217,6 +476,296 @@ async fn evaluate_permission_cache_expires_after_ttl() {
     assert_eq!(harness.policy_repository.find_calls(), 2);
 }
 
+#[tokio::test]
+async fn evaluate_permission_cache_evicts_least_recently_used_entry_at_capacity() {
+    let harness = create_query_harness_with_cache_config(Duration::from_secs(30), 2);
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+
+    for columns in [vec!["id"], vec!["nombre"], vec!["precio"]] {
+        harness
+            .service
+            .handle_evaluate_permission(evaluate_query_with_columns(columns))
+            .await
+            .expect("decision expected");
+    }
+    assert_eq!(harness.policy_repository.find_calls(), 3);
+
+    harness
+        .service
+        .handle_evaluate_permission(evaluate_query_with_columns(vec!["precio"]))
+        .await
+        .expect("decision expected");
+    assert_eq!(harness.policy_repository.find_calls(), 3);
+
+    harness
+        .service
+        .handle_evaluate_permission(evaluate_query_with_columns(vec!["id"]))
+        .await
+        .expect("decision expected");
+    assert_eq!(harness.policy_repository.find_calls(), 4);
+}
+
+#[tokio::test]
+async fn policy_upsert_invalidates_cached_tenant_decisions() {
+    let harness = create_policy_change_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Deny)]);
+
+    let before = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+    harness
+        .command_service
+        .handle_upsert_policy(upsert_policy_allow_all_command())
+        .await
+        .expect("policy upsert should succeed");
+
+    let after = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(!before.allowed);
+    assert!(after.allowed);
+    assert_eq!(harness.policy_repository.find_calls(), 2);
+}
+
+#[tokio::test]
+async fn evaluate_permission_ignores_expired_role_assignment() {
+    let harness = create_policy_change_harness(Duration::from_secs(30));
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+    harness
+        .command_service
+        .handle_assign_role(assign_role_command_expiring_at("2020-01-01T00:00:00Z"))
+        .await
+        .expect("assignment should succeed");
+
+    let decision = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.reason, "no roles assigned");
+}
+
+#[tokio::test]
+async fn evaluate_permission_honors_unexpired_role_assignment() {
+    let harness = create_policy_change_harness(Duration::from_secs(30));
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+    harness
+        .command_service
+        .handle_assign_role(assign_role_command_expiring_at("2999-01-01T00:00:00Z"))
+        .await
+        .expect("assignment should succeed");
+
+    let decision = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+}
+
+#[tokio::test]
+async fn role_revocation_invalidates_cached_tenant_decisions() {
+    let harness = create_policy_change_harness(Duration::from_secs(30));
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+    harness
+        .command_service
+        .handle_assign_role(assign_role_command_expiring_at("2999-01-01T00:00:00Z"))
+        .await
+        .expect("assignment should succeed");
+
+    let before = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+    harness
+        .command_service
+        .handle_revoke_role(revoke_role_command())
+        .await
+        .expect("revocation should succeed");
+    let after = harness
+        .query_service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(before.allowed);
+    assert!(!after.allowed);
+    assert_eq!(after.reason, "no roles assigned");
+}
+
+#[tokio::test]
+async fn list_effective_rules_returns_empty_list_without_roles() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+
+    let effective = harness
+        .service
+        .handle_list_effective_rules(list_effective_rules_query())
+        .await
+        .expect("effective rules expected");
+
+    assert!(effective.is_empty());
+    assert_eq!(harness.policy_repository.find_calls(), 0);
+}
+
+#[tokio::test]
+async fn list_effective_rules_orders_by_specificity_and_reports_winning_effect() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("*", "*", PermissionEffect::Deny),
+        rule("productos", "read", PermissionEffect::Allow),
+    ]);
+
+    let effective = harness
+        .service
+        .handle_list_effective_rules(list_effective_rules_query())
+        .await
+        .expect("effective rules expected");
+
+    let actions = effective
+        .iter()
+        .map(|entry| entry.action_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(actions, vec!["read", "create", "update", "delete"]);
+    assert_eq!(effective[0].winning_effect, PermissionEffect::Allow);
+    assert_eq!(effective[0].rules.len(), 2);
+    assert_eq!(effective[0].rules[0].resource_name, "productos");
+    assert_eq!(effective[0].rules[0].resource_specificity, 3);
+    assert_eq!(effective[0].rules[1].resource_specificity, 1);
+    assert_eq!(effective[1].winning_effect, PermissionEffect::Deny);
+    assert_eq!(effective[1].rules.len(), 1);
+}
+
+#[tokio::test]
+async fn list_effective_rules_winning_effect_honors_column_and_owner_applicability() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        PolicyRuleRecord {
+            allowed_columns: Some(vec!["nombre".to_string()]),
+            ..rule("productos", "read", PermissionEffect::Allow)
+        },
+        PolicyRuleRecord {
+            owner_scope: true,
+            ..rule("productos", "update", PermissionEffect::Allow)
+        },
+    ]);
+
+    let without_context = harness
+        .service
+        .handle_list_effective_rules(
+            list_effective_rules_query().with_requested_columns(vec!["precio".to_string()]),
+        )
+        .await
+        .expect("effective rules expected");
+    let with_context = harness
+        .service
+        .handle_list_effective_rules(
+            list_effective_rules_query()
+                .with_requested_columns(vec!["nombre".to_string()])
+                .with_owner_ids(Some("owner-1".to_string()), Some("owner-1".to_string())),
+        )
+        .await
+        .expect("effective rules expected");
+
+    let effects = |effective: &[EffectiveActionRules]| {
+        effective
+            .iter()
+            .map(|entry| (entry.action_name.clone(), entry.winning_effect))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        effects(&without_context),
+        vec![
+            ("read".to_string(), PermissionEffect::Deny),
+            ("update".to_string(), PermissionEffect::Deny),
+        ]
+    );
+    assert_eq!(
+        effects(&with_context),
+        vec![
+            ("read".to_string(), PermissionEffect::Allow),
+            ("update".to_string(), PermissionEffect::Allow),
+        ]
+    );
+    assert_eq!(harness.policy_repository.find_calls(), 2);
+}
+
+#[tokio::test]
+async fn list_effective_rules_reports_permitted_columns_per_action_from_one_rule_lookup() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness
+        .role_repository
+        .set_roles(vec!["editor".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        PolicyRuleRecord {
+            allowed_columns: Some(vec!["nombre".to_string(), "precio".to_string()]),
+            ..rule("productos", "create", PermissionEffect::Allow)
+        },
+        PolicyRuleRecord {
+            denied_columns: Some(vec!["precio".to_string()]),
+            ..rule("productos", "update", PermissionEffect::Allow)
+        },
+    ]);
+
+    let effective = harness
+        .service
+        .handle_list_effective_rules(list_effective_rules_query().with_requested_columns(vec![
+            "codigo".to_string(),
+            "nombre".to_string(),
+            "precio".to_string(),
+        ]))
+        .await
+        .expect("effective rules expected");
+
+    let permitted = effective
+        .into_iter()
+        .map(|entry| (entry.action_name, entry.permitted_columns))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        permitted,
+        vec![
+            (
+                "create".to_string(),
+                vec!["nombre".to_string(), "precio".to_string()]
+            ),
+            (
+                "update".to_string(),
+                vec!["codigo".to_string(), "nombre".to_string()]
+            ),
+        ]
+    );
+    assert_eq!(harness.policy_repository.find_calls(), 1);
+}
+
 #[tokio::test]
 async fn evaluate_permission_audits_request_id() {
     let harness = create_query_harness(Duration::from_secs(30));
@@ -242,3 +791,164 @@ async fn evaluate_permission_audits_request_id() {
     assert_eq!(events.len(), 1);
     assert_eq!(events[0].request_id.as_deref(), Some("req-123"));
 }
+
+#[tokio::test]
+async fn evaluate_permission_reports_matched_and_applicable_rule_counts() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        PolicyRuleRecord {
+            tenant_id: TENANT_A_ID.to_string(),
+            role_name: "admin".to_string(),
+            resource_name: "productos".to_string(),
+            action_name: "read".to_string(),
+            effect: PermissionEffect::Allow,
+            allowed_columns: None,
+            denied_columns: None,
+            owner_scope: false,
+        },
+        PolicyRuleRecord {
+            tenant_id: TENANT_A_ID.to_string(),
+            role_name: "admin".to_string(),
+            resource_name: "productos".to_string(),
+            action_name: "read".to_string(),
+            effect: PermissionEffect::Deny,
+            allowed_columns: None,
+            denied_columns: Some(vec!["precio".to_string()]),
+            owner_scope: false,
+        },
+        PolicyRuleRecord {
+            tenant_id: TENANT_A_ID.to_string(),
+            role_name: "admin".to_string(),
+            resource_name: "productos".to_string(),
+            action_name: "read".to_string(),
+            effect: PermissionEffect::Allow,
+            allowed_columns: None,
+            denied_columns: None,
+            owner_scope: true,
+        },
+    ]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query_with_columns(vec!["precio"]))
+        .await
+        .expect("decision expected");
+
+    assert!(decision.allowed);
+    assert_eq!(decision.matched_rules, 3);
+    assert_eq!(decision.applicable_rules, 1);
+}
+
+#[tokio::test]
+async fn evaluate_permission_exposes_client_reason_while_auditing_internal_reason() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![PolicyRuleRecord {
+        tenant_id: TENANT_A_ID.to_string(),
+        role_name: "admin".to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        effect: PermissionEffect::Deny,
+        allowed_columns: None,
+        denied_columns: None,
+        owner_scope: false,
+    }]);
+
+    let decision = harness
+        .service
+        .handle_evaluate_permission(evaluate_query())
+        .await
+        .expect("decision expected");
+
+    assert!(!decision.allowed);
+    assert_eq!(decision.reason, "explicit deny rule");
+    assert_eq!(decision.client_reason, "access denied by policy");
+    assert_ne!(decision.client_reason, decision.reason);
+
+    let events = harness.audit_repository.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].reason, "explicit deny rule");
+}
+
+#[tokio::test]
+async fn evaluate_permission_batch_rejects_batches_above_the_limit() {
+    let mut harness = create_query_harness(Duration::ZERO);
+    harness.service = harness.service.with_max_batch_size(2);
+
+    let result = harness
+        .service
+        .handle_evaluate_permission_batch(vec![
+            evaluate_query(),
+            evaluate_query(),
+            evaluate_query(),
+        ])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AccessControlDomainError::BatchTooLarge(2))
+    ));
+    assert_eq!(harness.role_repository.find_calls(), 0);
+    assert!(harness.audit_repository.events().is_empty());
+}
+
+#[tokio::test]
+async fn evaluate_permission_batch_deduplicates_identical_queries_and_keeps_request_ids() {
+    let harness = create_query_harness(Duration::ZERO);
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness
+        .policy_repository
+        .set_rules(vec![rule("productos", "read", PermissionEffect::Allow)]);
+
+    let decisions = harness
+        .service
+        .handle_evaluate_permission_batch(vec![
+            evaluate_query_with_request_id("item-1"),
+            evaluate_query_with_request_id("item-2"),
+            evaluate_query_with_columns(vec!["nombre"]),
+        ])
+        .await
+        .expect("decisions expected");
+
+    assert_eq!(decisions.len(), 3);
+    assert!(decisions.iter().all(|decision| decision.allowed));
+    assert_eq!(harness.role_repository.find_calls(), 2);
+    assert_eq!(harness.policy_repository.find_calls(), 2);
+    let request_ids = harness
+        .audit_repository
+        .events()
+        .into_iter()
+        .map(|event| event.request_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        request_ids,
+        vec![Some("item-1".to_string()), Some("item-2".to_string()), None]
+    );
+}
+
+#[tokio::test]
+async fn list_permitted_columns_evaluates_every_column_against_one_rule_lookup() {
+    let harness = create_query_harness(Duration::from_secs(30));
+    harness.role_repository.set_roles(vec!["admin".to_string()]);
+    harness.policy_repository.set_rules(vec![
+        rule("productos", "read", PermissionEffect::Allow),
+        PolicyRuleRecord {
+            allowed_columns: Some(vec!["precio".to_string()]),
+            ..rule("productos", "read", PermissionEffect::Deny)
+        },
+    ]);
+
+    let permitted = harness
+        .service
+        .handle_list_permitted_columns(evaluate_query_with_columns(vec![
+            "nombre",
+            "precio",
+            "image_url",
+        ]))
+        .await
+        .expect("permitted columns expected");
+
+    assert_eq!(permitted, vec!["nombre", "image_url"]);
+    assert_eq!(harness.policy_repository.find_calls(), 1);
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use swagger_axum_api::access_control::{
+    domain::model::value_objects::{
+        principal_id::PrincipalId, role_name::RoleName, tenant_id::TenantId,
+    },
+    infrastructure::persistence::repositories::{
+        postgres::sqlx_role_assignment_repository_impl::SqlxRoleAssignmentRepositoryImpl,
+        role_assignment_repository::RoleAssignmentRepository,
+    },
+};
+use uuid::Uuid;
+
+use crate::support::migrated_test_pool;
+
+#[tokio::test]
+async fn repeated_assignment_upserts_a_single_row() {
+    let Some(pool) = migrated_test_pool().await else {
+        return;
+    };
+    let repository = SqlxRoleAssignmentRepositoryImpl::new(pool.clone());
+    let tenant_id = TenantId::from_uuid(Uuid::now_v7());
+    let principal_id = PrincipalId::new(Uuid::now_v7().to_string()).expect("valid principal");
+    let role_name = RoleName::new("admin".to_string()).expect("valid role");
+
+    let mut changes = Vec::new();
+    for _ in 0..2 {
+        changes.push(
+            repository
+                .assign_role(&tenant_id, &principal_id, &role_name, None)
+                .await
+                .expect("repeated assignment should not error"),
+        );
+    }
+
+    let rows: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM access_role_assignments WHERE tenant_id = $1 AND principal_id = $2",
+    )
+    .bind(tenant_id.value())
+    .bind(principal_id.value())
+    .fetch_one(&pool)
+    .await
+    .expect("count should succeed");
+    assert_eq!(rows, 1);
+    assert_eq!(changes, vec![true, false]);
+}
+
+#[tokio::test]
+async fn assignment_without_expiry_keeps_an_existing_expiry() {
+    let Some(pool) = migrated_test_pool().await else {
+        return;
+    };
+    let repository = SqlxRoleAssignmentRepositoryImpl::new(pool.clone());
+    let tenant_id = TenantId::from_uuid(Uuid::now_v7());
+    let principal_id = PrincipalId::new(Uuid::now_v7().to_string()).expect("valid principal");
+    let role_name = RoleName::new("data_api_authenticated".to_string()).expect("valid role");
+    let expires_at = DateTime::parse_from_rfc3339("2999-01-01T00:00:00Z")
+        .expect("valid expiry")
+        .with_timezone(&Utc);
+
+    repository
+        .assign_role(&tenant_id, &principal_id, &role_name, Some(expires_at))
+        .await
+        .expect("expiring assignment should succeed");
+    let changed = repository
+        .assign_role(&tenant_id, &principal_id, &role_name, None)
+        .await
+        .expect("bootstrap assignment should succeed");
+
+    let stored: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT expires_at FROM access_role_assignments WHERE tenant_id = $1 AND principal_id = $2",
+    )
+    .bind(tenant_id.value())
+    .bind(principal_id.value())
+    .fetch_one(&pool)
+    .await
+    .expect("assignment should exist");
+    assert!(!changed);
+    assert_eq!(stored, Some(expires_at));
+}
@@ -1,3 +1,5 @@
+#[path = "support/database.rs"]
+mod database;
 #[path = "support/fakes.rs"]
 mod fakes;
 #[path = "support/fixtures.rs"]
@@ -5,9 +7,14 @@ pub mod fixtures;
 #[path = "support/harness.rs"]
 mod harness;
 
+pub use database::migrated_test_pool;
 pub use fixtures::{
-    TENANT_A_ID, assign_role_command, evaluate_query, evaluate_query_with_columns,
-    evaluate_query_with_request_id, upsert_policy_allow_all_command,
-    upsert_policy_deny_all_command,
+    TENANT_A_ID, assign_role_command, assign_role_command_expiring_at, evaluate_query,
+    evaluate_query_bypassing_cache, evaluate_query_with_columns, evaluate_query_with_request_id,
+    evaluate_query_without_audit, list_effective_rules_query, purge_tenant_command,
+    revoke_role_command, upsert_policy_allow_all_command, upsert_policy_deny_all_command,
+};
+pub use harness::{
+    create_command_harness, create_facade_harness, create_policy_change_harness,
+    create_query_harness, create_query_harness_with_cache_config,
 };
-pub use harness::{create_command_harness, create_query_harness};
@@ -0,0 +1,14 @@
+use sqlx::PgPool;
+
+pub async fn migrated_test_pool() -> Option<PgPool> {
+    let database_url = std::env::var("TEST_DATABASE_URL").ok()?;
+    let pool = PgPool::connect(&database_url)
+        .await
+        .expect("test database should accept connections");
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("migrations should apply to the test database");
+
+    Some(pool)
+}
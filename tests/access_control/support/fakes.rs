@@ -1,6 +1,7 @@
-use std::sync::Mutex;
+use std::{collections::HashMap, sync::Mutex};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use swagger_axum_api::access_control::{
     domain::model::{
         enums::access_control_domain_error::AccessControlDomainError,
@@ -20,8 +21,12 @@ use swagger_axum_api::access_control::{
 #[derive(Default)]
 struct FakeRoleAssignmentState {
     assign_calls: usize,
+    revoke_calls: usize,
     find_calls: usize,
     roles_by_principal: Vec<String>,
+    parent_roles: HashMap<String, Vec<String>>,
+    assignments: Vec<(String, String, String)>,
+    assignment_expiries: HashMap<(String, String, String), DateTime<Utc>>,
 }
 
 pub struct FakeRoleAssignmentRepository {
@@ -42,6 +47,17 @@ impl FakeRoleAssignmentRepository {
             .roles_by_principal = roles;
     }
 
+    pub fn set_parent_roles(&self, role_name: &str, parents: &[&str]) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .parent_roles
+            .insert(
+                role_name.to_string(),
+                parents.iter().map(|parent| parent.to_string()).collect(),
+            );
+    }
+
     pub fn assign_calls(&self) -> usize {
         self.state.lock().expect("mutex poisoned").assign_calls
     }
@@ -49,28 +65,121 @@ impl FakeRoleAssignmentRepository {
     pub fn find_calls(&self) -> usize {
         self.state.lock().expect("mutex poisoned").find_calls
     }
+
+    pub fn revoke_calls(&self) -> usize {
+        self.state.lock().expect("mutex poisoned").revoke_calls
+    }
+
+    pub fn assignments(&self) -> Vec<(String, String, String)> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .assignments
+            .clone()
+    }
 }
 
 #[async_trait]
 impl RoleAssignmentRepository for FakeRoleAssignmentRepository {
     async fn assign_role(
         &self,
-        _tenant_id: &TenantId,
-        _principal_id: &PrincipalId,
-        _role_name: &RoleName,
+        tenant_id: &TenantId,
+        principal_id: &PrincipalId,
+        role_name: &RoleName,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, AccessControlDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.assign_calls += 1;
+
+        let assignment = (
+            tenant_id.value().to_string(),
+            principal_id.value().to_string(),
+            role_name.value().to_string(),
+        );
+        let expiry_changed = expires_at.is_some_and(|expires_at| {
+            state
+                .assignment_expiries
+                .insert(assignment.clone(), expires_at)
+                != Some(expires_at)
+        });
+        let inserted = !state.assignments.contains(&assignment);
+        if inserted {
+            state.assignments.push(assignment);
+        }
+        Ok(inserted || expiry_changed)
+    }
+
+    async fn revoke_role(
+        &self,
+        tenant_id: &TenantId,
+        principal_id: &PrincipalId,
+        role_name: &RoleName,
     ) -> Result<(), AccessControlDomainError> {
-        self.state.lock().expect("mutex poisoned").assign_calls += 1;
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.revoke_calls += 1;
+
+        let assignment = (
+            tenant_id.value().to_string(),
+            principal_id.value().to_string(),
+            role_name.value().to_string(),
+        );
+        state.assignments.retain(|existing| *existing != assignment);
+        state.assignment_expiries.remove(&assignment);
         Ok(())
     }
 
     async fn find_roles_by_principal(
         &self,
-        _tenant_id: &TenantId,
-        _principal_id: &PrincipalId,
+        tenant_id: &TenantId,
+        principal_id: &PrincipalId,
     ) -> Result<Vec<String>, AccessControlDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.find_calls += 1;
-        Ok(state.roles_by_principal.clone())
+
+        let now = Utc::now();
+        let mut roles = state.roles_by_principal.clone();
+        for assignment in &state.assignments {
+            let (assigned_tenant, assigned_principal, role_name) = assignment;
+            let active = state
+                .assignment_expiries
+                .get(assignment)
+                .is_none_or(|expires_at| *expires_at > now);
+            if *assigned_tenant == tenant_id.value().to_string()
+                && *assigned_principal == principal_id.value().to_string()
+                && active
+                && !roles.contains(role_name)
+            {
+                roles.push(role_name.clone());
+            }
+        }
+        Ok(roles)
+    }
+
+    async fn find_parent_roles(
+        &self,
+        _tenant_id: &TenantId,
+        role_names: &[String],
+    ) -> Result<Vec<String>, AccessControlDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+        Ok(role_names
+            .iter()
+            .filter_map(|role_name| state.parent_roles.get(role_name))
+            .flatten()
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_tenant_assignments(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        let tenant_id = tenant_id.value().to_string();
+        let before = state.assignments.len();
+        state
+            .assignments
+            .retain(|(assignment_tenant, _, _)| assignment_tenant != &tenant_id);
+        Ok((before - state.assignments.len()) as u64)
     }
 }
 
@@ -79,7 +188,16 @@ struct FakePolicyRuleState {
     upsert_calls: usize,
     find_calls: usize,
     last_upsert: Option<PolicyRuleRecord>,
+    last_role_names: Vec<String>,
     rules_to_return: Vec<PolicyRuleRecord>,
+    stored_rules: Vec<PolicyRuleRecord>,
+}
+
+fn same_rule_key(a: &PolicyRuleRecord, b: &PolicyRuleRecord) -> bool {
+    a.tenant_id == b.tenant_id
+        && a.role_name == b.role_name
+        && a.resource_name == b.resource_name
+        && a.action_name == b.action_name
 }
 
 pub struct FakePolicyRuleRepository {
@@ -105,6 +223,22 @@ impl FakePolicyRuleRepository {
         self.state.lock().expect("mutex poisoned").find_calls
     }
 
+    pub fn stored_rules(&self) -> Vec<PolicyRuleRecord> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .stored_rules
+            .clone()
+    }
+
+    pub fn last_role_names(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .last_role_names
+            .clone()
+    }
+
     pub fn last_upsert(&self) -> Option<PolicyRuleRecord> {
         self.state
             .lock()
@@ -119,37 +253,90 @@ impl PolicyRuleRepository for FakePolicyRuleRepository {
     async fn upsert_rule(&self, rule: PolicyRuleRecord) -> Result<(), AccessControlDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.upsert_calls += 1;
+        state
+            .stored_rules
+            .retain(|existing| !same_rule_key(existing, &rule));
+        state.stored_rules.push(rule.clone());
         state.last_upsert = Some(rule);
         Ok(())
     }
 
+    async fn insert_rule_if_absent(
+        &self,
+        rule: PolicyRuleRecord,
+    ) -> Result<bool, AccessControlDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if state
+            .stored_rules
+            .iter()
+            .any(|existing| same_rule_key(existing, &rule))
+        {
+            return Ok(false);
+        }
+
+        state.stored_rules.push(rule);
+        Ok(true)
+    }
+
     async fn find_rules_for_roles(
         &self,
         _tenant_id: &TenantId,
         _resource_name: &ResourceName,
         _action_name: &ActionName,
-        _role_names: &[String],
+        role_names: &[String],
     ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.find_calls += 1;
+        state.last_role_names = role_names.to_vec();
         Ok(state.rules_to_return.clone())
     }
+
+    async fn find_resource_rules_for_roles(
+        &self,
+        _tenant_id: &TenantId,
+        _resource_name: &ResourceName,
+        role_names: &[String],
+    ) -> Result<Vec<PolicyRuleRecord>, AccessControlDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.find_calls += 1;
+        state.last_role_names = role_names.to_vec();
+        Ok(state.rules_to_return.clone())
+    }
+
+    async fn delete_tenant_rules(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        let tenant_id = tenant_id.value().to_string();
+        let before = state.stored_rules.len();
+        state
+            .stored_rules
+            .retain(|rule| rule.tenant_id != tenant_id);
+        Ok((before - state.stored_rules.len()) as u64)
+    }
 }
 
 pub struct FakeAuthorizationDecisionAuditRepository {
     events: Mutex<Vec<AuthorizationDecisionAuditedEvent>>,
+    archived_events: Mutex<Vec<AuthorizationDecisionAuditedEvent>>,
 }
 
 impl FakeAuthorizationDecisionAuditRepository {
     pub fn new() -> Self {
         Self {
             events: Mutex::new(Vec::new()),
+            archived_events: Mutex::new(Vec::new()),
         }
     }
 
     pub fn events(&self) -> Vec<AuthorizationDecisionAuditedEvent> {
         self.events.lock().expect("mutex poisoned").clone()
     }
+
+    pub fn archived_events(&self) -> Vec<AuthorizationDecisionAuditedEvent> {
+        self.archived_events.lock().expect("mutex poisoned").clone()
+    }
 }
 
 #[async_trait]
@@ -164,4 +351,22 @@ impl AuthorizationDecisionAuditRepository for FakeAuthorizationDecisionAuditRepo
             .push(event.clone());
         Ok(())
     }
+
+    async fn archive_tenant_decisions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<u64, AccessControlDomainError> {
+        let mut events = self.events.lock().expect("mutex poisoned");
+        let (archived, kept): (Vec<_>, Vec<_>) = events
+            .drain(..)
+            .partition(|event| event.tenant_id == tenant_id.value());
+        *events = kept;
+
+        let count = archived.len() as u64;
+        self.archived_events
+            .lock()
+            .expect("mutex poisoned")
+            .extend(archived);
+        Ok(count)
+    }
 }
@@ -1,10 +1,15 @@
 use swagger_axum_api::access_control::domain::model::{
     commands::{
         assign_role_to_principal_command::AssignRoleToPrincipalCommand,
+        purge_tenant_access_control_command::PurgeTenantAccessControlCommand,
+        revoke_role_from_principal_command::RevokeRoleFromPrincipalCommand,
         upsert_policy_rule_command::{UpsertPolicyRuleCommand, UpsertPolicyRuleCommandParts},
     },
     enums::permission_effect::PermissionEffect,
-    queries::evaluate_permission_query::{EvaluatePermissionQuery, EvaluatePermissionQueryParts},
+    queries::{
+        evaluate_permission_query::{EvaluatePermissionQuery, EvaluatePermissionQueryParts},
+        list_effective_rules_query::ListEffectiveRulesQuery,
+    },
 };
 
 // UUIDs de prueba consistentes
@@ -16,10 +21,35 @@ pub fn assign_role_command() -> AssignRoleToPrincipalCommand {
         TENANT_A_ID.to_string(),
         PRINCIPAL_1_ID.to_string(),
         "admin".to_string(),
+        None,
     )
     .expect("valid assign role command")
 }
 
+pub fn assign_role_command_expiring_at(expires_at: &str) -> AssignRoleToPrincipalCommand {
+    AssignRoleToPrincipalCommand::new(
+        TENANT_A_ID.to_string(),
+        PRINCIPAL_1_ID.to_string(),
+        "admin".to_string(),
+        Some(expires_at.to_string()),
+    )
+    .expect("valid expiring assign role command")
+}
+
+pub fn revoke_role_command() -> RevokeRoleFromPrincipalCommand {
+    RevokeRoleFromPrincipalCommand::new(
+        TENANT_A_ID.to_string(),
+        PRINCIPAL_1_ID.to_string(),
+        "admin".to_string(),
+    )
+    .expect("valid revoke role command")
+}
+
+pub fn purge_tenant_command(archive_audit_trail: bool) -> PurgeTenantAccessControlCommand {
+    PurgeTenantAccessControlCommand::new(TENANT_A_ID.to_string(), archive_audit_trail)
+        .expect("valid purge tenant command")
+}
+
 pub fn upsert_policy_allow_all_command() -> UpsertPolicyRuleCommand {
     UpsertPolicyRuleCommand::new(UpsertPolicyRuleCommandParts {
         tenant_id: TENANT_A_ID.to_string(),
@@ -58,10 +88,21 @@ pub fn evaluate_query() -> EvaluatePermissionQuery {
         subject_owner_id: None,
         row_owner_id: None,
         request_id: None,
+        bypass_cache: false,
+        audit_decision: true,
     })
     .expect("valid evaluate query")
 }
 
+pub fn list_effective_rules_query() -> ListEffectiveRulesQuery {
+    ListEffectiveRulesQuery::new(
+        TENANT_A_ID.to_string(),
+        PRINCIPAL_1_ID.to_string(),
+        "productos".to_string(),
+    )
+    .expect("valid list effective rules query")
+}
+
 pub fn evaluate_query_with_columns(columns: Vec<&str>) -> EvaluatePermissionQuery {
     EvaluatePermissionQuery::new(EvaluatePermissionQueryParts {
         tenant_id: TENANT_A_ID.to_string(),
@@ -72,6 +113,8 @@ pub fn evaluate_query_with_columns(columns: Vec<&str>) -> EvaluatePermissionQuer
         subject_owner_id: None,
         row_owner_id: None,
         request_id: None,
+        bypass_cache: false,
+        audit_decision: true,
     })
     .expect("valid evaluate query with columns")
 }
@@ -86,6 +129,40 @@ pub fn evaluate_query_with_request_id(request_id: &str) -> EvaluatePermissionQue
         subject_owner_id: None,
         row_owner_id: None,
         request_id: Some(request_id.to_string()),
+        bypass_cache: false,
+        audit_decision: true,
     })
     .expect("valid evaluate query with request_id")
 }
+
+pub fn evaluate_query_without_audit() -> EvaluatePermissionQuery {
+    EvaluatePermissionQuery::new(EvaluatePermissionQueryParts {
+        tenant_id: TENANT_A_ID.to_string(),
+        principal_id: PRINCIPAL_1_ID.to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        requested_columns: vec![],
+        subject_owner_id: None,
+        row_owner_id: None,
+        request_id: None,
+        bypass_cache: false,
+        audit_decision: false,
+    })
+    .expect("valid unaudited evaluate query")
+}
+
+pub fn evaluate_query_bypassing_cache() -> EvaluatePermissionQuery {
+    EvaluatePermissionQuery::new(EvaluatePermissionQueryParts {
+        tenant_id: TENANT_A_ID.to_string(),
+        principal_id: PRINCIPAL_1_ID.to_string(),
+        resource_name: "productos".to_string(),
+        action_name: "read".to_string(),
+        requested_columns: vec![],
+        subject_owner_id: None,
+        row_owner_id: None,
+        request_id: None,
+        bypass_cache: true,
+        audit_decision: true,
+    })
+    .expect("valid evaluate query bypassing cache")
+}
@@ -1,6 +1,8 @@
 use std::{sync::Arc, time::Duration};
 
 use swagger_axum_api::access_control::application::{
+    acl::access_control_facade_impl::AccessControlFacadeImpl,
+    cache::decision_cache_generations::DecisionCacheGenerations,
     command_services::access_control_command_service_impl::AccessControlCommandServiceImpl,
     query_services::access_control_query_service_impl::AccessControlQueryServiceImpl,
 };
@@ -13,6 +15,7 @@ use super::fakes::{
 pub struct AccessControlCommandHarness {
     pub role_repository: Arc<FakeRoleAssignmentRepository>,
     pub policy_repository: Arc<FakePolicyRuleRepository>,
+    pub audit_repository: Arc<FakeAuthorizationDecisionAuditRepository>,
     pub service: AccessControlCommandServiceImpl,
 }
 
@@ -23,30 +26,56 @@ pub struct AccessControlQueryHarness {
     pub service: AccessControlQueryServiceImpl,
 }
 
+pub struct AccessControlPolicyChangeHarness {
+    pub role_repository: Arc<FakeRoleAssignmentRepository>,
+    pub policy_repository: Arc<FakePolicyRuleRepository>,
+    pub command_service: AccessControlCommandServiceImpl,
+    pub query_service: AccessControlQueryServiceImpl,
+}
+
+pub struct AccessControlFacadeHarness {
+    pub role_repository: Arc<FakeRoleAssignmentRepository>,
+    pub policy_repository: Arc<FakePolicyRuleRepository>,
+    pub facade: AccessControlFacadeImpl,
+}
+
 pub fn create_command_harness() -> AccessControlCommandHarness {
     let role_repository = Arc::new(FakeRoleAssignmentRepository::new());
     let policy_repository = Arc::new(FakePolicyRuleRepository::new());
+    let audit_repository = Arc::new(FakeAuthorizationDecisionAuditRepository::new());
 
-    let service =
-        AccessControlCommandServiceImpl::new(role_repository.clone(), policy_repository.clone());
+    let service = AccessControlCommandServiceImpl::new(
+        role_repository.clone(),
+        policy_repository.clone(),
+        audit_repository.clone(),
+    );
 
     AccessControlCommandHarness {
         role_repository,
         policy_repository,
+        audit_repository,
         service,
     }
 }
 
 pub fn create_query_harness(cache_ttl: Duration) -> AccessControlQueryHarness {
+    create_query_harness_with_cache_config(cache_ttl, 10_000)
+}
+
+pub fn create_query_harness_with_cache_config(
+    cache_ttl: Duration,
+    max_entries: usize,
+) -> AccessControlQueryHarness {
     let role_repository = Arc::new(FakeRoleAssignmentRepository::new());
     let policy_repository = Arc::new(FakePolicyRuleRepository::new());
     let audit_repository = Arc::new(FakeAuthorizationDecisionAuditRepository::new());
 
-    let service = AccessControlQueryServiceImpl::new_with_cache_ttl(
+    let service = AccessControlQueryServiceImpl::new_with_cache_config(
         policy_repository.clone(),
         role_repository.clone(),
         audit_repository.clone(),
         cache_ttl,
+        max_entries,
     );
 
     AccessControlQueryHarness {
@@ -56,3 +85,61 @@ pub fn create_query_harness(cache_ttl: Duration) -> AccessControlQueryHarness {
         service,
     }
 }
+
+pub fn create_policy_change_harness(cache_ttl: Duration) -> AccessControlPolicyChangeHarness {
+    let role_repository = Arc::new(FakeRoleAssignmentRepository::new());
+    let policy_repository = Arc::new(FakePolicyRuleRepository::new());
+    let audit_repository = Arc::new(FakeAuthorizationDecisionAuditRepository::new());
+    let cache_generations = Arc::new(DecisionCacheGenerations::new());
+
+    let command_service = AccessControlCommandServiceImpl::new(
+        role_repository.clone(),
+        policy_repository.clone(),
+        audit_repository.clone(),
+    )
+    .with_cache_generations(cache_generations.clone());
+    let query_service = AccessControlQueryServiceImpl::new_with_cache_ttl(
+        policy_repository.clone(),
+        role_repository.clone(),
+        audit_repository,
+        cache_ttl,
+    )
+    .with_cache_generations(cache_generations);
+
+    AccessControlPolicyChangeHarness {
+        role_repository,
+        policy_repository,
+        command_service,
+        query_service,
+    }
+}
+
+pub fn create_facade_harness() -> AccessControlFacadeHarness {
+    let role_repository = Arc::new(FakeRoleAssignmentRepository::new());
+    let policy_repository = Arc::new(FakePolicyRuleRepository::new());
+    let audit_repository = Arc::new(FakeAuthorizationDecisionAuditRepository::new());
+    let cache_generations = Arc::new(DecisionCacheGenerations::new());
+
+    let command_service = Arc::new(
+        AccessControlCommandServiceImpl::new(
+            role_repository.clone(),
+            policy_repository.clone(),
+            audit_repository.clone(),
+        )
+        .with_cache_generations(cache_generations.clone()),
+    );
+    let query_service = Arc::new(
+        AccessControlQueryServiceImpl::new(
+            policy_repository.clone(),
+            role_repository.clone(),
+            audit_repository,
+        )
+        .with_cache_generations(cache_generations),
+    );
+
+    AccessControlFacadeHarness {
+        role_repository,
+        policy_repository,
+        facade: AccessControlFacadeImpl::new(command_service, query_service),
+    }
+}
@@ -1,6 +1,20 @@
 #[path = "data_api/command_service_tests.rs"]
 mod command_service_tests;
+#[path = "data_api/list_rows_statement_tests.rs"]
+mod list_rows_statement_tests;
+#[path = "data_api/metadata_sync_schedule_tests.rs"]
+mod metadata_sync_schedule_tests;
+#[path = "data_api/pool_cache_tests.rs"]
+mod pool_cache_tests;
 #[path = "data_api/query_service_tests.rs"]
 mod query_service_tests;
+#[path = "data_api/rest_controller_tests.rs"]
+mod rest_controller_tests;
+#[path = "data_api/sqlx_repository_tests.rs"]
+mod sqlx_repository_tests;
 #[path = "data_api/support.rs"]
 mod support;
+#[path = "data_api/tenant_connection_resolver_tests.rs"]
+mod tenant_connection_resolver_tests;
+#[path = "data_api/write_error_mapping_tests.rs"]
+mod write_error_mapping_tests;
@@ -1,11 +1,22 @@
+use serde_json::json;
 use std::collections::HashSet;
-use swagger_axum_api::data_api::domain::{
-    model::enums::{data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError},
-    services::data_api_command_service::DataApiCommandService,
+use swagger_axum_api::data_api::{
+    application::command_services::data_api_command_service_impl::DataApiCommandServiceSettings,
+    domain::{
+        model::{
+            commands::execute_transaction_command::{
+                DataApiTransactionOperation, ExecuteTransactionCommand,
+            },
+            enums::{data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError},
+        },
+        services::data_api_command_service::DataApiCommandService,
+    },
 };
 
 use crate::support::{
-    create_command_harness, create_row_command, fixtures, patch_row_command, sample_payload,
+    create_command_harness, create_command_harness_with_settings, create_row_command,
+    create_rows_command, delete_row_command, fixtures, import_rows_command, patch_row_command,
+    patch_row_command_skipping_nulls, replace_row_command, sample_payload,
 };
 
 #[tokio::test]
@@ -85,6 +96,160 @@ async fn handle_patch_returns_not_found_when_row_does_not_exist() {
     assert_eq!(audit_events[0].status_code, 404);
 }
 
+#[tokio::test]
+async fn handle_patch_sets_nullable_column_to_null_by_default() {
+    let harness = create_command_harness(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_patch(patch_row_command(
+            json!({ "nombre": "Teclado", "image_url": null }),
+        ))
+        .await;
+
+    let updated = result.expect("patch should succeed");
+    assert_eq!(
+        updated["payload"],
+        json!({ "nombre": "Teclado", "image_url": null })
+    );
+}
+
+#[tokio::test]
+async fn handle_patch_leaves_null_valued_columns_unchanged_in_skip_mode() {
+    let harness = create_command_harness(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_patch(patch_row_command_skipping_nulls(
+            json!({ "nombre": "Teclado", "image_url": null }),
+        ))
+        .await;
+
+    let updated = result.expect("patch should succeed");
+    assert_eq!(updated["payload"], json!({ "nombre": "Teclado" }));
+}
+
+#[tokio::test]
+async fn handle_replace_resets_omitted_writable_columns_to_default() {
+    let harness = create_command_harness(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_replace(replace_row_command(json!({ "nombre": "Teclado" })))
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        harness.repository.last_put_columns(),
+        Some((
+            vec!["nombre".to_string()],
+            vec!["precio".to_string(), "image_url".to_string()],
+        ))
+    );
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(matches!(audit_events[0].action, DataApiAction::Update));
+}
+
+#[tokio::test]
+async fn handle_replace_keeps_server_managed_columns_out_of_the_reset() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            row_owner_column: Some("owner_id".to_string()),
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+    harness.repository.add_writable_column("owner_id");
+    harness.repository.add_writable_column("deleted_at");
+    harness.repository.set_soft_delete_column("deleted_at");
+
+    let result = harness
+        .service
+        .handle_replace(replace_row_command(json!({ "nombre": "Teclado" })))
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        harness.repository.last_put_columns(),
+        Some((
+            vec!["nombre".to_string()],
+            vec!["precio".to_string(), "image_url".to_string()],
+        ))
+    );
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(acl_calls.len(), 1);
+    assert!(!acl_calls[0].columns.contains(&"owner_id".to_string()));
+    assert!(!acl_calls[0].columns.contains(&"deleted_at".to_string()));
+}
+
+#[tokio::test]
+async fn handle_replace_rejects_omitted_required_column_as_invalid_payload() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_put_not_null_violation(true);
+
+    let result = harness
+        .service
+        .handle_replace(replace_row_command(json!({ "nombre": "Teclado" })))
+        .await;
+
+    assert!(matches!(result, Err(DataApiDomainError::InvalidPayload)));
+    assert_eq!(harness.repository.put_calls(), 1);
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert_eq!(audit_events[0].status_code, 400);
+}
+
+#[tokio::test]
+async fn handle_patch_ignores_row_owner_header_when_owner_is_derived_from_row() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            row_owner_column: Some("owner_id".to_string()),
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+    harness.repository.set_stored_row_owner("owner-db");
+
+    let result = harness
+        .service
+        .handle_patch(patch_row_command(sample_payload()))
+        .await;
+
+    assert!(result.is_ok());
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(acl_calls.len(), 1);
+    assert_eq!(acl_calls[0].row_owner_id.as_deref(), Some("owner-db"));
+}
+
+#[tokio::test]
+async fn handle_patch_and_delete_reject_spoofed_owner_headers_when_owner_is_derived_from_row() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            row_owner_column: Some("owner_id".to_string()),
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+    harness.access_control.set_owner_scoped(true);
+    harness.repository.set_stored_row_owner("owner-1");
+
+    let patched = harness
+        .service
+        .handle_patch(patch_row_command(sample_payload()))
+        .await;
+    let deleted = harness.service.handle_delete(delete_row_command()).await;
+
+    assert!(matches!(patched, Err(DataApiDomainError::AccessDenied)));
+    assert!(matches!(deleted, Err(DataApiDomainError::AccessDenied)));
+    assert_eq!(harness.repository.patch_calls(), 0);
+    let acl_calls = harness.access_control.calls();
+    assert!(acl_calls.iter().all(|call| {
+        call.subject_owner_id.as_deref() == Some("api-key-test")
+            && call.row_owner_id.as_deref() == Some("owner-1")
+    }));
+}
+
 #[tokio::test]
 async fn handle_create_returns_infra_error_and_audits_failure() {
     let harness = create_command_harness(&["productos"]);
@@ -119,3 +284,375 @@ async fn handle_create_fails_when_acl_denies_access() {
 
     assert!(matches!(result, Err(DataApiDomainError::AccessDenied)));
 }
+
+#[tokio::test]
+async fn handle_create_batch_rejects_non_writable_columns_then_inserts_in_one_call() {
+    let harness = create_command_harness(&["productos"]);
+    let mut second = sample_payload();
+    second["nombre"] = json!("Teclado");
+    second["id"] = json!(99);
+
+    let result = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![sample_payload(), second]))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::NonEditableColumn(column)) if column == "id"
+    ));
+    assert_eq!(harness.repository.transaction_calls(), 0);
+
+    let mut second = sample_payload();
+    second["nombre"] = json!("Teclado");
+    let created = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![sample_payload(), second]))
+        .await
+        .expect("batch should succeed");
+
+    assert_eq!(created.len(), 2);
+    assert_eq!(harness.repository.create_rows_calls(), 1);
+    assert_eq!(harness.repository.created_rows()[1]["nombre"], "Teclado");
+
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(acl_calls.len(), 1);
+    assert_eq!(acl_calls[0].action_name, "create");
+
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(audit_events[0].success);
+    assert_eq!(audit_events[0].details.as_deref(), Some("created 2 rows"));
+}
+
+#[tokio::test]
+async fn handle_create_batch_enforces_batch_and_payload_limits() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            max_batch_size: 2,
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+
+    let too_many = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![sample_payload(); 3]))
+        .await;
+    let too_large = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![
+            json!({ "nombre": "x".repeat(40 * 1024) }),
+            json!({ "nombre": "y".repeat(40 * 1024) }),
+        ]))
+        .await;
+
+    assert!(matches!(
+        too_many,
+        Err(DataApiDomainError::BatchTooLarge(2))
+    ));
+    assert!(matches!(
+        too_large,
+        Err(DataApiDomainError::PayloadTooLarge)
+    ));
+    assert_eq!(harness.repository.create_rows_calls(), 0);
+}
+
+#[tokio::test]
+async fn handle_create_batch_reports_failure_when_insert_rolls_back() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_create_should_fail(true);
+
+    let result = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![sample_payload(); 2]))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::InfrastructureError(_))
+    ));
+    assert!(harness.repository.created_rows().is_empty());
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(!audit_events[0].success);
+    assert!(
+        audit_events[0]
+            .details
+            .as_deref()
+            .is_some_and(|details| details.starts_with("rolled back batch of 2 rows"))
+    );
+    assert_eq!(audit_events[0].status_code, 500);
+}
+
+#[tokio::test]
+async fn handle_create_batch_audits_the_status_of_the_rollback_error() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_create_unique_violation(true);
+
+    let result = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![sample_payload(); 2]))
+        .await;
+
+    assert!(matches!(result, Err(DataApiDomainError::UniqueViolation)));
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(!audit_events[0].success);
+    assert_eq!(audit_events[0].status_code, 409);
+}
+
+#[tokio::test]
+async fn handle_transaction_rolls_back_every_operation_when_second_operation_fails() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_patch_should_return_none(true);
+    let command = ExecuteTransactionCommand::new(vec![
+        DataApiTransactionOperation::Create(create_row_command(sample_payload())),
+        DataApiTransactionOperation::Patch(patch_row_command(json!({ "nombre": "Teclado" }))),
+    ])
+    .expect("valid transaction command");
+
+    let result = harness.service.handle_transaction(command).await;
+
+    assert!(matches!(result, Err(DataApiDomainError::RecordNotFound)));
+    assert_eq!(harness.repository.transaction_calls(), 1);
+    assert!(harness.repository.created_rows().is_empty());
+    assert_eq!(harness.access_control.calls().len(), 2);
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 2);
+    assert!(matches!(audit_events[0].action, DataApiAction::Create));
+    assert!(matches!(audit_events[1].action, DataApiAction::Update));
+    assert!(audit_events.iter().all(|event| !event.success));
+    assert!(audit_events.iter().all(|event| event.status_code == 404));
+}
+
+#[tokio::test]
+async fn handle_transaction_audits_rolled_back_operations_with_the_failure_status() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_append_only(true);
+    let command = ExecuteTransactionCommand::new(vec![
+        DataApiTransactionOperation::Create(create_row_command(sample_payload())),
+        DataApiTransactionOperation::Patch(patch_row_command(json!({ "nombre": "Teclado" }))),
+    ])
+    .expect("valid transaction command");
+
+    let result = harness.service.handle_transaction(command).await;
+
+    assert!(matches!(result, Err(DataApiDomainError::TableAppendOnly)));
+    assert_eq!(harness.repository.transaction_calls(), 1);
+    assert!(harness.repository.created_rows().is_empty());
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(matches!(audit_events[0].action, DataApiAction::Create));
+    assert!(!audit_events[0].success);
+    assert_eq!(audit_events[0].status_code, 405);
+}
+
+#[tokio::test]
+async fn role_required_mode_rejects_role_less_principal_without_full_acl() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_authorization_mode("role_required");
+
+    let rejected = harness
+        .service
+        .handle_create(create_row_command(sample_payload()))
+        .await;
+    harness
+        .access_control
+        .set_principals_with_roles(&["api-key-test"]);
+    let allowed = harness
+        .service
+        .handle_create(create_row_command(sample_payload()))
+        .await;
+
+    assert!(matches!(rejected, Err(DataApiDomainError::AccessDenied)));
+    assert!(allowed.is_ok());
+    assert_eq!(harness.access_control.role_membership_checks(), 2);
+    assert!(harness.access_control.calls().is_empty());
+    assert_eq!(harness.repository.create_calls(), 1);
+}
+
+#[tokio::test]
+async fn authenticated_mode_allows_role_less_principal() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_authorization_mode("authenticated");
+
+    let result = harness
+        .service
+        .handle_create(create_row_command(sample_payload()))
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(harness.access_control.role_membership_checks(), 0);
+    assert!(harness.access_control.calls().is_empty());
+}
+
+#[tokio::test]
+async fn handle_import_inserts_well_formed_csv_and_reports_malformed_lines() {
+    let harness = create_command_harness(&["productos"]);
+    let csv = "nombre,precio,image_url\nMouse,49.99,\n\"Teclado, mecánico\",120,https://image.example/k.png\nMonitor,caro,\nCable\n";
+
+    let result = harness
+        .service
+        .handle_import(import_rows_command(csv))
+        .await;
+
+    let report = result.expect("import should succeed");
+    assert_eq!(report["imported"], 2);
+    let errors = report["errors"].as_array().expect("errors array");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["line"], 4);
+    assert_eq!(errors[1]["line"], 5);
+
+    assert_eq!(harness.repository.transaction_calls(), 1);
+    assert_eq!(harness.repository.create_rows_calls(), 0);
+    let created = harness.repository.created_rows();
+    assert_eq!(created[0]["nombre"], "Mouse");
+    assert!(created[0]["image_url"].is_null());
+    assert_eq!(created[1]["nombre"], "Teclado, mecánico");
+
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(acl_calls.len(), 1);
+    assert_eq!(acl_calls[0].action_name, "create");
+
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(matches!(audit_events[0].action, DataApiAction::Create));
+    assert!(!audit_events[0].success);
+    assert_eq!(audit_events[0].status_code, 207);
+}
+
+#[tokio::test]
+async fn handle_import_rolls_back_whole_file_and_propagates_repository_error() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_create_should_fail(true);
+    harness.repository.set_create_failure_message(
+        "duplicate key value violates unique constraint \"productos_pkey\"",
+    );
+
+    let result = harness
+        .service
+        .handle_import(import_rows_command(
+            "nombre,precio\nMouse,49.99\nTeclado,120\n",
+        ))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::InfrastructureError(_))
+    ));
+    assert_eq!(harness.repository.transaction_calls(), 1);
+    assert!(harness.repository.created_rows().is_empty());
+
+    let audit_events = harness.audit.saved_events();
+    assert_eq!(audit_events.len(), 1);
+    assert!(!audit_events[0].success);
+    assert_eq!(audit_events[0].status_code, 500);
+}
+
+#[tokio::test]
+async fn handle_import_rejects_batch_larger_than_configured_maximum() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            max_batch_size: 2,
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+    let csv = "nombre,precio\nMouse,49.99\nTeclado,120\nMonitor,300\n";
+
+    let result = harness
+        .service
+        .handle_import(import_rows_command(csv))
+        .await;
+
+    assert!(matches!(result, Err(DataApiDomainError::BatchTooLarge(2))));
+    assert_eq!(harness.repository.transaction_calls(), 0);
+}
+
+#[tokio::test]
+async fn handle_import_rejects_non_writable_column_header() {
+    let harness = create_command_harness(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_import(import_rows_command("id,nombre\n1,Mouse\n"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::NonEditableColumn(column)) if column == "id"
+    ));
+    assert_eq!(harness.repository.create_rows_calls(), 0);
+}
+
+#[tokio::test]
+async fn delete_forwards_soft_delete_column_from_table_metadata() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_soft_delete_column("deleted_at");
+
+    let result = harness.service.handle_delete(delete_row_command()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        harness.repository.last_delete_soft_delete_column(),
+        Some("deleted_at".to_string())
+    );
+}
+
+#[tokio::test]
+async fn append_only_table_rejects_patch_and_delete_but_accepts_create() {
+    let harness = create_command_harness(&["productos"]);
+    harness.repository.set_append_only(true);
+
+    let patch_result = harness
+        .service
+        .handle_patch(patch_row_command(sample_payload()))
+        .await;
+    let delete_result = harness.service.handle_delete(delete_row_command()).await;
+    let create_result = harness
+        .service
+        .handle_create(create_row_command(sample_payload()))
+        .await;
+
+    assert!(matches!(
+        patch_result,
+        Err(DataApiDomainError::TableAppendOnly)
+    ));
+    assert!(matches!(
+        delete_result,
+        Err(DataApiDomainError::TableAppendOnly)
+    ));
+    assert!(create_result.is_ok());
+    assert_eq!(harness.repository.patch_calls(), 0);
+    assert_eq!(harness.repository.create_calls(), 1);
+}
+
+#[tokio::test]
+async fn handle_create_batch_strips_denied_columns_from_created_rows() {
+    let harness = create_command_harness_with_settings(
+        &["productos"],
+        DataApiCommandServiceSettings {
+            strip_denied_columns: true,
+            ..DataApiCommandServiceSettings::default()
+        },
+    );
+    harness.repository.set_authorization_mode("acl");
+    harness
+        .access_control
+        .set_column_scoped_denials(&["precio"]);
+
+    let rows = harness
+        .service
+        .handle_create_batch(create_rows_command(vec![
+            sample_payload(),
+            sample_payload(),
+        ]))
+        .await
+        .expect("batch create should succeed");
+
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row.get("precio").is_none()));
+    assert!(rows.iter().all(|row| row.get("nombre").is_some()));
+    assert_eq!(harness.access_control.permitted_columns_calls().len(), 1);
+}
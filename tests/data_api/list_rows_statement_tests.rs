@@ -0,0 +1,235 @@
+use serde_json::json;
+use swagger_axum_api::data_api::{
+    domain::model::{
+        enums::{
+            data_api_domain_error::DataApiDomainError,
+            data_api_filter_operator::DataApiFilterOperator,
+        },
+        value_objects::list_rows_cursor::ListRowsCursor,
+    },
+    infrastructure::persistence::repositories::{
+        data_api_repository::ListRowsCriteria,
+        postgres::sqlx_data_api_repository_impl::{ProjectedField, SqlxDataApiRepositoryImpl},
+    },
+};
+
+fn criteria(
+    filters: Vec<(String, DataApiFilterOperator)>,
+    order_by: Option<&str>,
+) -> ListRowsCriteria {
+    ListRowsCriteria {
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        fields: vec!["id".to_string(), "nombre".to_string()],
+        filters,
+        limit: 20,
+        offset: 0,
+        order_by: order_by.map(str::to_string),
+        order_desc: true,
+        primary_key_column: None,
+        after: None,
+        text_columns: vec![],
+        soft_delete_column: None,
+    }
+}
+
+#[test]
+fn statement_reflects_filters_and_ordering_with_placeholders() {
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(criteria(
+        vec![
+            (
+                "nombre".to_string(),
+                DataApiFilterOperator::Eq("tarjeta-secreta".to_string()),
+            ),
+            (
+                "precio".to_string(),
+                DataApiFilterOperator::Gte("100".to_string()),
+            ),
+        ],
+        Some("precio"),
+    ))
+    .expect("statement should build");
+
+    assert!(statement.sql.contains("WHERE t.\"nombre\"::text = $1"));
+    assert!(statement.sql.contains("AND t.\"precio\" >= "));
+    assert!(statement.sql.contains("$2::text"));
+    assert!(statement.sql.contains("ORDER BY t.\"precio\" DESC"));
+    assert!(statement.sql.contains("LIMIT $3 OFFSET $4"));
+    assert_eq!(statement.parameter_count, 4);
+    assert!(!statement.sql.contains("tarjeta-secreta"));
+    assert!(!statement.sql.contains("100"));
+}
+
+#[test]
+fn statement_binds_in_lists_as_a_single_parameter() {
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(criteria(
+        vec![(
+            "nombre".to_string(),
+            DataApiFilterOperator::In(vec!["Mouse".to_string(), "Teclado".to_string()]),
+        )],
+        None,
+    ))
+    .expect("statement should build");
+
+    assert!(statement.sql.contains("t.\"nombre\"::text = ANY($1)"));
+    assert!(!statement.sql.contains("ORDER BY"));
+    assert_eq!(statement.parameter_count, 3);
+    assert!(!statement.sql.contains("Mouse"));
+}
+
+#[test]
+fn keyset_statement_compares_order_and_primary_key_instead_of_offset() {
+    let mut keyset_criteria = criteria(
+        vec![(
+            "nombre".to_string(),
+            DataApiFilterOperator::Ne("Mouse".to_string()),
+        )],
+        Some("precio"),
+    );
+    keyset_criteria.primary_key_column = Some("id".to_string());
+    keyset_criteria.after = Some(ListRowsCursor::new(json!(4999), json!(77)));
+
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(keyset_criteria)
+        .expect("statement should build");
+
+    assert!(statement.sql.contains(
+        "AND (t.\"precio\", t.\"id\") < (SELECT c.\"precio\", c.\"id\" FROM jsonb_populate_record("
+    ));
+    assert!(
+        statement
+            .sql
+            .contains("ORDER BY t.\"precio\" DESC, t.\"id\" DESC")
+    );
+    assert!(statement.sql.ends_with("LIMIT $3) AS subq"));
+    assert!(!statement.sql.contains("OFFSET"));
+    assert_eq!(statement.parameter_count, 3);
+    assert!(!statement.sql.contains("4999"));
+}
+
+#[test]
+fn statement_excludes_soft_deleted_rows_when_column_is_configured() {
+    let mut soft_delete_criteria = criteria(vec![], None);
+    soft_delete_criteria.soft_delete_column = Some("deleted_at".to_string());
+
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(soft_delete_criteria)
+        .expect("statement should build");
+
+    assert!(statement.sql.contains("WHERE t.\"deleted_at\" IS NULL"));
+    assert_eq!(statement.parameter_count, 2);
+}
+
+#[test]
+fn keyset_statement_requires_order_and_primary_key_columns() {
+    let mut keyset_criteria = criteria(vec![], None);
+    keyset_criteria.after = Some(ListRowsCursor::new(json!(1), json!(1)));
+
+    let result = SqlxDataApiRepositoryImpl::list_rows_statement(keyset_criteria);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn cursor_round_trips_and_rejects_garbage() {
+    let cursor = ListRowsCursor::new(json!("Teclado"), json!(12));
+
+    let decoded = ListRowsCursor::decode(&cursor.encode()).expect("cursor should decode");
+
+    assert_eq!(decoded, cursor);
+    assert!(ListRowsCursor::decode("no-es-un-cursor").is_err());
+}
+
+#[test]
+fn projected_field_parser_accepts_columns_and_json_paths() {
+    assert_eq!(
+        SqlxDataApiRepositoryImpl::parse_projected_field("nombre").ok(),
+        Some(ProjectedField::Column("nombre".to_string()))
+    );
+    assert_eq!(
+        SqlxDataApiRepositoryImpl::parse_projected_field("metadata->>address.country").ok(),
+        Some(ProjectedField::JsonPath {
+            column: "metadata".to_string(),
+            path: vec!["address".to_string(), "country".to_string()],
+        })
+    );
+}
+
+#[test]
+fn projected_field_parser_rejects_injection_payloads() {
+    for field in [
+        "nombre'; DROP TABLE productos; --",
+        "metadata'; DROP TABLE productos; --->>country",
+        "metadata->>country'; DROP TABLE productos; --",
+        "metadata->>",
+        "metadata->>address..country",
+        "->>country",
+        "metadata->country",
+    ] {
+        assert!(
+            matches!(
+                SqlxDataApiRepositoryImpl::parse_projected_field(field),
+                Err(DataApiDomainError::InvalidQueryParameters)
+            ),
+            "{field}"
+        );
+    }
+}
+
+#[test]
+fn statement_binds_json_path_keys_and_paths() {
+    let mut criteria = criteria(vec![], None);
+    criteria.fields = vec!["id".to_string(), "metadata->>country".to_string()];
+
+    let statement =
+        SqlxDataApiRepositoryImpl::list_rows_statement(criteria).expect("statement should build");
+
+    assert!(
+        statement.sql.contains(
+            "jsonb_build_object('id', t.\"id\", $1::text, t.\"metadata\" #>> $2::text[])"
+        )
+    );
+    assert!(!statement.sql.contains("country"));
+    assert!(statement.sql.contains("LIMIT $3 OFFSET $4"));
+    assert_eq!(statement.parameter_count, 4);
+}
+
+#[test]
+fn statement_collapses_duplicate_projected_fields() {
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(ListRowsCriteria {
+        fields: vec![
+            "nombre".to_string(),
+            "id".to_string(),
+            "nombre".to_string(),
+            "atributos->>color".to_string(),
+            "atributos->>color".to_string(),
+        ],
+        ..criteria(vec![], None)
+    })
+    .expect("statement should build");
+
+    assert_eq!(statement.sql.matches("'nombre', t.\"nombre\"").count(), 1);
+    assert_eq!(statement.sql.matches("'id', t.\"id\"").count(), 1);
+    assert_eq!(statement.sql.matches("t.\"atributos\" #>> ").count(), 1);
+    assert_eq!(statement.parameter_count, 4);
+}
+
+#[test]
+fn statement_collapses_order_column_repeated_as_primary_key_tie_breaker() {
+    let statement = SqlxDataApiRepositoryImpl::list_rows_statement(ListRowsCriteria {
+        primary_key_column: Some("id".to_string()),
+        ..criteria(vec![], Some("id"))
+    })
+    .expect("statement should build");
+    let tie_broken = SqlxDataApiRepositoryImpl::list_rows_statement(ListRowsCriteria {
+        primary_key_column: Some("id".to_string()),
+        ..criteria(vec![], Some("precio"))
+    })
+    .expect("statement should build");
+
+    assert!(statement.sql.contains("ORDER BY t.\"id\" DESC LIMIT"));
+    assert_eq!(statement.sql.matches("t.\"id\" DESC").count(), 1);
+    assert!(
+        tie_broken
+            .sql
+            .contains("ORDER BY t.\"precio\" DESC, t.\"id\" DESC LIMIT")
+    );
+}
@@ -0,0 +1,118 @@
+use std::{sync::Arc, time::Duration};
+
+use swagger_axum_api::data_api::{
+    domain::model::value_objects::tenant_id::TenantId,
+    infrastructure::persistence::repositories::{
+        in_memory::in_memory_metadata_sync_schedule_repository_impl::InMemoryMetadataSyncScheduleRepositoryImpl,
+        metadata_sync_schedule_repository::MetadataSyncScheduleRepository,
+    },
+};
+
+use crate::support::fixtures;
+
+fn tenant_id() -> TenantId {
+    TenantId::new(fixtures::TENANT_1_ID.to_string()).expect("valid tenant id")
+}
+
+#[tokio::test]
+async fn sync_is_skipped_within_interval_and_runs_after_it_elapses() {
+    let schedule = InMemoryMetadataSyncScheduleRepositoryImpl::new(Duration::from_millis(50));
+    let tenant_id = tenant_id();
+
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_some()
+    );
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_none()
+    );
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "ventas")
+            .await
+            .is_some()
+    );
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn cleared_sync_runs_again_within_interval() {
+    let schedule = InMemoryMetadataSyncScheduleRepositoryImpl::new(Duration::from_secs(300));
+    let tenant_id = tenant_id();
+
+    let claimed_at = schedule
+        .try_begin_sync(&tenant_id, "public")
+        .await
+        .expect("first sync is claimed");
+    schedule.clear(&tenant_id, "public", claimed_at).await;
+
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn stale_clear_does_not_release_a_newer_claim() {
+    let schedule = InMemoryMetadataSyncScheduleRepositoryImpl::new(Duration::from_millis(20));
+    let tenant_id = tenant_id();
+
+    let stale_claim = schedule
+        .try_begin_sync(&tenant_id, "public")
+        .await
+        .expect("first sync is claimed");
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_some()
+    );
+
+    schedule.clear(&tenant_id, "public", stale_claim).await;
+
+    assert!(
+        schedule
+            .try_begin_sync(&tenant_id, "public")
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_callers_claim_a_sync_exactly_once() {
+    let schedule = Arc::new(InMemoryMetadataSyncScheduleRepositoryImpl::new(
+        Duration::from_secs(300),
+    ));
+
+    let handles = (0..32)
+        .map(|_| {
+            let schedule = schedule.clone();
+            tokio::spawn(async move { schedule.try_begin_sync(&tenant_id(), "public").await })
+        })
+        .collect::<Vec<_>>();
+
+    let mut claimed = 0;
+    for handle in handles {
+        if handle.await.expect("task completes").is_some() {
+            claimed += 1;
+        }
+    }
+
+    assert_eq!(claimed, 1);
+}
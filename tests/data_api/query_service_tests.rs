@@ -1,9 +1,26 @@
-use swagger_axum_api::data_api::domain::{
-    model::enums::data_api_domain_error::DataApiDomainError,
-    services::data_api_query_service::DataApiQueryService,
+use serde_json::json;
+use swagger_axum_api::data_api::{
+    domain::{
+        model::{
+            enums::{
+                data_api_domain_error::DataApiDomainError,
+                data_api_filter_operator::DataApiFilterOperator,
+                data_api_read_transform::DataApiReadTransform,
+            },
+            value_objects::tenant_id::TenantId,
+        },
+        services::data_api_query_service::DataApiQueryService,
+    },
+    infrastructure::persistence::repositories::data_api_repository::TableAccessCatalogEntry,
 };
 
-use crate::support::{create_query_harness, fixtures, get_row_query, list_rows_query};
+use crate::support::{
+    create_query_harness, create_query_harness_with_denied_column_stripping,
+    create_query_harness_with_owner_scoped_lists, create_query_harness_with_row_owner_column,
+    fixtures, get_row_query, list_rows_default_select_query, list_rows_lenient_projection_query,
+    list_rows_numeric_as_string_query, list_rows_ordered_by_query, list_rows_query,
+    list_rows_without_subject_owner_query, table_schema_introspection_query,
+};
 
 #[tokio::test]
 async fn handle_list_filters_unknown_columns_before_repository_call() {
@@ -19,9 +36,19 @@ async fn handle_list_filters_unknown_columns_before_repository_call() {
     assert_eq!(criteria.fields, vec!["nombre".to_string()]);
     assert_eq!(
         criteria.filters,
-        vec![("nombre".to_string(), "Mouse".to_string())]
+        vec![
+            (
+                "nombre".to_string(),
+                DataApiFilterOperator::Eq("Mouse".to_string())
+            ),
+            (
+                "precio".to_string(),
+                DataApiFilterOperator::Gte("10".to_string())
+            ),
+        ]
     );
     assert_eq!(criteria.order_by, None);
+    assert!(criteria.text_columns.is_empty());
     assert_eq!(
         harness.repository.last_tenant_for_list().as_deref(),
         Some(fixtures::TENANT_1_ID)
@@ -52,6 +79,50 @@ async fn handle_get_returns_not_found_when_repository_returns_none() {
     assert_eq!(audit_events[0].status_code, 404);
 }
 
+#[tokio::test]
+async fn handle_get_ignores_row_owner_header_when_owner_is_derived_from_row() {
+    let harness = create_query_harness_with_row_owner_column(&["productos"], Some("owner_id"));
+    harness.repository.set_stored_row_owner("owner-db");
+
+    let result = harness.service.handle_get(get_row_query()).await;
+
+    assert!(result.is_ok());
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(acl_calls.len(), 1);
+    assert_eq!(acl_calls[0].row_owner_id.as_deref(), Some("owner-db"));
+}
+
+#[tokio::test]
+async fn handle_get_rejects_spoofed_owner_headers_when_owner_is_derived_from_row() {
+    let harness = create_query_harness_with_row_owner_column(&["productos"], Some("owner_id"));
+    harness.access_control.set_owner_scoped(true);
+    harness.repository.set_stored_row_owner("owner-1");
+
+    let spoofed = harness.service.handle_get(get_row_query()).await;
+    harness.repository.set_stored_row_owner("api-key-test");
+    let owned = harness.service.handle_get(get_row_query()).await;
+
+    assert!(matches!(spoofed, Err(DataApiDomainError::AccessDenied)));
+    assert!(owned.is_ok());
+    let acl_calls = harness.access_control.calls();
+    assert_eq!(
+        acl_calls[0].subject_owner_id.as_deref(),
+        Some("api-key-test")
+    );
+    assert_eq!(acl_calls[0].row_owner_id.as_deref(), Some("owner-1"));
+}
+
+#[tokio::test]
+async fn handle_get_returns_schema_not_found_instead_of_table_not_allowed() {
+    let harness = create_query_harness(&["productos"]);
+    harness.repository.set_schema_missing(true);
+
+    let result = harness.service.handle_get(get_row_query()).await;
+
+    assert!(matches!(result, Err(DataApiDomainError::SchemaNotFound)));
+    assert!(harness.access_control.calls().is_empty());
+}
+
 #[tokio::test]
 async fn handle_get_fails_when_primary_key_is_missing() {
     let harness = create_query_harness(&["productos"]);
@@ -64,3 +135,525 @@ async fn handle_get_fails_when_primary_key_is_missing() {
         Err(DataApiDomainError::PrimaryKeyNotFound)
     ));
 }
+
+#[tokio::test]
+async fn handle_list_casts_numeric_columns_to_text_when_requested() {
+    let harness = create_query_harness(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_numeric_as_string_query())
+        .await;
+
+    assert!(result.is_ok());
+    let criteria = harness
+        .repository
+        .last_list_criteria()
+        .expect("list criteria should be captured");
+    assert_eq!(
+        criteria.fields,
+        vec!["id".to_string(), "precio".to_string()]
+    );
+    assert_eq!(criteria.text_columns, vec!["precio".to_string()]);
+}
+
+#[tokio::test]
+async fn handle_list_applies_last4_read_transform_to_text_column() {
+    let harness = create_query_harness(&["productos"]);
+    harness
+        .repository
+        .set_read_transform("nombre", DataApiReadTransform::Last4);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_default_select_query())
+        .await
+        .expect("list should succeed");
+
+    assert_eq!(result.rows, json!([{"id": 1, "nombre": "*********demo"}]));
+}
+
+#[tokio::test]
+async fn handle_list_rejects_filter_on_read_transformed_column() {
+    let harness = create_query_harness(&["productos"]);
+    harness
+        .repository
+        .set_read_transform("nombre", DataApiReadTransform::Last4);
+
+    let result = harness.service.handle_list(list_rows_query()).await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::NonFilterableColumn(column)) if column == "nombre"
+    ));
+    assert!(harness.repository.last_list_criteria().is_none());
+}
+
+#[tokio::test]
+async fn handle_list_denies_whole_request_when_a_field_is_denied_by_default() {
+    let harness = create_query_harness(&["productos"]);
+    harness.access_control.set_denied_columns(&["precio"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_numeric_as_string_query())
+        .await;
+
+    assert!(matches!(result, Err(DataApiDomainError::AccessDenied)));
+    assert!(harness.repository.last_list_criteria().is_none());
+}
+
+#[tokio::test]
+async fn handle_list_drops_denied_fields_in_lenient_projection_mode() {
+    let harness = create_query_harness(&["productos"]);
+    harness.access_control.set_denied_columns(&["precio"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_lenient_projection_query(&[
+            "id", "nombre", "precio",
+        ]))
+        .await
+        .expect("lenient list should succeed");
+
+    assert_eq!(result.dropped_fields, vec!["precio".to_string()]);
+    let criteria = harness
+        .repository
+        .last_list_criteria()
+        .expect("list criteria should be captured");
+    assert_eq!(
+        criteria.fields,
+        vec!["id".to_string(), "nombre".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn handle_get_keeps_numeric_columns_as_numbers_by_default() {
+    let harness = create_query_harness(&["productos"]);
+
+    let result = harness.service.handle_get(get_row_query()).await;
+
+    assert!(result.is_ok());
+    let criteria = harness
+        .repository
+        .last_get_criteria()
+        .expect("get criteria should be captured");
+    assert!(criteria.text_columns.is_empty());
+}
+
+fn catalog_entry(table_name: &str, authorization_mode: &str) -> TableAccessCatalogEntry {
+    TableAccessCatalogEntry {
+        table_name: table_name.to_string(),
+        exposed: true,
+        read_enabled: true,
+        create_enabled: true,
+        update_enabled: true,
+        delete_enabled: true,
+        append_only: false,
+        introspect_enabled: true,
+        authorization_mode: authorization_mode.to_string(),
+        soft_delete_column: None,
+        writable_columns: vec![],
+        creatable_columns: None,
+        updatable_columns: None,
+    }
+}
+
+#[tokio::test]
+async fn handle_list_rejects_filter_on_non_filterable_column() {
+    let harness = create_query_harness(&["productos"]);
+    harness.repository.set_non_filterable_column("precio");
+
+    let result = harness.service.handle_list(list_rows_query()).await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::NonFilterableColumn(column)) if column == "precio"
+    ));
+    assert!(harness.repository.last_list_criteria().is_none());
+}
+
+#[tokio::test]
+async fn effective_writable_catalog_reports_acl_create_and_update_columns_separately() {
+    let harness = create_query_harness(&["productos"]);
+    harness.access_control.set_denied_columns(&["precio"]);
+    harness
+        .access_control
+        .set_denied_update_columns(&["codigo"]);
+    let tenant_id = TenantId::new(fixtures::TENANT_1_ID.to_string()).expect("valid tenant");
+    let writable_entry = |table_name: &str, authorization_mode: &str| TableAccessCatalogEntry {
+        writable_columns: vec![
+            "codigo".to_string(),
+            "nombre".to_string(),
+            "precio".to_string(),
+        ],
+        ..catalog_entry(table_name, authorization_mode)
+    };
+
+    let catalog = harness
+        .service
+        .effective_writable_catalog(
+            &tenant_id,
+            "api-key-test",
+            vec![
+                writable_entry("productos", "acl"),
+                writable_entry("publico", "authenticated"),
+            ],
+        )
+        .await
+        .expect("effective writable catalog should resolve");
+
+    let effective = catalog
+        .into_iter()
+        .map(|entry| {
+            (
+                entry.table_name,
+                entry.writable_columns.len(),
+                entry.creatable_columns,
+                entry.updatable_columns,
+            )
+        })
+        .collect::<Vec<_>>();
+    let columns = |names: &[&str]| Some(names.iter().map(|name| name.to_string()).collect());
+    assert_eq!(
+        effective,
+        vec![
+            (
+                "productos".to_string(),
+                3,
+                columns(&["codigo", "nombre"]),
+                columns(&["nombre"]),
+            ),
+            (
+                "publico".to_string(),
+                3,
+                columns(&["codigo", "nombre", "precio"]),
+                columns(&["codigo", "nombre", "precio"]),
+            ),
+        ]
+    );
+    let requests = harness.access_control.effective_columns_calls();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].resource_name, "productos");
+    assert_eq!(
+        requests[0].subject_owner_id.as_deref(),
+        Some("api-key-test")
+    );
+    assert_eq!(requests[0].row_owner_id.as_deref(), Some("api-key-test"));
+    assert!(harness.access_control.calls().is_empty());
+}
+
+#[tokio::test]
+async fn filter_access_catalog_keeps_only_acl_permitted_tables() {
+    let harness = create_query_harness(&["productos"]);
+    harness.access_control.set_denied_tables(&["clientes"]);
+    let tenant_id = TenantId::new(fixtures::TENANT_1_ID.to_string()).expect("valid tenant");
+
+    let visible = harness
+        .service
+        .filter_access_catalog(
+            &tenant_id,
+            "api-key-test",
+            Some("req-6"),
+            vec![
+                catalog_entry("clientes", "acl"),
+                catalog_entry("productos", "acl"),
+                catalog_entry("publico", "authenticated"),
+            ],
+        )
+        .await
+        .expect("catalog filtering should succeed");
+
+    let tables = visible
+        .into_iter()
+        .map(|entry| entry.table_name)
+        .collect::<Vec<_>>();
+    assert_eq!(tables, vec!["productos", "publico"]);
+    let checked = harness
+        .access_control
+        .calls()
+        .into_iter()
+        .map(|call| (call.table_name, call.action_name))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        checked,
+        vec![
+            ("clientes".to_string(), "read".to_string()),
+            ("productos".to_string(), "read".to_string()),
+        ]
+    );
+    assert!(
+        harness
+            .access_control
+            .calls()
+            .iter()
+            .all(|call| !call.audit_decision)
+    );
+}
+
+#[tokio::test]
+async fn schema_introspection_lists_primary_key_columns_at_top_level() {
+    let harness = create_query_harness(&["productos"]);
+
+    let schema = harness
+        .service
+        .handle_schema_introspection(table_schema_introspection_query())
+        .await
+        .expect("introspection should succeed");
+
+    assert_eq!(schema["primary_key"], json!(["id"]));
+    let flagged = schema["columns"]
+        .as_array()
+        .expect("columns should be an array")
+        .iter()
+        .filter(|column| column["primary_key"] == json!(true))
+        .map(|column| column["name"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(json!(flagged), schema["primary_key"]);
+}
+
+#[tokio::test]
+async fn schema_introspection_exposes_ordinal_position_and_column_default() {
+    let harness = create_query_harness(&["productos"]);
+
+    let schema = harness
+        .service
+        .handle_schema_introspection(table_schema_introspection_query())
+        .await
+        .expect("introspection should succeed");
+
+    let columns = schema["columns"]
+        .as_array()
+        .expect("columns should be an array");
+    let ordinals = columns
+        .iter()
+        .map(|column| column["ordinal_position"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(json!(ordinals), json!([1, 2, 3, 4]));
+
+    let precio = columns
+        .iter()
+        .find(|column| column["name"] == "precio")
+        .expect("precio should be listed");
+    assert_eq!(precio["ordinal_position"], json!(3));
+    assert_eq!(precio["default"], json!("0"));
+
+    let nombre = columns
+        .iter()
+        .find(|column| column["name"] == "nombre")
+        .expect("nombre should be listed");
+    assert!(nombre["default"].is_null());
+}
+
+#[tokio::test]
+async fn schema_introspection_lists_every_column_of_a_composite_primary_key() {
+    let harness = create_query_harness(&["productos"]);
+    harness
+        .repository
+        .set_primary_key_columns(&["id", "nombre"]);
+
+    let schema = harness
+        .service
+        .handle_schema_introspection(table_schema_introspection_query())
+        .await
+        .expect("introspection should succeed");
+
+    assert_eq!(schema["primary_key"], json!(["id", "nombre"]));
+}
+
+#[tokio::test]
+async fn json_schema_requires_not_null_columns_without_default() {
+    let harness = create_query_harness(&["productos"]);
+
+    let schema = harness
+        .service
+        .handle_json_schema(table_schema_introspection_query())
+        .await
+        .expect("json schema should be generated");
+
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(schema["required"], json!(["nombre"]));
+    assert_eq!(schema["properties"]["nombre"]["type"], json!("string"));
+    assert_eq!(
+        schema["properties"]["image_url"]["type"],
+        json!(["string", "null"])
+    );
+    assert_eq!(schema["properties"]["precio"]["type"], json!("number"));
+    assert_eq!(schema["properties"]["id"]["type"], json!("integer"));
+    assert_eq!(schema["properties"]["id"]["readOnly"], json!(true));
+}
+
+#[tokio::test]
+async fn json_schema_is_gated_by_table_acl() {
+    let harness = create_query_harness(&["productos"]);
+    harness.repository.set_authorization_mode("acl");
+    harness.access_control.set_deny(true);
+
+    let result = harness
+        .service
+        .handle_json_schema(table_schema_introspection_query())
+        .await;
+
+    assert!(matches!(result, Err(DataApiDomainError::AccessDenied)));
+}
+
+#[tokio::test]
+async fn denied_column_is_stripped_from_default_select() {
+    let harness = create_query_harness_with_denied_column_stripping(&["productos"]);
+    harness.repository.set_authorization_mode("acl");
+    harness
+        .access_control
+        .set_column_scoped_denials(&["nombre"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_default_select_query())
+        .await
+        .expect("list should succeed");
+
+    assert_eq!(result.rows, json!([{ "id": 1 }]));
+}
+
+#[tokio::test]
+async fn ordering_by_denied_column_is_rejected_so_the_cursor_cannot_leak_it() {
+    let harness = create_query_harness_with_denied_column_stripping(&["productos"]);
+    harness.repository.set_authorization_mode("acl");
+    harness
+        .access_control
+        .set_column_scoped_denials(&["nombre"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_ordered_by_query("nombre"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::NonOrderableColumn(column)) if column == "nombre"
+    ));
+    assert!(harness.repository.last_list_criteria().is_none());
+}
+
+#[tokio::test]
+async fn denied_column_is_stripped_from_single_row_reads() {
+    let harness = create_query_harness_with_denied_column_stripping(&["productos"]);
+    harness.repository.set_authorization_mode("acl");
+    harness
+        .access_control
+        .set_column_scoped_denials(&["nombre"]);
+
+    let row = harness
+        .service
+        .handle_get(get_row_query())
+        .await
+        .expect("get should succeed");
+
+    assert!(row.get("nombre").is_none());
+    assert!(row.get("id").is_some());
+}
+
+#[tokio::test]
+async fn denied_columns_are_resolved_with_a_single_access_control_call() {
+    let harness = create_query_harness_with_denied_column_stripping(&["productos"]);
+    harness.repository.set_authorization_mode("acl");
+    harness
+        .access_control
+        .set_column_scoped_denials(&["nombre"]);
+
+    harness
+        .service
+        .handle_get(get_row_query())
+        .await
+        .expect("get should succeed");
+
+    let permitted_columns_calls = harness.access_control.permitted_columns_calls();
+    assert_eq!(harness.access_control.calls().len(), 1);
+    assert_eq!(permitted_columns_calls.len(), 1);
+    assert_eq!(permitted_columns_calls[0].action_name, "read");
+    assert_eq!(
+        permitted_columns_calls[0].columns,
+        vec!["id", "nombre", "precio", "image_url"]
+    );
+}
+
+#[tokio::test]
+async fn owner_scoped_list_injects_owner_predicate_for_authenticated_principal() {
+    let harness = create_query_harness_with_owner_scoped_lists(&["productos"]);
+    harness.access_control.set_owner_scoped(true);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_default_select_query())
+        .await;
+
+    assert!(result.is_ok());
+    let criteria = harness
+        .repository
+        .last_list_criteria()
+        .expect("list criteria should be captured");
+    assert_eq!(
+        criteria.filters,
+        vec![(
+            "owner_id".to_string(),
+            DataApiFilterOperator::Eq("api-key-test".to_string())
+        )]
+    );
+    let owners = harness
+        .access_control
+        .calls()
+        .into_iter()
+        .map(|call| (call.subject_owner_id, call.row_owner_id))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        owners,
+        vec![
+            (Some("api-key-test".to_string()), None),
+            (
+                Some("api-key-test".to_string()),
+                Some("api-key-test".to_string())
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn owner_scoped_list_without_subject_owner_scopes_to_authenticated_principal() {
+    let harness = create_query_harness_with_owner_scoped_lists(&["productos"]);
+    harness.access_control.set_owner_scoped(true);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_without_subject_owner_query())
+        .await;
+
+    assert!(result.is_ok());
+    let criteria = harness
+        .repository
+        .last_list_criteria()
+        .expect("list criteria should be captured");
+    assert_eq!(
+        criteria.filters,
+        vec![(
+            "owner_id".to_string(),
+            DataApiFilterOperator::Eq("api-key-test".to_string())
+        )]
+    );
+}
+
+#[tokio::test]
+async fn unscoped_list_rule_does_not_inject_owner_predicate() {
+    let harness = create_query_harness_with_owner_scoped_lists(&["productos"]);
+
+    let result = harness
+        .service
+        .handle_list(list_rows_default_select_query())
+        .await;
+
+    assert!(result.is_ok());
+    let criteria = harness
+        .repository
+        .last_list_criteria()
+        .expect("list criteria should be captured");
+    assert!(criteria.filters.is_empty());
+    assert_eq!(harness.access_control.calls().len(), 1);
+}
@@ -0,0 +1,507 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde_json::json;
+use sqlx::PgPool;
+use swagger_axum_api::data_api::{
+    domain::model::{
+        enums::{
+            data_api_domain_error::DataApiDomainError,
+            data_api_read_transform::DataApiReadTransform,
+        },
+        value_objects::tenant_id::TenantId,
+    },
+    infrastructure::persistence::repositories::{
+        data_api_repository::{
+            ColumnMetadataUpdateCriteria, CreateRowCriteria, DataApiRepository, DeleteRowCriteria,
+            GetRowByPrimaryKeyCriteria, PatchRowCriteria, PutRowCriteria,
+            TableMetadataUpdateCriteria, TransactionOperationCriteria,
+        },
+        in_memory::in_memory_metadata_sync_schedule_repository_impl::InMemoryMetadataSyncScheduleRepositoryImpl,
+        postgres::{
+            sqlx_data_api_repository_impl::SqlxDataApiRepositoryImpl,
+            sqlx_tenant_pool_cache_repository_impl::SqlxTenantPoolCacheRepositoryImpl,
+        },
+    },
+};
+use uuid::Uuid;
+
+use crate::support::FakeTenantConnectionResolverRepository;
+
+struct TenantSchemaFixture {
+    pool: PgPool,
+    schema_name: String,
+    tenant_id: TenantId,
+    repository: SqlxDataApiRepositoryImpl,
+}
+
+impl TenantSchemaFixture {
+    async fn create(statements: &[&str]) -> Option<Self> {
+        let database_url = std::env::var("TEST_DATABASE_URL").ok()?;
+        let pool = PgPool::connect(&database_url)
+            .await
+            .expect("test database should accept connections");
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("migrations should apply to the test database");
+        let schema_name = format!("it_{}", Uuid::now_v7().simple());
+        let tenant_id = TenantId::from_uuid(Uuid::now_v7());
+
+        sqlx::query(
+            "INSERT INTO provisioned_databases (id, database_name, username, password_hash, status, created_at) VALUES ($1, $2, $2, 'unused', 'active', NOW())",
+        )
+        .bind(tenant_id.value())
+        .bind(&schema_name)
+        .execute(&pool)
+        .await
+        .expect("test tenant should be registered");
+
+        sqlx::query(&format!("CREATE SCHEMA {schema_name}"))
+            .execute(&pool)
+            .await
+            .expect("test schema should be created");
+        for statement in statements {
+            sqlx::query(&statement.replace("{schema}", &schema_name))
+                .execute(&pool)
+                .await
+                .expect("fixture statement should run");
+        }
+
+        let repository = SqlxDataApiRepositoryImpl::new(
+            pool.clone(),
+            Arc::new(FakeTenantConnectionResolverRepository::new(&database_url)),
+            Arc::new(SqlxTenantPoolCacheRepositoryImpl::new()),
+            Arc::new(InMemoryMetadataSyncScheduleRepositoryImpl::new(
+                Duration::from_secs(0),
+            )),
+        );
+
+        Some(Self {
+            pool,
+            schema_name,
+            tenant_id,
+            repository,
+        })
+    }
+
+    async fn drop(self) {
+        let _ = sqlx::query("DELETE FROM provisioned_databases WHERE id = $1")
+            .bind(self.tenant_id.value())
+            .execute(&self.pool)
+            .await;
+        let _ = sqlx::query(&format!("DROP SCHEMA {} CASCADE", self.schema_name))
+            .execute(&self.pool)
+            .await;
+    }
+}
+
+fn table_metadata_criteria(
+    read_enabled: bool,
+    authorization_mode: &str,
+    soft_delete_column: Option<&str>,
+) -> TableMetadataUpdateCriteria {
+    TableMetadataUpdateCriteria {
+        exposed: true,
+        read_enabled,
+        create_enabled: true,
+        update_enabled: true,
+        delete_enabled: true,
+        append_only: None,
+        introspect_enabled: true,
+        authorization_mode: authorization_mode.to_string(),
+        soft_delete_column: soft_delete_column.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn delete_of_referenced_row_maps_to_foreign_key_violation() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.categorias (id INT PRIMARY KEY)",
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, categoria_id INT NOT NULL REFERENCES {schema}.categorias (id))",
+        "INSERT INTO {schema}.categorias (id) VALUES (1)",
+        "INSERT INTO {schema}.productos (id, categoria_id) VALUES (1, 1)",
+    ])
+    .await
+    else {
+        return;
+    };
+
+    let result = fixture
+        .repository
+        .delete_row(
+            &fixture.tenant_id,
+            DeleteRowCriteria {
+                schema_name: &fixture.schema_name,
+                table_name: "categorias",
+                primary_key_column: "id",
+                primary_key_value: "1",
+                soft_delete_column: None,
+            },
+        )
+        .await;
+
+    fixture.drop().await;
+    assert!(matches!(
+        result,
+        Err(DataApiDomainError::ForeignKeyViolation)
+    ));
+}
+
+#[tokio::test]
+async fn patch_and_put_skip_soft_deleted_rows() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, nombre TEXT, deleted_at TIMESTAMPTZ)",
+        "INSERT INTO {schema}.productos (id, nombre, deleted_at) VALUES (1, 'Mouse', NOW())",
+    ])
+    .await
+    else {
+        return;
+    };
+    let payload = json!({ "nombre": "Teclado" });
+    let columns = vec!["nombre".to_string()];
+
+    let patched = fixture
+        .repository
+        .patch_row(
+            &fixture.tenant_id,
+            PatchRowCriteria {
+                schema_name: &fixture.schema_name,
+                table_name: "productos",
+                primary_key_column: "id",
+                primary_key_value: "1",
+                payload: &payload,
+                allowed_columns: &columns,
+                soft_delete_column: Some("deleted_at"),
+            },
+        )
+        .await;
+    let replaced = fixture
+        .repository
+        .put_row(
+            &fixture.tenant_id,
+            PutRowCriteria {
+                schema_name: &fixture.schema_name,
+                table_name: "productos",
+                primary_key_column: "id",
+                primary_key_value: "1",
+                payload: &payload,
+                assigned_columns: &columns,
+                defaulted_columns: &[],
+                soft_delete_column: Some("deleted_at"),
+            },
+        )
+        .await;
+    let stored_name: String = sqlx::query_scalar(&format!(
+        "SELECT nombre FROM {}.productos WHERE id = 1",
+        fixture.schema_name
+    ))
+    .fetch_one(&fixture.pool)
+    .await
+    .expect("row should still exist");
+
+    fixture.drop().await;
+    assert!(matches!(patched, Ok(None)));
+    assert!(matches!(replaced, Ok(None)));
+    assert_eq!(stored_name, "Mouse");
+}
+
+#[tokio::test]
+async fn table_metadata_upsert_waits_for_the_table_advisory_lock() {
+    let Some(fixture) =
+        TenantSchemaFixture::create(&["CREATE TABLE {schema}.productos (id INT PRIMARY KEY)"])
+            .await
+    else {
+        return;
+    };
+    let mut holder = fixture
+        .pool
+        .begin()
+        .await
+        .expect("transaction should start");
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind(format!(
+            "data_api_table_metadata:{}:{}:productos",
+            fixture.tenant_id.value(),
+            fixture.schema_name
+        ))
+        .execute(&mut *holder)
+        .await
+        .expect("lock should be taken");
+
+    let upsert = async {
+        let result = fixture
+            .repository
+            .upsert_table_access_metadata(
+                &fixture.tenant_id,
+                &fixture.schema_name,
+                "productos",
+                table_metadata_criteria(true, "acl", None),
+            )
+            .await;
+        (result, Instant::now())
+    };
+    let release = async {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let released_at = Instant::now();
+        holder.commit().await.expect("lock holder should commit");
+        released_at
+    };
+    let ((result, finished_at), released_at) = tokio::join!(upsert, release);
+
+    fixture.drop().await;
+    assert!(result.is_ok());
+    assert!(finished_at >= released_at);
+}
+
+#[tokio::test]
+async fn concurrent_table_metadata_upserts_leave_a_single_consistent_row() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, deleted_at TIMESTAMPTZ)",
+    ])
+    .await
+    else {
+        return;
+    };
+
+    let (first, second) = tokio::join!(
+        fixture.repository.upsert_table_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            table_metadata_criteria(true, "acl", Some("deleted_at")),
+        ),
+        fixture.repository.upsert_table_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            table_metadata_criteria(false, "authenticated", None),
+        ),
+    );
+    let rows = sqlx::query_as::<_, (bool, String, Option<String>)>(
+        "SELECT read_enabled, authorization_mode, soft_delete_column FROM data_api_table_metadata WHERE tenant_id = $1 AND schema_name = $2 AND table_name = 'productos'",
+    )
+    .bind(fixture.tenant_id.value())
+    .bind(&fixture.schema_name)
+    .fetch_all(&fixture.pool)
+    .await
+    .expect("metadata rows should be readable");
+
+    fixture.drop().await;
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+    assert_eq!(rows.len(), 1);
+    assert!(
+        matches!(
+            &rows[0],
+            (true, mode, Some(column)) if mode == "acl" && column == "deleted_at"
+        ) || matches!(&rows[0], (false, mode, None) if mode == "authenticated")
+    );
+}
+
+#[tokio::test]
+async fn table_metadata_upsert_rejects_soft_delete_column_missing_from_table() {
+    let Some(fixture) =
+        TenantSchemaFixture::create(&["CREATE TABLE {schema}.productos (id INT PRIMARY KEY)"])
+            .await
+    else {
+        return;
+    };
+
+    let result = fixture
+        .repository
+        .upsert_table_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            table_metadata_criteria(true, "acl", Some("deleted_at")),
+        )
+        .await;
+
+    fixture.drop().await;
+    assert!(
+        matches!(&result, Err(DataApiDomainError::InvalidColumnName(column)) if column == "deleted_at"),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn column_metadata_upsert_keeps_stored_query_flags_when_omitted() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, precio INT NOT NULL)",
+    ])
+    .await
+    else {
+        return;
+    };
+    fixture
+        .repository
+        .upsert_table_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            table_metadata_criteria(true, "acl", None),
+        )
+        .await
+        .expect("table metadata should be stored");
+    let column_criteria = |filterable, orderable| ColumnMetadataUpdateCriteria {
+        readable: true,
+        writable: true,
+        read_transform: DataApiReadTransform::None,
+        filterable,
+        orderable,
+    };
+
+    fixture
+        .repository
+        .upsert_column_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            "precio",
+            column_criteria(Some(false), Some(false)),
+        )
+        .await
+        .expect("column metadata should be stored");
+    fixture
+        .repository
+        .upsert_column_access_metadata(
+            &fixture.tenant_id,
+            &fixture.schema_name,
+            "productos",
+            "precio",
+            column_criteria(None, Some(true)),
+        )
+        .await
+        .expect("column metadata should be updated");
+    let restrictions = fixture
+        .repository
+        .list_column_query_restrictions(&fixture.tenant_id, &fixture.schema_name, "productos")
+        .await;
+
+    fixture.drop().await;
+    let restrictions = restrictions.expect("restrictions should load");
+    assert!(!restrictions.is_filterable("precio"));
+    assert!(restrictions.is_orderable("precio"));
+}
+
+#[tokio::test]
+async fn transaction_sees_its_own_writes_and_rolls_back_when_dropped() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, nombre TEXT NOT NULL)",
+    ])
+    .await
+    else {
+        return;
+    };
+    let payload = json!({ "id": 7, "nombre": "Mouse" });
+    let create_columns = vec!["id".to_string(), "nombre".to_string()];
+    let row_criteria = |primary_key_value: &str| GetRowByPrimaryKeyCriteria {
+        schema_name: fixture.schema_name.clone(),
+        table_name: "productos".to_string(),
+        primary_key_column: "id".to_string(),
+        primary_key_value: primary_key_value.to_string(),
+        text_columns: vec![],
+        soft_delete_column: None,
+    };
+
+    let mut transaction = fixture
+        .repository
+        .begin_transaction(&fixture.tenant_id)
+        .await
+        .expect("transaction should start");
+    transaction
+        .execute(TransactionOperationCriteria::Create(CreateRowCriteria {
+            schema_name: &fixture.schema_name,
+            table_name: "productos",
+            payload: &payload,
+            allowed_columns: &create_columns,
+        }))
+        .await
+        .expect("row should be inserted");
+    let created = transaction
+        .get_row_by_primary_key(row_criteria("7"))
+        .await
+        .expect("row lookup should succeed");
+    let missing_delete = transaction
+        .execute(TransactionOperationCriteria::Delete(DeleteRowCriteria {
+            schema_name: &fixture.schema_name,
+            table_name: "productos",
+            primary_key_column: "id",
+            primary_key_value: "8",
+            soft_delete_column: None,
+        }))
+        .await;
+    drop(transaction);
+    let after_rollback = fixture
+        .repository
+        .get_row_by_primary_key(&fixture.tenant_id, row_criteria("7"))
+        .await;
+
+    fixture.drop().await;
+    assert_eq!(
+        created.and_then(|row| row.get("nombre").cloned()),
+        Some(json!("Mouse"))
+    );
+    assert!(matches!(
+        missing_delete,
+        Err(DataApiDomainError::RecordNotFound)
+    ));
+    assert!(matches!(after_rollback, Ok(None)));
+}
+
+#[tokio::test]
+async fn committed_transaction_persists_every_operation() {
+    let Some(fixture) = TenantSchemaFixture::create(&[
+        "CREATE TABLE {schema}.productos (id INT PRIMARY KEY, nombre TEXT NOT NULL)",
+    ])
+    .await
+    else {
+        return;
+    };
+    let payload = json!({ "id": 1, "nombre": "Mouse" });
+    let patch = json!({ "nombre": "Teclado" });
+    let create_columns = vec!["id".to_string(), "nombre".to_string()];
+    let patch_columns = vec!["nombre".to_string()];
+
+    let mut transaction = fixture
+        .repository
+        .begin_transaction(&fixture.tenant_id)
+        .await
+        .expect("transaction should start");
+    transaction
+        .execute(TransactionOperationCriteria::Create(CreateRowCriteria {
+            schema_name: &fixture.schema_name,
+            table_name: "productos",
+            payload: &payload,
+            allowed_columns: &create_columns,
+        }))
+        .await
+        .expect("row should be inserted");
+    transaction
+        .execute(TransactionOperationCriteria::Patch(PatchRowCriteria {
+            schema_name: &fixture.schema_name,
+            table_name: "productos",
+            primary_key_column: "id",
+            primary_key_value: "1",
+            payload: &patch,
+            allowed_columns: &patch_columns,
+            soft_delete_column: None,
+        }))
+        .await
+        .expect("row created in the same transaction should be patched");
+    let committed = transaction.commit().await;
+    let stored_name: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT nombre FROM {}.productos WHERE id = 1",
+        fixture.schema_name
+    ))
+    .fetch_optional(&fixture.pool)
+    .await
+    .expect("row should be readable");
+
+    fixture.drop().await;
+    assert!(committed.is_ok());
+    assert_eq!(stored_name.as_deref(), Some("Teclado"));
+}
@@ -5,7 +5,20 @@ pub mod fixtures;
 #[path = "support/harness.rs"]
 mod harness;
 
+pub use fakes::FakeTenantConnectionResolverRepository;
 pub use fixtures::{
-    create_row_command, get_row_query, list_rows_query, patch_row_command, sample_payload,
+    create_row_command, create_rows_command, delete_row_command, get_row_query,
+    import_rows_command, list_rows_default_select_query, list_rows_lenient_projection_query,
+    list_rows_numeric_as_string_query, list_rows_ordered_by_query, list_rows_query,
+    list_rows_without_subject_owner_query, patch_row_command, patch_row_command_skipping_nulls,
+    replace_row_command, sample_payload, table_schema_introspection_query,
+};
+pub use harness::{
+    TEST_ADMIN_TOKEN, create_command_harness, create_command_harness_with_settings,
+    create_query_harness, create_query_harness_with_denied_column_stripping,
+    create_query_harness_with_owner_scoped_lists, create_query_harness_with_row_owner_column,
+    create_router_harness, create_router_harness_with_debug_sql,
+    create_router_harness_with_deprecation, create_router_harness_with_max_page_size,
+    create_router_harness_with_read_audit_sample_rate,
+    create_router_harness_with_request_id_header, create_router_harness_with_strict_json,
 };
-pub use harness::{create_command_harness, create_query_harness};
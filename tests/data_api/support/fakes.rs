@@ -1,25 +1,38 @@
-use std::sync::Mutex;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
 
 use async_trait::async_trait;
 use serde_json::{Value, json};
 use swagger_axum_api::data_api::{
     domain::model::{
         entities::table_schema_metadata::{TableColumnMetadata, TableSchemaMetadata},
-        enums::data_api_domain_error::DataApiDomainError,
+        enums::{
+            data_api_action::DataApiAction, data_api_domain_error::DataApiDomainError,
+            data_api_read_transform::DataApiReadTransform,
+        },
         events::data_api_request_audited_event::DataApiRequestAuditedEvent,
         value_objects::{schema_name::SchemaName, tenant_id::TenantId},
     },
     infrastructure::persistence::repositories::{
         data_api_audit_log_repository::DataApiAuditLogRepository,
         data_api_repository::{
-            ColumnMetadataUpdateCriteria, CreateRowCriteria, DataApiRepository, DeleteRowCriteria,
-            GetRowByPrimaryKeyCriteria, ListRowsCriteria, PatchRowCriteria,
-            TableAccessCatalogEntry, TableAccessMetadata, TableMetadataUpdateCriteria,
+            ColumnMetadataUpdateCriteria, ColumnQueryRestrictions, CreateRowCriteria,
+            CreateRowsCriteria, DataApiRepository, DataApiTransaction, DeleteRowCriteria,
+            GetRowByPrimaryKeyCriteria, ListRowsCriteria, ListRowsStatement, PatchRowCriteria,
+            PutRowCriteria, TableAccessCatalogEntry, TableAccessMetadata,
+            TableMetadataUpdateCriteria, TransactionOperationCriteria,
+        },
+        tenant_connection_resolver_repository::{
+            TenantConnectionResolverRepository, TenantConnectionTarget,
         },
         tenant_schema_resolver_repository::TenantSchemaResolverRepository,
     },
     interfaces::acl::access_control_facade::{
-        AccessControlFacade, DataApiAuthorizationBootstrapRequest, DataApiAuthorizationCheckRequest,
+        AccessControlFacade, DataApiAuthorizationBootstrapRequest,
+        DataApiAuthorizationCheckRequest, DataApiEffectiveColumnsRequest,
+        DataApiEffectiveWriteColumns, DataApiRoleMembershipRequest,
     },
 };
 
@@ -27,23 +40,40 @@ use swagger_axum_api::data_api::{
 struct FakeDataApiRepositoryState {
     metadata: Option<TableSchemaMetadata>,
     access: Option<TableAccessMetadata>,
+    table_metadata_rows: BTreeMap<(String, String), TableAccessMetadata>,
     writable_columns: Vec<String>,
+    read_transforms: Vec<(String, DataApiReadTransform)>,
+    query_restrictions: ColumnQueryRestrictions,
     create_calls: usize,
+    create_rows_calls: usize,
+    created_rows: Vec<Value>,
+    transaction_calls: usize,
     patch_calls: usize,
+    put_calls: usize,
+    last_put_columns: Option<(Vec<String>, Vec<String>)>,
+    put_not_null_violation: bool,
     delete_calls: usize,
     list_calls: usize,
+    count_calls: usize,
     get_calls: usize,
     introspect_calls: usize,
+    column_metadata_batch_calls: usize,
     last_tenant_for_create: Option<String>,
     last_tenant_for_list: Option<String>,
     last_list_criteria: Option<ListRowsCriteria>,
+    last_get_criteria: Option<GetRowByPrimaryKeyCriteria>,
+    last_delete_soft_delete_column: Option<String>,
     create_should_fail: bool,
+    create_failure_message: Option<String>,
+    create_unique_violation: bool,
     patch_should_return_none: bool,
     get_should_return_none: bool,
+    stored_row_owner: Option<String>,
+    schema_missing: bool,
 }
 
 pub struct FakeDataApiRepository {
-    state: Mutex<FakeDataApiRepositoryState>,
+    state: Arc<Mutex<FakeDataApiRepositoryState>>,
 }
 
 impl FakeDataApiRepository {
@@ -57,30 +87,38 @@ impl FakeDataApiRepository {
                     is_nullable: false,
                     data_type: "integer".to_string(),
                     is_primary_key: true,
+                    ordinal_position: 1,
+                    column_default: Some("nextval('productos_id_seq'::regclass)".to_string()),
                 },
                 TableColumnMetadata {
                     column_name: "nombre".to_string(),
                     is_nullable: false,
                     data_type: "text".to_string(),
                     is_primary_key: false,
+                    ordinal_position: 2,
+                    column_default: None,
                 },
                 TableColumnMetadata {
                     column_name: "precio".to_string(),
                     is_nullable: false,
                     data_type: "numeric".to_string(),
                     is_primary_key: false,
+                    ordinal_position: 3,
+                    column_default: Some("0".to_string()),
                 },
                 TableColumnMetadata {
                     column_name: "image_url".to_string(),
                     is_nullable: true,
                     data_type: "text".to_string(),
                     is_primary_key: false,
+                    ordinal_position: 4,
+                    column_default: None,
                 },
             ],
         };
 
         Self {
-            state: Mutex::new(FakeDataApiRepositoryState {
+            state: Arc::new(Mutex::new(FakeDataApiRepositoryState {
                 metadata: Some(metadata),
                 access: Some(TableAccessMetadata {
                     exposed: true,
@@ -88,8 +126,10 @@ impl FakeDataApiRepository {
                     create_enabled: true,
                     update_enabled: true,
                     delete_enabled: true,
+                    append_only: false,
                     introspect_enabled: true,
                     authorization_mode: "acl".to_string(),
+                    soft_delete_column: None,
                 }),
                 writable_columns: vec![
                     "nombre".to_string(),
@@ -97,7 +137,69 @@ impl FakeDataApiRepository {
                     "image_url".to_string(),
                 ],
                 ..FakeDataApiRepositoryState::default()
-            }),
+            })),
+        }
+    }
+
+    fn stored_row(
+        state: &mut FakeDataApiRepositoryState,
+        criteria: GetRowByPrimaryKeyCriteria,
+    ) -> Option<Value> {
+        state.get_calls += 1;
+        state.last_get_criteria = Some(criteria.clone());
+        if state.get_should_return_none {
+            return None;
+        }
+
+        let mut row = json!({
+            criteria.primary_key_column: criteria.primary_key_value,
+            "nombre": "producto demo"
+        });
+        if let Some(owner_id) = &state.stored_row_owner {
+            row["owner_id"] = json!(owner_id);
+        }
+
+        Some(row)
+    }
+
+    fn apply_transaction_operation(
+        state: &FakeDataApiRepositoryState,
+        staged_rows: &mut Vec<Value>,
+        operation: TransactionOperationCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError> {
+        match operation {
+            TransactionOperationCriteria::Create(criteria) => {
+                if state.create_should_fail {
+                    return Err(DataApiDomainError::InfrastructureError(
+                        "create failed".to_string(),
+                    ));
+                }
+                staged_rows.push(criteria.payload.clone());
+                Ok(criteria.payload.clone())
+            }
+            TransactionOperationCriteria::CreateMany(criteria) => {
+                if state.create_should_fail {
+                    return Err(DataApiDomainError::InfrastructureError(
+                        state
+                            .create_failure_message
+                            .clone()
+                            .unwrap_or_else(|| "create failed".to_string()),
+                    ));
+                }
+                staged_rows.extend(criteria.payloads.iter().cloned());
+                Ok(Value::Array(criteria.payloads.to_vec()))
+            }
+            TransactionOperationCriteria::Patch(criteria) => {
+                if state.patch_should_return_none {
+                    return Err(DataApiDomainError::RecordNotFound);
+                }
+                Ok(json!({
+                    "table": criteria.table_name,
+                    "id": criteria.primary_key_value,
+                    "payload": criteria.payload,
+                }))
+            }
+            TransactionOperationCriteria::Delete(_) => Ok(Value::Null),
         }
     }
 
@@ -108,6 +210,19 @@ impl FakeDataApiRepository {
             .create_should_fail = value;
     }
 
+    pub fn set_create_failure_message(&self, message: &str) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.create_should_fail = true;
+        state.create_failure_message = Some(message.to_string());
+    }
+
+    pub fn set_create_unique_violation(&self, value: bool) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .create_unique_violation = value;
+    }
+
     pub fn set_patch_should_return_none(&self, value: bool) {
         self.state
             .lock()
@@ -115,6 +230,13 @@ impl FakeDataApiRepository {
             .patch_should_return_none = value;
     }
 
+    pub fn set_put_not_null_violation(&self, value: bool) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .put_not_null_violation = value;
+    }
+
     pub fn set_get_should_return_none(&self, value: bool) {
         self.state
             .lock()
@@ -122,6 +244,94 @@ impl FakeDataApiRepository {
             .get_should_return_none = value;
     }
 
+    pub fn set_schema_missing(&self, value: bool) {
+        self.state.lock().expect("mutex poisoned").schema_missing = value;
+    }
+
+    pub fn set_stored_row_owner(&self, owner_id: &str) {
+        self.state.lock().expect("mutex poisoned").stored_row_owner = Some(owner_id.to_string());
+    }
+
+    fn apply_column_metadata(
+        state: &mut FakeDataApiRepositoryState,
+        column_name: &str,
+        criteria: ColumnMetadataUpdateCriteria,
+    ) {
+        if criteria.writable {
+            if !state
+                .writable_columns
+                .iter()
+                .any(|column| column == column_name)
+            {
+                state.writable_columns.push(column_name.to_string());
+            }
+        } else {
+            state
+                .writable_columns
+                .retain(|column| column != column_name);
+        }
+        state
+            .read_transforms
+            .retain(|(column, _)| column != column_name);
+        if criteria.read_transform != DataApiReadTransform::None {
+            state
+                .read_transforms
+                .push((column_name.to_string(), criteria.read_transform));
+        }
+        let restrictions = &mut state.query_restrictions;
+        if let Some(filterable) = criteria.filterable {
+            restrictions
+                .non_filterable_columns
+                .retain(|column| column != column_name);
+            if !filterable {
+                restrictions
+                    .non_filterable_columns
+                    .push(column_name.to_string());
+            }
+        }
+        if let Some(orderable) = criteria.orderable {
+            restrictions
+                .non_orderable_columns
+                .retain(|column| column != column_name);
+            if !orderable {
+                restrictions
+                    .non_orderable_columns
+                    .push(column_name.to_string());
+            }
+        }
+    }
+
+    pub fn column_metadata_batch_calls(&self) -> usize {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .column_metadata_batch_calls
+    }
+
+    pub fn add_column(&self, column_name: &str) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if let Some(metadata) = &mut state.metadata {
+            let ordinal_position = metadata.columns.len() as i32 + 1;
+            metadata.columns.push(TableColumnMetadata {
+                column_name: column_name.to_string(),
+                is_nullable: true,
+                data_type: "text".to_string(),
+                is_primary_key: false,
+                ordinal_position,
+                column_default: None,
+            });
+        }
+    }
+
+    pub fn add_writable_column(&self, column_name: &str) {
+        self.add_column(column_name);
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .writable_columns
+            .push(column_name.to_string());
+    }
+
     pub fn set_without_primary_key(&self) {
         let mut state = self.state.lock().expect("mutex poisoned");
         if let Some(metadata) = &mut state.metadata {
@@ -131,6 +341,15 @@ impl FakeDataApiRepository {
         }
     }
 
+    pub fn set_primary_key_columns(&self, column_names: &[&str]) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if let Some(metadata) = &mut state.metadata {
+            for column in &mut metadata.columns {
+                column.is_primary_key = column_names.contains(&column.column_name.as_str());
+            }
+        }
+    }
+
     pub fn set_table_exposed(&self, exposed: bool) {
         let mut state = self.state.lock().expect("mutex poisoned");
         if let Some(access) = &mut state.access {
@@ -138,14 +357,100 @@ impl FakeDataApiRepository {
         }
     }
 
+    pub fn set_append_only(&self, append_only: bool) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if let Some(access) = &mut state.access {
+            access.append_only = append_only;
+        }
+    }
+
+    pub fn set_authorization_mode(&self, authorization_mode: &str) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if let Some(access) = &mut state.access {
+            access.authorization_mode = authorization_mode.to_string();
+        }
+    }
+
+    pub fn set_soft_delete_column(&self, column_name: &str) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        if let Some(access) = &mut state.access {
+            access.soft_delete_column = Some(column_name.to_string());
+        }
+    }
+
+    pub fn set_table_dropped(&self) {
+        self.state.lock().expect("mutex poisoned").metadata = None;
+    }
+
+    pub fn table_metadata_rows(&self) -> Vec<(String, String, TableAccessMetadata)> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .table_metadata_rows
+            .iter()
+            .map(|((schema_name, table_name), metadata)| {
+                (schema_name.clone(), table_name.clone(), metadata.clone())
+            })
+            .collect()
+    }
+
+    pub fn set_read_transform(&self, column_name: &str, transform: DataApiReadTransform) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .read_transforms
+            .push((column_name.to_string(), transform));
+    }
+
+    pub fn set_non_filterable_column(&self, column_name: &str) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .query_restrictions
+            .non_filterable_columns
+            .push(column_name.to_string());
+    }
+
     pub fn create_calls(&self) -> usize {
         self.state.lock().expect("mutex poisoned").create_calls
     }
 
+    pub fn create_rows_calls(&self) -> usize {
+        self.state.lock().expect("mutex poisoned").create_rows_calls
+    }
+
+    pub fn created_rows(&self) -> Vec<Value> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .created_rows
+            .clone()
+    }
+
+    pub fn transaction_calls(&self) -> usize {
+        self.state.lock().expect("mutex poisoned").transaction_calls
+    }
+
+    pub fn count_calls(&self) -> usize {
+        self.state.lock().expect("mutex poisoned").count_calls
+    }
+
     pub fn patch_calls(&self) -> usize {
         self.state.lock().expect("mutex poisoned").patch_calls
     }
 
+    pub fn put_calls(&self) -> usize {
+        self.state.lock().expect("mutex poisoned").put_calls
+    }
+
+    pub fn last_put_columns(&self) -> Option<(Vec<String>, Vec<String>)> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .last_put_columns
+            .clone()
+    }
+
     pub fn last_tenant_for_create(&self) -> Option<String> {
         self.state
             .lock()
@@ -162,6 +467,22 @@ impl FakeDataApiRepository {
             .clone()
     }
 
+    pub fn last_get_criteria(&self) -> Option<GetRowByPrimaryKeyCriteria> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .last_get_criteria
+            .clone()
+    }
+
+    pub fn last_delete_soft_delete_column(&self) -> Option<String> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .last_delete_soft_delete_column
+            .clone()
+    }
+
     pub fn last_tenant_for_list(&self) -> Option<String> {
         self.state
             .lock()
@@ -178,6 +499,10 @@ impl DataApiRepository for FakeDataApiRepository {
         _tenant_id: &TenantId,
         _schema_name: &str,
     ) -> Result<(), DataApiDomainError> {
+        if self.state.lock().expect("mutex poisoned").schema_missing {
+            return Err(DataApiDomainError::SchemaNotFound);
+        }
+
         Ok(())
     }
 
@@ -204,6 +529,26 @@ impl DataApiRepository for FakeDataApiRepository {
         Ok(state.writable_columns.clone())
     }
 
+    async fn list_read_transforms(
+        &self,
+        _tenant_id: &TenantId,
+        _schema_name: &str,
+        _table_name: &str,
+    ) -> Result<Vec<(String, DataApiReadTransform)>, DataApiDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+        Ok(state.read_transforms.clone())
+    }
+
+    async fn list_column_query_restrictions(
+        &self,
+        _tenant_id: &TenantId,
+        _schema_name: &str,
+        _table_name: &str,
+    ) -> Result<ColumnQueryRestrictions, DataApiDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+        Ok(state.query_restrictions.clone())
+    }
+
     async fn list_access_catalog(
         &self,
         _tenant_id: &TenantId,
@@ -222,29 +567,54 @@ impl DataApiRepository for FakeDataApiRepository {
             create_enabled: access.create_enabled,
             update_enabled: access.update_enabled,
             delete_enabled: access.delete_enabled,
+            append_only: access.append_only,
             introspect_enabled: access.introspect_enabled,
             authorization_mode: access.authorization_mode,
+            soft_delete_column: access.soft_delete_column,
             writable_columns: state.writable_columns.clone(),
+            creatable_columns: None,
+            updatable_columns: None,
         }])
     }
 
     async fn upsert_table_access_metadata(
         &self,
         _tenant_id: &TenantId,
-        _schema_name: &str,
-        _table_name: &str,
+        schema_name: &str,
+        table_name: &str,
         criteria: TableMetadataUpdateCriteria,
     ) -> Result<TableAccessMetadata, DataApiDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
+        let Some(table_schema) = &state.metadata else {
+            return Err(DataApiDomainError::TableNotFound);
+        };
+        if let Some(soft_delete_column) = criteria.soft_delete_column.as_deref()
+            && !table_schema.has_column(soft_delete_column)
+        {
+            return Err(DataApiDomainError::InvalidColumnName(
+                soft_delete_column.to_string(),
+            ));
+        }
+        let stored_append_only = state
+            .table_metadata_rows
+            .get(&(schema_name.to_string(), table_name.to_string()))
+            .or(state.access.as_ref())
+            .is_some_and(|metadata| metadata.append_only);
         let metadata = TableAccessMetadata {
             exposed: criteria.exposed,
             read_enabled: criteria.read_enabled,
             create_enabled: criteria.create_enabled,
             update_enabled: criteria.update_enabled,
             delete_enabled: criteria.delete_enabled,
+            append_only: criteria.append_only.unwrap_or(stored_append_only),
             introspect_enabled: criteria.introspect_enabled,
             authorization_mode: criteria.authorization_mode,
+            soft_delete_column: criteria.soft_delete_column,
         };
+        state.table_metadata_rows.insert(
+            (schema_name.to_string(), table_name.to_string()),
+            metadata.clone(),
+        );
         state.access = Some(metadata.clone());
         Ok(metadata)
     }
@@ -258,18 +628,21 @@ impl DataApiRepository for FakeDataApiRepository {
         criteria: ColumnMetadataUpdateCriteria,
     ) -> Result<(), DataApiDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
-        if criteria.writable {
-            if !state
-                .writable_columns
-                .iter()
-                .any(|column| column == column_name)
-            {
-                state.writable_columns.push(column_name.to_string());
-            }
-        } else {
-            state
-                .writable_columns
-                .retain(|column| column != column_name);
+        Self::apply_column_metadata(&mut state, column_name, criteria);
+        Ok(())
+    }
+
+    async fn upsert_column_access_metadata_batch(
+        &self,
+        _tenant_id: &TenantId,
+        _schema_name: &str,
+        _table_name: &str,
+        columns: &[(String, ColumnMetadataUpdateCriteria)],
+    ) -> Result<(), DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.column_metadata_batch_calls += 1;
+        for (column_name, criteria) in columns {
+            Self::apply_column_metadata(&mut state, column_name, criteria.clone());
         }
         Ok(())
     }
@@ -304,21 +677,35 @@ impl DataApiRepository for FakeDataApiRepository {
         Ok(json!([{"id": 1, "nombre": "producto demo"}]))
     }
 
+    async fn count_rows(
+        &self,
+        _tenant_id: &TenantId,
+        _criteria: ListRowsCriteria,
+    ) -> Result<i64, DataApiDomainError> {
+        self.state.lock().expect("mutex poisoned").count_calls += 1;
+        Ok(1)
+    }
+
+    fn describe_list_rows(
+        &self,
+        criteria: ListRowsCriteria,
+    ) -> Result<ListRowsStatement, DataApiDomainError> {
+        Ok(ListRowsStatement {
+            sql: format!(
+                "SELECT * FROM \"{}\".\"{}\"",
+                criteria.schema_name, criteria.table_name
+            ),
+            parameter_count: criteria.filters.len() + 2,
+        })
+    }
+
     async fn get_row_by_primary_key(
         &self,
         _tenant_id: &TenantId,
         criteria: GetRowByPrimaryKeyCriteria,
     ) -> Result<Option<Value>, DataApiDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
-        state.get_calls += 1;
-        if state.get_should_return_none {
-            return Ok(None);
-        }
-
-        Ok(Some(json!({
-            criteria.primary_key_column: criteria.primary_key_value,
-            "nombre": "producto demo"
-        })))
+        Ok(Self::stored_row(&mut state, criteria))
     }
 
     async fn create_row(
@@ -329,9 +716,15 @@ impl DataApiRepository for FakeDataApiRepository {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.create_calls += 1;
         state.last_tenant_for_create = Some(tenant_id.value().to_string());
+        if state.create_unique_violation {
+            return Err(DataApiDomainError::UniqueViolation);
+        }
         if state.create_should_fail {
             return Err(DataApiDomainError::InfrastructureError(
-                "create failed".to_string(),
+                state
+                    .create_failure_message
+                    .clone()
+                    .unwrap_or_else(|| "create failed".to_string()),
             ));
         }
 
@@ -343,6 +736,26 @@ impl DataApiRepository for FakeDataApiRepository {
         }))
     }
 
+    async fn create_rows(
+        &self,
+        _tenant_id: &TenantId,
+        criteria: CreateRowsCriteria<'_>,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.create_rows_calls += 1;
+        if state.create_unique_violation {
+            return Err(DataApiDomainError::UniqueViolation);
+        }
+        if state.create_should_fail {
+            return Err(DataApiDomainError::InfrastructureError(
+                "create failed".to_string(),
+            ));
+        }
+
+        state.created_rows.extend(criteria.payloads.iter().cloned());
+        Ok(criteria.payloads.to_vec())
+    }
+
     async fn patch_row(
         &self,
         _tenant_id: &TenantId,
@@ -362,15 +775,105 @@ impl DataApiRepository for FakeDataApiRepository {
         })))
     }
 
+    async fn put_row(
+        &self,
+        _tenant_id: &TenantId,
+        criteria: PutRowCriteria<'_>,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.put_calls += 1;
+        state.last_put_columns = Some((
+            criteria.assigned_columns.to_vec(),
+            criteria.defaulted_columns.to_vec(),
+        ));
+        if state.put_not_null_violation {
+            return Err(DataApiDomainError::InvalidPayload);
+        }
+
+        Ok(Some(json!({
+            "schema": criteria.schema_name,
+            "table": criteria.table_name,
+            "id": criteria.primary_key_value,
+            "payload": criteria.payload,
+        })))
+    }
+
     async fn delete_row(
         &self,
         _tenant_id: &TenantId,
-        _criteria: DeleteRowCriteria<'_>,
+        criteria: DeleteRowCriteria<'_>,
     ) -> Result<bool, DataApiDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.delete_calls += 1;
+        state.last_delete_soft_delete_column = criteria.soft_delete_column.map(str::to_string);
         Ok(true)
     }
+
+    async fn execute_transaction(
+        &self,
+        _tenant_id: &TenantId,
+        operations: Vec<TransactionOperationCriteria<'_>>,
+    ) -> Result<Vec<Value>, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.transaction_calls += 1;
+
+        let mut staged_rows = Vec::new();
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in operations {
+            results.push(Self::apply_transaction_operation(
+                &state,
+                &mut staged_rows,
+                operation,
+            )?);
+        }
+
+        state.created_rows.extend(staged_rows);
+        Ok(results)
+    }
+
+    async fn begin_transaction(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<Box<dyn DataApiTransaction>, DataApiDomainError> {
+        self.state.lock().expect("mutex poisoned").transaction_calls += 1;
+        Ok(Box::new(FakeDataApiTransaction {
+            state: self.state.clone(),
+            staged_rows: Vec::new(),
+        }))
+    }
+}
+
+pub struct FakeDataApiTransaction {
+    state: Arc<Mutex<FakeDataApiRepositoryState>>,
+    staged_rows: Vec<Value>,
+}
+
+#[async_trait]
+impl DataApiTransaction for FakeDataApiTransaction {
+    async fn get_row_by_primary_key(
+        &mut self,
+        criteria: GetRowByPrimaryKeyCriteria,
+    ) -> Result<Option<Value>, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        Ok(FakeDataApiRepository::stored_row(&mut state, criteria))
+    }
+
+    async fn execute(
+        &mut self,
+        operation: TransactionOperationCriteria<'_>,
+    ) -> Result<Value, DataApiDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+        FakeDataApiRepository::apply_transaction_operation(&state, &mut self.staged_rows, operation)
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), DataApiDomainError> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .created_rows
+            .extend(self.staged_rows);
+        Ok(())
+    }
 }
 
 #[derive(Default)]
@@ -411,6 +914,38 @@ impl TenantSchemaResolverRepository for FakeTenantSchemaResolverRepository {
     }
 }
 
+pub struct FakeTenantConnectionResolverRepository {
+    database_url: String,
+}
+
+impl FakeTenantConnectionResolverRepository {
+    pub fn new(database_url: &str) -> Self {
+        Self {
+            database_url: database_url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl TenantConnectionResolverRepository for FakeTenantConnectionResolverRepository {
+    async fn resolve_database_url(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<String, DataApiDomainError> {
+        Ok(self.database_url.clone())
+    }
+
+    async fn resolve_connection_target(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<TenantConnectionTarget, DataApiDomainError> {
+        Ok(TenantConnectionTarget {
+            database_url: self.database_url.clone(),
+            session_parameters: Vec::new(),
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AccessCheckCall {
     pub tenant_id: String,
@@ -421,12 +956,57 @@ pub struct AccessCheckCall {
     pub request_id: Option<String>,
     pub subject_owner_id: Option<String>,
     pub row_owner_id: Option<String>,
+    pub bypass_decision_cache: bool,
+    pub audit_decision: bool,
 }
 
 #[derive(Default)]
 struct FakeAccessControlState {
     calls: Vec<AccessCheckCall>,
+    permitted_columns_calls: Vec<AccessCheckCall>,
+    effective_columns_calls: Vec<DataApiEffectiveColumnsRequest>,
     deny: bool,
+    denied_tables: Vec<String>,
+    denied_columns: Vec<String>,
+    denied_update_columns: Vec<String>,
+    column_scoped_denials: Vec<String>,
+    owner_scoped: bool,
+    principals_with_roles: Vec<String>,
+    role_membership_checks: usize,
+}
+
+impl FakeAccessControlState {
+    fn denies(&self, request: &DataApiAuthorizationCheckRequest) -> bool {
+        self.deny
+            || self.denied_tables.contains(&request.resource_name)
+            || request
+                .requested_columns
+                .iter()
+                .any(|column| self.denied_columns.contains(column))
+            || (!request.requested_columns.is_empty()
+                && request
+                    .requested_columns
+                    .iter()
+                    .all(|column| self.column_scoped_denials.contains(column)))
+            || (self.owner_scoped
+                && (request.subject_owner_id.is_none()
+                    || request.subject_owner_id != request.row_owner_id))
+    }
+
+    fn record(calls: &mut Vec<AccessCheckCall>, request: DataApiAuthorizationCheckRequest) {
+        calls.push(AccessCheckCall {
+            tenant_id: request.tenant_id,
+            principal: request.principal_id,
+            table_name: request.resource_name,
+            action_name: request.action_name,
+            columns: request.requested_columns,
+            request_id: request.request_id,
+            subject_owner_id: request.subject_owner_id,
+            row_owner_id: request.row_owner_id,
+            bypass_decision_cache: request.bypass_decision_cache,
+            audit_decision: request.audit_decision,
+        });
+    }
 }
 
 pub struct FakeAccessControlFacade {
@@ -444,9 +1024,70 @@ impl FakeAccessControlFacade {
         self.state.lock().expect("mutex poisoned").calls.clone()
     }
 
+    pub fn permitted_columns_calls(&self) -> Vec<AccessCheckCall> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .permitted_columns_calls
+            .clone()
+    }
+
+    pub fn effective_columns_calls(&self) -> Vec<DataApiEffectiveColumnsRequest> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .effective_columns_calls
+            .clone()
+    }
+
     pub fn set_deny(&self, deny: bool) {
         self.state.lock().expect("mutex poisoned").deny = deny;
     }
+
+    pub fn set_denied_tables(&self, tables: &[&str]) {
+        self.state.lock().expect("mutex poisoned").denied_tables =
+            tables.iter().map(|table| table.to_string()).collect();
+    }
+
+    pub fn set_denied_columns(&self, columns: &[&str]) {
+        self.state.lock().expect("mutex poisoned").denied_columns =
+            columns.iter().map(|column| column.to_string()).collect();
+    }
+
+    pub fn set_denied_update_columns(&self, columns: &[&str]) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .denied_update_columns = columns.iter().map(|column| column.to_string()).collect();
+    }
+
+    pub fn set_column_scoped_denials(&self, columns: &[&str]) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .column_scoped_denials = columns.iter().map(|column| column.to_string()).collect();
+    }
+
+    pub fn set_owner_scoped(&self, owner_scoped: bool) {
+        self.state.lock().expect("mutex poisoned").owner_scoped = owner_scoped;
+    }
+
+    pub fn set_principals_with_roles(&self, principals: &[&str]) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .principals_with_roles = principals
+            .iter()
+            .map(|principal| principal.to_string())
+            .collect();
+    }
+
+    pub fn role_membership_checks(&self) -> usize {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .role_membership_checks
+    }
 }
 
 #[async_trait]
@@ -456,30 +1097,91 @@ impl AccessControlFacade for FakeAccessControlFacade {
         request: DataApiAuthorizationCheckRequest,
     ) -> Result<(), DataApiDomainError> {
         let mut state = self.state.lock().expect("mutex poisoned");
-        state.calls.push(AccessCheckCall {
-            tenant_id: request.tenant_id,
-            principal: request.principal_id,
-            table_name: request.resource_name,
-            action_name: request.action_name,
-            columns: request.requested_columns,
-            request_id: request.request_id,
-            subject_owner_id: request.subject_owner_id,
-            row_owner_id: request.row_owner_id,
-        });
+        let denied = state.denies(&request);
+        FakeAccessControlState::record(&mut state.calls, request);
 
-        if state.deny {
+        if denied {
             return Err(DataApiDomainError::AccessDenied);
         }
 
         Ok(())
     }
 
+    async fn permitted_columns(
+        &self,
+        request: DataApiAuthorizationCheckRequest,
+    ) -> Result<Vec<String>, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        let permitted = request
+            .requested_columns
+            .iter()
+            .filter(|column| {
+                !state.denies(&DataApiAuthorizationCheckRequest {
+                    requested_columns: vec![(*column).clone()],
+                    ..request.clone()
+                })
+            })
+            .cloned()
+            .collect();
+        FakeAccessControlState::record(&mut state.permitted_columns_calls, request);
+
+        Ok(permitted)
+    }
+
+    async fn effective_write_columns(
+        &self,
+        request: DataApiEffectiveColumnsRequest,
+    ) -> Result<DataApiEffectiveWriteColumns, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        let permitted = |action: DataApiAction| {
+            request
+                .requested_columns
+                .iter()
+                .filter(|column| {
+                    !state.denies(&DataApiAuthorizationCheckRequest {
+                        tenant_id: request.tenant_id.clone(),
+                        principal_id: request.principal_id.clone(),
+                        resource_name: request.resource_name.clone(),
+                        action_name: action.as_str().to_string(),
+                        requested_columns: vec![(*column).clone()],
+                        subject_owner_id: request.subject_owner_id.clone(),
+                        row_owner_id: request.row_owner_id.clone(),
+                        request_id: None,
+                        bypass_decision_cache: false,
+                        audit_decision: false,
+                    })
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let columns = DataApiEffectiveWriteColumns {
+            create_columns: permitted(DataApiAction::Create),
+            update_columns: permitted(DataApiAction::Update)
+                .into_iter()
+                .filter(|column| !state.denied_update_columns.contains(column))
+                .collect(),
+        };
+        state.effective_columns_calls.push(request);
+
+        Ok(columns)
+    }
+
     async fn bootstrap_table_access(
         &self,
         _request: DataApiAuthorizationBootstrapRequest,
     ) -> Result<(), DataApiDomainError> {
         Ok(())
     }
+
+    async fn principal_has_any_role(
+        &self,
+        request: DataApiRoleMembershipRequest,
+    ) -> Result<bool, DataApiDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.role_membership_checks += 1;
+
+        Ok(state.principals_with_roles.contains(&request.principal_id))
+    }
 }
 
 pub struct FakeDataApiAuditLogRepository {
@@ -1,15 +1,23 @@
-use std::collections::BTreeMap;
-
 use serde_json::{Value, json};
 use swagger_axum_api::data_api::domain::model::{
     commands::{
         create_row_command::{CreateRowCommand, CreateRowCommandParts},
+        create_rows_command::{CreateRowsCommand, CreateRowsCommandParts},
+        delete_row_command::{DeleteRowCommand, DeleteRowCommandParts},
+        import_rows_command::{ImportRowsCommand, ImportRowsCommandParts},
         patch_row_command::{PatchRowCommand, PatchRowCommandParts},
+        replace_row_command::{ReplaceRowCommand, ReplaceRowCommandParts},
+    },
+    enums::{
+        data_api_filter_operator::DataApiFilterOperator,
+        data_api_principal_type::DataApiPrincipalType,
     },
-    enums::data_api_principal_type::DataApiPrincipalType,
     queries::{
         get_row_query::{GetRowQuery, GetRowQueryParts},
         list_rows_query::{ListRowsQuery, ListRowsQueryParts},
+        table_schema_introspection_query::{
+            TableSchemaIntrospectionQuery, TableSchemaIntrospectionQueryParts,
+        },
     },
 };
 
@@ -32,6 +40,54 @@ pub fn create_row_command(payload: Value) -> CreateRowCommand {
     .expect("valid command")
 }
 
+pub fn create_rows_command(payloads: Vec<Value>) -> CreateRowsCommand {
+    CreateRowsCommand::new(CreateRowsCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-1".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        payloads,
+    })
+    .expect("valid command")
+}
+
+pub fn delete_row_command() -> DeleteRowCommand {
+    DeleteRowCommand::new(DeleteRowCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        row_identifier: "1".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-6".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+    })
+    .expect("valid command")
+}
+
+pub fn import_rows_command(csv_content: &str) -> ImportRowsCommand {
+    ImportRowsCommand::new(ImportRowsCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-5".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        csv_content: csv_content.to_string(),
+    })
+    .expect("valid command")
+}
+
 pub fn patch_row_command(payload: Value) -> PatchRowCommand {
     PatchRowCommand::new(PatchRowCommandParts {
         api_version: "v1".to_string(),
@@ -45,15 +101,61 @@ pub fn patch_row_command(payload: Value) -> PatchRowCommand {
         subject_owner_id: Some("owner-1".to_string()),
         row_owner_id: Some("owner-2".to_string()),
         payload,
+        skip_null_values: false,
+    })
+    .expect("valid command")
+}
+
+pub fn patch_row_command_skipping_nulls(payload: Value) -> PatchRowCommand {
+    PatchRowCommand::new(PatchRowCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        row_identifier: "1".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-2".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-2".to_string()),
+        payload,
+        skip_null_values: true,
+    })
+    .expect("valid command")
+}
+
+pub fn replace_row_command(payload: Value) -> ReplaceRowCommand {
+    ReplaceRowCommand::new(ReplaceRowCommandParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        row_identifier: "1".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-2".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-2".to_string()),
+        payload,
     })
     .expect("valid command")
 }
 
 pub fn list_rows_query() -> ListRowsQuery {
-    let filters = BTreeMap::from([
-        ("nombre".to_string(), "Mouse".to_string()),
-        ("campo_inexistente".to_string(), "x".to_string()),
-    ]);
+    let filters = vec![
+        (
+            "campo_inexistente".to_string(),
+            DataApiFilterOperator::Eq("x".to_string()),
+        ),
+        (
+            "nombre".to_string(),
+            DataApiFilterOperator::Eq("Mouse".to_string()),
+        ),
+        (
+            "precio".to_string(),
+            DataApiFilterOperator::Gte("10".to_string()),
+        ),
+    ];
 
     ListRowsQuery::new(ListRowsQueryParts {
         api_version: "v1".to_string(),
@@ -69,8 +171,125 @@ pub fn list_rows_query() -> ListRowsQuery {
         filters,
         limit: 20,
         offset: 0,
+        after: None,
         order_by: Some("campo_inexistente".to_string()),
         order_desc: false,
+        numeric_as_string: false,
+        lenient_projection: false,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
+
+pub fn list_rows_numeric_as_string_query() -> ListRowsQuery {
+    ListRowsQuery::new(ListRowsQueryParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-3".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        select_fields: vec!["id".to_string(), "precio".to_string()],
+        filters: Vec::new(),
+        limit: 20,
+        offset: 0,
+        after: None,
+        order_by: None,
+        order_desc: false,
+        numeric_as_string: true,
+        lenient_projection: false,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
+
+pub fn list_rows_default_select_query() -> ListRowsQuery {
+    ListRowsQuery::new(ListRowsQueryParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-3".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        select_fields: Vec::new(),
+        filters: Vec::new(),
+        limit: 20,
+        offset: 0,
+        after: None,
+        order_by: None,
+        order_desc: false,
+        numeric_as_string: false,
+        lenient_projection: false,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
+
+pub fn list_rows_without_subject_owner_query() -> ListRowsQuery {
+    ListRowsQuery::new(ListRowsQueryParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-3".to_string()),
+        subject_owner_id: None,
+        row_owner_id: None,
+        select_fields: Vec::new(),
+        filters: Vec::new(),
+        limit: 20,
+        offset: 0,
+        after: None,
+        order_by: None,
+        order_desc: false,
+        numeric_as_string: false,
+        lenient_projection: false,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
+
+pub fn list_rows_lenient_projection_query(select_fields: &[&str]) -> ListRowsQuery {
+    ListRowsQuery::new(ListRowsQueryParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-3".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        select_fields: select_fields
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
+        filters: Vec::new(),
+        limit: 20,
+        offset: 0,
+        after: None,
+        order_by: None,
+        order_desc: false,
+        numeric_as_string: false,
+        lenient_projection: true,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
     })
     .expect("valid query")
 }
@@ -87,6 +306,22 @@ pub fn get_row_query() -> GetRowQuery {
         request_id: Some("req-4".to_string()),
         subject_owner_id: Some("owner-1".to_string()),
         row_owner_id: Some("owner-1".to_string()),
+        numeric_as_string: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
+
+pub fn table_schema_introspection_query() -> TableSchemaIntrospectionQuery {
+    TableSchemaIntrospectionQuery::new(TableSchemaIntrospectionQueryParts {
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-5".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
     })
     .expect("valid query")
 }
@@ -98,3 +333,30 @@ pub fn sample_payload() -> Value {
         "image_url": "https://image.example/test.png"
     })
 }
+
+pub fn list_rows_ordered_by_query(order_by: &str) -> ListRowsQuery {
+    ListRowsQuery::new(ListRowsQueryParts {
+        api_version: "v1".to_string(),
+        tenant_id: TENANT_1_ID.to_string(),
+        schema_name: "public".to_string(),
+        table_name: "productos".to_string(),
+        principal: "api-key-test".to_string(),
+        principal_type: DataApiPrincipalType::ApiKey,
+        request_id: Some("req-3".to_string()),
+        subject_owner_id: Some("owner-1".to_string()),
+        row_owner_id: Some("owner-1".to_string()),
+        select_fields: Vec::new(),
+        filters: Vec::new(),
+        limit: 1,
+        offset: 0,
+        after: None,
+        order_by: Some(order_by.to_string()),
+        order_desc: false,
+        numeric_as_string: false,
+        lenient_projection: false,
+        debug_sql: false,
+        include_count: false,
+        bypass_decision_cache: false,
+    })
+    .expect("valid query")
+}
@@ -0,0 +1,90 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+use sqlx::error::{DatabaseError, ErrorKind};
+use swagger_axum_api::data_api::{
+    domain::model::enums::data_api_domain_error::DataApiDomainError,
+    infrastructure::persistence::repositories::postgres::sqlx_data_api_repository_impl::SqlxDataApiRepositoryImpl,
+};
+
+#[derive(Debug)]
+struct FakeDatabaseError {
+    code: &'static str,
+}
+
+impl fmt::Display for FakeDatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "database error {}", self.code)
+    }
+}
+
+impl Error for FakeDatabaseError {}
+
+impl DatabaseError for FakeDatabaseError {
+    fn message(&self) -> &str {
+        "database error"
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self.code))
+    }
+
+    fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+fn database_error(code: &'static str) -> sqlx::Error {
+    sqlx::Error::Database(Box::new(FakeDatabaseError { code }))
+}
+
+#[test]
+fn unique_violation_maps_to_dedicated_variant() {
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_write_error(database_error("23505")),
+        DataApiDomainError::UniqueViolation
+    ));
+}
+
+#[test]
+fn foreign_key_violation_maps_to_dedicated_variant() {
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_write_error(database_error("23503")),
+        DataApiDomainError::ForeignKeyViolation
+    ));
+}
+
+#[test]
+fn not_null_violation_on_replace_maps_to_invalid_payload() {
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_replace_error(database_error("23502")),
+        DataApiDomainError::InvalidPayload
+    ));
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_replace_error(database_error("23505")),
+        DataApiDomainError::UniqueViolation
+    ));
+}
+
+#[test]
+fn other_sql_states_remain_infrastructure_errors() {
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_write_error(database_error("23502")),
+        DataApiDomainError::InfrastructureError(_)
+    ));
+    assert!(matches!(
+        SqlxDataApiRepositoryImpl::map_write_error(sqlx::Error::RowNotFound),
+        DataApiDomainError::InfrastructureError(_)
+    ));
+}
@@ -0,0 +1,8 @@
+#[path = "health/deep_health_tests.rs"]
+mod deep_health_tests;
+#[path = "health/readiness_tests.rs"]
+mod readiness_tests;
+#[path = "health/support.rs"]
+mod support;
+#[path = "health/tenant_health_monitor_tests.rs"]
+mod tenant_health_monitor_tests;
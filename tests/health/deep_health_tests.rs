@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use swagger_axum_api::health::application::deep_health_service::DeepHealthService;
+
+use crate::support::FakeComponentHealthProbe;
+
+#[tokio::test]
+async fn check_reports_healthy_when_all_components_respond() {
+    let service = DeepHealthService::new(vec![
+        Arc::new(FakeComponentHealthProbe::healthy("provisioner")),
+        Arc::new(FakeComponentHealthProbe::healthy("data_api")),
+        Arc::new(FakeComponentHealthProbe::healthy("access_control")),
+    ]);
+
+    let report = service.check().await;
+
+    assert!(report.healthy);
+    assert_eq!(report.components.len(), 3);
+    assert!(report.components.values().all(|c| c.healthy));
+}
+
+#[tokio::test]
+async fn check_reports_degraded_component_when_dependency_is_down() {
+    let service = DeepHealthService::new(vec![
+        Arc::new(FakeComponentHealthProbe::healthy("provisioner")),
+        Arc::new(FakeComponentHealthProbe::failing(
+            "data_api",
+            "connection refused",
+        )),
+        Arc::new(FakeComponentHealthProbe::healthy("access_control")),
+    ]);
+
+    let report = service.check().await;
+
+    assert!(!report.healthy);
+    let data_api = report
+        .components
+        .get("data_api")
+        .expect("data_api component should be reported");
+    assert!(!data_api.healthy);
+    assert_eq!(data_api.detail.as_deref(), Some("connection refused"));
+    assert!(report.components["provisioner"].healthy);
+    assert!(report.components["access_control"].healthy);
+}
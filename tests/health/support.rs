@@ -0,0 +1,4 @@
+#[path = "support/fakes.rs"]
+mod fakes;
+
+pub use fakes::{FakeActiveTenantSource, FakeComponentHealthProbe, FakeTenantConnectionResolver};
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use swagger_axum_api::{
+    data_api::{
+        domain::model::{
+            enums::data_api_domain_error::DataApiDomainError, value_objects::tenant_id::TenantId,
+        },
+        infrastructure::persistence::repositories::tenant_connection_resolver_repository::{
+            TenantConnectionResolverRepository, TenantConnectionTarget,
+        },
+    },
+    health::domain::{
+        active_tenant_source::ActiveTenantSource, component_health_probe::ComponentHealthProbe,
+    },
+};
+
+pub struct FakeComponentHealthProbe {
+    component: String,
+    failure: Option<String>,
+}
+
+impl FakeComponentHealthProbe {
+    pub fn healthy(component: &str) -> Self {
+        Self {
+            component: component.to_string(),
+            failure: None,
+        }
+    }
+
+    pub fn failing(component: &str, failure: &str) -> Self {
+        Self {
+            component: component.to_string(),
+            failure: Some(failure.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl ComponentHealthProbe for FakeComponentHealthProbe {
+    fn component(&self) -> &str {
+        &self.component
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        match &self.failure {
+            Some(failure) => Err(failure.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct FakeActiveTenantSource {
+    tenant_ids: Vec<String>,
+}
+
+impl FakeActiveTenantSource {
+    pub fn new(tenant_ids: &[&str]) -> Self {
+        Self {
+            tenant_ids: tenant_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl ActiveTenantSource for FakeActiveTenantSource {
+    async fn list_active_tenant_ids(&self) -> Result<Vec<String>, String> {
+        Ok(self.tenant_ids.clone())
+    }
+}
+
+pub struct FakeTenantConnectionResolver {
+    database_urls: HashMap<String, String>,
+}
+
+impl FakeTenantConnectionResolver {
+    pub fn new(database_urls: &[(&str, &str)]) -> Self {
+        Self {
+            database_urls: database_urls
+                .iter()
+                .map(|(tenant_id, url)| (tenant_id.to_string(), url.to_string()))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl TenantConnectionResolverRepository for FakeTenantConnectionResolver {
+    async fn resolve_database_url(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<String, DataApiDomainError> {
+        self.database_urls
+            .get(&tenant_id.value().to_string())
+            .cloned()
+            .ok_or(DataApiDomainError::TenantDatabaseNotFound)
+    }
+
+    async fn resolve_connection_target(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<TenantConnectionTarget, DataApiDomainError> {
+        Ok(TenantConnectionTarget {
+            database_url: self.resolve_database_url(tenant_id).await?,
+            session_parameters: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "openapi/dump_openapi_tests.rs"]
+mod dump_openapi_tests;
@@ -0,0 +1,28 @@
+use std::process::Command;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+#[test]
+fn dump_openapi_writes_document_and_exits_without_database() {
+    let path = std::env::temp_dir().join(format!("openapi-{}.json", Uuid::now_v7()));
+
+    let status = Command::new(env!("CARGO_BIN_EXE_swagger-axum-api"))
+        .arg("--dump-openapi")
+        .arg(&path)
+        .env("POSTGRES_HOST", "invalid.invalid")
+        .status()
+        .expect("binary should run");
+
+    assert!(status.success());
+    let contents = std::fs::read_to_string(&path).expect("openapi file should exist");
+    std::fs::remove_file(&path).expect("openapi file should be removable");
+    let document: Value = serde_json::from_str(&contents).expect("openapi should be json");
+    assert!(
+        document["openapi"]
+            .as_str()
+            .is_some_and(|version| version.starts_with("3."))
+    );
+    assert!(document["paths"]["/api/v1/{table_name}"].is_object());
+    assert!(document["components"]["schemas"]["DataApiErrorResponseResource"].is_object());
+}
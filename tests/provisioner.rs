@@ -4,5 +4,17 @@ mod change_password_provisioning_tests;
 mod create_provisioning_tests;
 #[path = "provisioner/delete_provisioning_tests.rs"]
 mod delete_provisioning_tests;
+#[path = "provisioner/identifier_guard_tests.rs"]
+mod identifier_guard_tests;
+#[path = "provisioner/postgres_administration_repository_tests.rs"]
+mod postgres_administration_repository_tests;
+#[path = "provisioner/rest_controller_tests.rs"]
+mod rest_controller_tests;
+#[path = "provisioner/retry_provisioning_tests.rs"]
+mod retry_provisioning_tests;
+#[path = "provisioner/seed_source_tests.rs"]
+mod seed_source_tests;
 #[path = "provisioner/support.rs"]
 mod support;
+#[path = "provisioner/validate_provisioning_tests.rs"]
+mod validate_provisioning_tests;
@@ -1,12 +1,18 @@
-use swagger_axum_api::provisioner::domain::{
-    model::enums::{
-        provisioned_database_status::ProvisionedDatabaseStatus,
-        provisioner_domain_error::ProvisionerDomainError,
+use swagger_axum_api::{
+    config::credential_cipher::CredentialCipher,
+    provisioner::domain::{
+        model::enums::{
+            provisioned_database_status::ProvisionedDatabaseStatus,
+            provisioner_domain_error::ProvisionerDomainError,
+        },
+        services::database_provisioning_command_service::DatabaseProvisioningCommandService,
     },
-    services::database_provisioning_command_service::DatabaseProvisioningCommandService,
 };
 
-use crate::support::{change_password_command, create_harness, database_with_status};
+use crate::support::{
+    change_password_command, create_harness, create_harness_with_credential_cipher,
+    create_harness_with_password_change_limit, database_with_status,
+};
 
 #[tokio::test]
 async fn handle_change_password_succeeds_for_active_database() {
@@ -32,6 +38,35 @@ async fn handle_change_password_succeeds_for_active_database() {
     );
 }
 
+#[tokio::test]
+async fn handle_change_password_invalidates_cached_tenant_pools() {
+    let harness = create_harness(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        false,
+        false,
+    );
+    let blocked = create_harness_with_password_change_limit(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        0,
+    );
+
+    harness
+        .service
+        .handle_change_password(change_password_command())
+        .await
+        .expect("password change should succeed");
+    let _ = blocked
+        .service
+        .handle_change_password(change_password_command())
+        .await;
+
+    assert_eq!(
+        harness.tenant_pools.invalidated_databases(),
+        vec!["tenant_alpha".to_string()]
+    );
+    assert!(blocked.tenant_pools.invalidated_databases().is_empty());
+}
+
 #[tokio::test]
 async fn handle_change_password_returns_not_found_when_database_is_missing() {
     let harness = create_harness(vec![], false, false);
@@ -48,3 +83,58 @@ async fn handle_change_password_returns_not_found_when_database_is_missing() {
     assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
     assert!(harness.audit_repository.saved_event_names().is_empty());
 }
+
+#[tokio::test]
+async fn handle_change_password_blocks_attempts_over_configured_limit() {
+    let harness = create_harness_with_password_change_limit(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        2,
+    );
+
+    for _ in 0..2 {
+        harness
+            .service
+            .handle_change_password(change_password_command())
+            .await
+            .expect("attempt within limit should succeed");
+    }
+    let result = harness
+        .service
+        .handle_change_password(change_password_command())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::TooManyPasswordChangeAttempts)
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 2));
+    assert_eq!(
+        harness.audit_repository.saved_event_names().last(),
+        Some(&"database_password_change_rate_limited".to_string())
+    );
+}
+
+#[tokio::test]
+async fn handle_change_password_reencrypts_stored_password() {
+    let key = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+    let harness = create_harness_with_credential_cipher(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        CredentialCipher::from_base64_key(key).expect("valid key"),
+    );
+
+    harness
+        .service
+        .handle_change_password(change_password_command())
+        .await
+        .expect("password change should succeed");
+
+    let sealed = harness
+        .metadata_repository
+        .encrypted_password_of("tenant_alpha")
+        .expect("encrypted password should be stored");
+    let cipher = CredentialCipher::from_base64_key(key).expect("valid key");
+    assert_eq!(
+        cipher.decrypt("tenant_alpha", &sealed).as_deref(),
+        Ok("new_supersecret")
+    );
+}
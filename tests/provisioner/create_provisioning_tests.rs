@@ -1,12 +1,26 @@
-use swagger_axum_api::provisioner::domain::{
-    model::enums::{
-        provisioned_database_status::ProvisionedDatabaseStatus,
-        provisioner_domain_error::ProvisionerDomainError,
+use chrono::Utc;
+use swagger_axum_api::{
+    config::credential_cipher::CredentialCipher,
+    provisioner::{
+        domain::{
+            model::{
+                entities::provisioned_database::ProvisionedDatabase,
+                enums::{
+                    provisioned_database_status::ProvisionedDatabaseStatus,
+                    provisioner_domain_error::ProvisionerDomainError,
+                },
+                value_objects::provisioned_database_id::ProvisionedDatabaseId,
+            },
+            services::database_provisioning_command_service::DatabaseProvisioningCommandService,
+        },
+        infrastructure::persistence::repositories::provisioned_database_repository::ProvisionedDatabaseRepository,
     },
-    services::database_provisioning_command_service::DatabaseProvisioningCommandService,
 };
 
-use crate::support::{create_command, create_harness, database_with_status};
+use crate::support::{
+    create_command, create_command_with_extensions, create_command_with_idempotency_key,
+    create_harness, create_harness_with_credential_cipher, database_with_status,
+};
 
 #[tokio::test]
 async fn handle_create_succeeds_and_persists_active_status() {
@@ -78,3 +92,208 @@ async fn handle_create_marks_failed_and_rolls_back_when_infra_fails() {
         ]
     );
 }
+
+#[tokio::test]
+async fn handle_create_stores_password_encrypted_for_the_tenant() {
+    let key = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+    let harness = create_harness_with_credential_cipher(
+        vec![],
+        CredentialCipher::from_base64_key(key).expect("valid key"),
+    );
+
+    harness
+        .service
+        .handle_create(create_command())
+        .await
+        .expect("provisioning should succeed");
+
+    let sealed = harness
+        .metadata_repository
+        .encrypted_password_of("tenant_alpha")
+        .expect("encrypted password should be stored");
+    let cipher = CredentialCipher::from_base64_key(key).expect("valid key");
+    assert_ne!(sealed, "supersecret");
+    assert_eq!(
+        cipher.decrypt("tenant_alpha", &sealed).as_deref(),
+        Ok("supersecret")
+    );
+    assert!(cipher.decrypt("tenant_beta", &sealed).is_err());
+}
+
+#[tokio::test]
+async fn handle_create_skips_password_encryption_without_key() {
+    let harness = create_harness(vec![], false, false);
+
+    harness
+        .service
+        .handle_create(create_command())
+        .await
+        .expect("provisioning should succeed");
+
+    assert!(
+        harness
+            .metadata_repository
+            .encrypted_password_of("tenant_alpha")
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn handle_create_installs_requested_extensions_and_rejects_unlisted_ones() {
+    let harness = create_harness(vec![], false, false);
+    let command = create_command_with_extensions(&["uuid-ossp", "PGCRYPTO", "uuid-ossp"])
+        .expect("allowlisted extensions should be accepted");
+
+    harness
+        .service
+        .handle_create(command)
+        .await
+        .expect("provisioning should succeed");
+
+    assert_eq!(
+        harness.postgres_repository.installed_extensions(),
+        vec!["uuid-ossp".to_string(), "pgcrypto".to_string()]
+    );
+    assert!(matches!(
+        create_command_with_extensions(&["plpython3u"]),
+        Err(ProvisionerDomainError::InvalidDatabaseExtension(_))
+    ));
+    assert!(matches!(
+        create_command_with_extensions(&["pgcrypto\"; DROP DATABASE postgres; --"]),
+        Err(ProvisionerDomainError::InvalidDatabaseExtension(_))
+    ));
+}
+
+#[tokio::test]
+async fn handle_create_rolls_back_stack_when_extension_fails() {
+    let harness = create_harness(vec![], false, false);
+    harness
+        .postgres_repository
+        .set_failing_extension("pgcrypto");
+    let command = create_command_with_extensions(&["uuid-ossp", "pgcrypto"])
+        .expect("allowlisted extensions should be accepted");
+
+    let result = harness.service.handle_create(command).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::InfrastructureError(_))
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 1, 0));
+    assert_eq!(
+        harness.metadata_repository.saved_statuses(),
+        vec![
+            ProvisionedDatabaseStatus::Provisioning,
+            ProvisionedDatabaseStatus::Failed,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn handle_create_replays_original_database_for_repeated_idempotency_key() {
+    let harness = create_harness(vec![], false, false);
+
+    let original = harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", true))
+        .await
+        .expect("provisioning should succeed");
+    let replayed = harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", true))
+        .await
+        .expect("replay should return the original database");
+
+    assert_eq!(replayed.id(), original.id());
+    assert_eq!(replayed.status(), ProvisionedDatabaseStatus::Active);
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 0, 0));
+}
+
+#[tokio::test]
+async fn handle_create_rejects_reused_idempotency_key_with_different_payload() {
+    let harness = create_harness(vec![], false, false);
+
+    harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", true))
+        .await
+        .expect("provisioning should succeed");
+    let result = harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", false))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::IdempotencyKeyConflict)
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 0, 0));
+}
+
+#[tokio::test]
+async fn handle_create_retries_failed_attempt_for_repeated_idempotency_key() {
+    let harness = create_harness(vec![], true, false);
+
+    let failed = harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", true))
+        .await;
+    harness.postgres_repository.set_create_should_fail(false);
+    let retried = harness
+        .service
+        .handle_create(create_command_with_idempotency_key("request-1", true))
+        .await
+        .expect("retry should provision the database");
+
+    assert!(failed.is_err());
+    assert_eq!(retried.status(), ProvisionedDatabaseStatus::Active);
+    assert_eq!(harness.postgres_repository.stats(), (2, 0, 1, 0));
+}
+
+#[tokio::test]
+async fn handle_create_replays_in_flight_record_for_repeated_idempotency_key() {
+    let harness = create_harness(vec![], false, false);
+    let command = create_command_with_idempotency_key("request-1", true);
+    let in_flight = ProvisionedDatabase::new_provisioning(
+        ProvisionedDatabaseId::new_random(),
+        command.database_name().clone(),
+        command.username().clone(),
+        command.password_hash().clone(),
+        Utc::now(),
+    );
+    harness
+        .metadata_repository
+        .insert_provisioning(
+            &in_flight,
+            command.idempotency_key(),
+            &command.request_fingerprint(),
+        )
+        .await
+        .expect("record should insert");
+
+    let replayed = harness
+        .service
+        .handle_create(command)
+        .await
+        .expect("replay should return the in-flight database");
+
+    assert_eq!(replayed.id(), in_flight.id());
+    assert_eq!(replayed.status(), ProvisionedDatabaseStatus::Provisioning);
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+}
+
+#[tokio::test]
+async fn handle_create_drops_role_when_database_creation_fails() {
+    let harness = create_harness(vec![], false, false);
+    harness.postgres_repository.set_fail_after_role_creation();
+
+    let result = harness.service.handle_create(create_command()).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::InfrastructureError(message)) if message == "create database failed"
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 1, 0));
+    assert!(harness.postgres_repository.roles().is_empty());
+    assert!(harness.postgres_repository.databases().is_empty());
+}
@@ -66,3 +66,44 @@ async fn handle_delete_is_idempotent_when_database_is_already_deleted() {
     assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
     assert!(harness.audit_repository.saved_event_names().is_empty());
 }
+
+#[tokio::test]
+async fn handle_delete_cleans_up_tenant_access_control() {
+    let database = database_with_status(ProvisionedDatabaseStatus::Active);
+    let tenant_id = database.id().value().to_string();
+    let harness = create_harness(vec![database], false, false);
+
+    let result = harness.service.handle_delete(delete_command()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(harness.access_control.cleaned_tenants(), vec![tenant_id]);
+}
+
+#[tokio::test]
+async fn handle_delete_succeeds_when_access_control_cleanup_fails() {
+    let harness = create_harness(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        false,
+        false,
+    );
+    harness.access_control.set_cleanup_should_fail(true);
+
+    let result = harness.service.handle_delete(delete_command()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        harness.metadata_repository.saved_statuses(),
+        vec![
+            ProvisionedDatabaseStatus::Deleting,
+            ProvisionedDatabaseStatus::Deleted,
+        ]
+    );
+    assert_eq!(
+        harness.audit_repository.saved_event_names(),
+        vec![
+            "database_delete_started".to_string(),
+            "database_delete_succeeded".to_string(),
+            "tenant_access_cleanup_failed".to_string(),
+        ]
+    );
+}
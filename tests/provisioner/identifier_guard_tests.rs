@@ -0,0 +1,74 @@
+use swagger_axum_api::provisioner::domain::model::{
+    commands::{
+        create_provisioned_database_command::CreateProvisionedDatabaseCommand,
+        delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+    },
+    enums::provisioner_domain_error::ProvisionerDomainError,
+    value_objects::{
+        database_username::DatabaseUsername, provisioned_database_name::ProvisionedDatabaseName,
+    },
+};
+
+fn create_command(
+    database_name: &str,
+    username: &str,
+) -> Result<CreateProvisionedDatabaseCommand, ProvisionerDomainError> {
+    CreateProvisionedDatabaseCommand::new(
+        database_name.to_string(),
+        username.to_string(),
+        "supersecret".to_string(),
+        "$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$somehashvalue".to_string(),
+        false,
+        vec![],
+    )
+}
+
+#[test]
+fn injected_database_name_is_rejected_before_any_statement_is_built() {
+    assert!(matches!(
+        create_command("foo; DROP DATABASE", "tenant_alpha_user"),
+        Err(ProvisionerDomainError::InvalidDatabaseName)
+    ));
+    assert!(matches!(
+        DeleteProvisionedDatabaseCommand::new("foo; DROP DATABASE".to_string()),
+        Err(ProvisionerDomainError::InvalidDatabaseName)
+    ));
+}
+
+#[test]
+fn injected_username_is_rejected() {
+    assert!(matches!(
+        create_command("tenant_alpha", "foo; DROP ROLE postgres"),
+        Err(ProvisionerDomainError::InvalidDatabaseUsername)
+    ));
+}
+
+#[test]
+fn uppercase_short_reserved_and_system_identifiers_are_rejected() {
+    for value in [
+        "Tenant_Alpha",
+        "select",
+        "user",
+        "public",
+        "ab",
+        "postgres",
+        "template0",
+        "template1",
+    ] {
+        assert!(matches!(
+            ProvisionedDatabaseName::new(value.to_string()),
+            Err(ProvisionerDomainError::InvalidDatabaseName)
+        ));
+        assert!(matches!(
+            DatabaseUsername::new(value.to_string()),
+            Err(ProvisionerDomainError::InvalidDatabaseUsername)
+        ));
+    }
+
+    assert_eq!(
+        ProvisionedDatabaseName::new("tenant_alpha".to_string())
+            .expect("valid name")
+            .value(),
+        "tenant_alpha"
+    );
+}
@@ -0,0 +1,246 @@
+use sqlx::{
+    PgPool,
+    postgres::{PgConnectOptions, PgPoolOptions},
+};
+use swagger_axum_api::{
+    config::app_config::AppConfig,
+    provisioner::{
+        domain::model::value_objects::{
+            database_password::DatabasePassword, database_seed_source::DatabaseSeedSource,
+            database_username::DatabaseUsername,
+            provisioned_database_name::ProvisionedDatabaseName,
+        },
+        infrastructure::persistence::repositories::{
+            postgres::sqlx_postgres_database_administration_repository_impl::SqlxPostgresDatabaseAdministrationRepositoryImpl,
+            postgres_database_administration_repository::{
+                CreatedDatabaseStack, PostgresDatabaseAdministrationRepository,
+            },
+        },
+    },
+};
+use uuid::Uuid;
+
+struct AdministrationFixture {
+    admin_pool: PgPool,
+    repository: SqlxPostgresDatabaseAdministrationRepositoryImpl,
+    database_name: ProvisionedDatabaseName,
+    username: DatabaseUsername,
+}
+
+impl AdministrationFixture {
+    async fn create() -> Option<Self> {
+        let database_url = std::env::var("TEST_DATABASE_URL").ok()?;
+        let options: PgConnectOptions = database_url.parse().expect("valid test database url");
+        let mut config = AppConfig::from_env();
+        config.postgres_host = options.get_host().to_string();
+        config.postgres_port = options.get_port();
+        config.postgres_user = options.get_username().to_string();
+
+        let admin_pool = PgPool::connect(&database_url)
+            .await
+            .expect("test database should accept connections");
+        let suffix = Uuid::now_v7().simple().to_string();
+        let suffix = &suffix[suffix.len() - 12..];
+
+        Some(Self {
+            repository: SqlxPostgresDatabaseAdministrationRepositoryImpl::new(
+                admin_pool.clone(),
+                config,
+            ),
+            admin_pool,
+            database_name: ProvisionedDatabaseName::new(format!("stack_{suffix}"))
+                .expect("valid database name"),
+            username: DatabaseUsername::new(format!("stack_{suffix}_user"))
+                .expect("valid username"),
+        })
+    }
+
+    async fn exists(&self, catalog_query: &str, name: &str) -> bool {
+        sqlx::query_scalar::<_, bool>(catalog_query)
+            .bind(name)
+            .fetch_one(&self.admin_pool)
+            .await
+            .expect("catalog query should succeed")
+    }
+
+    async fn database_exists(&self) -> bool {
+        self.exists(
+            "SELECT EXISTS (SELECT 1 FROM pg_database WHERE datname = $1)",
+            self.database_name.value(),
+        )
+        .await
+    }
+
+    async fn role_exists(&self) -> bool {
+        self.exists(
+            "SELECT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = $1)",
+            self.username.value(),
+        )
+        .await
+    }
+
+    async fn drop(self) {
+        let _ = self
+            .repository
+            .rollback_database_stack(
+                &self.database_name,
+                &self.username,
+                CreatedDatabaseStack::complete(),
+            )
+            .await;
+        self.admin_pool.close().await;
+    }
+}
+
+#[tokio::test]
+async fn tenant_statements_connect_with_injected_admin_configuration() {
+    let mut config = AppConfig::from_env();
+    config.postgres_host = "ephemeral-postgres".to_string();
+    config.postgres_port = 55432;
+    config.postgres_user = "provisioner_admin".to_string();
+    config.postgres_password = "ephemeral".to_string();
+
+    let admin_pool = PgPoolOptions::new()
+        .connect_lazy(&config.admin_database_url())
+        .expect("lazy admin pool");
+    let repository = SqlxPostgresDatabaseAdministrationRepositoryImpl::new(admin_pool, config);
+
+    let options = repository.connect_options_for("tenant_alpha");
+
+    assert_eq!(options.get_host(), "ephemeral-postgres");
+    assert_eq!(options.get_port(), 55432);
+    assert_eq!(options.get_username(), "provisioner_admin");
+    assert_eq!(options.get_database(), Some("tenant_alpha"));
+}
+
+#[tokio::test]
+async fn failed_creation_reports_only_the_role_when_the_database_already_exists() {
+    let Some(fixture) = AdministrationFixture::create().await else {
+        return;
+    };
+    sqlx::query(&format!(
+        "CREATE DATABASE {}",
+        fixture.database_name.value()
+    ))
+    .execute(&fixture.admin_pool)
+    .await
+    .expect("existing database should be created");
+
+    let failure = fixture
+        .repository
+        .create_database_stack(
+            &fixture.database_name,
+            &fixture.username,
+            &DatabasePassword::new("supersecret".to_string()).expect("valid password"),
+            None,
+            &[],
+        )
+        .await
+        .expect_err("creating over an existing database should fail");
+    fixture
+        .repository
+        .rollback_database_stack(&fixture.database_name, &fixture.username, failure.created)
+        .await
+        .expect("rollback should succeed");
+
+    assert_eq!(
+        failure.created,
+        CreatedDatabaseStack {
+            role: true,
+            database: false,
+        }
+    );
+    assert!(fixture.database_exists().await);
+    assert!(!fixture.role_exists().await);
+    fixture.drop().await;
+}
+
+#[tokio::test]
+async fn failed_seed_rolls_back_the_whole_created_stack() {
+    let Some(fixture) = AdministrationFixture::create().await else {
+        return;
+    };
+
+    let failure = fixture
+        .repository
+        .create_database_stack(
+            &fixture.database_name,
+            &fixture.username,
+            &DatabasePassword::new("supersecret".to_string()).expect("valid password"),
+            Some(&DatabaseSeedSource::InlineSql("SELECT 1 / 0".to_string())),
+            &[],
+        )
+        .await
+        .expect_err("failing seed should fail creation");
+    fixture
+        .repository
+        .rollback_database_stack(&fixture.database_name, &fixture.username, failure.created)
+        .await
+        .expect("rollback should succeed");
+
+    assert_eq!(failure.created, CreatedDatabaseStack::complete());
+    assert!(!fixture.database_exists().await);
+    assert!(!fixture.role_exists().await);
+    fixture.drop().await;
+}
+
+#[tokio::test]
+async fn describe_privileges_reads_table_grants_through_the_admin_pool_options() {
+    let Some(fixture) = AdministrationFixture::create().await else {
+        return;
+    };
+    fixture
+        .repository
+        .create_database_stack(
+            &fixture.database_name,
+            &fixture.username,
+            &DatabasePassword::new("supersecret".to_string()).expect("valid password"),
+            None,
+            &[],
+        )
+        .await
+        .map_err(|failure| failure.error)
+        .expect("stack should be created");
+
+    let privileges = fixture
+        .repository
+        .describe_privileges(&fixture.database_name, &fixture.username)
+        .await;
+    let database_name = fixture.database_name.value().to_string();
+
+    fixture.drop().await;
+    let privileges = privileges.expect("privileges should be described");
+    assert_eq!(privileges.database_name, database_name);
+    assert!(privileges.table_grants.is_empty());
+    assert!(
+        privileges
+            .database_privileges
+            .contains(&"CONNECT".to_string())
+    );
+}
+
+#[tokio::test]
+async fn inline_seed_runs_as_the_tenant_role() {
+    let Some(fixture) = AdministrationFixture::create().await else {
+        return;
+    };
+    let seed = format!(
+        "DO $$ BEGIN IF current_user <> '{}' THEN RAISE EXCEPTION 'seed ran as %', current_user; END IF; END $$",
+        fixture.username.value()
+    );
+
+    let result = fixture
+        .repository
+        .create_database_stack(
+            &fixture.database_name,
+            &fixture.username,
+            &DatabasePassword::new("supersecret".to_string()).expect("valid password"),
+            Some(&DatabaseSeedSource::InlineSql(seed)),
+            &[],
+        )
+        .await
+        .map_err(|failure| failure.error);
+
+    fixture.drop().await;
+    result.expect("inline seed should run as the tenant role");
+}
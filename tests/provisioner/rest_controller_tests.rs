@@ -0,0 +1,423 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{Request, StatusCode},
+    response::Response,
+};
+use serde_json::{Value, json};
+use swagger_axum_api::provisioner::domain::model::enums::provisioned_database_status::ProvisionedDatabaseStatus;
+use tower::ServiceExt;
+
+use crate::support::{
+    ROUTER_MAX_FAILED_PASSWORD_ATTEMPTS, TEST_ADMIN_TOKEN, create_router_harness,
+    database_named_with_status, database_with_status, failed_database_with_password,
+};
+
+async fn json_body(response: Response) -> Value {
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body should be readable");
+    serde_json::from_slice(&body).expect("body should be json")
+}
+
+fn list_request(admin_token: Option<&str>) -> Request<Body> {
+    list_request_with_uri("/provisioner/databases", admin_token)
+}
+
+fn list_request_with_uri(uri: &str, admin_token: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method("GET").uri(uri);
+    if let Some(admin_token) = admin_token {
+        builder = builder.header("x-admin-token", admin_token);
+    }
+
+    builder.body(Body::empty()).expect("valid request")
+}
+
+#[tokio::test]
+async fn list_omits_username_in_restricted_projection_for_non_admin() {
+    let harness = create_router_harness(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        true,
+    );
+
+    let response = harness
+        .router
+        .oneshot(list_request(None))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = json_body(response).await;
+    assert_eq!(payload[0]["database_name"], "tenant_alpha");
+    assert!(payload[0].get("username").is_none());
+}
+
+#[tokio::test]
+async fn list_keeps_username_for_admin_in_restricted_projection() {
+    let harness = create_router_harness(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        true,
+    );
+
+    let response = harness
+        .router
+        .oneshot(list_request(Some(TEST_ADMIN_TOKEN)))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = json_body(response).await;
+    assert_eq!(payload[0]["username"], "tenant_alpha_user");
+}
+
+#[tokio::test]
+async fn unverified_admin_claims_do_not_unlock_admin_views() {
+    let harness = create_router_harness(vec![failed_database_with_password("supersecret")], true);
+    harness
+        .postgres_repository
+        .set_privileges(&["CONNECT"], &[]);
+
+    let list_response = harness
+        .router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/provisioner/databases")
+                .header("x-provisioner-role", "admin")
+                .header("x-admin-token", "not-the-admin-token")
+                .body(Body::empty())
+                .expect("valid request"),
+        )
+        .await
+        .expect("router should respond");
+    let privileges_response = harness
+        .router
+        .oneshot(privileges_request(&[("x-provisioner-role", "admin")]))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(list_response.status(), StatusCode::OK);
+    let payload = json_body(list_response).await;
+    assert!(payload[0].get("username").is_none());
+    assert_eq!(privileges_response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn create_returns_username_to_owner_in_restricted_projection() {
+    let harness = create_router_harness(vec![], true);
+    let request = Request::builder()
+        .method("POST")
+        .uri("/provisioner/databases")
+        .header("content-type", "application/json")
+        .body(Body::from(
+            json!({ "database_name": "tenant_beta", "password": "supersecret", "apply_seed_data": false }).to_string(),
+        ))
+        .expect("valid request");
+
+    let response = harness
+        .router
+        .oneshot(request)
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let payload = json_body(response).await;
+    assert!(payload["username"].is_string());
+}
+
+#[tokio::test]
+async fn list_filters_by_requested_status() {
+    let harness = create_router_harness(
+        vec![
+            database_named_with_status("tenant_alpha", ProvisionedDatabaseStatus::Active),
+            database_named_with_status("tenant_beta", ProvisionedDatabaseStatus::Failed),
+            database_named_with_status("tenant_gamma", ProvisionedDatabaseStatus::Deleted),
+        ],
+        false,
+    );
+
+    let response = harness
+        .router
+        .oneshot(list_request_with_uri(
+            "/provisioner/databases?status=failed",
+            None,
+        ))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = json_body(response).await;
+    let entries = payload.as_array().expect("payload should be a list");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["database_name"], "tenant_beta");
+    assert_eq!(entries[0]["status"], "failed");
+    assert_eq!(
+        harness.metadata_repository.status_filters(),
+        vec![vec![ProvisionedDatabaseStatus::Failed]]
+    );
+}
+
+#[tokio::test]
+async fn list_rejects_unknown_status_filter() {
+    let harness = create_router_harness(vec![], false);
+
+    let response = harness
+        .router
+        .oneshot(list_request_with_uri(
+            "/provisioner/databases?status=failed,broken",
+            None,
+        ))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(harness.metadata_repository.status_filters().is_empty());
+}
+
+#[tokio::test]
+async fn list_redacts_connection_details_from_infrastructure_errors() {
+    let harness = create_router_harness(vec![], false);
+    harness.metadata_repository.set_list_failure_message(
+        "pool timed out: host=10.0.4.12 port=5432 user=postgres password=admin dbname=postgres",
+    );
+
+    let response = harness
+        .router
+        .oneshot(list_request_with_uri(
+            "/provisioner/databases?status=failed",
+            Some("admin"),
+        ))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let payload = json_body(response).await;
+    assert_eq!(
+        payload["message"],
+        "infrastructure error: pool timed out: host=[redacted] port=[redacted] user=[redacted] password=[redacted] dbname=[redacted]"
+    );
+}
+
+#[tokio::test]
+async fn bad_request_is_rendered_as_problem_details_only_when_requested() {
+    let harness = create_router_harness(vec![], false);
+    let uri = "/provisioner/databases?status=archived";
+    let problem_request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .header("accept", "application/json, application/problem+json;q=0.9")
+        .body(Body::empty())
+        .expect("valid request");
+
+    let problem_response = harness
+        .router
+        .clone()
+        .oneshot(problem_request)
+        .await
+        .expect("router should respond");
+    let default_response = harness
+        .router
+        .oneshot(list_request_with_uri(uri, None))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(problem_response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        problem_response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok()),
+        Some("application/problem+json")
+    );
+    let problem = json_body(problem_response).await;
+    assert_eq!(problem["title"], "Bad Request");
+    assert_eq!(problem["status"], 400);
+    assert_eq!(problem["code"], "bad_request");
+    assert!(
+        problem["detail"]
+            .as_str()
+            .is_some_and(|detail| detail.contains("status"))
+    );
+    assert_eq!(default_response.status(), StatusCode::BAD_REQUEST);
+    let envelope = json_body(default_response).await;
+    assert!(envelope["message"].is_string());
+    assert!(envelope.get("status").is_none());
+}
+
+#[tokio::test]
+async fn create_rejects_seed_sql_or_template_without_seed_mode() {
+    let harness = create_router_harness(vec![], false);
+    let create_request = |body: Value| {
+        Request::builder()
+            .method("POST")
+            .uri("/provisioner/databases")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .expect("valid request")
+    };
+
+    let with_sql = harness
+        .router
+        .clone()
+        .oneshot(create_request(json!({
+            "database_name": "tenant_beta",
+            "password": "supersecret",
+            "apply_seed_data": true,
+            "seed_sql": "CREATE TABLE seeded (id INT)"
+        })))
+        .await
+        .expect("router should respond");
+    let with_template = harness
+        .router
+        .oneshot(create_request(json!({
+            "database_name": "tenant_beta",
+            "password": "supersecret",
+            "apply_seed_data": true,
+            "seed_template": "tenant_template"
+        })))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(with_sql.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(with_template.status(), StatusCode::BAD_REQUEST);
+    assert!(harness.metadata_repository.saved_statuses().is_empty());
+}
+
+fn privileges_request(headers: &[(&str, &str)]) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("GET")
+        .uri("/provisioner/databases/tenant_alpha/privileges");
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+
+    builder.body(Body::empty()).expect("valid request")
+}
+
+#[tokio::test]
+async fn privileges_returns_canned_grants_to_owner_and_admin() {
+    let harness = create_router_harness(vec![failed_database_with_password("supersecret")], true);
+    harness.postgres_repository.set_privileges(
+        &["CONNECT", "CREATE", "TEMPORARY"],
+        &[
+            ("public", "orders", "INSERT"),
+            ("public", "orders", "SELECT"),
+        ],
+    );
+
+    let owner_response = harness
+        .router
+        .clone()
+        .oneshot(privileges_request(&[(
+            "x-database-password",
+            "supersecret",
+        )]))
+        .await
+        .expect("router should respond");
+    let admin_response = harness
+        .router
+        .oneshot(privileges_request(&[("x-admin-token", TEST_ADMIN_TOKEN)]))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(owner_response.status(), StatusCode::OK);
+    let payload = json_body(owner_response).await;
+    assert_eq!(payload["database_name"], "tenant_alpha");
+    assert!(payload.get("username").is_none());
+    assert_eq!(
+        payload["database_privileges"],
+        json!(["CONNECT", "CREATE", "TEMPORARY"])
+    );
+    assert_eq!(
+        payload["table_grants"][1],
+        json!({ "table_schema": "public", "table_name": "orders", "privilege_type": "SELECT" })
+    );
+    assert_eq!(admin_response.status(), StatusCode::OK);
+    let payload = json_body(admin_response).await;
+    assert_eq!(payload["username"], "tenant_alpha_user");
+}
+
+#[tokio::test]
+async fn privileges_keeps_username_for_owner_without_restricted_projection() {
+    let harness = create_router_harness(vec![failed_database_with_password("supersecret")], false);
+    harness
+        .postgres_repository
+        .set_privileges(&["CONNECT"], &[]);
+
+    let response = harness
+        .router
+        .oneshot(privileges_request(&[(
+            "x-database-password",
+            "supersecret",
+        )]))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let payload = json_body(response).await;
+    assert_eq!(payload["username"], "tenant_alpha_user");
+}
+
+#[tokio::test]
+async fn privileges_locks_out_owner_password_after_repeated_failures() {
+    let harness = create_router_harness(vec![failed_database_with_password("supersecret")], false);
+    harness
+        .postgres_repository
+        .set_privileges(&["CONNECT"], &[]);
+
+    for _ in 0..ROUTER_MAX_FAILED_PASSWORD_ATTEMPTS {
+        let response = harness
+            .router
+            .clone()
+            .oneshot(privileges_request(&[(
+                "x-database-password",
+                "not-the-owner",
+            )]))
+            .await
+            .expect("router should respond");
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+    let locked = harness
+        .router
+        .clone()
+        .oneshot(privileges_request(&[(
+            "x-database-password",
+            "supersecret",
+        )]))
+        .await
+        .expect("router should respond");
+    let admin = harness
+        .router
+        .oneshot(privileges_request(&[("x-admin-token", TEST_ADMIN_TOKEN)]))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(locked.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(admin.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn privileges_rejects_callers_without_the_owner_password() {
+    let harness = create_router_harness(vec![failed_database_with_password("supersecret")], false);
+    harness
+        .postgres_repository
+        .set_privileges(&["CONNECT"], &[]);
+
+    let wrong_password = harness
+        .router
+        .clone()
+        .oneshot(privileges_request(&[(
+            "x-database-password",
+            "not-the-owner",
+        )]))
+        .await
+        .expect("router should respond");
+    let anonymous = harness
+        .router
+        .oneshot(privileges_request(&[]))
+        .await
+        .expect("router should respond");
+
+    assert_eq!(wrong_password.status(), StatusCode::FORBIDDEN);
+    assert_eq!(anonymous.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,156 @@
+use swagger_axum_api::provisioner::domain::{
+    model::enums::{
+        provisioned_database_status::ProvisionedDatabaseStatus,
+        provisioner_domain_error::ProvisionerDomainError,
+    },
+    services::database_provisioning_command_service::DatabaseProvisioningCommandService,
+};
+
+use crate::support::{
+    create_harness, create_harness_with_failed_password_limit, database_with_status,
+    failed_database_with_password, retry_command,
+};
+
+#[tokio::test]
+async fn handle_retry_moves_failed_database_to_active() {
+    let harness = create_harness(
+        vec![failed_database_with_password("supersecret")],
+        false,
+        false,
+    );
+
+    let result = harness
+        .service
+        .handle_retry(retry_command("supersecret"))
+        .await;
+
+    let database = result.expect("retry should succeed");
+    assert_eq!(database.status(), ProvisionedDatabaseStatus::Active);
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 1, 0));
+    assert_eq!(
+        harness.metadata_repository.saved_statuses(),
+        vec![
+            ProvisionedDatabaseStatus::Provisioning,
+            ProvisionedDatabaseStatus::Active,
+        ]
+    );
+    assert_eq!(
+        harness.audit_repository.saved_event_names(),
+        vec![
+            "database_reprovision_started".to_string(),
+            "database_reprovision_succeeded".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn handle_retry_rejects_caller_without_owner_password() {
+    let harness = create_harness(
+        vec![failed_database_with_password("supersecret")],
+        false,
+        false,
+    );
+
+    let result = harness
+        .service
+        .handle_retry(retry_command("not_the_owner"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::DatabaseOwnershipMismatch)
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+    assert_eq!(
+        harness.audit_repository.saved_event_names(),
+        vec!["database_reprovision_rejected".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn handle_retry_locks_database_after_repeated_wrong_passwords() {
+    let harness = create_harness_with_failed_password_limit(
+        vec![failed_database_with_password("supersecret")],
+        2,
+    );
+
+    for _ in 0..2 {
+        let result = harness
+            .service
+            .handle_retry(retry_command("not_the_owner"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ProvisionerDomainError::DatabaseOwnershipMismatch)
+        ));
+    }
+    let locked = harness
+        .service
+        .handle_retry(retry_command("supersecret"))
+        .await;
+
+    assert!(matches!(
+        locked,
+        Err(ProvisionerDomainError::TooManyFailedPasswordAttempts)
+    ));
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+    assert_eq!(
+        harness.audit_repository.saved_event_names().last(),
+        Some(&"database_reprovision_rate_limited".to_string())
+    );
+}
+
+#[tokio::test]
+async fn handle_retry_marks_failed_again_when_reprovisioning_fails() {
+    let harness = create_harness(
+        vec![failed_database_with_password("supersecret")],
+        true,
+        false,
+    );
+
+    let result = harness
+        .service
+        .handle_retry(retry_command("supersecret"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::InfrastructureError(_))
+    ));
+    assert_eq!(
+        harness.metadata_repository.saved_statuses(),
+        vec![
+            ProvisionedDatabaseStatus::Provisioning,
+            ProvisionedDatabaseStatus::Failed,
+        ]
+    );
+    assert_eq!(
+        harness.audit_repository.saved_event_names(),
+        vec![
+            "database_reprovision_started".to_string(),
+            "database_reprovision_failed".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn handle_retry_rejects_active_and_deleted_databases() {
+    for status in [
+        ProvisionedDatabaseStatus::Active,
+        ProvisionedDatabaseStatus::Deleted,
+    ] {
+        let harness = create_harness(vec![database_with_status(status)], false, false);
+
+        let result = harness
+            .service
+            .handle_retry(retry_command("supersecret"))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProvisionerDomainError::InvalidStatusTransition)
+        ));
+        assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+        assert!(harness.audit_repository.saved_event_names().is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use swagger_axum_api::config::app_config::AppConfig;
+use swagger_axum_api::provisioner::domain::{
+    model::{
+        enums::{
+            provisioned_database_status::ProvisionedDatabaseStatus,
+            provisioner_domain_error::ProvisionerDomainError,
+        },
+        value_objects::{
+            database_seed_source::{DatabaseSeedSource, MAX_INLINE_SEED_SQL_BYTES},
+            provisioned_database_name::ProvisionedDatabaseName,
+        },
+    },
+    services::database_provisioning_command_service::DatabaseProvisioningCommandService,
+};
+
+use crate::support::{create_command, create_harness};
+
+fn template_seed_source(template: &str) -> DatabaseSeedSource {
+    DatabaseSeedSource::parse("template", None, Some(template.to_string()))
+        .expect("valid template seed source")
+}
+
+fn allowed_template(template: &str) -> ProvisionedDatabaseName {
+    ProvisionedDatabaseName::new(template.to_string()).expect("valid template name")
+}
+
+#[tokio::test]
+async fn handle_create_skips_seeding_without_a_configured_default() {
+    let harness = create_harness(vec![], false, false);
+
+    harness
+        .service
+        .handle_create(create_command())
+        .await
+        .expect("provisioning should succeed");
+
+    assert_eq!(harness.postgres_repository.seed_sources(), vec![None]);
+}
+
+#[tokio::test]
+async fn handle_create_seeds_from_the_configured_default() {
+    let harness = create_harness(vec![], false, false);
+    let service = harness
+        .service
+        .with_default_seed_source(Some(DatabaseSeedSource::BundledFile));
+
+    service
+        .handle_create(create_command())
+        .await
+        .expect("provisioning should succeed");
+
+    assert_eq!(
+        harness.postgres_repository.seed_sources(),
+        vec![Some(DatabaseSeedSource::BundledFile)]
+    );
+}
+
+#[tokio::test]
+async fn handle_create_clones_allowlisted_template_requested_per_request() {
+    let harness = create_harness(vec![], false, false);
+    let service = harness
+        .service
+        .with_allowed_seed_templates(vec![allowed_template("tenant_template")]);
+    let command = create_command().with_seed_source(Some(template_seed_source("tenant_template")));
+
+    service
+        .handle_create(command)
+        .await
+        .expect("provisioning should succeed");
+
+    assert_eq!(
+        harness.postgres_repository.seed_sources(),
+        vec![Some(template_seed_source("tenant_template"))]
+    );
+}
+
+#[tokio::test]
+async fn handle_create_rejects_requested_template_outside_the_allowlist() {
+    let harness = create_harness(vec![], false, false);
+    let service = harness
+        .service
+        .with_allowed_seed_templates(vec![allowed_template("tenant_template")]);
+    let command = create_command().with_seed_source(Some(template_seed_source("tenant_alpha")));
+
+    let result = service.handle_create(command).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::InvalidSeedSource(_))
+    ));
+    assert!(harness.postgres_repository.seed_sources().is_empty());
+    assert!(harness.metadata_repository.saved_statuses().is_empty());
+}
+
+#[tokio::test]
+async fn handle_create_rolls_back_when_configured_template_seed_fails() {
+    let harness = create_harness(vec![], false, false);
+    harness.postgres_repository.set_seed_should_fail();
+    let service = harness
+        .service
+        .with_default_seed_source(Some(template_seed_source("tenant_template")));
+
+    let result = service.handle_create(create_command()).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::InfrastructureError(_))
+    ));
+    assert_eq!(
+        harness.postgres_repository.seed_sources(),
+        vec![Some(template_seed_source("tenant_template"))]
+    );
+    assert_eq!(harness.postgres_repository.stats(), (1, 0, 1, 0));
+    assert!(harness.postgres_repository.databases().is_empty());
+    assert!(harness.postgres_repository.roles().is_empty());
+    assert_eq!(
+        harness.metadata_repository.saved_statuses().last(),
+        Some(&ProvisionedDatabaseStatus::Failed)
+    );
+}
+
+#[test]
+fn inline_seed_source_rejects_empty_and_oversized_scripts() {
+    let empty = DatabaseSeedSource::parse("inline", Some("   ".to_string()), None);
+    let oversized = DatabaseSeedSource::parse(
+        "inline",
+        Some("x".repeat(MAX_INLINE_SEED_SQL_BYTES + 1)),
+        None,
+    );
+
+    assert!(matches!(
+        empty,
+        Err(ProvisionerDomainError::InvalidSeedSource(_))
+    ));
+    assert!(matches!(
+        oversized,
+        Err(ProvisionerDomainError::InvalidSeedSource(_))
+    ));
+}
+
+#[test]
+fn default_seed_source_is_none_without_a_seed_file() {
+    let mut config = AppConfig::from_env();
+    config.provisioner_seed_mode = None;
+    config.provisioner_seed_file = None;
+    let without_file = config.default_seed_source();
+    config.provisioner_seed_file = Some("/seed/tenant.sql".to_string());
+    let with_file = config.default_seed_source();
+    config.provisioner_seed_mode = Some("none".to_string());
+    let explicit_none = config.default_seed_source();
+
+    assert_eq!(without_file, Ok(None));
+    assert_eq!(with_file, Ok(Some(DatabaseSeedSource::BundledFile)));
+    assert_eq!(explicit_none, Ok(None));
+}
+
+#[test]
+fn file_seed_mode_without_a_seed_file_fails_at_startup() {
+    let mut config = AppConfig::from_env();
+    config.provisioner_seed_mode = Some("file".to_string());
+    config.provisioner_seed_file = None;
+
+    assert!(config.default_seed_source().is_err());
+}
@@ -5,5 +5,13 @@ mod fixtures;
 #[path = "support/harness.rs"]
 mod harness;
 
-pub use fixtures::{change_password_command, create_command, database_with_status, delete_command};
-pub use harness::create_harness;
+pub use fixtures::{
+    change_password_command, create_command, create_command_with_extensions,
+    create_command_with_idempotency_key, database_named_with_status, database_with_status,
+    delete_command, failed_database_with_password, retry_command,
+};
+pub use harness::{
+    ROUTER_MAX_FAILED_PASSWORD_ATTEMPTS, TEST_ADMIN_TOKEN, create_harness,
+    create_harness_with_credential_cipher, create_harness_with_failed_password_limit,
+    create_harness_with_password_change_limit, create_router_harness,
+};
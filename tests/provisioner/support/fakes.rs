@@ -9,23 +9,36 @@ use swagger_axum_api::provisioner::{
             provisioner_domain_error::ProvisionerDomainError,
         },
         value_objects::{
-            database_password::DatabasePassword, database_username::DatabaseUsername,
-            provisioned_database_name::ProvisionedDatabaseName,
+            database_extension_name::DatabaseExtensionName, database_password::DatabasePassword,
+            database_seed_source::DatabaseSeedSource, database_username::DatabaseUsername,
+            idempotency_key::IdempotencyKey, provisioned_database_name::ProvisionedDatabaseName,
         },
     },
     infrastructure::persistence::repositories::{
-        postgres_database_administration_repository::PostgresDatabaseAdministrationRepository,
-        provisioned_database_repository::ProvisionedDatabaseRepository,
+        postgres_database_administration_repository::{
+            CreatedDatabaseStack, DatabasePrivilegesRecord, DatabaseStackCreationError,
+            PostgresDatabaseAdministrationRepository, TableGrantRecord,
+        },
+        provisioned_database_repository::{
+            IdempotentProvisioningRecord, ProvisionedDatabaseRepository,
+        },
         provisioning_audit_event_repository::{
             ProvisioningAuditEventRecord, ProvisioningAuditEventRepository,
         },
     },
+    interfaces::acl::{
+        access_control_facade::{AccessControlFacade, TenantAccessCleanupRequest},
+        tenant_pool_facade::{TenantPoolFacade, TenantPoolInvalidationRequest},
+    },
 };
 
 #[derive(Default)]
 struct FakeMetadataRepositoryState {
     entries: HashMap<String, ProvisionedDatabase>,
     saved_statuses: Vec<ProvisionedDatabaseStatus>,
+    status_filters: Vec<Vec<ProvisionedDatabaseStatus>>,
+    list_failure_message: Option<String>,
+    idempotency_keys: HashMap<String, (String, String)>,
 }
 
 pub struct FakeMetadataRepository {
@@ -42,11 +55,18 @@ impl FakeMetadataRepository {
         Self {
             state: Mutex::new(FakeMetadataRepositoryState {
                 entries: map,
-                saved_statuses: Vec::new(),
+                ..FakeMetadataRepositoryState::default()
             }),
         }
     }
 
+    pub fn set_list_failure_message(&self, message: &str) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .list_failure_message = Some(message.to_string());
+    }
+
     pub fn saved_statuses(&self) -> Vec<ProvisionedDatabaseStatus> {
         self.state
             .lock()
@@ -54,6 +74,23 @@ impl FakeMetadataRepository {
             .saved_statuses
             .clone()
     }
+
+    pub fn status_filters(&self) -> Vec<Vec<ProvisionedDatabaseStatus>> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .status_filters
+            .clone()
+    }
+
+    pub fn encrypted_password_of(&self, database_name: &str) -> Option<String> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .entries
+            .get(database_name)
+            .and_then(|database| database.encrypted_password().map(str::to_string))
+    }
 }
 
 #[async_trait]
@@ -95,6 +132,69 @@ impl ProvisionedDatabaseRepository for FakeMetadataRepository {
             .cloned()
             .collect())
     }
+
+    async fn list_by_statuses(
+        &self,
+        statuses: &[ProvisionedDatabaseStatus],
+    ) -> Result<Vec<ProvisionedDatabase>, ProvisionerDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.status_filters.push(statuses.to_vec());
+        if let Some(message) = state.list_failure_message.clone() {
+            return Err(ProvisionerDomainError::InfrastructureError(message));
+        }
+        Ok(state
+            .entries
+            .values()
+            .filter(|database| statuses.contains(&database.status()))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_idempotency_key(
+        &self,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<Option<IdempotentProvisioningRecord>, ProvisionerDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+        Ok(state
+            .idempotency_keys
+            .get(idempotency_key.value())
+            .and_then(|(database_name, request_fingerprint)| {
+                state
+                    .entries
+                    .get(database_name)
+                    .map(|database| IdempotentProvisioningRecord {
+                        database: database.clone(),
+                        request_fingerprint: request_fingerprint.clone(),
+                    })
+            }))
+    }
+
+    async fn insert_provisioning(
+        &self,
+        database: &ProvisionedDatabase,
+        idempotency_key: Option<&IdempotencyKey>,
+        request_fingerprint: &str,
+    ) -> Result<bool, ProvisionerDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        let database_name = database.database_name().value().to_string();
+        if state.entries.contains_key(&database_name)
+            || idempotency_key.is_some_and(|key| state.idempotency_keys.contains_key(key.value()))
+        {
+            return Ok(false);
+        }
+
+        state.saved_statuses.push(database.status());
+        state
+            .entries
+            .insert(database_name.clone(), database.clone());
+        if let Some(idempotency_key) = idempotency_key {
+            state.idempotency_keys.insert(
+                idempotency_key.value().to_string(),
+                (database_name, request_fingerprint.to_string()),
+            );
+        }
+        Ok(true)
+    }
 }
 
 #[derive(Default)]
@@ -106,6 +206,15 @@ struct FakePostgresAdministrationState {
     create_should_fail: bool,
     delete_should_fail: bool,
     change_password_should_fail: bool,
+    failing_extension: Option<String>,
+    installed_extensions: Vec<String>,
+    fail_after_role_creation: bool,
+    roles: Vec<String>,
+    databases: Vec<String>,
+    database_privileges: Vec<String>,
+    table_grants: Vec<TableGrantRecord>,
+    seed_sources: Vec<Option<DatabaseSeedSource>>,
+    seed_should_fail: bool,
 }
 
 pub struct FakePostgresAdministrationRepository {
@@ -123,10 +232,87 @@ impl FakePostgresAdministrationRepository {
                 create_should_fail,
                 delete_should_fail,
                 change_password_should_fail: false,
+                failing_extension: None,
+                installed_extensions: Vec::new(),
+                fail_after_role_creation: false,
+                roles: Vec::new(),
+                databases: Vec::new(),
+                database_privileges: Vec::new(),
+                table_grants: Vec::new(),
+                seed_sources: Vec::new(),
+                seed_should_fail: false,
             }),
         }
     }
 
+    pub fn set_create_should_fail(&self, value: bool) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .create_should_fail = value;
+    }
+
+    pub fn set_failing_extension(&self, extension: &str) {
+        self.state.lock().expect("mutex poisoned").failing_extension = Some(extension.to_string());
+    }
+
+    pub fn set_fail_after_role_creation(&self) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .fail_after_role_creation = true;
+    }
+
+    pub fn set_privileges(
+        &self,
+        database_privileges: &[&str],
+        table_grants: &[(&str, &str, &str)],
+    ) {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.database_privileges = database_privileges
+            .iter()
+            .map(|privilege| privilege.to_string())
+            .collect();
+        state.table_grants = table_grants
+            .iter()
+            .map(
+                |(table_schema, table_name, privilege_type)| TableGrantRecord {
+                    table_schema: table_schema.to_string(),
+                    table_name: table_name.to_string(),
+                    privilege_type: privilege_type.to_string(),
+                },
+            )
+            .collect();
+    }
+
+    pub fn set_seed_should_fail(&self) {
+        self.state.lock().expect("mutex poisoned").seed_should_fail = true;
+    }
+
+    pub fn seed_sources(&self) -> Vec<Option<DatabaseSeedSource>> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .seed_sources
+            .clone()
+    }
+
+    pub fn roles(&self) -> Vec<String> {
+        self.state.lock().expect("mutex poisoned").roles.clone()
+    }
+
+    pub fn databases(&self) -> Vec<String> {
+        self.state.lock().expect("mutex poisoned").databases.clone()
+    }
+
+    pub fn installed_extensions(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .installed_extensions
+            .clone()
+    }
+
     pub fn stats(&self) -> (usize, usize, usize, usize) {
         let state = self.state.lock().expect("mutex poisoned");
         (
@@ -142,17 +328,53 @@ impl FakePostgresAdministrationRepository {
 impl PostgresDatabaseAdministrationRepository for FakePostgresAdministrationRepository {
     async fn create_database_stack(
         &self,
-        _database_name: &ProvisionedDatabaseName,
-        _username: &DatabaseUsername,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
         _password: &DatabasePassword,
-        _apply_seed_data: bool,
-    ) -> Result<(), ProvisionerDomainError> {
+        seed_source: Option<&DatabaseSeedSource>,
+        extensions: &[DatabaseExtensionName],
+    ) -> Result<(), DatabaseStackCreationError> {
         let mut state = self.state.lock().expect("mutex poisoned");
         state.create_calls += 1;
+        let mut created = CreatedDatabaseStack::default();
         if state.create_should_fail {
-            return Err(ProvisionerDomainError::InfrastructureError(
-                "create failed".to_string(),
-            ));
+            return Err(DatabaseStackCreationError {
+                created,
+                error: ProvisionerDomainError::InfrastructureError("create failed".to_string()),
+            });
+        }
+        state.roles.push(username.value().to_string());
+        created.role = true;
+        if state.fail_after_role_creation {
+            return Err(DatabaseStackCreationError {
+                created,
+                error: ProvisionerDomainError::InfrastructureError(
+                    "create database failed".to_string(),
+                ),
+            });
+        }
+        state.databases.push(database_name.value().to_string());
+        created.database = true;
+        for extension in extensions {
+            if state.failing_extension.as_deref() == Some(extension.value()) {
+                return Err(DatabaseStackCreationError {
+                    created,
+                    error: ProvisionerDomainError::InfrastructureError(format!(
+                        "extension \"{}\" is not available",
+                        extension.value()
+                    )),
+                });
+            }
+            state
+                .installed_extensions
+                .push(extension.value().to_string());
+        }
+        state.seed_sources.push(seed_source.cloned());
+        if state.seed_should_fail && seed_source.is_some() {
+            return Err(DatabaseStackCreationError {
+                created,
+                error: ProvisionerDomainError::InfrastructureError("seed failed".to_string()),
+            });
         }
         Ok(())
     }
@@ -174,10 +396,20 @@ impl PostgresDatabaseAdministrationRepository for FakePostgresAdministrationRepo
 
     async fn rollback_database_stack(
         &self,
-        _database_name: &ProvisionedDatabaseName,
-        _username: &DatabaseUsername,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
+        created: CreatedDatabaseStack,
     ) -> Result<(), ProvisionerDomainError> {
-        self.state.lock().expect("mutex poisoned").rollback_calls += 1;
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.rollback_calls += 1;
+        if created.database {
+            state
+                .databases
+                .retain(|database| database != database_name.value());
+        }
+        if created.role {
+            state.roles.retain(|role| role != username.value());
+        }
         Ok(())
     }
 
@@ -197,6 +429,21 @@ impl PostgresDatabaseAdministrationRepository for FakePostgresAdministrationRepo
 
         Ok(())
     }
+
+    async fn describe_privileges(
+        &self,
+        database_name: &ProvisionedDatabaseName,
+        username: &DatabaseUsername,
+    ) -> Result<DatabasePrivilegesRecord, ProvisionerDomainError> {
+        let state = self.state.lock().expect("mutex poisoned");
+
+        Ok(DatabasePrivilegesRecord {
+            database_name: database_name.value().to_string(),
+            username: username.value().to_string(),
+            database_privileges: state.database_privileges.clone(),
+            table_grants: state.table_grants.clone(),
+        })
+    }
 }
 
 #[derive(Default)]
@@ -238,3 +485,82 @@ impl ProvisioningAuditEventRepository for FakeAuditEventRepository {
         Ok(())
     }
 }
+
+#[derive(Default)]
+struct FakeAccessControlFacadeState {
+    cleaned_tenants: Vec<String>,
+    cleanup_should_fail: bool,
+}
+
+pub struct FakeAccessControlFacade {
+    state: Mutex<FakeAccessControlFacadeState>,
+}
+
+impl FakeAccessControlFacade {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(FakeAccessControlFacadeState::default()),
+        }
+    }
+
+    pub fn set_cleanup_should_fail(&self, value: bool) {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .cleanup_should_fail = value;
+    }
+
+    pub fn cleaned_tenants(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .expect("mutex poisoned")
+            .cleaned_tenants
+            .clone()
+    }
+}
+
+#[async_trait]
+impl AccessControlFacade for FakeAccessControlFacade {
+    async fn cleanup_tenant_access(
+        &self,
+        request: TenantAccessCleanupRequest,
+    ) -> Result<(), ProvisionerDomainError> {
+        let mut state = self.state.lock().expect("mutex poisoned");
+        state.cleaned_tenants.push(request.tenant_id);
+        if state.cleanup_should_fail {
+            return Err(ProvisionerDomainError::InfrastructureError(
+                "cleanup failed".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub struct FakeTenantPoolFacade {
+    invalidated_databases: Mutex<Vec<String>>,
+}
+
+impl FakeTenantPoolFacade {
+    pub fn new() -> Self {
+        Self {
+            invalidated_databases: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn invalidated_databases(&self) -> Vec<String> {
+        self.invalidated_databases
+            .lock()
+            .expect("mutex poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl TenantPoolFacade for FakeTenantPoolFacade {
+    async fn invalidate_tenant_pools(&self, request: TenantPoolInvalidationRequest) {
+        self.invalidated_databases
+            .lock()
+            .expect("mutex poisoned")
+            .push(request.database_name);
+    }
+}
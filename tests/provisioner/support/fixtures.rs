@@ -1,12 +1,20 @@
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+};
 use chrono::Utc;
 use swagger_axum_api::provisioner::domain::model::{
     commands::{
         change_provisioned_database_password_command::ChangeProvisionedDatabasePasswordCommand,
         create_provisioned_database_command::CreateProvisionedDatabaseCommand,
         delete_provisioned_database_command::DeleteProvisionedDatabaseCommand,
+        retry_provisioned_database_command::RetryProvisionedDatabaseCommand,
     },
     entities::provisioned_database::ProvisionedDatabase,
-    enums::provisioned_database_status::ProvisionedDatabaseStatus,
+    enums::{
+        provisioned_database_status::ProvisionedDatabaseStatus,
+        provisioner_domain_error::ProvisionerDomainError,
+    },
     value_objects::{
         database_password_hash::DatabasePasswordHash, database_username::DatabaseUsername,
         provisioned_database_id::ProvisionedDatabaseId,
@@ -15,13 +23,43 @@ use swagger_axum_api::provisioner::domain::model::{
 };
 
 pub fn create_command() -> CreateProvisionedDatabaseCommand {
+    create_command_with_extensions(&[]).expect("valid create command")
+}
+
+pub fn create_command_with_extensions(
+    extensions: &[&str],
+) -> Result<CreateProvisionedDatabaseCommand, ProvisionerDomainError> {
     CreateProvisionedDatabaseCommand::new(
         "tenant_alpha".to_string(),
         "tenant_alpha_user".to_string(),
         "supersecret".to_string(),
         "$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$somehashvalue".to_string(),
         true,
+        extensions
+            .iter()
+            .map(|extension| extension.to_string())
+            .collect(),
+    )
+}
+
+pub fn create_command_with_idempotency_key(
+    idempotency_key: &str,
+    apply_seed_data: bool,
+) -> CreateProvisionedDatabaseCommand {
+    let password_hash = Argon2::default()
+        .hash_password(b"supersecret", &SaltString::generate(&mut OsRng))
+        .expect("password should hash")
+        .to_string();
+
+    CreateProvisionedDatabaseCommand::new(
+        "tenant_alpha".to_string(),
+        "tenant_alpha_user".to_string(),
+        "supersecret".to_string(),
+        password_hash,
+        apply_seed_data,
+        vec![],
     )
+    .and_then(|command| command.with_idempotency_key(Some(idempotency_key.to_string())))
     .expect("valid create command")
 }
 
@@ -39,10 +77,17 @@ pub fn change_password_command() -> ChangeProvisionedDatabasePasswordCommand {
 }
 
 pub fn database_with_status(status: ProvisionedDatabaseStatus) -> ProvisionedDatabase {
+    database_named_with_status("tenant_alpha", status)
+}
+
+pub fn database_named_with_status(
+    database_name: &str,
+    status: ProvisionedDatabaseStatus,
+) -> ProvisionedDatabase {
     ProvisionedDatabase::restore(
         ProvisionedDatabaseId::new_random(),
-        ProvisionedDatabaseName::new("tenant_alpha".to_string()).expect("valid name"),
-        DatabaseUsername::new("tenant_alpha_user".to_string()).expect("valid username"),
+        ProvisionedDatabaseName::new(database_name.to_string()).expect("valid name"),
+        DatabaseUsername::new(format!("{database_name}_user")).expect("valid username"),
         DatabasePasswordHash::new(
             "$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$somehashvalue".to_string(),
         )
@@ -51,3 +96,30 @@ pub fn database_with_status(status: ProvisionedDatabaseStatus) -> ProvisionedDat
         Utc::now(),
     )
 }
+
+pub fn retry_command(password: &str) -> RetryProvisionedDatabaseCommand {
+    RetryProvisionedDatabaseCommand::new(
+        "tenant_alpha".to_string(),
+        password.to_string(),
+        true,
+        vec![],
+    )
+    .expect("valid command")
+}
+
+// Base fallida cuyo hash corresponde a la contraseña indicada
+pub fn failed_database_with_password(password: &str) -> ProvisionedDatabase {
+    let password_hash = Argon2::default()
+        .hash_password(password.as_bytes(), &SaltString::generate(&mut OsRng))
+        .expect("password should hash")
+        .to_string();
+
+    ProvisionedDatabase::restore(
+        ProvisionedDatabaseId::new_random(),
+        ProvisionedDatabaseName::new("tenant_alpha".to_string()).expect("valid name"),
+        DatabaseUsername::new("tenant_alpha_user".to_string()).expect("valid username"),
+        DatabasePasswordHash::new(password_hash).expect("valid password hash"),
+        ProvisionedDatabaseStatus::Failed,
+        Utc::now(),
+    )
+}
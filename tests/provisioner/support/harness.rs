@@ -1,25 +1,107 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use swagger_axum_api::provisioner::{
-    application::command_services::database_provisioning_command_service_impl::DatabaseProvisioningCommandServiceImpl,
-    domain::model::entities::provisioned_database::ProvisionedDatabase,
+use axum::Router;
+use swagger_axum_api::{
+    config::{
+        admin_token::AdminToken, credential_cipher::CredentialCipher,
+        error_redaction::ErrorRedaction,
+    },
+    provisioner::{
+        application::{
+            command_services::database_provisioning_command_service_impl::DatabaseProvisioningCommandServiceImpl,
+            query_services::database_provisioning_query_service_impl::DatabaseProvisioningQueryServiceImpl,
+        },
+        domain::model::entities::provisioned_database::ProvisionedDatabase,
+        infrastructure::persistence::repositories::{
+            in_memory::{
+                in_memory_failed_password_attempt_repository_impl::InMemoryFailedPasswordAttemptRepositoryImpl,
+                in_memory_password_change_attempt_repository_impl::InMemoryPasswordChangeAttemptRepositoryImpl,
+            },
+            password_change_attempt_repository::PasswordChangeAttemptRepository,
+        },
+        interfaces::rest::controllers::provisioner_rest_controller::{
+            ProvisionerRestControllerState, router,
+        },
+    },
 };
 
 use super::fakes::{
-    FakeAuditEventRepository, FakeMetadataRepository, FakePostgresAdministrationRepository,
+    FakeAccessControlFacade, FakeAuditEventRepository, FakeMetadataRepository,
+    FakePostgresAdministrationRepository, FakeTenantPoolFacade,
 };
 
+pub const TEST_ADMIN_TOKEN: &str = "provisioner-test-admin-token";
+
 pub struct ProvisioningTestHarness {
     pub metadata_repository: Arc<FakeMetadataRepository>,
     pub postgres_repository: Arc<FakePostgresAdministrationRepository>,
     pub audit_repository: Arc<FakeAuditEventRepository>,
+    pub access_control: Arc<FakeAccessControlFacade>,
+    pub tenant_pools: Arc<FakeTenantPoolFacade>,
     pub service: DatabaseProvisioningCommandServiceImpl,
 }
 
+pub struct ProvisionerRouterTestHarness {
+    pub metadata_repository: Arc<FakeMetadataRepository>,
+    pub postgres_repository: Arc<FakePostgresAdministrationRepository>,
+    pub router: Router,
+}
+
 pub fn create_harness(
     entries: Vec<ProvisionedDatabase>,
     create_should_fail: bool,
     delete_should_fail: bool,
+) -> ProvisioningTestHarness {
+    build_harness(entries, create_should_fail, delete_should_fail, None, None)
+}
+
+pub fn create_harness_with_password_change_limit(
+    entries: Vec<ProvisionedDatabase>,
+    max_attempts: usize,
+) -> ProvisioningTestHarness {
+    let password_change_attempts: Arc<dyn PasswordChangeAttemptRepository> = Arc::new(
+        InMemoryPasswordChangeAttemptRepositoryImpl::new(max_attempts, Duration::from_secs(300)),
+    );
+
+    build_harness(entries, false, false, Some(password_change_attempts), None)
+}
+
+pub fn create_harness_with_failed_password_limit(
+    entries: Vec<ProvisionedDatabase>,
+    max_failures: usize,
+) -> ProvisioningTestHarness {
+    let harness = build_harness(entries, false, false, None, None);
+
+    ProvisioningTestHarness {
+        service: harness.service.with_failed_password_attempts(Arc::new(
+            InMemoryFailedPasswordAttemptRepositoryImpl::new(
+                max_failures,
+                Duration::from_secs(300),
+            ),
+        )),
+        ..harness
+    }
+}
+
+pub fn create_harness_with_credential_cipher(
+    entries: Vec<ProvisionedDatabase>,
+    credential_cipher: CredentialCipher,
+) -> ProvisioningTestHarness {
+    build_harness(
+        entries,
+        false,
+        false,
+        None,
+        Some(Arc::new(credential_cipher)),
+    )
+}
+
+fn build_harness(
+    entries: Vec<ProvisionedDatabase>,
+    create_should_fail: bool,
+    delete_should_fail: bool,
+    password_change_attempts: Option<Arc<dyn PasswordChangeAttemptRepository>>,
+    credential_cipher: Option<Arc<CredentialCipher>>,
 ) -> ProvisioningTestHarness {
     let metadata_repository = Arc::new(FakeMetadataRepository::with_entries(entries));
     let postgres_repository = Arc::new(FakePostgresAdministrationRepository::new(
@@ -27,17 +109,58 @@ pub fn create_harness(
         delete_should_fail,
     ));
     let audit_repository = Arc::new(FakeAuditEventRepository::new());
+    let access_control = Arc::new(FakeAccessControlFacade::new());
+    let tenant_pools = Arc::new(FakeTenantPoolFacade::new());
 
-    let service = DatabaseProvisioningCommandServiceImpl::new(
+    let service = DatabaseProvisioningCommandServiceImpl::new_with_password_change_attempts(
         metadata_repository.clone(),
         postgres_repository.clone(),
         audit_repository.clone(),
-    );
+        access_control.clone(),
+        password_change_attempts,
+    )
+    .with_credential_cipher(credential_cipher)
+    .with_tenant_pool_facade(tenant_pools.clone());
 
     ProvisioningTestHarness {
         metadata_repository,
         postgres_repository,
         audit_repository,
+        access_control,
+        tenant_pools,
         service,
     }
 }
+
+pub const ROUTER_MAX_FAILED_PASSWORD_ATTEMPTS: usize = 3;
+
+pub fn create_router_harness(
+    entries: Vec<ProvisionedDatabase>,
+    restricted_username_projection: bool,
+) -> ProvisionerRouterTestHarness {
+    let harness = create_harness(entries, false, false);
+    let query_service = Arc::new(
+        DatabaseProvisioningQueryServiceImpl::new(
+            harness.metadata_repository.clone(),
+            harness.postgres_repository.clone(),
+        )
+        .with_failed_password_attempts(Arc::new(
+            InMemoryFailedPasswordAttemptRepositoryImpl::new(
+                ROUTER_MAX_FAILED_PASSWORD_ATTEMPTS,
+                Duration::from_secs(300),
+            ),
+        )),
+    );
+
+    ProvisionerRouterTestHarness {
+        metadata_repository: harness.metadata_repository,
+        postgres_repository: harness.postgres_repository,
+        router: router(ProvisionerRestControllerState {
+            error_redaction: ErrorRedaction::new(true),
+            command_service: Arc::new(harness.service),
+            query_service,
+            restricted_username_projection,
+            admin_token: Some(AdminToken::new(TEST_ADMIN_TOKEN).expect("valid admin token")),
+        }),
+    }
+}
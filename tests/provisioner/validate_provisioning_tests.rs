@@ -0,0 +1,39 @@
+use swagger_axum_api::provisioner::domain::{
+    model::enums::{
+        provisioned_database_status::ProvisionedDatabaseStatus,
+        provisioner_domain_error::ProvisionerDomainError,
+    },
+    services::database_provisioning_command_service::DatabaseProvisioningCommandService,
+};
+
+use crate::support::{create_command, create_harness, database_with_status};
+
+#[tokio::test]
+async fn handle_validate_passes_for_available_name_without_side_effects() {
+    let harness = create_harness(vec![], false, false);
+
+    let result = harness.service.handle_validate(create_command()).await;
+
+    assert!(result.is_ok());
+    assert!(harness.metadata_repository.saved_statuses().is_empty());
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+    assert!(harness.audit_repository.saved_event_names().is_empty());
+}
+
+#[tokio::test]
+async fn handle_validate_fails_when_name_is_taken() {
+    let harness = create_harness(
+        vec![database_with_status(ProvisionedDatabaseStatus::Active)],
+        false,
+        false,
+    );
+
+    let result = harness.service.handle_validate(create_command()).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionerDomainError::DatabaseAlreadyProvisioned)
+    ));
+    assert!(harness.metadata_repository.saved_statuses().is_empty());
+    assert_eq!(harness.postgres_repository.stats(), (0, 0, 0, 0));
+}